@@ -28,7 +28,7 @@ impl App {
         // Setup terminal
         let terminal = Terminal::new(CrosstermBackend::new(io()))?;
         log::debug!("terminal size: {}", terminal.size()?);
-        let mut tui = Tui::new(terminal);
+        let mut tui = Tui::new(terminal, self.config.ui.mouse);
         tui.start()?;
 
         // Create main component
@@ -78,6 +78,9 @@ impl App {
                         if i % 60 == 0 {
                             main_component.save().await?;
                         }
+                        if let Some(action) = main_component.update(action.clone())? {
+                            action_tx.send(action)?;
+                        }
                     }
                     Action::Render => {
                         tui.draw(|f| {
@@ -112,12 +115,14 @@ impl App {
                 break main_component.save().await?;
             }
         }
+        main_component.shutdown().await;
         tui.end()?;
         Ok(())
     }
     fn handle_events(&mut self, event: Event) -> Option<Action> {
         match event {
             Event::Tick(i) => return Some(Action::Tick(i)),
+            Event::Error(e) => log::error!("failed to read the terminal events: {e}"),
             Event::Key(key_event) => {
                 if let Some(action) = self.handle_key_events(key_event) {
                     return Some(action);
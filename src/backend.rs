@@ -1,6 +1,29 @@
+pub mod analytics;
+pub mod cache;
+pub mod client;
+pub mod comments;
 pub mod config;
+pub mod discover;
+pub mod export;
+pub mod graph;
+mod history;
+mod metrics;
+pub mod muted_words;
+pub mod posts;
+pub mod preferences;
+pub mod quotes;
+pub mod rate_limit;
+pub mod rss;
+pub mod search;
+pub mod skeleton;
+pub mod tags;
+pub mod translation;
 pub mod types;
+pub mod video;
 mod watch;
 mod watches;
 
+pub use history::PostHistory;
+pub use metrics::WatcherMetrics;
+pub(crate) use watch::WatcherTasks;
 pub use watch::{Watch, Watcher};
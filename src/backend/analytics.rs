@@ -0,0 +1,199 @@
+use crate::backend::client::BskyAgent;
+use bsky_sdk::api::app::bsky::feed::defs::PostView;
+use bsky_sdk::api::app::bsky::feed::get_post_thread::OutputThreadRefs;
+use bsky_sdk::api::types::Union;
+use chrono::Utc;
+use color_eyre::{eyre, Result};
+use rusqlite::{params, Connection, OptionalExtension};
+use std::path::{Path, PathBuf};
+
+/// Snapshots taken more recently than this are not recorded again.
+const MIN_INTERVAL_SECS: i64 = 60 * 60;
+/// Snapshots older than this are removed, except for the ones still compared against as "7 d ago".
+const RETENTION_SECS: i64 = 7 * 24 * 60 * 60 + MIN_INTERVAL_SECS;
+
+/// The past points in time that the current counts are compared against.
+pub const PERIODS: [(&str, i64); 3] = [
+    ("1 h ago", 60 * 60),
+    ("24 h ago", 24 * 60 * 60),
+    ("7 d ago", 7 * 24 * 60 * 60),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Snapshot {
+    pub snapshot_at: i64,
+    pub like_count: i64,
+    pub repost_count: i64,
+    pub reply_count: i64,
+}
+
+impl Snapshot {
+    pub fn new(post_view: &PostView, snapshot_at: i64) -> Self {
+        Self {
+            snapshot_at,
+            like_count: post_view.like_count.unwrap_or_default(),
+            repost_count: post_view.repost_count.unwrap_or_default(),
+            reply_count: post_view.reply_count.unwrap_or_default(),
+        }
+    }
+}
+
+/// Local history of the engagement counts of posts.
+pub struct AnalyticsStore {
+    conn: Connection,
+}
+
+impl AnalyticsStore {
+    pub fn open(path: &Path) -> Result<Self> {
+        let conn = Connection::open(path)?;
+        conn.execute_batch(
+            "CREATE TABLE IF NOT EXISTS snapshots (
+                uri TEXT NOT NULL,
+                snapshot_at INTEGER NOT NULL,
+                like_count INT NOT NULL,
+                repost_count INT NOT NULL,
+                reply_count INT NOT NULL
+            );
+            CREATE INDEX IF NOT EXISTS snapshots_uri ON snapshots (uri, snapshot_at);",
+        )?;
+        Ok(Self { conn })
+    }
+    /// Record a snapshot unless the latest one of the post is newer than [`MIN_INTERVAL_SECS`].
+    ///
+    /// Returns `true` if the snapshot was recorded.
+    pub fn record(&self, uri: &str, snapshot: &Snapshot) -> Result<bool> {
+        if let Some(latest) = self.latest(uri, snapshot.snapshot_at)? {
+            if snapshot.snapshot_at - latest.snapshot_at < MIN_INTERVAL_SECS {
+                return Ok(false);
+            }
+        }
+        self.conn.execute(
+            "INSERT INTO snapshots (uri, snapshot_at, like_count, repost_count, reply_count)
+            VALUES (?1, ?2, ?3, ?4, ?5)",
+            params![
+                uri,
+                snapshot.snapshot_at,
+                snapshot.like_count,
+                snapshot.repost_count,
+                snapshot.reply_count
+            ],
+        )?;
+        Ok(true)
+    }
+    /// Remove the snapshots taken before `before` (in unix seconds), and return their number.
+    pub fn prune(&self, before: i64) -> Result<usize> {
+        Ok(self.conn.execute(
+            "DELETE FROM snapshots WHERE snapshot_at < ?1",
+            params![before],
+        )?)
+    }
+    /// Returns the latest snapshot of the post taken at or before `at` (in unix seconds).
+    pub fn latest(&self, uri: &str, at: i64) -> Result<Option<Snapshot>> {
+        Ok(self
+            .conn
+            .query_row(
+                "SELECT snapshot_at, like_count, repost_count, reply_count FROM snapshots
+                WHERE uri = ?1 AND snapshot_at <= ?2
+                ORDER BY snapshot_at DESC LIMIT 1",
+                params![uri, at],
+                |row| {
+                    Ok(Snapshot {
+                        snapshot_at: row.get(0)?,
+                        like_count: row.get(1)?,
+                        repost_count: row.get(2)?,
+                        reply_count: row.get(3)?,
+                    })
+                },
+            )
+            .optional()?)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PostAnalytics {
+    pub current: Snapshot,
+    pub history: Vec<(&'static str, Option<Snapshot>)>,
+}
+
+/// Get the current counts of the post, record them, and look up the snapshots of [`PERIODS`].
+pub async fn post_analytics(agent: &BskyAgent, uri: &str, path: PathBuf) -> Result<PostAnalytics> {
+    let thread = agent
+        .api
+        .app
+        .bsky
+        .feed
+        .get_post_thread(
+            bsky_sdk::api::app::bsky::feed::get_post_thread::ParametersData {
+                depth: 0.try_into().ok(),
+                parent_height: 0.try_into().ok(),
+                uri: uri.to_string(),
+            }
+            .into(),
+        )
+        .await?
+        .data
+        .thread;
+    let Union::Refs(OutputThreadRefs::AppBskyFeedDefsThreadViewPost(thread_view)) = thread else {
+        return Err(eyre::eyre!("post not found: {uri}"));
+    };
+    let now = Utc::now().timestamp();
+    let current = Snapshot::new(&thread_view.post, now);
+    let uri = uri.to_string();
+    tokio::task::spawn_blocking(move || {
+        let store = AnalyticsStore::open(&path)?;
+        store.record(&uri, &current)?;
+        let history = PERIODS
+            .iter()
+            .map(|(label, secs)| Ok((*label, store.latest(&uri, now - secs)?)))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(PostAnalytics { current, history })
+    })
+    .await?
+}
+
+/// Record snapshots of the posts in the background, so that there is history to compare against.
+pub fn record_posts(post_views: Vec<PostView>, path: PathBuf) {
+    tokio::task::spawn_blocking(move || {
+        let now = Utc::now().timestamp();
+        let result = AnalyticsStore::open(&path).and_then(|store| {
+            post_views.iter().try_for_each(|post_view| {
+                store
+                    .record(&post_view.uri, &Snapshot::new(post_view, now))
+                    .map(|_| ())
+            })?;
+            store.prune(now - RETENTION_SECS).map(|_| ())
+        });
+        if let Err(e) = result {
+            log::warn!("failed to record post snapshots: {e}");
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snapshot(snapshot_at: i64, like_count: i64) -> Snapshot {
+        Snapshot {
+            snapshot_at,
+            like_count,
+            repost_count: 0,
+            reply_count: 0,
+        }
+    }
+
+    #[test]
+    fn record_and_query() -> Result<()> {
+        let store = AnalyticsStore::open(Path::new(":memory:"))?;
+        assert!(store.record("at://post", &snapshot(0, 1))?);
+        assert!(!store.record("at://post", &snapshot(60, 2))?);
+        assert!(store.record("at://post", &snapshot(3600, 3))?);
+        assert_eq!(store.latest("at://post", 3599)?, Some(snapshot(0, 1)));
+        assert_eq!(store.latest("at://post", 7200)?, Some(snapshot(3600, 3)));
+        assert_eq!(store.latest("at://other", 7200)?, None);
+        assert_eq!(store.prune(3600)?, 1);
+        assert_eq!(store.latest("at://post", 3599)?, None);
+        assert_eq!(store.latest("at://post", 7200)?, Some(snapshot(3600, 3)));
+        Ok(())
+    }
+}
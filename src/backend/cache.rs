@@ -0,0 +1,59 @@
+use crate::backend::client::BskyAgent;
+use crate::backend::posts::get_posts;
+use bsky_sdk::api::app::bsky::feed::defs::PostView;
+use bsky_sdk::Result;
+use indexmap::IndexMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// The number of posts kept in the cache, the oldest ones are dropped first.
+const MAX_ENTRIES: usize = 2_000;
+/// How long a cached post is used, as its counts and the viewer state change over time.
+const TTL: Duration = Duration::from_secs(5 * 60);
+
+/// The posts fetched by URI, with the state of the viewer of one session.
+#[derive(Debug, Clone, Default)]
+pub struct PostCache(Arc<Mutex<IndexMap<String, (Instant, PostView)>>>);
+
+impl PostCache {
+    /// Get the views of the posts `uris`, fetching only the ones which are not cached yet.
+    ///
+    /// The posts which are deleted or not visible are missing from the result.
+    pub async fn posts(&self, agent: &BskyAgent, uris: &[String]) -> Result<Vec<PostView>> {
+        let (mut posts, mut missing) = (Vec::with_capacity(uris.len()), Vec::new());
+        if let Ok(mut cache) = self.0.lock() {
+            let now = Instant::now();
+            cache.retain(|_, (fetched_at, _)| now.duration_since(*fetched_at) < TTL);
+            for uri in uris {
+                match cache.get(uri) {
+                    Some((_, post_view)) => posts.push(post_view.clone()),
+                    None => missing.push(uri.clone()),
+                }
+            }
+        }
+        if missing.is_empty() {
+            return Ok(posts);
+        }
+        let fetched = get_posts(agent, &missing).await?;
+        if let Ok(mut cache) = self.0.lock() {
+            let now = Instant::now();
+            cache.extend(
+                fetched
+                    .iter()
+                    .map(|post_view| (post_view.uri.clone(), (now, post_view.clone()))),
+            );
+            if cache.len() > MAX_ENTRIES {
+                let excess = cache.len() - MAX_ENTRIES;
+                cache.drain(..excess);
+            }
+        }
+        posts.extend(fetched);
+        Ok(posts)
+    }
+    /// Drop the cached post, e.g. after it is liked, reposted or deleted.
+    pub fn evict(&self, uri: &str) {
+        if let Ok(mut cache) = self.0.lock() {
+            cache.shift_remove(uri);
+        }
+    }
+}
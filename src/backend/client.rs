@@ -0,0 +1,80 @@
+use super::rate_limit::RateLimit;
+use async_trait::async_trait;
+use atrium_xrpc_client::reqwest::{ReqwestClient, ReqwestClientBuilder};
+use bsky_sdk::agent::config::Config;
+use bsky_sdk::agent::BskyAgentBuilder;
+use bsky_sdk::api::xrpc::http::{Request, Response};
+use bsky_sdk::api::xrpc::{HttpClient, XrpcClient};
+use color_eyre::{eyre, Result};
+use reqwest::{Client, Proxy};
+
+const PROXY_SCHEMES: [&str; 4] = ["http", "https", "socks5", "socks5h"];
+
+/// Parse and validate a proxy URL such as `http://localhost:8080` or `socks5://127.0.0.1:9050`.
+pub fn proxy(url: &str) -> std::result::Result<Proxy, String> {
+    let Some((scheme, _)) = url.split_once("://") else {
+        return Err(format!("invalid proxy url `{url}`: missing scheme"));
+    };
+    if !PROXY_SCHEMES.contains(&scheme) {
+        return Err(format!(
+            "invalid proxy url `{url}`: unsupported scheme `{scheme}` (expected one of {})",
+            PROXY_SCHEMES.join(", ")
+        ));
+    }
+    Proxy::all(url).map_err(|e| format!("invalid proxy url `{url}`: {e}"))
+}
+
+/// The agent of a column, whose client keeps track of the rate limit.
+pub type BskyAgent = bsky_sdk::BskyAgent<RateLimitedClient>;
+
+/// A client which updates its rate limit from the headers of each response.
+#[derive(Clone)]
+pub struct RateLimitedClient {
+    inner: ReqwestClient,
+    rate_limit: RateLimit,
+}
+
+#[async_trait]
+impl HttpClient for RateLimitedClient {
+    async fn send_http(
+        &self,
+        request: Request<Vec<u8>>,
+    ) -> core::result::Result<Response<Vec<u8>>, Box<dyn std::error::Error + Send + Sync + 'static>>
+    {
+        let response = self.inner.send_http(request).await?;
+        self.rate_limit.update(response.headers());
+        Ok(response)
+    }
+}
+
+impl XrpcClient for RateLimitedClient {
+    fn base_uri(&self) -> String {
+        self.inner.base_uri()
+    }
+}
+
+/// Create an agent builder whose requests are routed through `proxy`, if given.
+///
+/// The responses update `rate_limit`, which should not be shared with the other agents.
+pub fn agent_builder(
+    proxy: Option<&str>,
+    rate_limit: RateLimit,
+) -> Result<BskyAgentBuilder<RateLimitedClient>> {
+    let mut builder = ReqwestClientBuilder::new(Config::default().endpoint);
+    if proxy.is_some() {
+        builder = builder.client(http_client(proxy)?);
+    }
+    Ok(BskyAgentBuilder::new(RateLimitedClient {
+        inner: builder.build(),
+        rate_limit,
+    }))
+}
+
+/// Create a client for the requests outside of Bluesky, routed through `proxy` if given.
+pub fn http_client(proxy: Option<&str>) -> Result<Client> {
+    let mut builder = Client::builder();
+    if let Some(url) = proxy {
+        builder = builder.proxy(self::proxy(url).map_err(|e| eyre::eyre!(e))?);
+    }
+    Ok(builder.build()?)
+}
@@ -0,0 +1,134 @@
+use chrono::Utc;
+use color_eyre::Result;
+use rusqlite::{params, Connection, OptionalExtension};
+use std::collections::HashSet;
+use std::path::Path;
+
+/// A private comment on a post, which is never sent to the server.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Comment {
+    pub uri: String,
+    pub cid: String,
+    pub handle: String,
+    /// The text of the post when the comment was written.
+    pub post_text: String,
+    pub text: String,
+}
+
+/// Local comments on posts, stored in their own database.
+pub struct CommentStore {
+    conn: Connection,
+}
+
+impl CommentStore {
+    pub fn open(path: &Path) -> Result<Self> {
+        let conn = Connection::open(path)?;
+        conn.execute_batch(
+            "CREATE TABLE IF NOT EXISTS comments (
+                uri TEXT PRIMARY KEY,
+                cid TEXT NOT NULL,
+                handle TEXT NOT NULL,
+                post_text TEXT NOT NULL,
+                text TEXT NOT NULL,
+                updated_at INTEGER NOT NULL
+            );",
+        )?;
+        Ok(Self { conn })
+    }
+    /// Save the comment, or delete it if its text is empty.
+    pub fn save(&self, comment: &Comment) -> Result<()> {
+        if comment.text.trim().is_empty() {
+            self.conn
+                .execute("DELETE FROM comments WHERE uri = ?1", params![comment.uri])?;
+            return Ok(());
+        }
+        self.conn.execute(
+            "INSERT OR REPLACE INTO comments (uri, cid, handle, post_text, text, updated_at)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+            params![
+                comment.uri,
+                comment.cid,
+                comment.handle,
+                comment.post_text,
+                comment.text,
+                Utc::now().timestamp()
+            ],
+        )?;
+        Ok(())
+    }
+    pub fn get(&self, uri: &str) -> Result<Option<Comment>> {
+        Ok(self
+            .conn
+            .query_row(
+                "SELECT uri, cid, handle, post_text, text FROM comments WHERE uri = ?1",
+                params![uri],
+                Self::comment,
+            )
+            .optional()?)
+    }
+    /// Returns the comments whose text or post contains `query`, most recently updated first.
+    pub fn search(&self, query: &str) -> Result<Vec<Comment>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT uri, cid, handle, post_text, text FROM comments
+            WHERE instr(lower(text), lower(?1)) > 0 OR instr(lower(post_text), lower(?1)) > 0
+            ORDER BY updated_at DESC",
+        )?;
+        let comments = stmt
+            .query_map(params![query], Self::comment)?
+            .collect::<rusqlite::Result<Vec<_>>>()?;
+        Ok(comments)
+    }
+    /// Returns the URIs of all the posts with a comment.
+    pub fn uris(&self) -> Result<HashSet<String>> {
+        let mut stmt = self.conn.prepare("SELECT uri FROM comments")?;
+        let uris = stmt
+            .query_map([], |row| row.get(0))?
+            .collect::<rusqlite::Result<HashSet<_>>>()?;
+        Ok(uris)
+    }
+    fn comment(row: &rusqlite::Row<'_>) -> rusqlite::Result<Comment> {
+        Ok(Comment {
+            uri: row.get(0)?,
+            cid: row.get(1)?,
+            handle: row.get(2)?,
+            post_text: row.get(3)?,
+            text: row.get(4)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn comment(uri: &str, text: &str) -> Comment {
+        Comment {
+            uri: uri.to_string(),
+            cid: String::from("bafyrei"),
+            handle: String::from("alice.test"),
+            post_text: String::from("Hello, world"),
+            text: text.to_string(),
+        }
+    }
+
+    #[test]
+    fn save_and_search() -> Result<()> {
+        let store = CommentStore::open(Path::new(":memory:"))?;
+        store.save(&comment("at://post/1", "Read later"))?;
+        store.save(&comment("at://post/2", "A good point"))?;
+        store.save(&comment("at://post/1", "Read it"))?;
+        assert_eq!(
+            store.get("at://post/1")?,
+            Some(comment("at://post/1", "Read it"))
+        );
+        assert_eq!(
+            store.search("good")?,
+            vec![comment("at://post/2", "A good point")]
+        );
+        assert_eq!(store.search("WORLD")?.len(), 2);
+        // an empty comment removes it
+        store.save(&comment("at://post/2", " "))?;
+        assert_eq!(store.uris()?, HashSet::from([String::from("at://post/1")]));
+        Ok(())
+    }
+}
@@ -3,6 +3,9 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Default)]
 pub struct Config {
     pub intervals: Intervals,
+    /// Receive new posts of the following timeline from the event stream, in addition to polling.
+    #[serde(default)]
+    pub firehose: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
@@ -11,6 +14,10 @@ pub struct Intervals {
     pub preferences: u64,
     pub feed: u64,
     pub post_thread: u64,
+    pub notification_count: u64,
+    pub chat_unread_count: u64,
+    pub rss: u64,
+    pub suggested: u64,
 }
 
 impl Default for Intervals {
@@ -19,6 +26,10 @@ impl Default for Intervals {
             preferences: 600,
             feed: 30,
             post_thread: 60,
+            notification_count: 30,
+            chat_unread_count: 30,
+            rss: 600,
+            suggested: 600,
         }
     }
 }
@@ -0,0 +1,68 @@
+use crate::backend::client::BskyAgent;
+use bsky_sdk::api::app::bsky::feed::defs::{
+    FeedViewPost, GeneratorView, InteractionData, REQUEST_LESS,
+};
+use bsky_sdk::api::types::string::Did;
+use color_eyre::{eyre, Result};
+
+/// The "Discover" feed of Bluesky, with the suggested posts from outside of the follows.
+pub const DISCOVER_FEED: &str =
+    "at://did:plc:z72i7hdynmk6r22z27h6tvur/app.bsky.feed.generator/whats-hot";
+/// The service of the Discover feed generator, which receives the interactions with its posts.
+pub const DISCOVER_SERVICE: &str = "did:web:discover.bsky.app";
+
+/// Tell the feed generator of the `service` to show less content like the post.
+pub async fn request_less(
+    agent: &BskyAgent,
+    service: &str,
+    feed_view_post: &FeedViewPost,
+) -> Result<()> {
+    let did = service.parse::<Did>().map_err(|e| eyre::eyre!(e))?;
+    agent
+        .api_with_proxy(did, "bsky_fg")
+        .app
+        .bsky
+        .feed
+        .send_interactions(
+            bsky_sdk::api::app::bsky::feed::send_interactions::InputData {
+                interactions: vec![InteractionData {
+                    event: Some(String::from(REQUEST_LESS)),
+                    feed_context: feed_view_post.feed_context.clone(),
+                    item: Some(feed_view_post.post.uri.clone()),
+                }
+                .into()],
+            }
+            .into(),
+        )
+        .await?;
+    Ok(())
+}
+
+/// A page of the popular feed generators.
+#[derive(Debug, Clone)]
+pub struct PopularFeeds {
+    pub feeds: Vec<GeneratorView>,
+    pub cursor: Option<String>,
+}
+
+/// Get a page of the feed generators popular on Bluesky.
+pub async fn popular_feeds(agent: &BskyAgent, cursor: Option<String>) -> Result<PopularFeeds> {
+    let output = agent
+        .api
+        .app
+        .bsky
+        .unspecced
+        .get_popular_feed_generators(
+            bsky_sdk::api::app::bsky::unspecced::get_popular_feed_generators::ParametersData {
+                cursor,
+                limit: 30.try_into().ok(),
+                query: None,
+            }
+            .into(),
+        )
+        .await?;
+    Ok(PopularFeeds {
+        feeds: output.data.feeds,
+        cursor: output.data.cursor.filter(|cursor| !cursor.is_empty()),
+    })
+}
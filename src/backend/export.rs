@@ -0,0 +1,219 @@
+use bsky_sdk::api::app::bsky::embed::record::ViewRecordRefs;
+use bsky_sdk::api::app::bsky::embed::record_with_media::ViewMediaRefs;
+use bsky_sdk::api::app::bsky::feed::defs::{FeedViewPost, PostView, PostViewEmbedRefs};
+use bsky_sdk::api::records::{KnownRecord, Record};
+use bsky_sdk::api::types::Union;
+use chrono::Local;
+use color_eyre::Result;
+use std::fmt::Write;
+use std::path::Path;
+
+const HTML_STYLE: &str =
+    "body { font-family: sans-serif; max-width: 40em; margin: 2em auto; color: #222; }
+article { border-bottom: 1px solid #ddd; padding: 1em 0; }
+h2 { font-size: 1em; color: #555; }
+p { white-space: pre-wrap; }";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    Markdown,
+    Html,
+}
+
+impl ExportFormat {
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Self::Markdown => "md",
+            Self::Html => "html",
+        }
+    }
+}
+
+/// Write the posts to `path`, one section per post with the links of the embeds.
+pub fn export_feed(posts: &[FeedViewPost], format: ExportFormat, path: &Path) -> Result<()> {
+    let content = match format {
+        ExportFormat::Markdown => markdown(posts),
+        ExportFormat::Html => html(posts),
+    };
+    std::fs::write(path, content)?;
+    log::info!("exported {} posts to {path:?}", posts.len());
+    Ok(())
+}
+
+pub(crate) fn markdown(posts: &[FeedViewPost]) -> String {
+    let mut s = String::new();
+    for feed_view_post in posts {
+        let post_view = &feed_view_post.post;
+        let _ = writeln!(
+            s,
+            "## @{} – {}\n\n{}\n",
+            post_view.author.handle.as_str(),
+            timestamp(post_view),
+            text(post_view)
+        );
+        for (label, url) in links(post_view) {
+            let _ = writeln!(s, "- [{label}]({url})");
+        }
+        let _ = writeln!(s, "\n---\n");
+    }
+    s
+}
+
+fn html(posts: &[FeedViewPost]) -> String {
+    let mut s = format!(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>tuisky export</title>\n<style>\n{HTML_STYLE}\n</style>\n</head>\n<body>\n"
+    );
+    for feed_view_post in posts {
+        let post_view = &feed_view_post.post;
+        let _ = writeln!(
+            s,
+            "<article>\n<h2>@{} – {}</h2>\n<p>{}</p>",
+            escape(post_view.author.handle.as_str()),
+            timestamp(post_view),
+            escape(text(post_view))
+        );
+        let links = links(post_view);
+        if !links.is_empty() {
+            let _ = writeln!(s, "<ul>");
+            for (label, url) in links {
+                let _ = writeln!(
+                    s,
+                    "<li><a href=\"{}\">{}</a></li>",
+                    escape(&url),
+                    escape(&label)
+                );
+            }
+            let _ = writeln!(s, "</ul>");
+        }
+        let _ = writeln!(s, "</article>");
+    }
+    s.push_str("</body>\n</html>\n");
+    s
+}
+
+pub(crate) fn text(post_view: &PostView) -> &str {
+    match &post_view.record {
+        Record::Known(KnownRecord::AppBskyFeedPost(record)) => &record.text,
+        _ => "",
+    }
+}
+
+pub(crate) fn timestamp(post_view: &PostView) -> String {
+    post_view
+        .indexed_at
+        .as_ref()
+        .with_timezone(&Local)
+        .format("%Y-%m-%d %H:%M:%S %z")
+        .to_string()
+}
+
+fn web_url(uri: &str, handle: &str) -> Option<String> {
+    let rkey = uri.rsplit_once("/app.bsky.feed.post/")?.1;
+    Some(format!("https://bsky.app/profile/{handle}/post/{rkey}"))
+}
+
+/// The links of the embedded images, external page and quoted post, followed by the post itself.
+fn links(post_view: &PostView) -> Vec<(String, String)> {
+    let mut links = Vec::new();
+    let (images, external, quoted) = match &post_view.embed {
+        Some(Union::Refs(PostViewEmbedRefs::AppBskyEmbedImagesView(images))) => {
+            (Some(&images.images), None, None)
+        }
+        Some(Union::Refs(PostViewEmbedRefs::AppBskyEmbedExternalView(external))) => {
+            (None, Some(&external.external), None)
+        }
+        Some(Union::Refs(PostViewEmbedRefs::AppBskyEmbedRecordView(record))) => {
+            (None, None, Some(&record.record))
+        }
+        Some(Union::Refs(PostViewEmbedRefs::AppBskyEmbedRecordWithMediaView(view))) => {
+            match &view.media {
+                Union::Refs(ViewMediaRefs::AppBskyEmbedImagesView(images)) => {
+                    (Some(&images.images), None, Some(&view.record.record))
+                }
+                Union::Refs(ViewMediaRefs::AppBskyEmbedExternalView(external)) => {
+                    (None, Some(&external.external), Some(&view.record.record))
+                }
+                _ => (None, None, Some(&view.record.record)),
+            }
+        }
+        _ => (None, None, None),
+    };
+    for image in images.into_iter().flatten() {
+        let label = if image.alt.is_empty() {
+            String::from("image")
+        } else {
+            format!("image: {}", image.alt)
+        };
+        links.push((label, image.fullsize.clone()));
+    }
+    if let Some(external) = external {
+        links.push((external.title.clone(), external.uri.clone()));
+    }
+    if let Some(Union::Refs(ViewRecordRefs::ViewRecord(record))) = quoted {
+        if let Some(url) = web_url(&record.uri, record.author.handle.as_str()) {
+            links.push((
+                format!("quoted post by @{}", record.author.handle.as_str()),
+                url,
+            ));
+        }
+    }
+    if let Some(url) = web_url(&post_view.uri, post_view.author.handle.as_str()) {
+        links.push((String::from("View on Bluesky"), url));
+    }
+    links
+}
+
+fn escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn post() -> FeedViewPost {
+        serde_json::from_value(json!({
+            "post": {
+                "author": { "did": "did:plc:alice", "handle": "alice.test" },
+                "cid": "bafyreidfayvfuwqa7qlnopdjiqrxzs6blmoeu4rujcjtnci5beludirz2a",
+                "embed": {
+                    "$type": "app.bsky.embed.external#view",
+                    "external": { "uri": "https://example.com/?a=1&b=2", "title": "<Example>", "description": "" },
+                },
+                "indexedAt": "2024-09-10T12:00:00Z",
+                "record": {
+                    "$type": "app.bsky.feed.post",
+                    "text": "a < b",
+                    "createdAt": "2024-09-10T12:00:00Z",
+                },
+                "uri": "at://did:plc:alice/app.bsky.feed.post/1",
+            },
+        }))
+        .expect("invalid post")
+    }
+
+    #[test]
+    fn export_markdown() {
+        let posts = [post()];
+        assert_eq!(
+            markdown(&posts),
+            format!(
+                "## @alice.test – {}\n\na < b\n\n- [<Example>](https://example.com/?a=1&b=2)\n- [View on Bluesky](https://bsky.app/profile/alice.test/post/1)\n\n---\n\n",
+                timestamp(&posts[0].post)
+            )
+        );
+    }
+
+    #[test]
+    fn export_html() {
+        let html = html(&[post()]);
+        assert!(html.contains("<p>a &lt; b</p>"));
+        assert!(html
+            .contains("<li><a href=\"https://example.com/?a=1&amp;b=2\">&lt;Example&gt;</a></li>"));
+        assert!(html.ends_with("</article>\n</body>\n</html>\n"));
+    }
+}
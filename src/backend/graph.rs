@@ -0,0 +1,174 @@
+use crate::backend::client::BskyAgent;
+use bsky_sdk::api::app::bsky::actor::defs::ProfileView;
+use bsky_sdk::api::app::bsky::graph::defs::ListView;
+use bsky_sdk::api::app::bsky::graph::get_relationships::OutputRelationshipsItem;
+use bsky_sdk::api::types::string::{AtIdentifier, Datetime, Did};
+use bsky_sdk::api::types::Union;
+use bsky_sdk::{Error, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+
+/// Maximum number of `others` accepted by `app.bsky.graph.getRelationships`.
+const RELATIONSHIPS_BATCH_SIZE: usize = 30;
+
+/// Get the DIDs of all the accounts followed by the logged-in user.
+pub async fn follows(agent: &BskyAgent) -> Result<HashSet<Did>> {
+    let actor = agent
+        .get_session()
+        .await
+        .ok_or(Error::NotLoggedIn)?
+        .data
+        .did;
+    let (mut follows, mut cursor) = (HashSet::new(), None);
+    loop {
+        let output = agent
+            .api
+            .app
+            .bsky
+            .graph
+            .get_follows(
+                bsky_sdk::api::app::bsky::graph::get_follows::ParametersData {
+                    actor: AtIdentifier::Did(actor.clone()),
+                    cursor,
+                    limit: 100.try_into().ok(),
+                }
+                .into(),
+            )
+            .await?;
+        follows.extend(
+            output
+                .data
+                .follows
+                .into_iter()
+                .map(|profile| profile.data.did),
+        );
+        match output.data.cursor {
+            Some(next) if !next.is_empty() => cursor = Some(next),
+            _ => break,
+        }
+    }
+    Ok(follows)
+}
+
+/// Check whether each of `others` and the logged-in user follow each other.
+pub async fn mutual_follows(agent: &BskyAgent, others: &[Did]) -> Result<HashMap<Did, bool>> {
+    let actor = agent
+        .get_session()
+        .await
+        .ok_or(Error::NotLoggedIn)?
+        .data
+        .did;
+    let mut mutuals = HashMap::new();
+    for chunk in others.chunks(RELATIONSHIPS_BATCH_SIZE) {
+        let output = agent
+            .api
+            .app
+            .bsky
+            .graph
+            .get_relationships(
+                bsky_sdk::api::app::bsky::graph::get_relationships::ParametersData {
+                    actor: AtIdentifier::Did(actor.clone()),
+                    others: Some(chunk.iter().cloned().map(AtIdentifier::Did).collect()),
+                }
+                .into(),
+            )
+            .await?;
+        for relationship in output.data.relationships {
+            if let Union::Refs(OutputRelationshipsItem::AppBskyGraphDefsRelationship(
+                relationship,
+            )) = relationship
+            {
+                mutuals.insert(
+                    relationship.did.clone(),
+                    relationship.following.is_some() && relationship.followed_by.is_some(),
+                );
+            }
+        }
+    }
+    Ok(mutuals)
+}
+
+/// Get the followers of `actor` who are followed by the logged-in user.
+pub async fn known_followers(agent: &BskyAgent, actor: &Did) -> Result<Vec<ProfileView>> {
+    Ok(agent
+        .api
+        .app
+        .bsky
+        .graph
+        .get_known_followers(
+            bsky_sdk::api::app::bsky::graph::get_known_followers::ParametersData {
+                actor: AtIdentifier::Did(actor.clone()),
+                cursor: None,
+                limit: 100.try_into().ok(),
+            }
+            .into(),
+        )
+        .await?
+        .data
+        .followers)
+}
+
+/// Get the lists of accounts created by `actor`.
+pub async fn lists(agent: &BskyAgent, actor: &Did) -> Result<Vec<ListView>> {
+    Ok(agent
+        .api
+        .app
+        .bsky
+        .graph
+        .get_lists(
+            bsky_sdk::api::app::bsky::graph::get_lists::ParametersData {
+                actor: AtIdentifier::Did(actor.clone()),
+                cursor: None,
+                limit: 100.try_into().ok(),
+            }
+            .into(),
+        )
+        .await?
+        .data
+        .lists)
+}
+
+/// Add the accounts to the list at `list`, and return the number of the added ones.
+pub async fn add_to_list(agent: &BskyAgent, list: &str, subjects: Vec<Did>) -> usize {
+    let mut added = 0;
+    for subject in subjects {
+        let record_data = bsky_sdk::api::app::bsky::graph::listitem::RecordData {
+            created_at: Datetime::now(),
+            list: list.to_string(),
+            subject,
+        };
+        match agent.create_record(record_data).await {
+            Ok(_) => added += 1,
+            Err(e) => log::error!("failed to create listitem record: {e}"),
+        }
+    }
+    added
+}
+
+/// A page of the accounts suggested to follow.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Suggestions {
+    pub actors: Vec<ProfileView>,
+    pub cursor: Option<String>,
+}
+
+/// Get a page of the accounts suggested to follow for the logged-in user.
+pub async fn suggestions(agent: &BskyAgent, cursor: Option<String>) -> Result<Suggestions> {
+    let output = agent
+        .api
+        .app
+        .bsky
+        .actor
+        .get_suggestions(
+            bsky_sdk::api::app::bsky::actor::get_suggestions::ParametersData {
+                cursor,
+                limit: 30.try_into().ok(),
+            }
+            .into(),
+        )
+        .await?;
+    Ok(Suggestions {
+        actors: output.data.actors,
+        cursor: output.data.cursor.filter(|cursor| !cursor.is_empty()),
+    })
+}
@@ -0,0 +1,21 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+/// Previous texts of the posts whose record changed between two updates, keyed by post URI.
+#[derive(Debug, Clone, Default)]
+pub struct PostHistory(Arc<Mutex<HashMap<String, String>>>);
+
+impl PostHistory {
+    pub fn record(&self, uri: &str, text: String) {
+        if let Ok(mut history) = self.0.lock() {
+            history.insert(uri.to_string(), text);
+        }
+    }
+    /// Returns the previous text of the post `uri`, if it has been edited.
+    pub fn previous(&self, uri: &str) -> Option<String> {
+        self.0
+            .lock()
+            .ok()
+            .and_then(|history| history.get(uri).cloned())
+    }
+}
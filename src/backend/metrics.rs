@@ -0,0 +1,61 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+
+#[derive(Debug, Clone, Default)]
+pub struct WatcherStat {
+    pub last_success: Option<Instant>,
+    pub consecutive_errors: usize,
+    pub updates: usize,
+    pub subscribers: usize,
+}
+
+/// Health of the active watchers, keyed by a descriptor label such as `feed: Following`.
+#[derive(Debug, Clone, Default)]
+pub struct WatcherMetrics(Arc<Mutex<HashMap<String, WatcherStat>>>);
+
+impl WatcherMetrics {
+    pub fn subscribe(&self, label: &str) {
+        self.modify(label, |stat| stat.subscribers += 1);
+    }
+    pub fn unsubscribe(&self, label: &str) {
+        if let Ok(mut stats) = self.0.lock() {
+            if let Some(stat) = stats.get_mut(label) {
+                stat.subscribers = stat.subscribers.saturating_sub(1);
+                if stat.subscribers == 0 {
+                    stats.remove(label);
+                }
+            }
+        }
+    }
+    pub fn success(&self, label: &str) {
+        self.modify(label, |stat| {
+            stat.last_success = Some(Instant::now());
+            stat.consecutive_errors = 0;
+            stat.updates += 1;
+        });
+    }
+    pub fn error(&self, label: &str) {
+        self.modify(label, |stat| stat.consecutive_errors += 1);
+    }
+    /// Returns a snapshot of the stats, sorted by label.
+    pub fn stats(&self) -> Vec<(String, WatcherStat)> {
+        let mut stats = self
+            .0
+            .lock()
+            .map(|stats| {
+                stats
+                    .iter()
+                    .map(|(label, stat)| (label.clone(), stat.clone()))
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+        stats.sort_by(|a, b| a.0.cmp(&b.0));
+        stats
+    }
+    fn modify(&self, label: &str, f: impl FnOnce(&mut WatcherStat)) {
+        if let Ok(mut stats) = self.0.lock() {
+            f(stats.entry(label.to_string()).or_default());
+        }
+    }
+}
@@ -0,0 +1,126 @@
+use bsky_sdk::api::app::bsky::actor::defs::{MutedWord, MutedWordData};
+use chrono::{DateTime, FixedOffset, SecondsFormat, Utc};
+use ipld_core::ipld::Ipld;
+use std::collections::BTreeMap;
+
+/// Where a muted word is matched.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Targets {
+    #[default]
+    ContentAndTags,
+    Content,
+    Tags,
+}
+
+impl Targets {
+    pub fn next(&self) -> Self {
+        match self {
+            Self::ContentAndTags => Self::Content,
+            Self::Content => Self::Tags,
+            Self::Tags => Self::ContentAndTags,
+        }
+    }
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::ContentAndTags => "text & tags",
+            Self::Content => "text",
+            Self::Tags => "tags",
+        }
+    }
+    fn values(&self) -> Vec<String> {
+        match self {
+            Self::ContentAndTags => vec![String::from("content"), String::from("tag")],
+            Self::Content => vec![String::from("content")],
+            Self::Tags => vec![String::from("tag")],
+        }
+    }
+}
+
+/// Create a muted word, with the fields which are not typed in the bundled API client.
+///
+/// `exclude_following` keeps the posts of the followed accounts, and `expires_at` unmutes the
+/// word at that time.
+pub fn muted_word(
+    value: &str,
+    targets: Targets,
+    exclude_following: bool,
+    expires_at: Option<DateTime<Utc>>,
+) -> MutedWord {
+    let mut extra = BTreeMap::new();
+    extra.insert(
+        String::from("actorTarget"),
+        Ipld::String(String::from(if exclude_following {
+            "exclude-following"
+        } else {
+            "all"
+        })),
+    );
+    if let Some(expires_at) = expires_at {
+        extra.insert(
+            String::from("expiresAt"),
+            Ipld::String(expires_at.to_rfc3339_opts(SecondsFormat::Millis, true)),
+        );
+    }
+    MutedWord {
+        data: MutedWordData {
+            targets: targets.values(),
+            value: value.trim().trim_start_matches('#').to_string(),
+        },
+        extra_data: Ipld::Map(extra),
+    }
+}
+
+pub fn targets(word: &MutedWord) -> Targets {
+    let (content, tag) = (
+        word.targets.iter().any(|target| target == "content"),
+        word.targets.iter().any(|target| target == "tag"),
+    );
+    match (content, tag) {
+        (true, false) => Targets::Content,
+        (false, true) => Targets::Tags,
+        _ => Targets::ContentAndTags,
+    }
+}
+
+pub fn excludes_following(word: &MutedWord) -> bool {
+    extra_string(word, "actorTarget") == Some("exclude-following")
+}
+
+pub fn expires_at(word: &MutedWord) -> Option<DateTime<FixedOffset>> {
+    DateTime::parse_from_rfc3339(extra_string(word, "expiresAt")?).ok()
+}
+
+fn extra_string<'a>(word: &'a MutedWord, key: &str) -> Option<&'a str> {
+    let Ipld::Map(map) = &word.extra_data else {
+        return None;
+    };
+    match map.get(key) {
+        Some(Ipld::String(s)) => Some(s),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn muted_word_fields() {
+        let expires = Utc.with_ymd_and_hms(2024, 9, 10, 12, 0, 0).unwrap();
+        let word = muted_word(" #rust ", Targets::Tags, true, Some(expires));
+        assert_eq!(word.value, "rust");
+        assert_eq!(targets(&word), Targets::Tags);
+        assert!(excludes_following(&word));
+        assert_eq!(expires_at(&word), Some(expires.fixed_offset()));
+
+        let json = serde_json::to_value(&word).expect("failed to serialize");
+        assert_eq!(json["actorTarget"], "exclude-following");
+        assert_eq!(json["expiresAt"], "2024-09-10T12:00:00.000Z");
+
+        let word = muted_word("rust", Targets::ContentAndTags, false, None);
+        assert_eq!(targets(&word), Targets::ContentAndTags);
+        assert!(!excludes_following(&word));
+        assert_eq!(expires_at(&word), None);
+    }
+}
@@ -0,0 +1,218 @@
+use crate::backend::client::BskyAgent;
+use bsky_sdk::api::app::bsky::feed::defs::{
+    FeedViewPost, FeedViewPostData, PostView, ThreadViewPost, ThreadViewPostRepliesItem,
+};
+use bsky_sdk::api::app::bsky::feed::get_post_thread::OutputThreadRefs;
+use bsky_sdk::api::records::{KnownRecord, Record};
+use bsky_sdk::api::types::string::{AtIdentifier, Did};
+use bsky_sdk::api::types::Union;
+use bsky_sdk::{Error, Result};
+use std::collections::{HashMap, HashSet};
+
+/// Maximum number of `uris` accepted by `app.bsky.feed.getPosts`.
+const POSTS_BATCH_SIZE: usize = 25;
+/// The number of the recent posts of the user looked up for the replies.
+const REPLIES_LIMIT: u8 = 100;
+
+/// Get the views of the posts `uris`, in as few requests as possible.
+///
+/// The posts which are deleted or not visible are missing from the result.
+pub async fn get_posts(agent: &BskyAgent, uris: &[String]) -> Result<Vec<PostView>> {
+    let mut posts = Vec::with_capacity(uris.len());
+    for chunk in uris.chunks(POSTS_BATCH_SIZE) {
+        posts.extend(
+            agent
+                .api
+                .app
+                .bsky
+                .feed
+                .get_posts(
+                    bsky_sdk::api::app::bsky::feed::get_posts::ParametersData {
+                        uris: chunk.to_vec(),
+                    }
+                    .into(),
+                )
+                .await?
+                .data
+                .posts,
+        );
+    }
+    Ok(posts)
+}
+
+/// Get the record at the AT URI `uri` from the repository of its author, as stored in the PDS.
+pub async fn raw_record(
+    agent: &BskyAgent,
+    uri: &str,
+) -> Result<bsky_sdk::api::com::atproto::repo::get_record::OutputData> {
+    let mut parts = uri
+        .strip_prefix("at://")
+        .ok_or(Error::InvalidAtUri)?
+        .splitn(3, '/');
+    let (Some(repo), Some(collection), Some(rkey)) = (parts.next(), parts.next(), parts.next())
+    else {
+        return Err(Error::InvalidAtUri);
+    };
+    Ok(agent
+        .api
+        .com
+        .atproto
+        .repo
+        .get_record(
+            bsky_sdk::api::com::atproto::repo::get_record::ParametersData {
+                cid: None,
+                collection: collection.parse().map_err(|_| Error::InvalidAtUri)?,
+                repo: repo.parse().map_err(|_| Error::InvalidAtUri)?,
+                rkey: rkey.to_string(),
+            }
+            .into(),
+        )
+        .await?
+        .data)
+}
+
+/// The direct replies to the post `uri`, as the posts of a feed from the newest one.
+pub async fn direct_replies(agent: &BskyAgent, uri: &str) -> Result<Vec<FeedViewPost>> {
+    let thread = agent
+        .api
+        .app
+        .bsky
+        .feed
+        .get_post_thread(
+            bsky_sdk::api::app::bsky::feed::get_post_thread::ParametersData {
+                depth: 1.try_into().ok(),
+                parent_height: 0.try_into().ok(),
+                uri: uri.to_string(),
+            }
+            .into(),
+        )
+        .await?
+        .data
+        .thread;
+    let Union::Refs(OutputThreadRefs::AppBskyFeedDefsThreadViewPost(thread)) = thread else {
+        return Ok(Vec::new());
+    };
+    let mut replies = thread
+        .data
+        .replies
+        .into_iter()
+        .flatten()
+        .filter_map(|reply| match reply {
+            Union::Refs(ThreadViewPostRepliesItem::ThreadViewPost(reply)) => Some(reply.data.post),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    replies.sort_by(|a, b| b.indexed_at.as_ref().cmp(a.indexed_at.as_ref()));
+    Ok(replies
+        .into_iter()
+        .map(|post| {
+            FeedViewPostData {
+                feed_context: None,
+                post,
+                reason: None,
+                reply: None,
+            }
+            .into()
+        })
+        .collect())
+}
+
+/// The authors with the most replies in the thread of `uri` at any depth, with their numbers of
+/// replies, up to `limit`.
+pub async fn top_repliers(
+    agent: &BskyAgent,
+    uri: &str,
+    limit: usize,
+) -> Result<Vec<(String, usize)>> {
+    let thread = agent
+        .api
+        .app
+        .bsky
+        .feed
+        .get_post_thread(
+            bsky_sdk::api::app::bsky::feed::get_post_thread::ParametersData {
+                depth: 1000.try_into().ok(),
+                parent_height: 0.try_into().ok(),
+                uri: uri.to_string(),
+            }
+            .into(),
+        )
+        .await?
+        .data
+        .thread;
+    let mut handles = Vec::new();
+    if let Union::Refs(OutputThreadRefs::AppBskyFeedDefsThreadViewPost(thread)) = &thread {
+        reply_authors(thread, &mut handles);
+    }
+    Ok(rank(handles, limit))
+}
+
+fn reply_authors(thread: &ThreadViewPost, handles: &mut Vec<String>) {
+    for reply in thread.replies.iter().flatten() {
+        if let Union::Refs(ThreadViewPostRepliesItem::ThreadViewPost(reply)) = reply {
+            handles.push(reply.post.author.handle.to_string());
+            reply_authors(reply, handles);
+        }
+    }
+}
+
+/// Count the occurrences of the handles, and returns the most frequent ones in descending order.
+fn rank(handles: Vec<String>, limit: usize) -> Vec<(String, usize)> {
+    let mut counts = HashMap::<String, usize>::new();
+    for handle in handles {
+        *counts.entry(handle).or_default() += 1;
+    }
+    let mut counts = counts.into_iter().collect::<Vec<_>>();
+    counts.sort_by(|(h1, c1), (h2, c2)| c2.cmp(c1).then_with(|| h1.cmp(h2)));
+    counts.truncate(limit);
+    counts
+}
+
+/// The URIs of the posts replied to by `did`, and the roots of their threads,
+/// among the recent posts of the user.
+pub async fn replied_uris(agent: &BskyAgent, did: &Did) -> Result<HashSet<String>> {
+    let feed = agent
+        .api
+        .app
+        .bsky
+        .feed
+        .get_author_feed(
+            bsky_sdk::api::app::bsky::feed::get_author_feed::ParametersData {
+                actor: AtIdentifier::Did(did.clone()),
+                cursor: None,
+                filter: Some(String::from("posts_with_replies")),
+                limit: REPLIES_LIMIT.try_into().ok(),
+            }
+            .into(),
+        )
+        .await?
+        .data
+        .feed;
+    Ok(feed
+        .iter()
+        .filter(|feed_view_post| {
+            feed_view_post.reason.is_none() && &feed_view_post.post.author.did == did
+        })
+        .filter_map(|feed_view_post| match &feed_view_post.post.record {
+            Record::Known(KnownRecord::AppBskyFeedPost(record)) => record.reply.as_ref(),
+            _ => None,
+        })
+        .flat_map(|reply| [reply.parent.uri.clone(), reply.root.uri.clone()])
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rank_repliers() {
+        let handles = ["bob", "alice", "carol", "bob", "alice", "bob"]
+            .map(String::from)
+            .to_vec();
+        assert_eq!(
+            rank(handles, 2),
+            vec![(String::from("bob"), 3), (String::from("alice"), 2)]
+        );
+    }
+}
@@ -0,0 +1,284 @@
+use crate::backend::client::BskyAgent;
+use async_trait::async_trait;
+use bsky_sdk::api::app::bsky::actor::defs::{
+    ContentLabelPrefData, MutedWord, MutedWordsPrefData, PreferencesItem, SavedFeed, SavedFeedData,
+    SavedFeedsPrefV2Data,
+};
+use bsky_sdk::api::app::bsky::actor::{get_preferences, put_preferences};
+use bsky_sdk::api::types::string::Did;
+use bsky_sdk::api::types::Union;
+use color_eyre::{eyre, Result};
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tokio::sync::{mpsc, oneshot};
+
+/// Mutations queued within this window are applied with a single `putPreferences` call.
+const COALESCE_WINDOW: Duration = Duration::from_millis(500);
+
+type Preferences = Vec<Union<PreferencesItem>>;
+type Mutation = Box<dyn FnOnce(&mut Preferences) + Send>;
+
+/// Where the preferences are read from and written to, i.e. the agent except in tests.
+#[async_trait]
+pub trait PreferencesApi: Send + Sync + 'static {
+    async fn get_preferences(&self) -> Result<Preferences>;
+    async fn put_preferences(&self, preferences: Preferences) -> Result<()>;
+}
+
+#[async_trait]
+impl PreferencesApi for Arc<BskyAgent> {
+    async fn get_preferences(&self) -> Result<Preferences> {
+        Ok(self
+            .api
+            .app
+            .bsky
+            .actor
+            .get_preferences(get_preferences::ParametersData {}.into())
+            .await?
+            .data
+            .preferences)
+    }
+    async fn put_preferences(&self, preferences: Preferences) -> Result<()> {
+        self.api
+            .app
+            .bsky
+            .actor
+            .put_preferences(put_preferences::InputData { preferences }.into())
+            .await?;
+        Ok(())
+    }
+}
+
+/// Applies the changes of the preferences in the background.
+///
+/// The changes made in rapid succession (e.g. toggling several label preferences) are
+/// coalesced into a single read-modify-write of the preferences.
+#[derive(Clone)]
+pub struct PreferencesQueue {
+    tx: mpsc::UnboundedSender<(Mutation, oneshot::Sender<Result<()>>)>,
+}
+
+impl PreferencesQueue {
+    pub fn new(api: impl PreferencesApi) -> Self {
+        let (tx, rx) = mpsc::unbounded_channel();
+        tokio::spawn(run(api, rx));
+        Self { tx }
+    }
+    /// Save the feed generator `uri` to the user's saved feeds, pinned.
+    pub async fn save_feed(&self, uri: &str) -> Result<()> {
+        let uri = uri.to_string();
+        self.update_saved_feeds(move |items| {
+            if let Some(item) = items.iter_mut().find(|item| item.value == uri) {
+                item.pinned = true;
+            } else {
+                items.push(
+                    SavedFeedData {
+                        id: tid(),
+                        pinned: true,
+                        r#type: String::from("feed"),
+                        value: uri,
+                    }
+                    .into(),
+                );
+            }
+        })
+        .await
+    }
+    /// Remove the feed generator `uri` from the user's saved feeds.
+    pub async fn unsave_feed(&self, uri: &str) -> Result<()> {
+        let uri = uri.to_string();
+        self.update_saved_feeds(move |items| items.retain(|item| item.value != uri))
+            .await
+    }
+    /// Set the visibility (`ignore`, `warn` or `hide`) of the `label` published by the labeler `did`.
+    pub async fn set_label_preference(
+        &self,
+        did: &Did,
+        label: &str,
+        visibility: &str,
+    ) -> Result<()> {
+        let (did, label, visibility) = (did.clone(), label.to_string(), visibility.to_string());
+        self.update(move |preferences| {
+            if let Some(Union::Refs(PreferencesItem::ContentLabelPref(pref))) =
+                preferences.iter_mut().find(|pref| {
+                    matches!(pref, Union::Refs(PreferencesItem::ContentLabelPref(pref))
+                        if pref.label == label && pref.labeler_did.as_ref() == Some(&did))
+                })
+            {
+                pref.visibility = visibility;
+            } else {
+                preferences.push(Union::Refs(PreferencesItem::ContentLabelPref(Box::new(
+                    ContentLabelPrefData {
+                        label,
+                        labeler_did: Some(did),
+                        visibility,
+                    }
+                    .into(),
+                ))));
+            }
+        })
+        .await
+    }
+    /// Replace all the muted words of the user.
+    pub async fn set_muted_words(&self, words: Vec<MutedWord>) -> Result<()> {
+        self.update(move |preferences| {
+            preferences
+                .retain(|pref| !matches!(pref, Union::Refs(PreferencesItem::MutedWordsPref(_))));
+            preferences.push(Union::Refs(PreferencesItem::MutedWordsPref(Box::new(
+                MutedWordsPrefData { items: words }.into(),
+            ))));
+        })
+        .await
+    }
+    async fn update_saved_feeds<F>(&self, f: F) -> Result<()>
+    where
+        F: FnOnce(&mut Vec<SavedFeed>) + Send + 'static,
+    {
+        self.update(|preferences| {
+            if let Some(Union::Refs(PreferencesItem::SavedFeedsPrefV2(pref))) = preferences
+                .iter_mut()
+                .find(|pref| matches!(pref, Union::Refs(PreferencesItem::SavedFeedsPrefV2(_))))
+            {
+                f(&mut pref.items);
+            } else {
+                let mut items = Vec::new();
+                f(&mut items);
+                preferences.push(Union::Refs(PreferencesItem::SavedFeedsPrefV2(Box::new(
+                    SavedFeedsPrefV2Data { items }.into(),
+                ))));
+            }
+        })
+        .await
+    }
+    /// Queue the mutation, and wait until it is applied together with the others in the window.
+    async fn update<F>(&self, f: F) -> Result<()>
+    where
+        F: FnOnce(&mut Preferences) + Send + 'static,
+    {
+        let (tx, rx) = oneshot::channel();
+        self.tx
+            .send((Box::new(f), tx))
+            .map_err(|_| eyre::eyre!("preferences queue closed"))?;
+        rx.await?
+    }
+}
+
+async fn run(
+    api: impl PreferencesApi,
+    mut rx: mpsc::UnboundedReceiver<(Mutation, oneshot::Sender<Result<()>>)>,
+) {
+    while let Some(first) = rx.recv().await {
+        let mut batch = vec![first];
+        let window = tokio::time::sleep(COALESCE_WINDOW);
+        tokio::pin!(window);
+        loop {
+            tokio::select! {
+                _ = &mut window => break,
+                mutation = rx.recv() => match mutation {
+                    Some(mutation) => batch.push(mutation),
+                    None => break,
+                },
+            }
+        }
+        let (mutations, responders): (Vec<_>, Vec<_>) = batch.into_iter().unzip();
+        let result = async {
+            let mut preferences = api.get_preferences().await?;
+            for f in mutations {
+                f(&mut preferences);
+            }
+            api.put_preferences(preferences).await
+        }
+        .await;
+        log::debug!("applied {} preference changes", responders.len());
+        for tx in responders {
+            tx.send(match &result {
+                Ok(()) => Ok(()),
+                Err(e) => Err(eyre::eyre!("{e}")),
+            })
+            .ok();
+        }
+    }
+}
+
+/// Generate a new TID (timestamp identifier) from the current time.
+fn tid() -> String {
+    const CHARS: &[u8] = b"234567abcdefghijklmnopqrstuvwxyz";
+    let micros = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_micros() as u64)
+        .unwrap_or_default();
+    let clock_id = u64::from(std::process::id()) & 0x3ff;
+    let value = ((micros << 10) | clock_id) & 0x7fff_ffff_ffff_ffff;
+    (0..13)
+        .rev()
+        .map(|i| char::from(CHARS[((value >> (i * 5)) & 0x1f) as usize]))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bsky_sdk::api::types::string::Tid;
+    use std::sync::Mutex;
+
+    #[derive(Clone, Default)]
+    struct MockApi {
+        preferences: Arc<Mutex<Preferences>>,
+        puts: Arc<Mutex<usize>>,
+    }
+
+    #[async_trait]
+    impl PreferencesApi for MockApi {
+        async fn get_preferences(&self) -> Result<Preferences> {
+            Ok(self.preferences.lock().unwrap().clone())
+        }
+        async fn put_preferences(&self, preferences: Preferences) -> Result<()> {
+            *self.preferences.lock().unwrap() = preferences;
+            *self.puts.lock().unwrap() += 1;
+            Ok(())
+        }
+    }
+
+    fn saved_feeds(preferences: &Preferences) -> Vec<String> {
+        preferences
+            .iter()
+            .filter_map(|pref| match pref {
+                Union::Refs(PreferencesItem::SavedFeedsPrefV2(pref)) => Some(&pref.items),
+                _ => None,
+            })
+            .flatten()
+            .map(|item| item.value.clone())
+            .collect()
+    }
+
+    #[test]
+    fn valid_tid() {
+        let tid = tid();
+        assert_eq!(tid.len(), 13);
+        assert!(Tid::new(tid).is_ok());
+    }
+
+    #[tokio::test]
+    async fn coalesce_mutations() {
+        let api = MockApi::default();
+        let queue = PreferencesQueue::new(api.clone());
+        let (a, b, c) = tokio::join!(
+            queue.save_feed("at://feed/a"),
+            queue.save_feed("at://feed/b"),
+            queue.save_feed("at://feed/c"),
+        );
+        assert!(a.is_ok() && b.is_ok() && c.is_ok());
+        assert_eq!(*api.puts.lock().unwrap(), 1);
+
+        // after the window, in a separate call
+        queue
+            .unsave_feed("at://feed/b")
+            .await
+            .expect("failed to unsave");
+        assert_eq!(*api.puts.lock().unwrap(), 2);
+        assert_eq!(
+            saved_feeds(&api.preferences.lock().unwrap()),
+            vec!["at://feed/a", "at://feed/c"]
+        );
+    }
+}
@@ -0,0 +1,76 @@
+use crate::backend::client::BskyAgent;
+use bsky_sdk::api::app::bsky::feed::defs::PostView;
+use color_eyre::{eyre, Result};
+use ipld_core::ipld::Ipld;
+use serde::Deserialize;
+
+const NSID: &str = "app.bsky.feed.getQuotes";
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Quotes {
+    pub posts: Vec<PostView>,
+    pub cursor: Option<String>,
+}
+
+/// Get a page of posts quoting the post `uri`.
+///
+/// `app.bsky.feed.getQuotes` is not available in the bundled API client,
+/// so the request is sent directly to the agent's endpoint with the session's access token.
+pub async fn get_quotes(agent: &BskyAgent, uri: &str, cursor: Option<&str>) -> Result<Quotes> {
+    let session = agent
+        .get_session()
+        .await
+        .ok_or_else(|| eyre::eyre!("not logged in"))?;
+    let mut query = vec![("uri", uri), ("limit", "30")];
+    if let Some(cursor) = cursor {
+        query.push(("cursor", cursor));
+    }
+    let body = reqwest::Client::new()
+        .get(format!("{}/xrpc/{NSID}", agent.get_endpoint().await))
+        .query(&query)
+        .bearer_auth(&session.access_jwt)
+        .send()
+        .await?
+        .error_for_status()?
+        .bytes()
+        .await?;
+    Ok(serde_json::from_slice(&body)?)
+}
+
+/// Returns the `quoteCount` of a post, which is not a typed field in the bundled API client.
+pub fn quote_count(post_view: &PostView) -> Option<i64> {
+    let Ipld::Map(map) = &post_view.extra_data else {
+        return None;
+    };
+    match map.get("quoteCount") {
+        Some(Ipld::Integer(count)) => i64::try_from(*count).ok(),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn quote_count_of_post() {
+        let mut post = json!({
+            "author": { "did": "did:plc:alice", "handle": "alice.test" },
+            "cid": "bafyreidfayvfuwqa7qlnopdjiqrxzs6blmoeu4rujcjtnci5beludirz2a",
+            "indexedAt": "2024-09-10T12:00:00Z",
+            "record": {
+                "$type": "app.bsky.feed.post",
+                "text": "",
+                "createdAt": "2024-09-10T12:00:00Z",
+            },
+            "uri": "at://did:plc:alice/app.bsky.feed.post/1",
+        });
+        let count = |post: &serde_json::Value| {
+            quote_count(&serde_json::from_value(post.clone()).expect("invalid post"))
+        };
+        assert_eq!(count(&post), None);
+        post["quoteCount"] = json!(3);
+        assert_eq!(count(&post), Some(3));
+    }
+}
@@ -0,0 +1,126 @@
+use bsky_sdk::api::xrpc::http::HeaderMap;
+use chrono::{DateTime, TimeDelta, Utc};
+use std::sync::{Arc, Mutex};
+
+/// Length of the rate limit window if it is not given by the `RateLimit-Policy` header.
+const DEFAULT_WINDOW: TimeDelta = TimeDelta::minutes(5);
+
+/// A token bucket of the requests allowed until the rate limit window is reset.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Bucket {
+    limit: u64,
+    remaining: u64,
+    reset: DateTime<Utc>,
+    window: TimeDelta,
+}
+
+impl Bucket {
+    fn from_headers(headers: &HeaderMap) -> Option<Self> {
+        let get = |name| {
+            headers
+                .get(name)?
+                .to_str()
+                .ok()?
+                .split(';')
+                .next()?
+                .trim()
+                .parse::<u64>()
+                .ok()
+        };
+        // e.g. `3000;w=300`
+        let window = headers
+            .get("ratelimit-policy")
+            .and_then(|value| value.to_str().ok())
+            .and_then(|policy| {
+                policy
+                    .split(';')
+                    .find_map(|param| param.trim().strip_prefix("w="))?
+                    .parse::<i64>()
+                    .ok()
+            })
+            .and_then(TimeDelta::try_seconds)
+            .unwrap_or(DEFAULT_WINDOW);
+        let limit = get("ratelimit-limit")?;
+        Some(Self {
+            limit,
+            remaining: get("ratelimit-remaining").unwrap_or(limit),
+            reset: DateTime::from_timestamp(i64::try_from(get("ratelimit-reset")?).ok()?, 0)?,
+            window,
+        })
+    }
+    fn try_acquire(&mut self, now: DateTime<Utc>) -> bool {
+        if now >= self.reset {
+            self.remaining = self.limit;
+            self.reset = now + self.window;
+        }
+        if self.remaining == 0 {
+            return false;
+        }
+        self.remaining -= 1;
+        true
+    }
+}
+
+/// The rate limit of the requests of one agent, shared by its clones.
+#[derive(Debug, Clone, Default)]
+pub struct RateLimit(Arc<Mutex<Option<Bucket>>>);
+
+impl RateLimit {
+    /// Update the bucket from the `RateLimit-*` headers of a response, if it has them.
+    ///
+    /// The most recent response tells the current state, so it replaces the bucket.
+    pub fn update(&self, headers: &HeaderMap) {
+        if let Some(bucket) = Bucket::from_headers(headers) {
+            if let Ok(mut current) = self.0.lock() {
+                *current = Some(bucket);
+            }
+        }
+    }
+    /// Take a token for a request, or returns `false` if the request would exceed the rate limit.
+    ///
+    /// Always succeeds until a response with the rate limit headers is received.
+    pub fn try_acquire(&self) -> bool {
+        self.0
+            .lock()
+            .ok()
+            .and_then(|mut bucket| bucket.as_mut().map(|bucket| bucket.try_acquire(Utc::now())))
+            .unwrap_or(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bsky_sdk::api::xrpc::http::HeaderValue;
+
+    #[test]
+    fn token_bucket() {
+        let mut headers = HeaderMap::new();
+        headers.insert("ratelimit-limit", HeaderValue::from_static("3000"));
+        headers.insert("ratelimit-remaining", HeaderValue::from_static("1"));
+        headers.insert("ratelimit-reset", HeaderValue::from_static("1700000300"));
+        headers.insert("ratelimit-policy", HeaderValue::from_static("3000;w=300"));
+        let mut bucket = Bucket::from_headers(&headers).expect("failed to parse headers");
+        let now = DateTime::from_timestamp(1_700_000_000, 0).unwrap();
+        assert!(bucket.try_acquire(now));
+        assert!(!bucket.try_acquire(now));
+        // refilled after the reset
+        let later = DateTime::from_timestamp(1_700_000_300, 0).unwrap();
+        assert!(bucket.try_acquire(later));
+        assert_eq!(bucket.remaining, 2999);
+        assert!(Bucket::from_headers(&HeaderMap::new()).is_none());
+    }
+
+    #[test]
+    fn separate_rate_limits() {
+        let mut headers = HeaderMap::new();
+        headers.insert("ratelimit-limit", HeaderValue::from_static("3000"));
+        headers.insert("ratelimit-remaining", HeaderValue::from_static("0"));
+        headers.insert("ratelimit-reset", HeaderValue::from_static("32503680000"));
+        let (limited, other) = (RateLimit::default(), RateLimit::default());
+        limited.update(&headers);
+        assert!(!limited.try_acquire());
+        assert!(!limited.clone().try_acquire());
+        assert!(other.try_acquire());
+    }
+}
@@ -0,0 +1,133 @@
+use chrono::{DateTime, FixedOffset};
+use color_eyre::{eyre, Result};
+use feed_rs::model::Entry;
+use reqwest::Client;
+
+/// An RSS or Atom feed, shown in a column next to the Bluesky feeds.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct RssFeed {
+    pub title: String,
+    pub items: Vec<RssItem>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RssItem {
+    pub title: String,
+    pub link: Option<String>,
+    pub published: Option<DateTime<FixedOffset>>,
+    /// The description of the item as plain text, without the HTML tags.
+    pub summary: String,
+}
+
+pub async fn get_feed(client: &Client, url: &str) -> Result<RssFeed> {
+    let body = client
+        .get(url)
+        .send()
+        .await?
+        .error_for_status()?
+        .text()
+        .await?;
+    parse(&body)
+}
+
+/// Parse the items of an RSS, Atom or JSON feed.
+pub fn parse(body: &str) -> Result<RssFeed> {
+    let feed = feed_rs::parser::parse(body.as_bytes())
+        .map_err(|e| eyre::eyre!("not an RSS or Atom feed: {e}"))?;
+    Ok(RssFeed {
+        title: feed.title.map(|title| title.content).unwrap_or_default(),
+        items: feed.entries.into_iter().map(item).collect(),
+    })
+}
+
+fn item(entry: Entry) -> RssItem {
+    // the alternate link of Atom, which is also the only link of RSS
+    let link = entry
+        .links
+        .iter()
+        .find(|link| link.rel.as_deref().map_or(true, |rel| rel == "alternate"))
+        .map(|link| link.href.clone());
+    let summary = entry
+        .summary
+        .map(|summary| summary.content)
+        .or_else(|| entry.content.and_then(|content| content.body))
+        .map(|summary| plain_text(&summary))
+        .unwrap_or_default();
+    RssItem {
+        title: entry.title.map(|title| title.content).unwrap_or_default(),
+        link,
+        published: entry
+            .published
+            .or(entry.updated)
+            .map(|published| published.fixed_offset()),
+        summary,
+    }
+}
+
+/// The text of an HTML description, with the whitespaces collapsed.
+fn plain_text(html: &str) -> String {
+    let text = html2text::config::plain_no_decorate()
+        .string_from_read(html.as_bytes(), usize::MAX)
+        .unwrap_or_else(|_| html.to_string());
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_rss() {
+        let feed = parse(
+            r#"<?xml version="1.0"?>
+<rss version="2.0"><channel>
+  <title>Example &amp; News</title>
+  <link>https://example.com/</link>
+  <item>
+    <title><![CDATA[First <post>]]></title>
+    <link>https://example.com/1</link>
+    <pubDate>Tue, 10 Sep 2024 12:00:00 +0000</pubDate>
+    <description>&lt;p&gt;Hello,   &lt;b&gt;world&lt;/b&gt;&lt;/p&gt;</description>
+  </item>
+  <item><title>Second</title></item>
+</channel></rss>"#,
+        )
+        .expect("failed to parse");
+        assert_eq!(feed.title, "Example & News");
+        assert_eq!(feed.items.len(), 2);
+        assert_eq!(feed.items[0].title, "First <post>");
+        assert_eq!(feed.items[0].link.as_deref(), Some("https://example.com/1"));
+        assert_eq!(
+            feed.items[0].published,
+            DateTime::parse_from_rfc3339("2024-09-10T12:00:00Z").ok()
+        );
+        assert_eq!(feed.items[0].summary, "Hello, world");
+        assert_eq!(feed.items[1].link, None);
+    }
+
+    #[test]
+    fn parse_atom() {
+        let feed = parse(
+            r#"<feed xmlns="http://www.w3.org/2005/Atom">
+  <title>Atom</title>
+  <link rel="self" href="https://example.com/feed.xml"/>
+  <entry>
+    <title type="html">Entry</title>
+    <link rel="edit" href="https://example.com/edit/1"/>
+    <link href="https://example.com/1?a=1&amp;b=2"/>
+    <updated>2024-09-10T12:00:00+09:00</updated>
+    <summary>Summary</summary>
+  </entry>
+</feed>"#,
+        )
+        .expect("failed to parse");
+        assert_eq!(feed.title, "Atom");
+        assert_eq!(
+            feed.items[0].link.as_deref(),
+            Some("https://example.com/1?a=1&b=2")
+        );
+        assert!(feed.items[0].published.is_some());
+        assert_eq!(feed.items[0].summary, "Summary");
+        assert!(parse("<html></html>").is_err());
+    }
+}
@@ -0,0 +1,93 @@
+use crate::backend::client::BskyAgent;
+use bsky_sdk::api::app::bsky::feed::defs::PostView;
+use color_eyre::{eyre, Result};
+
+#[derive(Debug, Clone)]
+pub struct SearchResults {
+    pub posts: Vec<PostView>,
+    pub cursor: Option<String>,
+}
+
+/// The terms of a search of the posts.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SearchQuery {
+    pub text: String,
+    /// The handle or the DID of the author of the posts.
+    pub author: Option<String>,
+}
+
+impl SearchQuery {
+    pub fn new(text: String) -> Self {
+        Self { text, author: None }
+    }
+    /// The posts by `author`, which are all matched until a text is given.
+    pub fn by_author(author: String) -> Self {
+        Self {
+            text: String::new(),
+            author: Some(author),
+        }
+    }
+    /// The query string of the API, which must not be empty.
+    fn q(&self) -> &str {
+        if self.text.trim().is_empty() {
+            "*"
+        } else {
+            &self.text
+        }
+    }
+}
+
+/// Get a page of posts matching `query`.
+pub async fn search_posts(
+    agent: &BskyAgent,
+    query: &SearchQuery,
+    cursor: Option<&str>,
+) -> Result<SearchResults> {
+    let author = query
+        .author
+        .as_deref()
+        .map(|author| {
+            author
+                .parse()
+                .map_err(|e| eyre::eyre!("invalid author: {e}"))
+        })
+        .transpose()?;
+    let output = agent
+        .api
+        .app
+        .bsky
+        .feed
+        .search_posts(
+            bsky_sdk::api::app::bsky::feed::search_posts::ParametersData {
+                author,
+                cursor: cursor.map(String::from),
+                domain: None,
+                lang: None,
+                limit: 30.try_into().ok(),
+                mentions: None,
+                q: query.q().to_string(),
+                since: None,
+                sort: None,
+                tag: None,
+                until: None,
+                url: None,
+            }
+            .into(),
+        )
+        .await?;
+    Ok(SearchResults {
+        posts: output.data.posts,
+        cursor: output.data.cursor,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn query_string() {
+        assert_eq!(SearchQuery::new(String::from("rust")).q(), "rust");
+        assert_eq!(SearchQuery::by_author(String::from("alice.test")).q(), "*");
+    }
+}
@@ -0,0 +1,128 @@
+use crate::backend::client::BskyAgent;
+use bsky_sdk::api::app::bsky::feed::defs::{FeedViewPost, FeedViewPostData, GeneratorView};
+use color_eyre::{eyre, Result};
+use reqwest::Client;
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use std::collections::HashMap;
+
+const NSID: &str = "app.bsky.feed.getFeedSkeleton";
+const PLC_DIRECTORY: &str = "https://plc.directory";
+/// Maximum number of `uris` accepted by `app.bsky.feed.getPosts`.
+const GET_POSTS_BATCH_SIZE: usize = 25;
+
+#[derive(Debug, Deserialize)]
+struct DidDocument {
+    #[serde(default)]
+    service: Vec<Service>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Service {
+    id: String,
+    service_endpoint: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct Skeleton {
+    feed: Vec<SkeletonFeedPost>,
+}
+
+#[derive(Debug, Deserialize)]
+struct SkeletonFeedPost {
+    post: String,
+}
+
+/// Get a feed from its generator service, and hydrate the posts of the skeleton with the agent.
+///
+/// Unlike `app.bsky.feed.getFeed`, this does not go through the AppView,
+/// so the request is not authenticated and personalized feeds may return generic results.
+pub async fn get_feed_skeleton(
+    agent: &BskyAgent,
+    generator_view: &GeneratorView,
+) -> Result<Vec<FeedViewPost>> {
+    let client = Client::new();
+    let endpoint = service_endpoint(&client, generator_view.did.as_str()).await?;
+    let skeleton = get_json::<Skeleton>(
+        &client,
+        &format!("{endpoint}/xrpc/{NSID}"),
+        &[("feed", generator_view.uri.as_str()), ("limit", "30")],
+    )
+    .await?;
+    let uris = skeleton
+        .feed
+        .into_iter()
+        .map(|feed_post| feed_post.post)
+        .collect::<Vec<_>>();
+    let mut posts = HashMap::new();
+    for chunk in uris.chunks(GET_POSTS_BATCH_SIZE) {
+        let output = agent
+            .api
+            .app
+            .bsky
+            .feed
+            .get_posts(
+                bsky_sdk::api::app::bsky::feed::get_posts::ParametersData {
+                    uris: chunk.to_vec(),
+                }
+                .into(),
+            )
+            .await?;
+        posts.extend(
+            output
+                .data
+                .posts
+                .into_iter()
+                .map(|post| (post.uri.clone(), post)),
+        );
+    }
+    // keep the order of the skeleton, skipping the posts that could not be hydrated
+    Ok(uris
+        .iter()
+        .filter_map(|uri| posts.remove(uri))
+        .map(|post| {
+            FeedViewPostData {
+                feed_context: None,
+                post,
+                reason: None,
+                reply: None,
+            }
+            .into()
+        })
+        .collect())
+}
+
+/// Resolve the endpoint of the feed generator service of `did`.
+async fn service_endpoint(client: &Client, did: &str) -> Result<String> {
+    let url = if let Some(host) = did.strip_prefix("did:web:") {
+        format!("https://{host}/.well-known/did.json")
+    } else if did.starts_with("did:plc:") {
+        format!("{PLC_DIRECTORY}/{did}")
+    } else {
+        return Err(eyre::eyre!("unsupported DID method: {did}"));
+    };
+    get_json::<DidDocument>(client, &url, &[])
+        .await?
+        .service
+        .into_iter()
+        .find(|service| service.id == "#bsky_fg" || service.id == format!("{did}#bsky_fg"))
+        .map(|service| service.service_endpoint.trim_end_matches('/').to_string())
+        .ok_or_else(|| eyre::eyre!("no feed generator service in the DID document of {did}"))
+}
+
+async fn get_json<T: DeserializeOwned>(
+    client: &Client,
+    url: &str,
+    query: &[(&str, &str)],
+) -> Result<T> {
+    let body = client
+        .get(url)
+        .query(query)
+        .send()
+        .await?
+        .error_for_status()?
+        .bytes()
+        .await?;
+    Ok(serde_json::from_slice(&body)?)
+}
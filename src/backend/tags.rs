@@ -0,0 +1,75 @@
+use color_eyre::Result;
+use rusqlite::{params, Connection};
+use std::collections::{BTreeSet, HashMap};
+use std::path::Path;
+
+/// Local tags of posts by their CID, stored in their own database.
+pub struct TagStore {
+    conn: Connection,
+}
+
+impl TagStore {
+    pub fn open(path: &Path) -> Result<Self> {
+        let conn = Connection::open(path)?;
+        conn.execute_batch(
+            "CREATE TABLE IF NOT EXISTS tags (
+                cid TEXT NOT NULL,
+                tag TEXT NOT NULL,
+                PRIMARY KEY (cid, tag)
+            );",
+        )?;
+        Ok(Self { conn })
+    }
+    /// Replace the tags of the post `cid`.
+    pub fn set(&self, cid: &str, tags: &BTreeSet<String>) -> Result<()> {
+        self.conn
+            .execute("DELETE FROM tags WHERE cid = ?1", params![cid])?;
+        for tag in tags {
+            self.conn.execute(
+                "INSERT INTO tags (cid, tag) VALUES (?1, ?2)",
+                params![cid, tag],
+            )?;
+        }
+        Ok(())
+    }
+    /// Returns the tags of all the tagged posts.
+    pub fn all(&self) -> Result<HashMap<String, BTreeSet<String>>> {
+        let mut stmt = self.conn.prepare("SELECT cid, tag FROM tags")?;
+        let mut tags = HashMap::<String, BTreeSet<String>>::new();
+        for row in stmt.query_map([], |row| Ok((row.get(0)?, row.get(1)?)))? {
+            let (cid, tag) = row?;
+            tags.entry(cid).or_default().insert(tag);
+        }
+        Ok(tags)
+    }
+}
+
+/// Parses comma-separated tag names, e.g. `work, interesting, later`.
+pub fn parse_tags(input: &str) -> BTreeSet<String> {
+    input
+        .split(',')
+        .map(|tag| tag.trim().to_lowercase())
+        .filter(|tag| !tag.is_empty())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn set_and_list() -> Result<()> {
+        let store = TagStore::open(Path::new(":memory:"))?;
+        store.set("bafyrei1", &parse_tags("Work, later,, work"))?;
+        store.set("bafyrei2", &parse_tags("interesting"))?;
+        store.set("bafyrei2", &parse_tags(" "))?;
+        assert_eq!(
+            store.all()?,
+            HashMap::from_iter([(
+                String::from("bafyrei1"),
+                BTreeSet::from_iter([String::from("later"), String::from("work")])
+            )])
+        );
+        Ok(())
+    }
+}
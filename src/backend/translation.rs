@@ -0,0 +1,120 @@
+use color_eyre::{eyre, Result};
+use reqwest::header::{AUTHORIZATION, CONTENT_TYPE};
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Config {
+    /// Endpoint of the translation API, e.g. `http://localhost:5000/translate`.
+    pub url: String,
+    #[serde(default)]
+    pub provider: Provider,
+    pub api_key: Option<String>,
+    /// Language to translate the posts into.
+    #[serde(default = "default_target")]
+    pub target: String,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub enum Provider {
+    #[default]
+    LibreTranslate,
+    DeepL,
+}
+
+fn default_target() -> String {
+    String::from("en")
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Translation {
+    /// Language of the original text, as given by the post or detected by the API.
+    pub source: String,
+    pub text: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct LibreTranslateResponse {
+    translated_text: String,
+    detected_language: Option<LibreTranslateDetected>,
+}
+
+#[derive(Debug, Deserialize)]
+struct LibreTranslateDetected {
+    language: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct DeepLResponse {
+    translations: Vec<DeepLTranslation>,
+}
+
+#[derive(Debug, Deserialize)]
+struct DeepLTranslation {
+    detected_source_language: String,
+    text: String,
+}
+
+/// Translate `text` into the target language of `config`.
+///
+/// The source language is detected by the API unless `source` is given.
+pub async fn translate(config: &Config, text: &str, source: Option<&str>) -> Result<Translation> {
+    let client = Client::new();
+    let mut request = client
+        .post(&config.url)
+        .header(CONTENT_TYPE, "application/json");
+    let body = match config.provider {
+        Provider::LibreTranslate => json!({
+            "q": text,
+            "source": source.unwrap_or("auto"),
+            "target": config.target,
+            "format": "text",
+            "api_key": config.api_key,
+        }),
+        Provider::DeepL => {
+            if let Some(api_key) = &config.api_key {
+                request = request.header(AUTHORIZATION, format!("DeepL-Auth-Key {api_key}"));
+            }
+            json!({
+                "text": [text],
+                // DeepL expects upper-case codes without the region for the source language
+                "source_lang": source.map(|lang| lang.split('-').next().unwrap_or(lang).to_uppercase()),
+                "target_lang": config.target.to_uppercase(),
+            })
+        }
+    };
+    let bytes = request
+        .body(serde_json::to_vec(&body)?)
+        .send()
+        .await?
+        .error_for_status()?
+        .bytes()
+        .await?;
+    match config.provider {
+        Provider::LibreTranslate => {
+            let response = serde_json::from_slice::<LibreTranslateResponse>(&bytes)?;
+            Ok(Translation {
+                source: source
+                    .map(String::from)
+                    .or(response.detected_language.map(|detected| detected.language))
+                    .unwrap_or_else(|| String::from("auto")),
+                text: response.translated_text,
+            })
+        }
+        Provider::DeepL => {
+            let translation = serde_json::from_slice::<DeepLResponse>(&bytes)?
+                .translations
+                .into_iter()
+                .next()
+                .ok_or_else(|| eyre::eyre!("no translation in the response"))?;
+            Ok(Translation {
+                source: source
+                    .map(String::from)
+                    .unwrap_or_else(|| translation.detected_source_language.to_lowercase()),
+                text: translation.text,
+            })
+        }
+    }
+}
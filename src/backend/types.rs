@@ -1,10 +1,9 @@
-use bsky_sdk::api::app::bsky::actor::defs::SavedFeed;
-use bsky_sdk::api::app::bsky::feed::defs::GeneratorView;
+use bsky_sdk::api::app::bsky::feed::defs::{FeedViewPost, GeneratorView};
 use bsky_sdk::api::app::bsky::graph::defs::ListView;
+use bsky_sdk::api::app::bsky::labeler::defs::LabelerViewDetailed;
 
 #[derive(Debug, Clone)]
 pub struct PinnedFeed {
-    pub saved_feed: SavedFeed,
     pub info: FeedSourceInfo,
 }
 
@@ -13,4 +12,93 @@ pub enum FeedSourceInfo {
     Feed(Box<GeneratorView>),
     List(Box<ListView>),
     Timeline(String),
+    /// A feed assembled from the skeleton returned by its generator service.
+    Skeleton(Box<GeneratorView>),
+    /// The following timeline, with only the posts of the mutual follows.
+    Mutuals(String),
+    /// The reposts of the following timeline, i.e. what the follows have shared.
+    Activity(String),
+    /// The posts of the logged-in user, whose DID is taken from the session.
+    Own,
+    /// The posts of an account, by its DID or handle.
+    Author(String),
+    /// The posts suggested by the Discover feed of Bluesky.
+    Suggested,
+    /// Several sources merged into a single feed.
+    Combined(Vec<FeedSourceInfo>),
+    /// Only the posts with an external link card, from another source.
+    ExternalLinks(Box<FeedSourceInfo>),
+    /// The direct replies to a post, by its AT-URI.
+    Conversation(String),
+}
+
+impl FeedSourceInfo {
+    /// A short description of the source, e.g. `list: Friends`.
+    pub fn label(&self) -> String {
+        match self {
+            Self::Feed(generator_view) => format!("feed: {}", generator_view.display_name),
+            Self::List(list_view) => format!("list: {}", list_view.name),
+            Self::Timeline(_) => String::from("timeline: Following"),
+            Self::Mutuals(_) => String::from("timeline: Mutuals"),
+            Self::Activity(_) => String::from("timeline: Repost activity"),
+            Self::Own => String::from("author: Own posts"),
+            Self::Author(actor) => format!("author: {actor}"),
+            Self::Suggested => String::from("discover: Suggested"),
+            Self::Combined(feed_infos) => format!("combined: {} feeds", feed_infos.len()),
+            Self::ExternalLinks(feed_info) => format!("links: {}", feed_info.label()),
+            Self::Conversation(uri) => format!("conversation: {uri}"),
+            Self::Skeleton(generator_view) => {
+                format!("skeleton: {}", generator_view.display_name)
+            }
+        }
+    }
+    /// Identifies the source across restarts, unlike the label which may change.
+    pub fn key(&self) -> String {
+        match self {
+            Self::Feed(generator_view) => format!("feed:{}", generator_view.uri),
+            Self::List(list_view) => format!("list:{}", list_view.uri),
+            Self::Timeline(value) => format!("timeline:{value}"),
+            Self::Mutuals(value) => format!("mutuals:{value}"),
+            Self::Activity(value) => format!("activity:{value}"),
+            Self::Own => String::from("own"),
+            Self::Author(actor) => format!("author:{actor}"),
+            Self::Suggested => String::from("suggested"),
+            Self::Combined(feed_infos) => format!(
+                "combined:{}",
+                feed_infos
+                    .iter()
+                    .map(Self::key)
+                    .collect::<Vec<_>>()
+                    .join(",")
+            ),
+            Self::Skeleton(generator_view) => format!("skeleton:{}", generator_view.uri),
+            Self::ExternalLinks(feed_info) => format!("links:{}", feed_info.key()),
+            Self::Conversation(uri) => format!("conversation:{uri}"),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct FeedGeneratorDetail {
+    pub view: GeneratorView,
+    pub is_online: bool,
+    pub is_valid: bool,
+    pub saved: bool,
+    pub posts: Vec<FeedViewPost>,
+}
+
+#[derive(Debug, Clone)]
+pub struct LabelerSettings {
+    pub view: LabelerViewDetailed,
+    pub policies: Vec<LabelPolicy>,
+}
+
+#[derive(Debug, Clone)]
+pub struct LabelPolicy {
+    pub identifier: String,
+    pub name: String,
+    /// `inform`, `alert` or `none`, as defined by the labeler.
+    pub severity: String,
+    /// `ignore`, `warn` or `hide`, as configured by the user.
+    pub visibility: String,
 }
@@ -0,0 +1,186 @@
+use crate::backend::client::BskyAgent;
+use bsky_sdk::api::app::bsky::feed::post::RecordEmbedRefs;
+use bsky_sdk::api::types::{Union, UnknownData};
+use chrono::{TimeDelta, Utc};
+use color_eyre::{eyre, Result};
+use reqwest::header::{AUTHORIZATION, CONTENT_TYPE};
+use reqwest::{Client, Response, Url};
+use serde::Deserialize;
+use serde_json::{json, Value};
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+/// The service processing the videos before they are stored in the PDS of the user.
+const VIDEO_SERVICE: &str = "https://video.bsky.app";
+const POLL_INTERVAL: Duration = Duration::from_secs(1);
+/// How long to wait for the video to be processed, before giving up.
+const PROCESSING_TIMEOUT: Duration = Duration::from_secs(10 * 60);
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct JobStatus {
+    job_id: String,
+    state: String,
+    progress: Option<u8>,
+    blob: Option<Value>,
+    error: Option<String>,
+    message: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum JobStatusOutput {
+    Wrapped {
+        #[serde(rename = "jobStatus")]
+        job_status: JobStatus,
+    },
+    // the video service may return the status itself, e.g. for a video uploaded before
+    Bare(JobStatus),
+}
+
+impl From<JobStatusOutput> for JobStatus {
+    fn from(output: JobStatusOutput) -> Self {
+        match output {
+            JobStatusOutput::Wrapped { job_status } | JobStatusOutput::Bare(job_status) => {
+                job_status
+            }
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct ServiceAuth {
+    token: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct XrpcError {
+    error: Option<String>,
+    message: Option<String>,
+}
+
+/// Upload the video at `path` and wait until it is processed, calling `progress` with the percentage.
+///
+/// Returns the embed of the processed video, to be attached to a post.
+pub async fn upload_video(
+    agent: &BskyAgent,
+    client: &Client,
+    path: &Path,
+    max_size_mb: u64,
+    mut progress: impl FnMut(u8),
+) -> Result<Union<RecordEmbedRefs>> {
+    let mime_type = match path
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .map(str::to_lowercase)
+        .as_deref()
+    {
+        Some("mp4") => "video/mp4",
+        Some("webm") => "video/webm",
+        Some("mov") => "video/quicktime",
+        Some("mpeg" | "mpg") => "video/mpeg",
+        _ => {
+            return Err(eyre::eyre!(
+                "unsupported video format (mp4, webm, mov or mpeg)"
+            ))
+        }
+    };
+    let size = tokio::fs::metadata(path).await?.len();
+    if size > max_size_mb * 1024 * 1024 {
+        return Err(eyre::eyre!(
+            "the video is too large: {} MB (max: {max_size_mb} MB)",
+            size / 1024 / 1024
+        ));
+    }
+    let session = agent
+        .get_session()
+        .await
+        .ok_or(bsky_sdk::Error::NotLoggedIn)?;
+    let endpoint = agent.get_endpoint().await;
+    let host = Url::parse(&endpoint)?
+        .host_str()
+        .map(String::from)
+        .ok_or_else(|| eyre::eyre!("invalid endpoint: {endpoint}"))?;
+
+    // the video service uploads the processed video to the PDS on behalf of the user
+    let exp = (Utc::now() + TimeDelta::minutes(30))
+        .timestamp()
+        .to_string();
+    let auth = client
+        .get(format!("{endpoint}/xrpc/com.atproto.server.getServiceAuth"))
+        .query(&[
+            ("aud", format!("did:web:{host}").as_str()),
+            ("lxm", "com.atproto.repo.uploadBlob"),
+            ("exp", exp.as_str()),
+        ])
+        .header(AUTHORIZATION, format!("Bearer {}", session.access_jwt))
+        .send()
+        .await?;
+    let auth = serde_json::from_slice::<ServiceAuth>(&check(auth).await?)?;
+
+    let name = path
+        .file_name()
+        .and_then(|name| name.to_str())
+        .unwrap_or("video");
+    let upload = client
+        .post(format!("{VIDEO_SERVICE}/xrpc/app.bsky.video.uploadVideo"))
+        .query(&[("did", session.did.as_str()), ("name", name)])
+        .header(AUTHORIZATION, format!("Bearer {}", auth.token))
+        .header(CONTENT_TYPE, mime_type)
+        .body(tokio::fs::read(path).await?)
+        .send()
+        .await?;
+    let mut status = JobStatus::from(serde_json::from_slice::<JobStatusOutput>(
+        &check(upload).await?,
+    )?);
+    let started = Instant::now();
+    loop {
+        match status.state.as_str() {
+            "JOB_STATE_COMPLETED" => {
+                let blob = status
+                    .blob
+                    .ok_or_else(|| eyre::eyre!("no blob in the completed job"))?;
+                return Ok(Union::Unknown(serde_json::from_value::<UnknownData>(
+                    json!({ "$type": "app.bsky.embed.video", "video": blob }),
+                )?));
+            }
+            "JOB_STATE_FAILED" => {
+                return Err(eyre::eyre!(
+                    "failed to process the video: {}",
+                    status
+                        .message
+                        .or(status.error)
+                        .unwrap_or_else(|| String::from("unknown error"))
+                ));
+            }
+            _ => progress(status.progress.unwrap_or_default()),
+        }
+        if started.elapsed() > PROCESSING_TIMEOUT {
+            return Err(eyre::eyre!(
+                "timed out while processing the video after {} minutes",
+                PROCESSING_TIMEOUT.as_secs() / 60
+            ));
+        }
+        tokio::time::sleep(POLL_INTERVAL).await;
+        let response = client
+            .get(format!("{VIDEO_SERVICE}/xrpc/app.bsky.video.getJobStatus"))
+            .query(&[("jobId", status.job_id.as_str())])
+            .send()
+            .await?;
+        status = serde_json::from_slice::<JobStatusOutput>(&check(response).await?)?.into();
+    }
+}
+
+/// Returns the body of the response, or its XRPC error message, e.g. for an unsupported codec.
+async fn check(response: Response) -> Result<Vec<u8>> {
+    let status = response.status();
+    let bytes = response.bytes().await?;
+    if status.is_success() {
+        return Ok(bytes.to_vec());
+    }
+    let message = serde_json::from_slice::<XrpcError>(&bytes)
+        .ok()
+        .and_then(|e| e.message.or(e.error))
+        .unwrap_or_else(|| status.to_string());
+    Err(eyre::eyre!(message))
+}
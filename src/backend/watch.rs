@@ -1,7 +1,18 @@
+use super::cache::PostCache;
 use super::config::Config;
-use bsky_sdk::BskyAgent;
-use std::sync::Arc;
+use super::history::PostHistory;
+use super::metrics::WatcherMetrics;
+use super::preferences::PreferencesQueue;
+use super::rate_limit::RateLimit;
+use crate::backend::client::BskyAgent;
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
 use tokio::sync::watch;
+use tokio::task::JoinHandle;
+
+/// How long to wait for the aborted tasks to finish on shutdown.
+const SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(1);
 
 pub trait Watch {
     type Output;
@@ -11,13 +22,117 @@ pub trait Watch {
     fn refresh(&self);
 }
 
+impl<W: Watch + ?Sized> Watch for Box<W> {
+    type Output = W::Output;
+
+    fn subscribe(&self) -> watch::Receiver<Self::Output> {
+        (**self).subscribe()
+    }
+    fn unsubscribe(&self) {
+        (**self).unsubscribe();
+    }
+    fn refresh(&self) {
+        (**self).refresh();
+    }
+}
+
 pub struct Watcher {
     pub agent: Arc<BskyAgent>,
     pub(crate) config: Config,
+    pub metrics: WatcherMetrics,
+    pub history: PostHistory,
+    pub posts: PostCache,
+    pub preferences_queue: PreferencesQueue,
+    pub(crate) rate_limit: RateLimit,
+    pub(crate) tasks: WatcherTasks,
 }
 
 impl Watcher {
-    pub fn new(agent: Arc<BskyAgent>, config: Config) -> Self {
-        Self { agent, config }
+    pub fn new(agent: Arc<BskyAgent>, rate_limit: RateLimit, config: Config) -> Self {
+        Self {
+            preferences_queue: PreferencesQueue::new(agent.clone()),
+            agent,
+            config,
+            metrics: WatcherMetrics::default(),
+            history: PostHistory::default(),
+            posts: PostCache::default(),
+            rate_limit,
+            tasks: WatcherTasks::default(),
+        }
+    }
+    /// Stop the background tasks of all the watches, e.g. on exit.
+    ///
+    /// The watches should be unsubscribed first, so that the tasks are not aborted in the middle
+    /// of an update.
+    pub async fn shutdown(&self) {
+        self.tasks.shutdown().await;
+    }
+}
+
+/// The long-running tasks spawned by the subscriptions of the watches.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct WatcherTasks(Arc<Mutex<Vec<JoinHandle<()>>>>);
+
+impl WatcherTasks {
+    pub fn spawn<F>(&self, future: F)
+    where
+        F: Future<Output = ()> + Send + 'static,
+    {
+        let handle = tokio::spawn(future);
+        if let Ok(mut handles) = self.0.lock() {
+            handles.retain(|handle| !handle.is_finished());
+            handles.push(handle);
+        }
+    }
+    async fn shutdown(&self) {
+        let handles = self
+            .0
+            .lock()
+            .map(|mut handles| std::mem::take(&mut *handles))
+            .unwrap_or_default();
+        log::debug!("shutting down {} watcher tasks", handles.len());
+        for handle in &handles {
+            handle.abort();
+        }
+        for handle in handles {
+            if tokio::time::timeout(SHUTDOWN_TIMEOUT, handle)
+                .await
+                .is_err()
+            {
+                log::warn!("watcher task did not finish in time");
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::sync::oneshot;
+
+    #[tokio::test]
+    async fn shutdown_tasks() {
+        let tasks = WatcherTasks::default();
+        let mut receivers = Vec::new();
+        for _ in 0..3 {
+            let (tx, rx) = oneshot::channel::<()>();
+            receivers.push(rx);
+            tasks.spawn(async move {
+                // dropped when the task is aborted
+                let _tx = tx;
+                std::future::pending::<()>().await;
+            });
+        }
+        tokio::time::timeout(SHUTDOWN_TIMEOUT, tasks.shutdown())
+            .await
+            .expect("shutdown timed out");
+        for rx in receivers {
+            // the sender is dropped without sending
+            assert!(tokio::time::timeout(SHUTDOWN_TIMEOUT, rx)
+                .await
+                .expect("task did not terminate")
+                .is_err());
+        }
+        assert!(tasks.0.lock().unwrap().is_empty());
     }
 }
@@ -1,4 +1,16 @@
+mod chat_unread;
+mod combined;
+mod created_feeds;
+mod debounce;
+mod digest;
 mod feed;
+mod feed_generator;
+mod filtered;
+mod firehose;
+mod labelers;
+mod list_blocks;
 mod pinned_feeds;
 mod post_thread;
 mod preferences;
+mod rss;
+mod unread_count;
@@ -0,0 +1,141 @@
+use super::super::{Watch, Watcher, WatcherTasks};
+use crate::backend::client::BskyAgent;
+use bsky_sdk::api::agent::bluesky::{AtprotoServiceType, BSKY_CHAT_DID};
+use bsky_sdk::api::chat::bsky::convo::defs::ConvoViewLastMessageRefs;
+use bsky_sdk::api::types::Union;
+use bsky_sdk::Result;
+use std::{sync::Arc, time::Duration};
+use tokio::sync::{broadcast, watch};
+use tokio::time;
+
+impl Watcher {
+    /// Watch the number of unread chat messages and the handle of the latest unread sender.
+    pub fn chat_unread(&self) -> impl Watch<Output = (u64, Option<String>)> {
+        let (tx, _) = broadcast::channel(1);
+        ChatUnreadWatcher {
+            tasks: self.tasks.clone(),
+            agent: self.agent.clone(),
+            period: Duration::from_secs(self.config.intervals.chat_unread_count),
+            tx,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Command {
+    Quit,
+    Refresh,
+}
+
+struct ChatUnreadWatcher {
+    tasks: WatcherTasks,
+    agent: Arc<BskyAgent>,
+    period: Duration,
+    tx: broadcast::Sender<Command>,
+}
+
+impl Watch for ChatUnreadWatcher {
+    type Output = (u64, Option<String>);
+
+    fn subscribe(&self) -> watch::Receiver<Self::Output> {
+        let agent = self.agent.clone();
+        let mut command = self.tx.subscribe();
+        let mut interval = time::interval(self.period);
+        let (tx, rx) = watch::channel((0, None));
+        self.tasks.spawn(async move {
+            loop {
+                let tick = interval.tick();
+                let (agent, tx) = (agent.clone(), tx.clone());
+                tokio::select! {
+                    Ok(command) = command.recv() => {
+                        match command {
+                            Command::Refresh => {
+                                tokio::spawn(async move {
+                                    update(&agent, &tx).await;
+                                });
+                            }
+                            Command::Quit => {
+                                break;
+                            }
+                        }
+                    }
+                    _ = tick => {
+                        tokio::spawn(async move {
+                            update(&agent, &tx).await;
+                        });
+                    }
+                }
+            }
+        });
+        rx
+    }
+    fn unsubscribe(&self) {
+        if let Err(e) = self.tx.send(Command::Quit) {
+            log::error!("failed to send quit command: {e}");
+        }
+    }
+    fn refresh(&self) {
+        if let Err(e) = self.tx.send(Command::Refresh) {
+            log::error!("failed to send refresh command: {e}");
+        }
+    }
+}
+
+async fn update(agent: &BskyAgent, tx: &watch::Sender<(u64, Option<String>)>) {
+    match get_chat_unread(agent).await {
+        Ok(unread) => {
+            // do not notify the receivers if nothing has changed
+            tx.send_if_modified(|current| {
+                let modified = *current != unread;
+                *current = unread;
+                modified
+            });
+        }
+        Err(e) => {
+            log::warn!("failed to get chat unread count: {e}");
+        }
+    }
+}
+
+async fn get_chat_unread(agent: &BskyAgent) -> Result<(u64, Option<String>)> {
+    let convos = agent
+        .api_with_proxy(
+            BSKY_CHAT_DID.parse().expect("valid DID"),
+            AtprotoServiceType::BskyChat,
+        )
+        .chat
+        .bsky
+        .convo
+        .list_convos(
+            bsky_sdk::api::chat::bsky::convo::list_convos::ParametersData {
+                cursor: None,
+                limit: 100.try_into().ok(),
+            }
+            .into(),
+        )
+        .await?
+        .data
+        .convos;
+    let count = convos
+        .iter()
+        .filter(|convo| !convo.muted)
+        .map(|convo| u64::try_from(convo.unread_count).unwrap_or_default())
+        .sum();
+    // the conversations are sorted by their latest message
+    let sender = convos
+        .iter()
+        .filter(|convo| !convo.muted && convo.unread_count > 0)
+        .find_map(|convo| {
+            let Some(Union::Refs(ConvoViewLastMessageRefs::MessageView(message))) =
+                &convo.last_message
+            else {
+                return None;
+            };
+            convo
+                .members
+                .iter()
+                .find(|member| member.did == message.sender.did)
+                .map(|member| member.handle.as_str().to_string())
+        });
+    Ok((count, sender))
+}
@@ -0,0 +1,108 @@
+use super::super::types::FeedSourceInfo;
+use super::super::{Watch, Watcher, WatcherTasks};
+use bsky_sdk::api::app::bsky::feed::defs::{FeedViewPost, FeedViewPostReasonRefs};
+use bsky_sdk::api::types::string::Datetime;
+use bsky_sdk::api::types::Union;
+use futures_util::future::select_all;
+use std::collections::HashSet;
+use tokio::sync::{broadcast, watch};
+
+impl Watcher {
+    /// Watch several feeds as a single one, from the newest post of all of them.
+    pub fn combined_feed(
+        &self,
+        feed_infos: Vec<FeedSourceInfo>,
+    ) -> impl Watch<Output = Vec<FeedViewPost>> {
+        let (tx, _) = broadcast::channel(1);
+        CombinedFeedWatcher {
+            tasks: self.tasks.clone(),
+            watchers: feed_infos
+                .into_iter()
+                .map(|feed_info| self.feed(feed_info))
+                .collect(),
+            tx,
+        }
+    }
+}
+
+pub struct CombinedFeedWatcher<W> {
+    tasks: WatcherTasks,
+    watchers: Vec<W>,
+    tx: broadcast::Sender<()>,
+}
+
+impl<W> Watch for CombinedFeedWatcher<W>
+where
+    W: Watch<Output = Vec<FeedViewPost>>,
+{
+    type Output = Vec<FeedViewPost>;
+
+    fn subscribe(&self) -> watch::Receiver<Self::Output> {
+        let mut receivers = self
+            .watchers
+            .iter()
+            .map(Watch::subscribe)
+            .collect::<Vec<_>>();
+        let (tx, rx) = watch::channel(Vec::new());
+        let mut quit = self.tx.subscribe();
+        self.tasks.spawn(async move {
+            while !receivers.is_empty() {
+                let changed = select_all(
+                    receivers
+                        .iter_mut()
+                        .map(|receiver| Box::pin(receiver.changed())),
+                );
+                let closed = tokio::select! {
+                    (result, index, _) = changed => result.is_err().then_some(index),
+                    _ = quit.recv() => {
+                        break;
+                    }
+                };
+                if let Some(index) = closed {
+                    log::warn!("feed channel closed");
+                    receivers.remove(index);
+                    continue;
+                }
+                let feeds = receivers
+                    .iter_mut()
+                    .map(|receiver| receiver.borrow_and_update().clone())
+                    .collect::<Vec<_>>();
+                if tx.send(merge(feeds)).is_err() {
+                    break;
+                }
+            }
+            log::debug!("quit");
+        });
+        rx
+    }
+    fn unsubscribe(&self) {
+        if let Err(e) = self.tx.send(()) {
+            log::error!("failed to send quit: {e}");
+        }
+        for watcher in &self.watchers {
+            watcher.unsubscribe();
+        }
+    }
+    fn refresh(&self) {
+        for watcher in &self.watchers {
+            watcher.refresh();
+        }
+    }
+}
+
+/// Merge the feeds from the newest, keeping only the first appearance of each post.
+fn merge(feeds: Vec<Vec<FeedViewPost>>) -> Vec<FeedViewPost> {
+    let mut ret = feeds.into_iter().flatten().collect::<Vec<_>>();
+    ret.sort_by(|a, b| sort_at(b).cmp(sort_at(a)));
+    let mut seen = HashSet::new();
+    ret.retain(|feed_view_post| seen.insert(feed_view_post.post.cid.clone()));
+    ret
+}
+
+/// The time the post appeared in the feed: when it was reposted, or when it was indexed.
+fn sort_at(feed_view_post: &FeedViewPost) -> &Datetime {
+    match &feed_view_post.reason {
+        Some(Union::Refs(FeedViewPostReasonRefs::ReasonRepost(repost))) => &repost.indexed_at,
+        _ => &feed_view_post.post.indexed_at,
+    }
+}
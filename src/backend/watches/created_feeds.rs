@@ -0,0 +1,99 @@
+use super::super::{Watch, Watcher, WatcherTasks};
+use crate::backend::client::BskyAgent;
+use bsky_sdk::api::app::bsky::feed::defs::GeneratorView;
+use bsky_sdk::api::types::string::{AtIdentifier, Did};
+use bsky_sdk::Result;
+use std::sync::Arc;
+use tokio::sync::{broadcast, watch};
+
+impl Watcher {
+    pub fn created_feeds(&self, actor: Did) -> impl Watch<Output = Vec<GeneratorView>> {
+        let (tx, _) = broadcast::channel(1);
+        CreatedFeedsWatcher {
+            tasks: self.tasks.clone(),
+            actor,
+            agent: self.agent.clone(),
+            tx,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Command {
+    Quit,
+    Refresh,
+}
+
+pub struct CreatedFeedsWatcher {
+    tasks: WatcherTasks,
+    actor: Did,
+    agent: Arc<BskyAgent>,
+    tx: broadcast::Sender<Command>,
+}
+
+impl Watch for CreatedFeedsWatcher {
+    type Output = Vec<GeneratorView>;
+
+    fn subscribe(&self) -> watch::Receiver<Self::Output> {
+        let (tx, rx) = watch::channel(Vec::new());
+        let (agent, actor) = (self.agent.clone(), Arc::new(self.actor.clone()));
+        let mut command = self.tx.subscribe();
+        self.tasks.spawn(async move {
+            update(&agent, &actor, &tx).await;
+            while let Ok(command) = command.recv().await {
+                match command {
+                    Command::Refresh => {
+                        let (agent, actor, tx) = (agent.clone(), actor.clone(), tx.clone());
+                        tokio::spawn(async move {
+                            update(&agent, &actor, &tx).await;
+                        });
+                    }
+                    Command::Quit => {
+                        break;
+                    }
+                }
+            }
+        });
+        rx
+    }
+    fn unsubscribe(&self) {
+        if let Err(e) = self.tx.send(Command::Quit) {
+            log::error!("failed to send quit command: {e}");
+        }
+    }
+    fn refresh(&self) {
+        if let Err(e) = self.tx.send(Command::Refresh) {
+            log::error!("failed to send refresh command: {e}");
+        }
+    }
+}
+
+async fn update(agent: &BskyAgent, actor: &Did, tx: &watch::Sender<Vec<GeneratorView>>) {
+    match get_actor_feeds(agent, actor).await {
+        Ok(feeds) => {
+            tx.send(feeds).ok();
+        }
+        Err(e) => {
+            log::error!("failed to get actor feeds: {e}");
+        }
+    }
+}
+
+async fn get_actor_feeds(agent: &BskyAgent, actor: &Did) -> Result<Vec<GeneratorView>> {
+    Ok(agent
+        .api
+        .app
+        .bsky
+        .feed
+        .get_actor_feeds(
+            bsky_sdk::api::app::bsky::feed::get_actor_feeds::ParametersData {
+                actor: AtIdentifier::Did(actor.clone()),
+                cursor: None,
+                limit: None,
+            }
+            .into(),
+        )
+        .await?
+        .data
+        .feeds)
+}
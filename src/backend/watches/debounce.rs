@@ -0,0 +1,123 @@
+use super::super::Watch;
+use std::time::Duration;
+use tokio::sync::{broadcast, watch};
+use tokio::time;
+
+/// Default window of [`DebouncedWatch`].
+pub const DEBOUNCE_WINDOW: Duration = Duration::from_millis(200);
+
+/// Wraps a [`Watch`] and coalesces the updates received within `window` into a single one,
+/// always propagating the last value.
+pub struct DebouncedWatch<W> {
+    inner: W,
+    window: Duration,
+    tx: broadcast::Sender<()>,
+}
+
+impl<W> DebouncedWatch<W> {
+    pub fn new(inner: W, window: Duration) -> Self {
+        let (tx, _) = broadcast::channel(1);
+        Self { inner, window, tx }
+    }
+}
+
+impl<W> Watch for DebouncedWatch<W>
+where
+    W: Watch,
+    W::Output: Clone + Send + Sync + 'static,
+{
+    type Output = W::Output;
+
+    fn subscribe(&self) -> watch::Receiver<Self::Output> {
+        let mut inner = self.inner.subscribe();
+        let (tx, rx) = watch::channel(inner.borrow_and_update().clone());
+        let (window, mut quit) = (self.window, self.tx.subscribe());
+        tokio::spawn(async move {
+            loop {
+                tokio::select! {
+                    changed = inner.changed() => {
+                        if changed.is_err() {
+                            break log::warn!("inner channel closed");
+                        }
+                    }
+                    _ = quit.recv() => {
+                        break;
+                    }
+                }
+                // wait for the window to close, collecting all the updates in the meantime
+                tokio::select! {
+                    _ = time::sleep(window) => {}
+                    _ = quit.recv() => {
+                        break;
+                    }
+                }
+                if tx.send(inner.borrow_and_update().clone()).is_err() {
+                    break;
+                }
+            }
+            log::debug!("quit");
+        });
+        rx
+    }
+    fn unsubscribe(&self) {
+        if let Err(e) = self.tx.send(()) {
+            log::error!("failed to send quit: {e}");
+        }
+        self.inner.unsubscribe();
+    }
+    fn refresh(&self) {
+        self.inner.refresh();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WINDOW: Duration = Duration::from_millis(50);
+
+    struct MockWatcher {
+        tx: watch::Sender<u32>,
+    }
+
+    impl Watch for MockWatcher {
+        type Output = u32;
+
+        fn subscribe(&self) -> watch::Receiver<u32> {
+            self.tx.subscribe()
+        }
+        fn unsubscribe(&self) {}
+        fn refresh(&self) {}
+    }
+
+    fn debounced() -> (watch::Sender<u32>, DebouncedWatch<MockWatcher>) {
+        let (tx, _) = watch::channel(0);
+        let inner = MockWatcher { tx: tx.clone() };
+        (tx, DebouncedWatch::new(inner, WINDOW))
+    }
+
+    #[tokio::test]
+    async fn coalesce_updates() {
+        let (tx, watcher) = debounced();
+        let mut rx = watcher.subscribe();
+        for value in 1..=3 {
+            tx.send(value).expect("failed to send");
+            tokio::task::yield_now().await;
+        }
+        rx.changed().await.expect("failed to receive");
+        assert_eq!(*rx.borrow_and_update(), 3);
+        // no more updates after the window is closed
+        assert!(time::timeout(WINDOW * 2, rx.changed()).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn separate_updates() {
+        let (tx, watcher) = debounced();
+        let mut rx = watcher.subscribe();
+        for value in 1..=2 {
+            tx.send(value).expect("failed to send");
+            rx.changed().await.expect("failed to receive");
+            assert_eq!(*rx.borrow_and_update(), value);
+        }
+    }
+}
@@ -0,0 +1,127 @@
+use super::super::types::FeedSourceInfo;
+use super::super::{Watch, Watcher};
+use bsky_sdk::api::app::bsky::feed::defs::FeedViewPost;
+use chrono::{DateTime, DurationRound, Local, TimeDelta, TimeZone};
+use std::time::Duration;
+use tokio::sync::{broadcast, watch};
+use tokio::time::{self, Instant};
+
+impl Watcher {
+    /// Watch the feed of any source, updated once an hour at the top of the hour.
+    pub fn digest_feed_source(
+        &self,
+        feed_info: FeedSourceInfo,
+    ) -> Box<dyn Watch<Output = Vec<FeedViewPost>>> {
+        Box::new(DigestWatcher::new(self.feed_source(feed_info)))
+    }
+}
+
+/// The start of the hour after `now`.
+fn next_hour<Tz: TimeZone>(now: DateTime<Tz>) -> DateTime<Tz> {
+    let hour = TimeDelta::hours(1);
+    now.clone()
+        .duration_trunc(hour)
+        .map_or(now + hour, |start| start + hour)
+}
+
+fn next_emit() -> Instant {
+    let now = Local::now();
+    Instant::now() + (next_hour(now) - now).to_std().unwrap_or(Duration::ZERO)
+}
+
+/// Wraps a feed [`Watch`] and holds its updates until the top of the next hour.
+///
+/// The first feed is sent as soon as it is received, so that the column is not empty until then.
+/// Since each update is the whole feed, the last one received within the hour has all the posts.
+pub struct DigestWatcher<W> {
+    inner: W,
+    tx: broadcast::Sender<()>,
+}
+
+impl<W> DigestWatcher<W> {
+    pub fn new(inner: W) -> Self {
+        let (tx, _) = broadcast::channel(1);
+        Self { inner, tx }
+    }
+}
+
+impl<W> Watch for DigestWatcher<W>
+where
+    W: Watch<Output = Vec<FeedViewPost>>,
+{
+    type Output = Vec<FeedViewPost>;
+
+    fn subscribe(&self) -> watch::Receiver<Self::Output> {
+        let mut inner = self.inner.subscribe();
+        let (tx, rx) = watch::channel(inner.borrow_and_update().clone());
+        let mut quit = self.tx.subscribe();
+        tokio::spawn(async move {
+            let mut sent = !tx.borrow().is_empty();
+            let mut pending: Option<Vec<FeedViewPost>> = None;
+            let mut next_emit = next_emit();
+            loop {
+                tokio::select! {
+                    changed = inner.changed() => {
+                        if changed.is_err() {
+                            break log::warn!("inner channel closed");
+                        }
+                        let feed = inner.borrow_and_update().clone();
+                        if sent {
+                            pending = Some(feed);
+                        } else {
+                            sent = !feed.is_empty();
+                            if tx.send(feed).is_err() {
+                                break;
+                            }
+                        }
+                    }
+                    _ = time::sleep_until(next_emit) => {
+                        next_emit = self::next_emit();
+                        if let Some(feed) = pending.take() {
+                            if tx.send(feed).is_err() {
+                                break;
+                            }
+                        }
+                    }
+                    _ = quit.recv() => {
+                        break;
+                    }
+                }
+            }
+            log::debug!("quit");
+        });
+        rx
+    }
+    fn unsubscribe(&self) {
+        if let Err(e) = self.tx.send(()) {
+            log::error!("failed to send quit: {e}");
+        }
+        self.inner.unsubscribe();
+    }
+    fn refresh(&self) {
+        self.inner.refresh();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    #[test]
+    fn top_of_next_hour() {
+        let at = |s: &str| {
+            DateTime::parse_from_rfc3339(s)
+                .expect("invalid datetime")
+                .with_timezone(&Utc)
+        };
+        assert_eq!(
+            next_hour(at("2024-09-10T12:34:56Z")),
+            at("2024-09-10T13:00:00Z")
+        );
+        assert_eq!(
+            next_hour(at("2024-09-10T23:00:00Z")),
+            at("2024-09-11T00:00:00Z")
+        );
+    }
+}
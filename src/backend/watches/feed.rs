@@ -1,14 +1,22 @@
+use super::super::discover::DISCOVER_FEED;
+use super::super::posts::direct_replies;
+use super::super::rate_limit::RateLimit;
+use super::super::skeleton::get_feed_skeleton;
 use super::super::types::FeedSourceInfo;
-use super::super::{Watch, Watcher};
+use super::super::{PostHistory, Watch, Watcher, WatcherMetrics, WatcherTasks};
+use super::debounce::{DebouncedWatch, DEBOUNCE_WINDOW};
+use crate::backend::client::BskyAgent;
 use bsky_sdk::api::app::bsky::feed::defs::{
     FeedViewPost, FeedViewPostReasonRefs, PostViewEmbedRefs, ReplyRefParentRefs,
 };
-use bsky_sdk::api::types::string::Cid;
+use bsky_sdk::api::records::{KnownRecord, Record};
+use bsky_sdk::api::types::string::{AtIdentifier, Cid};
 use bsky_sdk::api::types::Union;
 use bsky_sdk::moderation::decision::DecisionContext;
+use bsky_sdk::moderation::Moderator;
+use bsky_sdk::preference::Preferences;
 use bsky_sdk::preference::{FeedViewPreference, FeedViewPreferenceData};
-use bsky_sdk::Result;
-use bsky_sdk::{preference::Preferences, BskyAgent};
+use color_eyre::{eyre, Result};
 use indexmap::IndexMap;
 use std::sync::Arc;
 use std::time::Duration;
@@ -18,29 +26,55 @@ use tokio::time;
 impl Watcher {
     pub fn feed(&self, feed_info: FeedSourceInfo) -> impl Watch<Output = Vec<FeedViewPost>> {
         let (tx, _) = broadcast::channel(1);
+        let firehose = (self.config.firehose
+            && matches!(
+                feed_info,
+                FeedSourceInfo::Timeline(_) | FeedSourceInfo::Mutuals(_)
+            ))
+        .then(|| self.firehose());
+        // suggested content changes slowly
+        let period = if feed_info == FeedSourceInfo::Suggested {
+            self.config.intervals.suggested
+        } else {
+            self.config.intervals.feed
+        };
         FeedWatcher {
+            tasks: self.tasks.clone(),
+            label: feed_info.label(),
             feed_info,
             agent: self.agent.clone(),
-            preferences: self.preferences(),
-            period: Duration::from_secs(self.config.intervals.feed),
+            // coalesce preference changes applied in quick succession
+            preferences: DebouncedWatch::new(self.preferences(), DEBOUNCE_WINDOW),
+            period: Duration::from_secs(period),
             tx,
             current: Default::default(),
+            metrics: self.metrics.clone(),
+            history: self.history.clone(),
+            rate_limit: self.rate_limit.clone(),
+            firehose,
         }
     }
 }
 
-pub struct FeedWatcher<W> {
+pub struct FeedWatcher<W, F> {
+    tasks: WatcherTasks,
+    label: String,
     feed_info: FeedSourceInfo,
     agent: Arc<BskyAgent>,
     preferences: W,
     period: Duration,
     tx: broadcast::Sender<()>,
     current: Arc<Mutex<IndexMap<Cid, FeedViewPost>>>,
+    metrics: WatcherMetrics,
+    history: PostHistory,
+    rate_limit: RateLimit,
+    firehose: Option<F>,
 }
 
-impl<W> Watch for FeedWatcher<W>
+impl<W, F> Watch for FeedWatcher<W, F>
 where
     W: Watch<Output = Preferences>,
+    F: Watch<Output = Vec<FeedViewPost>>,
 {
     type Output = Vec<FeedViewPost>;
 
@@ -51,10 +85,16 @@ where
             current: self.current.clone(),
             feed_info: Arc::new(self.feed_info.clone()),
             tx,
+            label: Arc::new(self.label.clone()),
+            metrics: self.metrics.clone(),
+            history: self.history.clone(),
+            rate_limit: self.rate_limit.clone(),
         };
+        self.metrics.subscribe(&self.label);
         let (mut preferences, mut quit) = (self.preferences.subscribe(), self.tx.subscribe());
+        let mut firehose = self.firehose.as_ref().map(Watch::subscribe);
         let mut interval = time::interval(self.period);
-        tokio::spawn(async move {
+        self.tasks.spawn(async move {
             // skip the first tick
             interval.tick().await;
             loop {
@@ -71,6 +111,18 @@ where
                             break log::warn!("preferences channel closed");
                         }
                     }
+                    posts = changed_posts(&mut firehose) => {
+                        let Some(posts) = posts else {
+                            log::warn!("firehose channel closed");
+                            firehose = None;
+                            continue;
+                        };
+                        let preferences = preferences.borrow().clone();
+                        let updater = updater.clone();
+                        tokio::spawn(async move {
+                            updater.push(&posts, &preferences).await;
+                        });
+                    }
                     _ = tick => {
                         let preferences = preferences.borrow().clone();
                         let updater = updater.clone();
@@ -91,41 +143,91 @@ where
         if let Err(e) = self.tx.send(()) {
             log::error!("failed to send quit: {e}");
         }
+        self.metrics.unsubscribe(&self.label);
         self.preferences.unsubscribe();
+        if let Some(firehose) = &self.firehose {
+            firehose.unsubscribe();
+        }
     }
     fn refresh(&self) {
         self.preferences.refresh();
     }
 }
 
+/// Wait for the next posts from the firehose, or forever if there is none.
+async fn changed_posts(
+    firehose: &mut Option<watch::Receiver<Vec<FeedViewPost>>>,
+) -> Option<Vec<FeedViewPost>> {
+    let Some(rx) = firehose else {
+        return std::future::pending().await;
+    };
+    rx.changed().await.ok()?;
+    let posts = rx.borrow_and_update().clone();
+    Some(posts)
+}
+
 #[derive(Clone)]
 struct Updater {
     agent: Arc<BskyAgent>,
     current: Arc<Mutex<IndexMap<Cid, FeedViewPost>>>,
     feed_info: Arc<FeedSourceInfo>,
     tx: watch::Sender<Vec<FeedViewPost>>,
+    label: Arc<String>,
+    metrics: WatcherMetrics,
+    history: PostHistory,
+    rate_limit: RateLimit,
 }
 
 impl Updater {
     async fn update(&self, preferences: &Preferences) {
+        // a request over the limit would only be rejected with 429
+        if !self.rate_limit.try_acquire() {
+            log::info!("rate limit reached, skipping the update of {}", self.label);
+            return;
+        }
         match self.calculate_feed(preferences).await {
             Ok(feed) => {
+                self.metrics.success(&self.label);
                 self.tx.send(feed).ok();
             }
             Err(e) => {
+                self.metrics.error(&self.label);
                 log::error!("failed to get feed view posts: {e}");
             }
         }
     }
+    /// Add the posts received from the firehose to the current feed.
+    async fn push(&self, posts: &[FeedViewPost], preferences: &Preferences) {
+        match self.agent.moderator(preferences).await {
+            Ok(moderator) => {
+                let feed = self.merge_feed(posts, &moderator, preferences).await;
+                self.tx.send(feed).ok();
+            }
+            Err(e) => {
+                log::error!("failed to get moderator: {e}");
+            }
+        }
+    }
     async fn calculate_feed(&self, preferences: &Preferences) -> Result<Vec<FeedViewPost>> {
         // TODO: It should not be necessary to get moderator every time unless moderation_prefs has been changed?
         let (moderator, feed) = tokio::join!(self.agent.moderator(preferences), self.get_feed());
         let moderator = moderator?;
         let mut feed = feed?;
         feed.reverse();
+        Ok(self.merge_feed(&feed, &moderator, preferences).await)
+    }
+    /// Merge `feed` (in chronological order) into the current feed,
+    /// and return the posts to show from the newest.
+    async fn merge_feed(
+        &self,
+        feed: &[FeedViewPost],
+        moderator: &Moderator,
+        preferences: &Preferences,
+    ) -> Vec<FeedViewPost> {
         let mut ret = {
             let mut feed_map = self.current.lock().await;
-            update_feeds(&feed, &mut feed_map);
+            record_edits(feed, &mut feed_map, &self.history);
+            update_feeds(feed, &mut feed_map);
             feed_map.values().rev().cloned().collect::<Vec<_>>()
         };
         // filter by moderator
@@ -136,7 +238,10 @@ impl Updater {
             !ui.filter()
         });
         // filter by preferences (following timeline only)
-        if matches!(self.feed_info.as_ref(), FeedSourceInfo::Timeline(_)) {
+        if matches!(
+            self.feed_info.as_ref(),
+            FeedSourceInfo::Timeline(_) | FeedSourceInfo::Mutuals(_)
+        ) {
             let pref = if let Some(pref) = preferences.feed_view_prefs.get("home") {
                 pref.clone()
             } else {
@@ -144,10 +249,25 @@ impl Updater {
             };
             ret.retain(|feed_view_post| filter_feed(feed_view_post, &pref));
         }
-        Ok(ret)
+        if matches!(self.feed_info.as_ref(), FeedSourceInfo::Mutuals(_)) {
+            ret.retain(is_mutual);
+        }
+        // the opposite of the preferences: only the reposts of the follows, regardless of them
+        if matches!(self.feed_info.as_ref(), FeedSourceInfo::Activity(_)) {
+            ret.retain(|feed_view_post| {
+                matches!(
+                    feed_view_post.reason,
+                    Some(Union::Refs(FeedViewPostReasonRefs::ReasonRepost(_)))
+                )
+            });
+        }
+        ret
     }
     async fn get_feed(&self) -> Result<Vec<FeedViewPost>> {
         Ok(match self.feed_info.as_ref() {
+            FeedSourceInfo::Skeleton(generator_view) => {
+                get_feed_skeleton(&self.agent, generator_view).await?
+            }
             FeedSourceInfo::Feed(generator_view) => {
                 self.agent
                     .api
@@ -166,6 +286,24 @@ impl Updater {
                     .data
                     .feed
             }
+            FeedSourceInfo::Suggested => {
+                self.agent
+                    .api
+                    .app
+                    .bsky
+                    .feed
+                    .get_feed(
+                        bsky_sdk::api::app::bsky::feed::get_feed::ParametersData {
+                            cursor: None,
+                            feed: String::from(DISCOVER_FEED),
+                            limit: 30.try_into().ok(),
+                        }
+                        .into(),
+                    )
+                    .await?
+                    .data
+                    .feed
+            }
             FeedSourceInfo::List(list_view) => {
                 self.agent
                     .api
@@ -184,7 +322,9 @@ impl Updater {
                     .data
                     .feed
             }
-            FeedSourceInfo::Timeline(_) => {
+            FeedSourceInfo::Timeline(_)
+            | FeedSourceInfo::Mutuals(_)
+            | FeedSourceInfo::Activity(_) => {
                 self.agent
                     .api
                     .app
@@ -202,10 +342,78 @@ impl Updater {
                     .data
                     .feed
             }
+            FeedSourceInfo::Own | FeedSourceInfo::Author(_) => {
+                let actor = if let FeedSourceInfo::Author(actor) = self.feed_info.as_ref() {
+                    actor.parse().map_err(|e| eyre::eyre!("{e}: {actor}"))?
+                } else {
+                    AtIdentifier::Did(
+                        self.agent
+                            .get_session()
+                            .await
+                            .ok_or(bsky_sdk::Error::NotLoggedIn)?
+                            .data
+                            .did,
+                    )
+                };
+                self.agent
+                    .api
+                    .app
+                    .bsky
+                    .feed
+                    .get_author_feed(
+                        bsky_sdk::api::app::bsky::feed::get_author_feed::ParametersData {
+                            actor,
+                            cursor: None,
+                            filter: None,
+                            limit: 30.try_into().ok(),
+                        }
+                        .into(),
+                    )
+                    .await?
+                    .data
+                    .feed
+            }
+            FeedSourceInfo::Conversation(uri) => direct_replies(&self.agent, uri).await?,
+            FeedSourceInfo::Combined(_) | FeedSourceInfo::ExternalLinks(_) => {
+                return Err(eyre::eyre!(
+                    "derived feeds must be watched with `Watcher::feed_source`"
+                ))
+            }
         })
     }
 }
 
+/// Keep the previous text of the posts whose record has changed, and drop their old versions.
+///
+/// A changed record has a new CID, so the versions are matched by URI.
+fn record_edits(
+    feed: &[FeedViewPost],
+    feed_map: &mut IndexMap<Cid, FeedViewPost>,
+    history: &PostHistory,
+) {
+    for feed_view_post in feed {
+        let Some(old) = feed_map
+            .values()
+            .find(|old| {
+                old.post.uri == feed_view_post.post.uri && old.post.cid != feed_view_post.post.cid
+            })
+            .map(|old| old.post.clone())
+        else {
+            continue;
+        };
+        if let (
+            Record::Known(KnownRecord::AppBskyFeedPost(prev)),
+            Record::Known(KnownRecord::AppBskyFeedPost(next)),
+        ) = (&old.record, &feed_view_post.post.record)
+        {
+            if prev.text != next.text {
+                history.record(&old.uri, prev.text.clone());
+            }
+        }
+        feed_map.shift_remove(&old.cid);
+    }
+}
+
 fn update_feeds(feed: &[FeedViewPost], feed_map: &mut IndexMap<Cid, FeedViewPost>) {
     for post in feed {
         if let Some(entry) = feed_map.get_mut(&post.post.cid) {
@@ -230,6 +438,16 @@ fn update_feeds(feed: &[FeedViewPost], feed_map: &mut IndexMap<Cid, FeedViewPost
     }
 }
 
+/// Whether the author of the post and the logged-in user follow each other.
+fn is_mutual(feed_view_post: &FeedViewPost) -> bool {
+    feed_view_post
+        .post
+        .author
+        .viewer
+        .as_ref()
+        .is_some_and(|viewer| viewer.following.is_some() && viewer.followed_by.is_some())
+}
+
 fn filter_feed(feed_view_post: &FeedViewPost, pref: &FeedViewPreference) -> bool {
     // is repost?
     if matches!(
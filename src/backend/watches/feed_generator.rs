@@ -0,0 +1,122 @@
+use super::super::types::FeedGeneratorDetail;
+use super::super::{Watch, Watcher, WatcherTasks};
+use crate::backend::client::BskyAgent;
+use bsky_sdk::preference::Preferences;
+use bsky_sdk::Result;
+use std::sync::Arc;
+use tokio::sync::{broadcast, watch};
+
+impl Watcher {
+    pub fn feed_generator(&self, uri: String) -> impl Watch<Output = Option<FeedGeneratorDetail>> {
+        let (tx, _) = broadcast::channel(1);
+        FeedGeneratorWatcher {
+            tasks: self.tasks.clone(),
+            uri,
+            agent: self.agent.clone(),
+            preferences: self.preferences(),
+            tx,
+        }
+    }
+}
+
+pub struct FeedGeneratorWatcher<W> {
+    tasks: WatcherTasks,
+    uri: String,
+    agent: Arc<BskyAgent>,
+    preferences: W,
+    tx: broadcast::Sender<()>,
+}
+
+impl<W> Watch for FeedGeneratorWatcher<W>
+where
+    W: Watch<Output = Preferences>,
+{
+    type Output = Option<FeedGeneratorDetail>;
+
+    fn subscribe(&self) -> watch::Receiver<Self::Output> {
+        let (tx, rx) = watch::channel(None);
+        let (agent, uri) = (self.agent.clone(), Arc::new(self.uri.clone()));
+        let (mut preferences, mut quit) = (self.preferences.subscribe(), self.tx.subscribe());
+        self.tasks.spawn(async move {
+            loop {
+                tokio::select! {
+                    changed = preferences.changed() => {
+                        if changed.is_ok() {
+                            let preferences = preferences.borrow_and_update().clone();
+                            let (agent, uri, tx) = (agent.clone(), uri.clone(), tx.clone());
+                            tokio::spawn(async move {
+                                update(&agent, &uri, &preferences, &tx).await;
+                            });
+                        } else {
+                            break log::warn!("preferences channel closed");
+                        }
+                    }
+                    _ = quit.recv() => {
+                        break;
+                    }
+                }
+            }
+        });
+        rx
+    }
+    fn unsubscribe(&self) {
+        if let Err(e) = self.tx.send(()) {
+            log::error!("failed to send quit: {e}");
+        }
+        self.preferences.unsubscribe();
+    }
+    fn refresh(&self) {
+        self.preferences.refresh();
+    }
+}
+
+async fn update(
+    agent: &BskyAgent,
+    uri: &str,
+    preferences: &Preferences,
+    tx: &watch::Sender<Option<FeedGeneratorDetail>>,
+) {
+    match get_feed_generator(agent, uri, preferences).await {
+        Ok(detail) => {
+            tx.send(Some(detail)).ok();
+        }
+        Err(e) => {
+            log::error!("failed to get feed generator: {e}");
+        }
+    }
+}
+
+async fn get_feed_generator(
+    agent: &BskyAgent,
+    uri: &str,
+    preferences: &Preferences,
+) -> Result<FeedGeneratorDetail> {
+    let (generator, feed) = tokio::join!(
+        agent.api.app.bsky.feed.get_feed_generator(
+            bsky_sdk::api::app::bsky::feed::get_feed_generator::ParametersData {
+                feed: uri.to_string(),
+            }
+            .into(),
+        ),
+        agent.api.app.bsky.feed.get_feed(
+            bsky_sdk::api::app::bsky::feed::get_feed::ParametersData {
+                cursor: None,
+                feed: uri.to_string(),
+                limit: 5.try_into().ok(),
+            }
+            .into(),
+        )
+    );
+    let generator = generator?;
+    Ok(FeedGeneratorDetail {
+        view: generator.data.view,
+        is_online: generator.data.is_online,
+        is_valid: generator.data.is_valid,
+        saved: preferences
+            .saved_feeds
+            .iter()
+            .any(|saved_feed| saved_feed.value == uri),
+        // the sample posts are optional: an offline generator should not hide its details
+        posts: feed.map(|output| output.data.feed).unwrap_or_default(),
+    })
+}
@@ -0,0 +1,98 @@
+use super::super::types::FeedSourceInfo;
+use super::super::{Watch, Watcher};
+use bsky_sdk::api::app::bsky::feed::defs::{FeedViewPost, PostViewEmbedRefs};
+use bsky_sdk::api::types::Union;
+use tokio::sync::{broadcast, watch};
+
+impl Watcher {
+    /// Watch the feed of any source, including the ones built on the other sources.
+    pub fn feed_source(
+        &self,
+        feed_info: FeedSourceInfo,
+    ) -> Box<dyn Watch<Output = Vec<FeedViewPost>>> {
+        match feed_info {
+            FeedSourceInfo::Combined(feed_infos) => Box::new(self.combined_feed(feed_infos)),
+            FeedSourceInfo::ExternalLinks(feed_info) => Box::new(FilteredFeedWatcher::new(
+                self.feed_source(*feed_info),
+                has_external_link,
+            )),
+            _ => Box::new(self.feed(feed_info)),
+        }
+    }
+}
+
+fn has_external_link(feed_view_post: &FeedViewPost) -> bool {
+    matches!(
+        feed_view_post.post.embed,
+        Some(Union::Refs(PostViewEmbedRefs::AppBskyEmbedExternalView(_)))
+    )
+}
+
+/// Wraps a feed [`Watch`] and keeps only the posts matching `predicate`, without fetching
+/// anything else.
+pub struct FilteredFeedWatcher<W, F> {
+    inner: W,
+    predicate: F,
+    tx: broadcast::Sender<()>,
+}
+
+impl<W, F> FilteredFeedWatcher<W, F> {
+    pub fn new(inner: W, predicate: F) -> Self {
+        let (tx, _) = broadcast::channel(1);
+        Self {
+            inner,
+            predicate,
+            tx,
+        }
+    }
+}
+
+impl<W, F> Watch for FilteredFeedWatcher<W, F>
+where
+    W: Watch<Output = Vec<FeedViewPost>>,
+    F: Fn(&FeedViewPost) -> bool + Clone + Send + 'static,
+{
+    type Output = Vec<FeedViewPost>;
+
+    fn subscribe(&self) -> watch::Receiver<Self::Output> {
+        let mut inner = self.inner.subscribe();
+        let predicate = self.predicate.clone();
+        let filter = move |feed: &[FeedViewPost]| {
+            feed.iter()
+                .filter(|feed_view_post| predicate(feed_view_post))
+                .cloned()
+                .collect::<Vec<_>>()
+        };
+        let (tx, rx) = watch::channel(filter(&inner.borrow_and_update()));
+        let mut quit = self.tx.subscribe();
+        tokio::spawn(async move {
+            loop {
+                tokio::select! {
+                    changed = inner.changed() => {
+                        if changed.is_err() {
+                            break log::warn!("inner channel closed");
+                        }
+                        let feed = filter(&inner.borrow_and_update());
+                        if tx.send(feed).is_err() {
+                            break;
+                        }
+                    }
+                    _ = quit.recv() => {
+                        break;
+                    }
+                }
+            }
+            log::debug!("quit");
+        });
+        rx
+    }
+    fn unsubscribe(&self) {
+        if let Err(e) = self.tx.send(()) {
+            log::error!("failed to send quit: {e}");
+        }
+        self.inner.unsubscribe();
+    }
+    fn refresh(&self) {
+        self.inner.refresh();
+    }
+}
@@ -0,0 +1,270 @@
+use super::super::graph::follows;
+use super::super::{Watch, Watcher, WatcherTasks};
+use crate::backend::client::BskyAgent;
+use bsky_sdk::api::app::bsky::feed::defs::{FeedViewPost, FeedViewPostData};
+use bsky_sdk::api::types::string::Did;
+use color_eyre::Result;
+use futures_util::StreamExt;
+use serde::Deserialize;
+use std::collections::HashSet;
+use std::{sync::Arc, time::Duration};
+use tokio::sync::{broadcast, watch};
+use tokio::time;
+use tokio_tungstenite::tungstenite::Message;
+
+/// Jetstream endpoint, which delivers the commits of the network as JSON.
+const JETSTREAM_URL: &str = "wss://jetstream2.us-east.bsky.network/subscribe";
+/// The max number of the accounts in the `wantedDids` of a connection, to keep the URL short.
+const DIDS_PER_CONNECTION: usize = 200;
+/// The max number of the connections, above which all the posts are received and filtered here.
+const MAX_CONNECTIONS: usize = 5;
+/// How long a connection is kept before reconnecting with the follows fetched again.
+const FOLLOWS_REFRESH: Duration = Duration::from_secs(30 * 60);
+/// Time to wait before fetching a new post, to let the AppView index it.
+const INDEX_DELAY: Duration = Duration::from_secs(3);
+/// Number of the most recent posts kept in the output.
+const MAX_POSTS: usize = 30;
+const MIN_RECONNECT_DELAY: Duration = Duration::from_secs(1);
+const MAX_RECONNECT_DELAY: Duration = Duration::from_secs(60);
+
+impl Watcher {
+    /// Watch the new posts of the accounts followed by the logged-in user.
+    ///
+    /// The output holds the most recent posts, so that no post is lost even if the receiver
+    /// misses some updates. The follows are fetched on each connection, which is renewed every
+    /// 30 minutes to take the new follows into account.
+    pub fn firehose(&self) -> impl Watch<Output = Vec<FeedViewPost>> {
+        let (tx, _) = broadcast::channel(1);
+        FirehoseWatcher {
+            tasks: self.tasks.clone(),
+            agent: self.agent.clone(),
+            tx,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct Event {
+    did: Did,
+    commit: Option<Commit>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Commit {
+    operation: String,
+    collection: String,
+    rkey: String,
+    record: Option<serde_json::Value>,
+}
+
+struct FirehoseWatcher {
+    tasks: WatcherTasks,
+    agent: Arc<BskyAgent>,
+    tx: broadcast::Sender<()>,
+}
+
+impl Watch for FirehoseWatcher {
+    type Output = Vec<FeedViewPost>;
+
+    fn subscribe(&self) -> watch::Receiver<Self::Output> {
+        let (agent, tasks) = (self.agent.clone(), self.tasks.clone());
+        let mut quit = self.tx.subscribe();
+        let (tx, rx) = watch::channel(Vec::new());
+        self.tasks.spawn(async move {
+            let mut delay = MIN_RECONNECT_DELAY;
+            loop {
+                tokio::select! {
+                    result = connect(&agent, &tasks, &tx) => {
+                        match result {
+                            Ok(()) => {
+                                log::info!("firehose disconnected");
+                                delay = MIN_RECONNECT_DELAY;
+                            }
+                            Err(e) => {
+                                log::warn!("firehose error: {e}");
+                                delay = (delay * 2).min(MAX_RECONNECT_DELAY);
+                            }
+                        }
+                    }
+                    _ = quit.recv() => {
+                        break;
+                    }
+                }
+                tokio::select! {
+                    _ = time::sleep(delay) => {}
+                    _ = quit.recv() => {
+                        break;
+                    }
+                }
+            }
+            log::debug!("quit");
+        });
+        rx
+    }
+    fn unsubscribe(&self) {
+        if let Err(e) = self.tx.send(()) {
+            log::error!("failed to send quit: {e}");
+        }
+    }
+    fn refresh(&self) {}
+}
+
+/// The URLs of the connections receiving the posts of the follows.
+///
+/// The follows are split into several connections, or not passed at all if there are too many.
+fn jetstream_urls(follows: &HashSet<Did>) -> Vec<String> {
+    let url = format!("{JETSTREAM_URL}?wantedCollections=app.bsky.feed.post");
+    if follows.len() > DIDS_PER_CONNECTION * MAX_CONNECTIONS {
+        return vec![url];
+    }
+    let mut dids = follows.iter().map(Did::as_str).collect::<Vec<_>>();
+    dids.sort_unstable();
+    dids.chunks(DIDS_PER_CONNECTION)
+        .map(|chunk| {
+            chunk
+                .iter()
+                .fold(url.clone(), |url, did| format!("{url}&wantedDids={did}"))
+        })
+        .collect()
+}
+
+/// Receive the events until the connection is closed, or until the follows are refreshed.
+async fn connect(
+    agent: &Arc<BskyAgent>,
+    tasks: &WatcherTasks,
+    tx: &watch::Sender<Vec<FeedViewPost>>,
+) -> Result<()> {
+    let follows = follows(agent).await?;
+    let mut streams = Vec::new();
+    for url in jetstream_urls(&follows) {
+        streams.push(tokio_tungstenite::connect_async(url).await?.0);
+    }
+    log::info!(
+        "firehose connected with {} connections, following {} accounts",
+        streams.len(),
+        follows.len()
+    );
+    let mut stream = futures_util::stream::select_all(streams);
+    let refresh = time::sleep(FOLLOWS_REFRESH);
+    tokio::pin!(refresh);
+    loop {
+        let message = tokio::select! {
+            message = stream.next() => message,
+            () = &mut refresh => break,
+        };
+        // no connection without follows, until they are refreshed
+        let Some(message) = message else {
+            refresh.as_mut().await;
+            break;
+        };
+        let text = match message? {
+            Message::Text(text) => text,
+            Message::Close(_) => break,
+            _ => continue,
+        };
+        let event = match serde_json::from_str::<Event>(&text) {
+            Ok(event) => event,
+            Err(e) => {
+                log::warn!("failed to parse event: {e}");
+                continue;
+            }
+        };
+        if let Some(uri) = new_post_uri(&event, &follows) {
+            let (agent, tx) = (agent.clone(), tx.clone());
+            tasks.spawn(async move {
+                time::sleep(INDEX_DELAY).await;
+                if let Err(e) = push_post(&agent, uri, &tx).await {
+                    log::warn!("failed to get post: {e}");
+                }
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Returns the URI of the created post if it is a top-level post of a followed account.
+///
+/// Replies are left to the polling, as their context is only available from the timeline.
+fn new_post_uri(event: &Event, follows: &HashSet<Did>) -> Option<String> {
+    let commit = event.commit.as_ref()?;
+    if commit.operation != "create"
+        || commit.collection != "app.bsky.feed.post"
+        || !follows.contains(&event.did)
+        || commit
+            .record
+            .as_ref()
+            .map_or(true, |record| record.get("reply").is_some())
+    {
+        return None;
+    }
+    Some(format!(
+        "at://{}/{}/{}",
+        event.did.as_str(),
+        commit.collection,
+        commit.rkey
+    ))
+}
+
+async fn push_post(
+    agent: &BskyAgent,
+    uri: String,
+    tx: &watch::Sender<Vec<FeedViewPost>>,
+) -> Result<()> {
+    let posts = agent
+        .api
+        .app
+        .bsky
+        .feed
+        .get_posts(
+            bsky_sdk::api::app::bsky::feed::get_posts::ParametersData { uris: vec![uri] }.into(),
+        )
+        .await?
+        .data
+        .posts;
+    for post in posts {
+        tx.send_modify(|current| {
+            current.push(
+                FeedViewPostData {
+                    feed_context: None,
+                    post,
+                    reason: None,
+                    reply: None,
+                }
+                .into(),
+            );
+            if current.len() > MAX_POSTS {
+                current.remove(0);
+            }
+        });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn split_follows_into_connections() {
+        let follows = |n: usize| {
+            (0..n)
+                .map(|i| {
+                    format!("did:plc:{i:04}")
+                        .parse::<Did>()
+                        .expect("invalid did")
+                })
+                .collect::<HashSet<_>>()
+        };
+        assert!(jetstream_urls(&follows(0)).is_empty());
+        let urls = jetstream_urls(&follows(DIDS_PER_CONNECTION + 1));
+        assert_eq!(urls.len(), 2);
+        assert_eq!(urls[0].matches("&wantedDids=").count(), DIDS_PER_CONNECTION);
+        assert!(urls[1].ends_with("&wantedDids=did:plc:0200"), "{}", urls[1]);
+        assert_eq!(
+            jetstream_urls(&follows(DIDS_PER_CONNECTION * MAX_CONNECTIONS + 1)),
+            [format!(
+                "{JETSTREAM_URL}?wantedCollections=app.bsky.feed.post"
+            )]
+        );
+    }
+}
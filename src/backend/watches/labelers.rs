@@ -0,0 +1,148 @@
+use super::super::types::{LabelPolicy, LabelerSettings};
+use super::super::{Watch, Watcher, WatcherTasks};
+use crate::backend::client::BskyAgent;
+use bsky_sdk::api::app::bsky::labeler::get_services::OutputViewsItem;
+use bsky_sdk::api::types::Union;
+use bsky_sdk::Result;
+use std::sync::Arc;
+use tokio::sync::{broadcast, watch};
+
+impl Watcher {
+    pub fn labelers(&self) -> impl Watch<Output = Vec<LabelerSettings>> {
+        let (tx, _) = broadcast::channel(1);
+        LabelersWatcher {
+            tasks: self.tasks.clone(),
+            agent: self.agent.clone(),
+            tx,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Command {
+    Quit,
+    Refresh,
+}
+
+pub struct LabelersWatcher {
+    tasks: WatcherTasks,
+    agent: Arc<BskyAgent>,
+    tx: broadcast::Sender<Command>,
+}
+
+impl Watch for LabelersWatcher {
+    type Output = Vec<LabelerSettings>;
+
+    fn subscribe(&self) -> watch::Receiver<Self::Output> {
+        let (tx, rx) = watch::channel(Vec::new());
+        let agent = self.agent.clone();
+        let mut command = self.tx.subscribe();
+        self.tasks.spawn(async move {
+            update(&agent, &tx).await;
+            while let Ok(command) = command.recv().await {
+                match command {
+                    Command::Refresh => {
+                        let (agent, tx) = (agent.clone(), tx.clone());
+                        tokio::spawn(async move {
+                            update(&agent, &tx).await;
+                        });
+                    }
+                    Command::Quit => {
+                        break;
+                    }
+                }
+            }
+        });
+        rx
+    }
+    fn unsubscribe(&self) {
+        if let Err(e) = self.tx.send(Command::Quit) {
+            log::error!("failed to send quit command: {e}");
+        }
+    }
+    fn refresh(&self) {
+        if let Err(e) = self.tx.send(Command::Refresh) {
+            log::error!("failed to send refresh command: {e}");
+        }
+    }
+}
+
+async fn update(agent: &BskyAgent, tx: &watch::Sender<Vec<LabelerSettings>>) {
+    match get_labelers(agent).await {
+        Ok(labelers) => {
+            tx.send(labelers).ok();
+        }
+        Err(e) => {
+            log::error!("failed to get labelers: {e}");
+        }
+    }
+}
+
+async fn get_labelers(agent: &BskyAgent) -> Result<Vec<LabelerSettings>> {
+    let preferences = agent.get_preferences(true).await?;
+    let labelers = preferences.moderation_prefs.labelers;
+    let views = agent
+        .api
+        .app
+        .bsky
+        .labeler
+        .get_services(
+            bsky_sdk::api::app::bsky::labeler::get_services::ParametersData {
+                detailed: Some(true),
+                dids: labelers.iter().map(|labeler| labeler.did.clone()).collect(),
+            }
+            .into(),
+        )
+        .await?
+        .data
+        .views;
+    Ok(views
+        .into_iter()
+        .filter_map(|view| {
+            let Union::Refs(OutputViewsItem::AppBskyLabelerDefsLabelerViewDetailed(view)) = view
+            else {
+                return None;
+            };
+            let labels = labelers
+                .iter()
+                .find(|labeler| labeler.did == view.creator.did)
+                .map(|labeler| labeler.labels.clone())
+                .unwrap_or_default();
+            let definitions = view
+                .policies
+                .label_value_definitions
+                .clone()
+                .unwrap_or_default();
+            let policies = view
+                .policies
+                .label_values
+                .iter()
+                .map(|value| {
+                    let definition = definitions
+                        .iter()
+                        .find(|definition| &definition.identifier == value);
+                    LabelPolicy {
+                        identifier: value.clone(),
+                        name: definition
+                            .and_then(|definition| definition.locales.first())
+                            .map_or(value.clone(), |locale| locale.name.clone()),
+                        severity: definition
+                            .map(|definition| definition.severity.clone())
+                            .unwrap_or_default(),
+                        visibility: labels
+                            .get(value)
+                            .map(|pref| pref.as_ref().to_string())
+                            .or_else(|| {
+                                definition.and_then(|definition| definition.default_setting.clone())
+                            })
+                            .unwrap_or_else(|| String::from("warn")),
+                    }
+                })
+                .collect();
+            Some(LabelerSettings {
+                view: *view,
+                policies,
+            })
+        })
+        .collect())
+}
@@ -0,0 +1,95 @@
+use super::super::{Watch, Watcher, WatcherTasks};
+use crate::backend::client::BskyAgent;
+use bsky_sdk::api::app::bsky::graph::defs::ListView;
+use bsky_sdk::Result;
+use std::sync::Arc;
+use tokio::sync::{broadcast, watch};
+
+impl Watcher {
+    pub fn list_blocks(&self) -> impl Watch<Output = Vec<ListView>> {
+        let (tx, _) = broadcast::channel(1);
+        ListBlocksWatcher {
+            tasks: self.tasks.clone(),
+            agent: self.agent.clone(),
+            tx,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Command {
+    Quit,
+    Refresh,
+}
+
+pub struct ListBlocksWatcher {
+    tasks: WatcherTasks,
+    agent: Arc<BskyAgent>,
+    tx: broadcast::Sender<Command>,
+}
+
+impl Watch for ListBlocksWatcher {
+    type Output = Vec<ListView>;
+
+    fn subscribe(&self) -> watch::Receiver<Self::Output> {
+        let (tx, rx) = watch::channel(Vec::new());
+        let agent = self.agent.clone();
+        let mut command = self.tx.subscribe();
+        self.tasks.spawn(async move {
+            update(&agent, &tx).await;
+            while let Ok(command) = command.recv().await {
+                match command {
+                    Command::Refresh => {
+                        let (agent, tx) = (agent.clone(), tx.clone());
+                        tokio::spawn(async move {
+                            update(&agent, &tx).await;
+                        });
+                    }
+                    Command::Quit => {
+                        break;
+                    }
+                }
+            }
+        });
+        rx
+    }
+    fn unsubscribe(&self) {
+        if let Err(e) = self.tx.send(Command::Quit) {
+            log::error!("failed to send quit command: {e}");
+        }
+    }
+    fn refresh(&self) {
+        if let Err(e) = self.tx.send(Command::Refresh) {
+            log::error!("failed to send refresh command: {e}");
+        }
+    }
+}
+
+async fn update(agent: &BskyAgent, tx: &watch::Sender<Vec<ListView>>) {
+    match get_list_blocks(agent).await {
+        Ok(feeds) => {
+            tx.send(feeds).ok();
+        }
+        Err(e) => {
+            log::error!("failed to get list blocks: {e}");
+        }
+    }
+}
+
+async fn get_list_blocks(agent: &BskyAgent) -> Result<Vec<ListView>> {
+    Ok(agent
+        .api
+        .app
+        .bsky
+        .graph
+        .get_list_blocks(
+            bsky_sdk::api::app::bsky::graph::get_list_blocks::ParametersData {
+                cursor: None,
+                limit: None,
+            }
+            .into(),
+        )
+        .await?
+        .data
+        .lists)
+}
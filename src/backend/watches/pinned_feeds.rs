@@ -1,8 +1,9 @@
 use super::super::types::{FeedSourceInfo, PinnedFeed};
-use super::super::{Watch, Watcher};
+use super::super::{Watch, Watcher, WatcherTasks};
+use crate::backend::client::BskyAgent;
 use bsky_sdk::api::app::bsky::actor::defs::SavedFeed;
 use bsky_sdk::preference::Preferences;
-use bsky_sdk::{BskyAgent, Result};
+use bsky_sdk::Result;
 use futures_util::future;
 use std::collections::HashMap;
 use std::sync::Arc;
@@ -13,14 +14,20 @@ impl Watcher {
     pub fn pinned_feeds(&self) -> impl Watch<Output = Vec<PinnedFeed>> {
         let (tx, _) = broadcast::channel(1);
         PinnedFeedsWatcher {
+            tasks: self.tasks.clone(),
             agent: self.agent.clone(),
             preferences: self.preferences(),
             tx,
         }
     }
+    /// Resolve the pinned ones of `saved_feeds` to their sources, in the same order.
+    pub async fn resolve_feeds(&self, saved_feeds: &[SavedFeed]) -> Result<Vec<PinnedFeed>> {
+        collect_feeds(&self.agent, saved_feeds).await
+    }
 }
 
 pub struct PinnedFeedsWatcher<W> {
+    tasks: WatcherTasks,
     agent: Arc<BskyAgent>,
     preferences: W,
     tx: broadcast::Sender<()>,
@@ -37,7 +44,7 @@ where
         let agent = self.agent.clone();
         let mut quit = self.tx.subscribe();
         let mut preferences = self.preferences.subscribe();
-        tokio::spawn(async move {
+        self.tasks.spawn(async move {
             loop {
                 tokio::select! {
                     changed = preferences.changed() => {
@@ -146,15 +153,11 @@ async fn collect_feeds(agent: &BskyAgent, saved_feeds: &[SavedFeed]) -> Result<V
         match saved_feed.r#type.as_str() {
             "feed" | "list" => {
                 if let Some(info) = resolved.remove(&saved_feed.value) {
-                    ret.push(PinnedFeed {
-                        saved_feed: saved_feed.clone(),
-                        info,
-                    });
+                    ret.push(PinnedFeed { info });
                 }
             }
             "timeline" => {
                 ret.push(PinnedFeed {
-                    saved_feed: saved_feed.clone(),
                     info: FeedSourceInfo::Timeline(saved_feed.value.clone()),
                 });
             }
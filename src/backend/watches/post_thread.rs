@@ -1,9 +1,10 @@
-use super::super::{Watch, Watcher};
+use super::super::{Watch, Watcher, WatcherTasks};
+use crate::backend::client::BskyAgent;
 use bsky_sdk::api::app::bsky::feed::defs::NotFoundPostData;
 use bsky_sdk::api::app::bsky::feed::get_post_thread::OutputThreadRefs;
 use bsky_sdk::api::types::Union;
 use bsky_sdk::preference::Preferences;
-use bsky_sdk::{BskyAgent, Result};
+use bsky_sdk::Result;
 use std::sync::Arc;
 use std::time::Duration;
 use tokio::sync::{broadcast, watch};
@@ -13,6 +14,7 @@ impl Watcher {
     pub fn post_thread(&self, uri: String) -> impl Watch<Output = Union<OutputThreadRefs>> {
         let (tx, _) = broadcast::channel(1);
         PostThreadWatcher {
+            tasks: self.tasks.clone(),
             uri,
             agent: self.agent.clone(),
             preferences: self.preferences(),
@@ -23,6 +25,7 @@ impl Watcher {
 }
 
 pub struct PostThreadWatcher<W> {
+    tasks: WatcherTasks,
     uri: String,
     agent: Arc<BskyAgent>,
     preferences: W,
@@ -52,7 +55,7 @@ where
         };
         let (mut preferences, mut quit) = (self.preferences.subscribe(), self.tx.subscribe());
         let mut interval = time::interval(self.period);
-        tokio::spawn(async move {
+        self.tasks.spawn(async move {
             loop {
                 let tick = interval.tick();
                 tokio::select! {
@@ -1,5 +1,6 @@
-use super::super::{Watch, Watcher};
-use bsky_sdk::{preference::Preferences, BskyAgent};
+use super::super::{Watch, Watcher, WatcherTasks};
+use crate::backend::client::BskyAgent;
+use bsky_sdk::preference::Preferences;
 use std::{sync::Arc, time::Duration};
 use tokio::sync::{broadcast, watch};
 use tokio::time;
@@ -8,6 +9,7 @@ impl Watcher {
     pub fn preferences(&self) -> impl Watch<Output = Preferences> {
         let (tx, _) = broadcast::channel(1);
         PreferencesWatcher {
+            tasks: self.tasks.clone(),
             agent: self.agent.clone(),
             period: Duration::from_secs(self.config.intervals.preferences),
             tx,
@@ -22,6 +24,7 @@ enum Command {
 }
 
 struct PreferencesWatcher {
+    tasks: WatcherTasks,
     agent: Arc<BskyAgent>,
     period: Duration,
     tx: broadcast::Sender<Command>,
@@ -35,7 +38,7 @@ impl Watch for PreferencesWatcher {
         let mut command = self.tx.subscribe();
         let mut interval = time::interval(self.period);
         let (tx, rx) = watch::channel(Preferences::default());
-        tokio::spawn(async move {
+        self.tasks.spawn(async move {
             loop {
                 let tick = interval.tick();
                 let (agent, tx) = (agent.clone(), tx.clone());
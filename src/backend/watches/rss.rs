@@ -0,0 +1,82 @@
+use super::super::rss::{get_feed, RssFeed};
+use super::super::{Watch, Watcher, WatcherTasks};
+use reqwest::Client;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::{broadcast, watch};
+use tokio::time;
+
+impl Watcher {
+    pub fn rss(&self, url: String, client: Client) -> impl Watch<Output = Option<RssFeed>> {
+        let (tx, _) = broadcast::channel(1);
+        RssWatcher {
+            tasks: self.tasks.clone(),
+            url,
+            client,
+            tx,
+            period: Duration::from_secs(self.config.intervals.rss),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Command {
+    Quit,
+    Refresh,
+}
+
+pub struct RssWatcher {
+    tasks: WatcherTasks,
+    url: String,
+    client: Client,
+    tx: broadcast::Sender<Command>,
+    period: Duration,
+}
+
+impl Watch for RssWatcher {
+    type Output = Option<RssFeed>;
+
+    fn subscribe(&self) -> watch::Receiver<Self::Output> {
+        let (tx, rx) = watch::channel(None);
+        let (client, url) = (self.client.clone(), Arc::new(self.url.clone()));
+        let mut command = self.tx.subscribe();
+        let mut interval = time::interval(self.period);
+        self.tasks.spawn(async move {
+            loop {
+                tokio::select! {
+                    _ = interval.tick() => {}
+                    command = command.recv() => match command {
+                        Ok(Command::Refresh) => {}
+                        Ok(Command::Quit) | Err(_) => break,
+                    },
+                }
+                let (client, url, tx) = (client.clone(), url.clone(), tx.clone());
+                tokio::spawn(async move {
+                    update(&client, &url, &tx).await;
+                });
+            }
+        });
+        rx
+    }
+    fn unsubscribe(&self) {
+        if let Err(e) = self.tx.send(Command::Quit) {
+            log::error!("failed to send quit command: {e}");
+        }
+    }
+    fn refresh(&self) {
+        if let Err(e) = self.tx.send(Command::Refresh) {
+            log::error!("failed to send refresh command: {e}");
+        }
+    }
+}
+
+async fn update(client: &Client, url: &str, tx: &watch::Sender<Option<RssFeed>>) {
+    match get_feed(client, url).await {
+        Ok(feed) => {
+            tx.send(Some(feed)).ok();
+        }
+        Err(e) => {
+            log::error!("failed to get rss feed {url}: {e}");
+        }
+    }
+}
@@ -0,0 +1,110 @@
+use super::super::{Watch, Watcher, WatcherTasks};
+use crate::backend::client::BskyAgent;
+use bsky_sdk::Result;
+use std::{sync::Arc, time::Duration};
+use tokio::sync::{broadcast, watch};
+use tokio::time;
+
+impl Watcher {
+    pub fn unread_count(&self) -> impl Watch<Output = i64> {
+        let (tx, _) = broadcast::channel(1);
+        UnreadCountWatcher {
+            tasks: self.tasks.clone(),
+            agent: self.agent.clone(),
+            period: Duration::from_secs(self.config.intervals.notification_count),
+            tx,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Command {
+    Quit,
+    Refresh,
+}
+
+struct UnreadCountWatcher {
+    tasks: WatcherTasks,
+    agent: Arc<BskyAgent>,
+    period: Duration,
+    tx: broadcast::Sender<Command>,
+}
+
+impl Watch for UnreadCountWatcher {
+    type Output = i64;
+
+    fn subscribe(&self) -> watch::Receiver<Self::Output> {
+        let agent = self.agent.clone();
+        let mut command = self.tx.subscribe();
+        let mut interval = time::interval(self.period);
+        let (tx, rx) = watch::channel(0);
+        self.tasks.spawn(async move {
+            loop {
+                let tick = interval.tick();
+                let (agent, tx) = (agent.clone(), tx.clone());
+                tokio::select! {
+                    Ok(command) = command.recv() => {
+                        match command {
+                            Command::Refresh => {
+                                tokio::spawn(async move {
+                                    update(&agent, &tx).await;
+                                });
+                            }
+                            Command::Quit => {
+                                break;
+                            }
+                        }
+                    }
+                    _ = tick => {
+                        tokio::spawn(async move {
+                            update(&agent, &tx).await;
+                        });
+                    }
+                }
+            }
+        });
+        rx
+    }
+    fn unsubscribe(&self) {
+        if let Err(e) = self.tx.send(Command::Quit) {
+            log::error!("failed to send quit command: {e}");
+        }
+    }
+    fn refresh(&self) {
+        if let Err(e) = self.tx.send(Command::Refresh) {
+            log::error!("failed to send refresh command: {e}");
+        }
+    }
+}
+
+async fn update(agent: &BskyAgent, tx: &watch::Sender<i64>) {
+    match get_unread_count(agent).await {
+        Ok(count) => {
+            tx.send_if_modified(|current| {
+                let modified = *current != count;
+                *current = count;
+                modified
+            });
+        }
+        Err(e) => {
+            log::warn!("failed to get unread count: {e}");
+        }
+    }
+}
+
+async fn get_unread_count(agent: &BskyAgent) -> Result<i64> {
+    Ok(agent
+        .api
+        .app
+        .bsky
+        .notification
+        .get_unread_count(
+            bsky_sdk::api::app::bsky::notification::get_unread_count::ParametersData {
+                seen_at: None,
+            }
+            .into(),
+        )
+        .await?
+        .data
+        .count)
+}
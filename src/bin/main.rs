@@ -1,10 +1,11 @@
-use clap::Parser;
+use clap::{Parser, ValueEnum};
 use color_eyre::Result;
 use std::path::PathBuf;
-use std::{env, fs};
+use std::{env, fs, io};
 use tuisky::app::App;
-use tuisky::config::Config;
-use tuisky::utils::{get_config_dir, initialize_panic_handler};
+use tuisky::config::{Config, SecureConfig};
+use tuisky::output::{print_feed, watch_feed, WatchFormat};
+use tuisky::utils::{get_config_dir, initialize_panic_handler, LogBuffer};
 
 #[derive(Parser, Debug)]
 #[command(version, about, long_about = None)]
@@ -17,6 +18,59 @@ struct Args {
     /// The number of columns will be determined by the terminal width.
     #[arg(short, long)]
     num_columns: Option<usize>,
+    /// Render posts as plain text without emoji and colors, for screen readers.
+    #[arg(long)]
+    accessible: bool,
+    /// Print the posts of a feed to stdout instead of starting the TUI.
+    #[arg(short, long, value_enum, default_value_t = Output::Tui)]
+    output: Output,
+    /// Feed to print with `--output`: the AT-URI of a feed generator or a list, or `following`.
+    /// Defaults to the first pinned feed.
+    #[arg(long)]
+    feed: Option<String>,
+    /// Write the new posts of a feed to stdout as they arrive instead of starting the TUI:
+    /// the AT-URI of a feed generator or a list, or `timeline`.
+    #[arg(long, value_name = "FEED")]
+    watch: Option<String>,
+    /// Format of the posts written with `--watch`.
+    #[arg(long, value_enum, default_value_t = WatchOutput::Json)]
+    watch_format: WatchOutput,
+    /// Path to the age identity file which decrypts an encrypted configuration file.
+    #[arg(long, value_name = "PATH")]
+    age_identity: Option<PathBuf>,
+    /// Store a sensitive field of the configuration in the keyring of the OS and exit.
+    /// The secret is read from stdin, and an empty one removes the field.
+    #[arg(long, value_name = "FIELD", value_parser = SecureConfig::SENSITIVE_FIELDS)]
+    set_secret: Option<String>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+enum WatchOutput {
+    /// One JSON object per line.
+    Json,
+    /// One line per post.
+    Text,
+    /// One section per post.
+    Markdown,
+}
+
+impl From<WatchOutput> for WatchFormat {
+    fn from(output: WatchOutput) -> Self {
+        match output {
+            WatchOutput::Json => Self::Json,
+            WatchOutput::Text => Self::Text,
+            WatchOutput::Markdown => Self::Markdown,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+enum Output {
+    Tui,
+    /// A JSON array of the posts.
+    Json,
+    /// One JSON object per line.
+    Ndjson,
 }
 
 impl Args {
@@ -27,6 +81,13 @@ impl Args {
             Self::default_config_path()
         }
     }
+    fn age_identity_path(&self) -> Result<PathBuf> {
+        if let Some(path) = &self.age_identity {
+            Ok(path.clone())
+        } else {
+            Ok(get_config_dir()?.join("age-identity.txt"))
+        }
+    }
     fn default_config_path() -> Result<PathBuf> {
         let config_dir = get_config_dir()?;
         fs::create_dir_all(&config_dir)?;
@@ -39,14 +100,26 @@ fn init_logger() {
     if env::var("RUST_LOG").is_err() {
         builder.filter_level(log::LevelFilter::Off);
     }
-    builder.init();
+    LogBuffer::init(builder.build()).expect("failed to set the logger");
 }
 
 #[tokio::main]
 async fn main() -> Result<()> {
     let args = Args::parse();
+    if let Some(field) = &args.set_secret {
+        let mut secret = String::new();
+        io::stdin().read_line(&mut secret)?;
+        SecureConfig::store_secret(field, secret.trim())?;
+        if secret.trim().is_empty() {
+            println!("{field} is removed from the keyring");
+        } else {
+            println!("{field} is stored in the keyring");
+        }
+        return Ok(());
+    }
     let mut config = if args.config_path()?.exists() {
-        toml::from_str(&fs::read_to_string(args.config_path()?)?)?
+        SecureConfig::parse(&fs::read(args.config_path()?)?, &args.age_identity_path()?)?
+            .into_inner()
     } else {
         Config::default()
     };
@@ -54,9 +127,19 @@ async fn main() -> Result<()> {
     if let Some(num_columns) = args.num_columns {
         config.num_columns = Some(num_columns);
     }
+    if args.accessible {
+        config.ui.accessible = true;
+    }
 
     init_logger();
 
+    if let Some(feed) = &args.watch {
+        return watch_feed(&config, feed, args.watch_format.into()).await;
+    }
+    if args.output != Output::Tui {
+        return print_feed(&config, args.feed.as_deref(), args.output == Output::Ndjson).await;
+    }
+
     initialize_panic_handler()?;
 
     App::new(config).run().await
@@ -0,0 +1,212 @@
+use crate::backend::search::SearchQuery;
+use crate::backend::types::FeedSourceInfo;
+use crate::components::views::types::{Action as ViewAction, Transition, View};
+use crate::config::{ColumnAction, ColumnSizing};
+use crate::types::{Action, IdType};
+use color_eyre::{eyre, Result};
+
+/// The commands that are not column actions.
+const APP_COMMANDS: [&str; 8] = [
+    "quit", "next", "prev", "columns", "sizing", "feed", "search", "tag",
+];
+
+/// The column actions available as commands, in kebab-case.
+const COLUMN_COMMANDS: [&str; 54] = [
+    "next-item",
+    "prev-item",
+    "enter",
+    "back",
+    "refresh",
+    "new-post",
+    "menu",
+    "tack",
+    "untack",
+    "toggle",
+    "focus-mode",
+    "info",
+    "feeds",
+    "open",
+    "feedback",
+    "delete",
+    "quotes",
+    "metrics",
+    "media",
+    "expand",
+    "analytics",
+    "compact",
+    "header",
+    "live",
+    "increase",
+    "decrease",
+    "note",
+    "translate",
+    "mutuals",
+    "search-author",
+    "diff",
+    "comment",
+    "calendar",
+    "mark-all-read",
+    "not-interested",
+    "word-cloud",
+    "export",
+    "group-threads",
+    "next-by-author",
+    "prev-by-author",
+    "max-age",
+    "focus-author",
+    "external-links",
+    "timestamp-format",
+    "block-domain",
+    "feed-stats",
+    "compare-anchor",
+    "pds-record",
+    "raw-record",
+    "repliers",
+    "pin-conversation",
+    "copy-cid",
+    "tag-post",
+    "visual",
+];
+
+/// Parses the input of the command mode (without the leading `:`) into actions.
+pub struct CommandParser {
+    // the column that receives the column actions
+    column: Option<IdType>,
+}
+
+impl CommandParser {
+    pub fn new(column: Option<IdType>) -> Self {
+        Self { column }
+    }
+    pub fn parse(&self, input: &str) -> Result<Action> {
+        let mut words = input.split_whitespace();
+        let Some(command) = words.next() else {
+            return Err(eyre::eyre!("empty command"));
+        };
+        let args = words.collect::<Vec<_>>();
+        match (command, args.as_slice()) {
+            ("quit", []) => Ok(Action::Quit),
+            ("next", []) => Ok(Action::NextFocus),
+            ("prev", []) => Ok(Action::PrevFocus),
+            ("columns", []) => Ok(Action::ColumnManager),
+            ("columns", _) => Err(eyre::eyre!(
+                "the number of columns can only be set with `num_columns` in the config file"
+            )),
+            ("sizing", ["equal"]) => Ok(Action::ColumnSizing(ColumnSizing::Equal)),
+            ("sizing", ["weighted"]) => Ok(Action::ColumnSizing(ColumnSizing::Weighted)),
+            ("sizing", ["fixed", widths @ ..]) if !widths.is_empty() => widths
+                .iter()
+                .map(|width| width.parse::<u16>())
+                .collect::<Result<Vec<_>, _>>()
+                .map(|widths| Action::ColumnSizing(ColumnSizing::Fixed(widths)))
+                .map_err(|e| eyre::eyre!("invalid width: {e}")),
+            ("sizing", _) => Err(eyre::eyre!(
+                "usage: sizing equal | sizing weighted | sizing fixed <width>..."
+            )),
+            ("feed", ["following"]) => self.column_action(ViewAction::Transition(
+                Transition::Push(Box::new(View::Feed(Box::new(FeedSourceInfo::Timeline(
+                    String::from("following"),
+                ))))),
+            )),
+            ("feed", ["mutuals"]) => {
+                self.column_action(ViewAction::Transition(Transition::Push(Box::new(
+                    View::Feed(Box::new(FeedSourceInfo::Mutuals(String::from("following")))),
+                ))))
+            }
+            ("feed", ["activity"]) => self.column_action(ViewAction::Transition(Transition::Push(
+                Box::new(View::Feed(Box::new(FeedSourceInfo::Activity(
+                    String::from("following"),
+                )))),
+            ))),
+            ("feed", ["own"]) => self.column_action(ViewAction::Transition(Transition::Push(
+                Box::new(View::Feed(Box::new(FeedSourceInfo::Own))),
+            ))),
+            ("feed", ["discover"]) => self.column_action(ViewAction::Transition(
+                Transition::Push(Box::new(View::Feed(Box::new(FeedSourceInfo::Suggested)))),
+            )),
+            ("feed", ["rss", url]) => self.column_action(ViewAction::Transition(Transition::Push(
+                Box::new(View::Rss(url.to_string())),
+            ))),
+            ("feed", _) => Err(eyre::eyre!(
+                "usage: feed following | feed mutuals | feed activity | feed own | feed discover | feed rss <url>"
+            )),
+            ("tag", []) => self.column_action(ViewAction::FilterTag(None)),
+            ("tag", [name]) => self.column_action(ViewAction::FilterTag(Some(name.to_lowercase()))),
+            ("tag", _) => Err(eyre::eyre!("usage: tag [<name>]")),
+            ("search", []) => Err(eyre::eyre!("usage: search <query>")),
+            ("search", query) => self.column_action(ViewAction::Transition(Transition::Push(
+                Box::new(View::Search(Box::new(SearchQuery::new(query.join(" "))))),
+            ))),
+            (command, []) if COLUMN_COMMANDS.contains(&command) => {
+                let name = command
+                    .split('-')
+                    .map(|word| word[..1].to_uppercase() + &word[1..])
+                    .collect::<String>();
+                let action = serde_json::from_value::<ColumnAction>(name.into())?;
+                self.column_action((&action).into())
+            }
+            (command, _) if COLUMN_COMMANDS.contains(&command) => {
+                Err(eyre::eyre!("{command} takes no arguments"))
+            }
+            (command, _) => Err(eyre::eyre!("unknown command: {command}")),
+        }
+    }
+    /// Returns the commands starting with `input`.
+    pub fn complete(input: &str) -> Vec<&'static str> {
+        APP_COMMANDS
+            .iter()
+            .chain(COLUMN_COMMANDS.iter())
+            .filter(|command| command.starts_with(input))
+            .copied()
+            .collect()
+    }
+    fn column_action(&self, action: ViewAction) -> Result<Action> {
+        self.column
+            .map(|id| Action::View((id, action)))
+            .ok_or_else(|| eyre::eyre!("no column is selected"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse() {
+        let parser = CommandParser::new(Some(1));
+        assert!(matches!(parser.parse("quit"), Ok(Action::Quit)));
+        assert!(matches!(
+            parser.parse(" columns "),
+            Ok(Action::ColumnManager)
+        ));
+        assert!(matches!(
+            parser.parse("new-post"),
+            Ok(Action::View((1, ViewAction::NewPost)))
+        ));
+        assert!(matches!(
+            parser.parse("focus-mode"),
+            Ok(Action::View((1, ViewAction::FocusMode)))
+        ));
+        assert!(matches!(
+            parser.parse("sizing fixed 80 60"),
+            Ok(Action::ColumnSizing(ColumnSizing::Fixed(widths))) if widths == [80, 60]
+        ));
+        assert!(parser.parse("sizing fixed").is_err());
+        assert!(parser.parse("").is_err());
+        assert!(parser.parse("columns 3").is_err());
+        assert!(parser.parse("refresh now").is_err());
+        assert!(matches!(
+            parser.parse("tag Later"),
+            Ok(Action::View((1, ViewAction::FilterTag(Some(tag))))) if tag == "later"
+        ));
+        assert!(parser.parse("unknown").is_err());
+        assert!(CommandParser::new(None).parse("refresh").is_err());
+    }
+
+    #[test]
+    fn complete() {
+        assert_eq!(CommandParser::complete("qu"), vec!["quit", "quotes"]);
+        assert_eq!(CommandParser::complete("new"), vec!["new-post"]);
+        assert!(CommandParser::complete("x").is_empty());
+    }
+}
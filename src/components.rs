@@ -1,6 +1,14 @@
+pub mod blocked_domains;
 pub mod column;
+pub mod duplicates;
 pub mod main;
+pub mod noteboard;
+pub mod read_history;
+pub mod scroll;
+pub mod store;
+pub mod suggestions;
 pub mod views;
+pub mod widgets;
 
 use crate::config::Config;
 use crate::types::{Action, Event};
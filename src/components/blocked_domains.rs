@@ -0,0 +1,35 @@
+use indexmap::IndexSet;
+use serde::{Deserialize, Serialize, Serializer};
+
+/// The domains blocked with the keybinding, in addition to `blocked_domains` of the config.
+#[derive(Debug, Default, Deserialize)]
+#[serde(from = "Vec<String>")]
+pub struct BlockedDomains(IndexSet<String>);
+
+impl From<Vec<String>> for BlockedDomains {
+    fn from(domains: Vec<String>) -> Self {
+        Self(IndexSet::from_iter(domains))
+    }
+}
+
+impl Serialize for BlockedDomains {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(&self.0)
+    }
+}
+
+impl BlockedDomains {
+    /// Whether `domain` (or its parent domain) is blocked here or in `configured`.
+    pub fn is_blocked(&self, domain: &str, configured: &[String]) -> bool {
+        let matches = |blocked: &String| {
+            domain == blocked
+                || domain
+                    .strip_suffix(blocked.as_str())
+                    .is_some_and(|sub| sub.ends_with('.'))
+        };
+        configured.iter().any(matches) || self.0.iter().any(matches)
+    }
+    pub fn block(&mut self, domain: &str) {
+        self.0.insert(domain.to_lowercase());
+    }
+}
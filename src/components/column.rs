@@ -1,19 +1,32 @@
+use super::store::LocalStore;
 use super::views::types::{Action as ViewAction, Transition, View};
 use super::views::{
-    FeedViewComponent, LoginComponent, MenuViewComponent, NewPostViewComponent, PostViewComponent,
-    RootComponent, ViewComponent,
+    BlockedListsViewComponent, CalendarViewComponent, CommentViewComponent,
+    ConversationViewComponent, CreatedFeedsViewComponent, DiffViewComponent, ExportViewComponent,
+    FeedGeneratorDetailViewComponent, FeedViewComponent, FeedbackViewComponent,
+    FocusModeViewComponent, JsonViewComponent, KnownFollowersViewComponent, LabelersViewComponent,
+    LoginComponent, MenuViewComponent, MutedWordsViewComponent, NewPostViewComponent,
+    PopularFeedsViewComponent, PostAnalyticsViewComponent, PostContext, PostViewComponent,
+    QuotePostsViewComponent, RootComponent, RssViewComponent, SearchViewComponent,
+    SuggestionsViewComponent, ViewComponent, WordCloudViewComponent,
 };
 use super::Component;
-use crate::backend::Watcher;
-use crate::config::Config;
+use crate::backend::client::{agent_builder, http_client, BskyAgent};
+use crate::backend::rate_limit::RateLimit;
+use crate::backend::types::FeedSourceInfo;
+use crate::backend::{Watch, Watcher};
+use crate::config::{ColumnConfig, Config, FeedConfig, FeedType};
 use crate::types::{Action, IdType};
 use bsky_sdk::agent::config::Config as AgentConfig;
 use bsky_sdk::api::agent::Session;
-use bsky_sdk::BskyAgent;
 use color_eyre::{eyre, Result};
-use crossterm::event::KeyEvent;
-use ratatui::{layout::Rect, Frame};
-use std::sync::atomic::{AtomicU32, Ordering};
+use crossterm::event::{KeyEvent, MouseEvent};
+use ratatui::layout::{Constraint, Rect};
+use ratatui::style::{Style, Stylize};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Cell, Clear, Row, Table};
+use ratatui::Frame;
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
 use std::sync::{Arc, RwLock};
 use tokio::sync::mpsc::{self, UnboundedSender};
 
@@ -25,14 +38,33 @@ pub struct ColumnComponent {
     pub views: Vec<Box<dyn ViewComponent>>,
     menu: MenuViewComponent,
     pub is_menu_active: bool,
+    is_metrics_active: bool,
     config: Config,
+    column_config: ColumnConfig,
+    // opened on top of the root view after the login
+    feed_config: Option<FeedConfig>,
     action_tx: UnboundedSender<Action>,
     view_tx: UnboundedSender<ViewAction>,
     session: Arc<RwLock<Option<Session>>>,
+    unread_count: Arc<RwLock<i64>>,
+    unread_count_watcher: Option<Box<dyn Watch<Output = i64>>>,
+    chat_unread: Arc<RwLock<(u64, Option<String>)>>,
+    chat_unread_watcher: Option<Box<dyn Watch<Output = (u64, Option<String>)>>>,
+    store: Arc<LocalStore>,
+    // the rate limit of the agent of the column
+    rate_limit: RateLimit,
+    // updated by the main component on every draw
+    focused: Arc<AtomicBool>,
 }
 
 impl ColumnComponent {
-    pub fn new(config: Config, action_tx: UnboundedSender<Action>) -> Self {
+    pub fn new(
+        config: Config,
+        column_config: ColumnConfig,
+        feed_config: Option<FeedConfig>,
+        action_tx: UnboundedSender<Action>,
+        store: Arc<LocalStore>,
+    ) -> Self {
         let id = COUNTER.fetch_add(1, Ordering::SeqCst);
         let (view_tx, mut view_rx) = mpsc::unbounded_channel();
         let tx = action_tx.clone();
@@ -58,17 +90,28 @@ impl ColumnComponent {
             views: Vec::new(),
             menu: MenuViewComponent::new(view_tx.clone(), &config.keybindings),
             is_menu_active: false,
+            is_metrics_active: false,
             config,
+            column_config,
+            feed_config,
             action_tx,
             view_tx,
             session: Arc::new(RwLock::new(None)),
+            unread_count: Arc::new(RwLock::new(0)),
+            unread_count_watcher: None,
+            chat_unread: Arc::new(RwLock::new((0, None))),
+            chat_unread_watcher: None,
+            store,
+            rate_limit: RateLimit::default(),
+            focused: Arc::new(AtomicBool::new(false)),
         }
     }
     pub fn init_with_config(&mut self, config: &AgentConfig) -> Result<()> {
-        let config = config.clone();
+        let builder = agent_builder(self.config.proxy.as_deref(), self.rate_limit.clone())?
+            .config(config.clone());
         let (id, tx) = (self.id, self.action_tx.clone());
         tokio::spawn(async move {
-            let Ok(agent) = BskyAgent::builder().config(config).build().await else {
+            let Ok(agent) = builder.build().await else {
                 return log::error!("failed to build agent from config");
             };
             if let Err(e) = tx.send(Action::Login((id, Box::new(agent)))) {
@@ -77,12 +120,177 @@ impl ColumnComponent {
         });
         Ok(())
     }
-    pub fn title(&self) -> String {
-        if let Some(session) = self.session.read().ok().as_ref().and_then(|s| s.as_ref()) {
-            format!(" {} ", session.handle.as_str())
+    pub fn title(&self) -> Line<'static> {
+        let mut spans = vec![if let Some(session) =
+            self.session.read().ok().as_ref().and_then(|s| s.as_ref())
+        {
+            Span::from(format!(" {} ", session.handle.as_str()))
         } else {
-            format!(" id: {} ", self.id)
+            Span::from(format!(" id: {} ", self.id))
+        }];
+        let count = self.unread_count();
+        if count > 0 {
+            spans.push(Span::from(format!("●{count} ")).red());
         }
+        if let Some((count, sender)) = self
+            .chat_unread
+            .read()
+            .ok()
+            .filter(|unread| unread.0 > 0)
+            .map(|unread| unread.clone())
+        {
+            spans.push(Span::from(format!("✉{count} ")).blue());
+            if let Some(sender) = sender {
+                spans.push(Span::from(format!("@{sender} ")).gray());
+            }
+        }
+        Line::from(spans)
+    }
+    /// Number of unread notifications of the logged-in user.
+    pub fn unread_count(&self) -> i64 {
+        self.unread_count.read().map(|c| *c).unwrap_or_default()
+    }
+    /// Describe what the column shows, e.g. the name of the current feed.
+    pub fn label(&self) -> String {
+        self.views
+            .iter()
+            .rev()
+            .find_map(|view| match view.view() {
+                View::Feed(feed_info) => Some(
+                    self.feed_config
+                        .as_ref()
+                        .filter(|feed| feed.key() == feed_info.key())
+                        .and_then(|feed| feed.label.clone())
+                        .unwrap_or_else(|| feed_info.label()),
+                ),
+                View::Rss(url) => Some(format!("rss: {url}")),
+                _ => None,
+            })
+            .unwrap_or_else(|| {
+                String::from(if self.watcher.is_some() {
+                    "home"
+                } else {
+                    "login"
+                })
+            })
+    }
+    pub fn border_style(&self) -> Option<Style> {
+        self.views.last().and_then(|view| view.border_style())
+    }
+    pub fn set_focused(&self, focused: bool) {
+        self.focused.store(focused, Ordering::Relaxed);
+    }
+    pub fn is_focus_mode(&self) -> bool {
+        self.views
+            .last()
+            .map(|view| matches!(view.view(), View::FocusMode(_)))
+            .unwrap_or_default()
+    }
+    fn watch_unread_count(&mut self, watcher: &Watcher) {
+        let unread_count_watcher = watcher.unread_count();
+        let mut rx = unread_count_watcher.subscribe();
+        let (unread_count, tx) = (self.unread_count.clone(), self.action_tx.clone());
+        tokio::spawn(async move {
+            while rx.changed().await.is_ok() {
+                let count = *rx.borrow_and_update();
+                if let Ok(mut unread_count) = unread_count.write() {
+                    *unread_count = count;
+                }
+                if let Err(e) = tx.send(Action::Render) {
+                    log::error!("failed to send render action: {e}");
+                }
+            }
+            log::debug!("unread count subscription finished");
+        });
+        self.unread_count_watcher = Some(Box::new(unread_count_watcher));
+
+        let chat_unread_watcher = watcher.chat_unread();
+        let mut rx = chat_unread_watcher.subscribe();
+        let (chat_unread, tx) = (self.chat_unread.clone(), self.action_tx.clone());
+        tokio::spawn(async move {
+            while rx.changed().await.is_ok() {
+                let unread = rx.borrow_and_update().clone();
+                if let Ok(mut chat_unread) = chat_unread.write() {
+                    *chat_unread = unread;
+                }
+                if let Err(e) = tx.send(Action::Render) {
+                    log::error!("failed to send render action: {e}");
+                }
+            }
+            log::debug!("chat unread subscription finished");
+        });
+        self.chat_unread_watcher = Some(Box::new(chat_unread_watcher));
+    }
+    fn unwatch_unread_count(&mut self) {
+        if let Some(watcher) = self.unread_count_watcher.take() {
+            watcher.unsubscribe();
+        }
+        if let Ok(mut unread_count) = self.unread_count.write() {
+            *unread_count = 0;
+        }
+        if let Some(watcher) = self.chat_unread_watcher.take() {
+            watcher.unsubscribe();
+        }
+        if let Ok(mut chat_unread) = self.chat_unread.write() {
+            *chat_unread = (0, None);
+        }
+    }
+    /// Unsubscribe the watches of the active view, and stop the tasks of the watcher.
+    pub async fn shutdown(&mut self) {
+        if let Some(view) = self.views.last_mut() {
+            if let Err(e) = view.deactivate() {
+                log::error!("failed to deactivate view: {e}");
+            }
+        }
+        self.unwatch_unread_count();
+        if let Some(watcher) = &self.watcher {
+            watcher.shutdown().await;
+        }
+    }
+    fn draw_metrics(&self, f: &mut Frame<'_>, area: Rect) {
+        let Some(watcher) = &self.watcher else {
+            return;
+        };
+        let stats = watcher.metrics.stats();
+        let rows = stats.iter().map(|(label, stat)| {
+            let errors = Cell::from(stat.consecutive_errors.to_string());
+            Row::new(vec![
+                Cell::from(label.as_str()),
+                Cell::from(stat.last_success.map_or(String::from("-"), |instant| {
+                    format!("{}s", instant.elapsed().as_secs())
+                })),
+                if stat.consecutive_errors > 0 {
+                    errors.red()
+                } else {
+                    errors
+                },
+                Cell::from(stat.updates.to_string()),
+                Cell::from(stat.subscribers.to_string()),
+            ])
+        });
+        let height = (stats.len() as u16 + 3).min(area.height);
+        let area = Rect::new(
+            area.x,
+            area.y + area.height.saturating_sub(height),
+            area.width,
+            height,
+        );
+        f.render_widget(Clear, area);
+        f.render_widget(
+            Table::new(
+                rows,
+                [
+                    Constraint::Fill(1),
+                    Constraint::Length(6),
+                    Constraint::Length(6),
+                    Constraint::Length(7),
+                    Constraint::Length(4),
+                ],
+            )
+            .header(Row::new(vec!["watcher", "last", "errors", "updates", "subs"]).bold())
+            .block(Block::bordered().title("Watchers").dim()),
+            area,
+        );
     }
     pub(crate) fn transition(&mut self, transition: &Transition) -> Result<Option<Action>> {
         match transition {
@@ -119,16 +327,57 @@ impl ColumnComponent {
             .as_ref()
             .ok_or_else(|| eyre::eyre!("watcher not initialized"))?;
         Ok(match view {
-            View::Login => Box::new(LoginComponent::new(self.view_tx.clone())),
-            View::Root => Box::new(RootComponent::new(self.view_tx.clone(), watcher.clone())),
+            View::Login => Box::new(LoginComponent::new(
+                self.view_tx.clone(),
+                self.config.proxy.clone(),
+                self.rate_limit.clone(),
+            )),
+            View::Root => Box::new(RootComponent::new(
+                self.view_tx.clone(),
+                watcher.clone(),
+                self.config.rss_feeds.clone(),
+            )),
             View::NewPost => Box::new(NewPostViewComponent::new(
                 self.view_tx.clone(),
                 watcher.agent.clone(),
+                http_client(self.config.proxy.as_deref())?,
+                self.config.compose.max_video_mb,
+                self.store.clone(),
+                None,
+            )),
+            View::Reply(post_view) => Box::new(NewPostViewComponent::new(
+                self.view_tx.clone(),
+                watcher.agent.clone(),
+                http_client(self.config.proxy.as_deref())?,
+                self.config.compose.max_video_mb,
+                self.store.clone(),
+                Some(PostContext::Reply(post_view.clone())),
+            )),
+            View::Quote(post_view) => Box::new(NewPostViewComponent::new(
+                self.view_tx.clone(),
+                watcher.agent.clone(),
+                http_client(self.config.proxy.as_deref())?,
+                self.config.compose.max_video_mb,
+                self.store.clone(),
+                Some(PostContext::Quote(post_view.clone())),
             )),
             View::Feed(info) => Box::new(FeedViewComponent::new(
                 self.view_tx.clone(),
                 watcher.clone(),
                 info.as_ref().clone(),
+                &self.config,
+                &self.column_config,
+                self.feed_config
+                    .as_ref()
+                    .filter(|feed| feed.key() == info.key()),
+                self.session
+                    .read()
+                    .ok()
+                    .as_ref()
+                    .and_then(|s| s.as_ref())
+                    .map(|session| session.did.clone()),
+                self.store.clone(),
+                self.focused.clone(),
             )),
             View::Post(boxed) => {
                 let (post_view, reply) = boxed.as_ref();
@@ -145,13 +394,116 @@ impl ColumnComponent {
                         .cloned(),
                 ))
             }
+            View::Conversation(uri) => Box::new(ConversationViewComponent::new(
+                self.view_tx.clone(),
+                watcher.clone(),
+                uri.clone(),
+            )),
+            View::WordCloud(feed_info) => Box::new(WordCloudViewComponent::new(
+                self.view_tx.clone(),
+                watcher.clone(),
+                feed_info.as_ref().clone(),
+                &self.config.ui.stop_words,
+            )),
+            View::Export(posts) => Box::new(ExportViewComponent::new(posts.clone())),
+            View::RawRecord(boxed) => {
+                let (uri, json) = boxed.as_ref();
+                Box::new(JsonViewComponent::new(uri.clone(), json.clone()))
+            }
+            View::KnownFollowers(boxed) => {
+                let (profile, followers) = boxed.as_ref();
+                Box::new(KnownFollowersViewComponent::new(
+                    profile.clone(),
+                    followers.clone(),
+                ))
+            }
+            View::Comment(post_view) => Box::new(CommentViewComponent::new(
+                post_view.as_ref().clone(),
+                self.store.clone(),
+            )),
+            View::Calendar(boxed) => {
+                let (counts, selected) = boxed.as_ref().clone();
+                Box::new(CalendarViewComponent::new(
+                    self.view_tx.clone(),
+                    counts,
+                    selected,
+                ))
+            }
+            View::Diff(boxed) => {
+                let (previous, post_view) = boxed.as_ref().clone();
+                Box::new(DiffViewComponent::new(previous, post_view))
+            }
+            View::FocusMode(post_view) => {
+                Box::new(FocusModeViewComponent::new(post_view.as_ref().clone()))
+            }
+            View::FeedGenerator(uri) => Box::new(FeedGeneratorDetailViewComponent::new(
+                self.view_tx.clone(),
+                watcher.clone(),
+                uri.clone(),
+            )),
+            View::Labelers => Box::new(LabelersViewComponent::new(
+                self.view_tx.clone(),
+                watcher.clone(),
+            )),
+            View::MutedWords => Box::new(MutedWordsViewComponent::new(
+                self.view_tx.clone(),
+                watcher.clone(),
+            )),
+            View::BlockedLists => Box::new(BlockedListsViewComponent::new(
+                self.view_tx.clone(),
+                watcher.clone(),
+            )),
+            View::Rss(url) => Box::new(RssViewComponent::new(
+                self.view_tx.clone(),
+                watcher.clone(),
+                url.clone(),
+                http_client(self.config.proxy.as_deref())?,
+                self.config.browser_command.clone(),
+            )),
+            View::Search(query) => Box::new(SearchViewComponent::new(
+                self.view_tx.clone(),
+                watcher.agent.clone(),
+                query.as_ref().clone(),
+                self.store.clone(),
+            )),
+            View::Quotes(post_view) => Box::new(QuotePostsViewComponent::new(
+                self.view_tx.clone(),
+                watcher.agent.clone(),
+                post_view.as_ref().clone(),
+            )),
+            View::Suggestions => Box::new(SuggestionsViewComponent::new(
+                self.view_tx.clone(),
+                watcher.agent.clone(),
+                self.store.clone(),
+            )),
+            View::PopularFeeds => Box::new(PopularFeedsViewComponent::new(
+                self.view_tx.clone(),
+                watcher.clone(),
+            )),
+            View::Analytics(post_view) => Box::new(PostAnalyticsViewComponent::new(
+                self.view_tx.clone(),
+                watcher.agent.clone(),
+                post_view.as_ref().clone(),
+            )),
+            View::Feedback => Box::new(FeedbackViewComponent::new(
+                self.config.browser_command.clone(),
+            )),
+            View::CreatedFeeds(profile) => Box::new(CreatedFeedsViewComponent::new(
+                self.view_tx.clone(),
+                watcher.clone(),
+                profile.as_ref().clone(),
+            )),
         })
     }
 }
 
 impl Component for ColumnComponent {
     fn init(&mut self, _area: Rect) -> Result<()> {
-        self.views = vec![Box::new(LoginComponent::new(self.view_tx.clone()))];
+        self.views = vec![Box::new(LoginComponent::new(
+            self.view_tx.clone(),
+            self.config.proxy.clone(),
+            self.rate_limit.clone(),
+        ))];
         Ok(())
     }
     fn handle_key_events(&mut self, key: KeyEvent) -> Result<Option<Action>> {
@@ -168,6 +520,18 @@ impl Component for ColumnComponent {
             Ok(None)
         }
     }
+    fn handle_mouse_events(&mut self, mouse: MouseEvent) -> Result<Option<Action>> {
+        if self.is_menu_active {
+            return Ok(None);
+        }
+        Ok(self
+            .views
+            .last_mut()
+            .map(|view| view.handle_mouse_events(mouse))
+            .transpose()?
+            .flatten()
+            .map(|action| Action::View((self.id, action))))
+    }
     fn update(&mut self, action: Action) -> Result<Option<Action>> {
         match action {
             Action::View((id, view_action)) if id == self.id => {
@@ -175,20 +539,40 @@ impl Component for ColumnComponent {
                     ViewAction::Render => {
                         return Ok(Some(Action::Render));
                     }
-                    ViewAction::NewPost if self.watcher.is_some() => {
-                        if !self
-                            .views
-                            .last()
-                            .map(|view| view.view() == View::NewPost)
-                            .unwrap_or_default()
-                        {
-                            return self.transition(&Transition::Push(Box::new(View::NewPost)));
-                        }
+                    ViewAction::NewPost
+                        if self.watcher.is_some()
+                            && !self
+                                .views
+                                .last()
+                                .map(|view| view.view() == View::NewPost)
+                                .unwrap_or_default() =>
+                    {
+                        return self.transition(&Transition::Push(Box::new(View::NewPost)));
+                    }
+                    ViewAction::Feedback
+                        if self.watcher.is_some()
+                            && !self
+                                .views
+                                .last()
+                                .map(|view| view.view() == View::Feedback)
+                                .unwrap_or_default() =>
+                    {
+                        return self.transition(&Transition::Push(Box::new(View::Feedback)));
+                    }
+                    ViewAction::Transition(transition) if self.watcher.is_some() => {
+                        return self.transition(&transition);
+                    }
+                    ViewAction::Command => {
+                        return Ok(Some(Action::CommandMode));
                     }
                     ViewAction::Menu if self.watcher.is_some() => {
                         self.is_menu_active = !self.is_menu_active;
                         return Ok(Some(Action::Render));
                     }
+                    ViewAction::Metrics if self.config.dev && self.watcher.is_some() => {
+                        self.is_metrics_active = !self.is_metrics_active;
+                        return Ok(Some(Action::Render));
+                    }
                     _ => {}
                 }
                 if self.is_menu_active {
@@ -204,12 +588,22 @@ impl Component for ColumnComponent {
                                 session.take();
                             }
                             self.watcher.take();
-                            self.views = vec![Box::new(LoginComponent::new(self.view_tx.clone()))];
+                            self.unwatch_unread_count();
+                            // the next account may be on another server
+                            self.rate_limit = RateLimit::default();
+                            self.views = vec![Box::new(LoginComponent::new(
+                                self.view_tx.clone(),
+                                self.config.proxy.clone(),
+                                self.rate_limit.clone(),
+                            ))];
                             return Ok(Some(Action::Render));
                         }
                         Ok(Some(ViewAction::Transition(transition))) => {
                             return self.transition(transition);
                         }
+                        Ok(Some(ViewAction::AddNote(post_view))) => {
+                            return Ok(Some(Action::AddNote(post_view.clone())));
+                        }
                         _ => {}
                     }
                     result.map(|action| action.map(|a| Action::View((self.id, a))))
@@ -229,12 +623,37 @@ impl Component for ColumnComponent {
                         }
                     });
                 }
-                self.watcher = Some(Arc::new(Watcher::new(
+                let watcher = Arc::new(Watcher::new(
                     Arc::new(*agent),
+                    self.rate_limit.clone(),
                     self.config.watcher.clone(),
-                )));
+                ));
+                self.unwatch_unread_count();
+                self.watch_unread_count(&watcher);
+                if let Some(feed) = self.feed_config.clone() {
+                    let (agent, tx) = (watcher.agent.clone(), self.view_tx.clone());
+                    tokio::spawn(async move {
+                        match feed_source(&agent, &feed).await {
+                            Ok(feed_info) => {
+                                tx.send(ViewAction::Transition(Transition::Push(Box::new(
+                                    View::Feed(Box::new(feed_info)),
+                                ))))
+                                .ok();
+                            }
+                            Err(e) => log::error!("failed to open the configured feed: {e}"),
+                        }
+                    });
+                }
+                self.watcher = Some(watcher);
                 return self.transition(&Transition::Replace(Box::new(View::Root)));
             }
+            Action::Tick(i) => {
+                if let Some(view) = self.views.last_mut() {
+                    if let Some(ViewAction::Render) = view.update(ViewAction::Tick(i))? {
+                        return Ok(Some(Action::Render));
+                    }
+                }
+            }
             _ => {}
         }
         Ok(None)
@@ -246,6 +665,58 @@ impl Component for ColumnComponent {
         if self.is_menu_active {
             self.menu.draw(f, area)?;
         }
+        if self.is_metrics_active {
+            self.draw_metrics(f, area);
+        }
         Ok(())
     }
 }
+
+/// Fetch what is needed to open the configured feed, e.g. the view of the feed generator.
+async fn feed_source(agent: &BskyAgent, feed: &FeedConfig) -> Result<FeedSourceInfo> {
+    let uri = feed.uri.clone();
+    Ok(match (feed.kind, uri) {
+        (FeedType::Timeline, _) => FeedSourceInfo::Timeline(String::from("following")),
+        (FeedType::Feed, Some(uri)) => FeedSourceInfo::Feed(Box::new(
+            agent
+                .api
+                .app
+                .bsky
+                .feed
+                .get_feed_generator(
+                    bsky_sdk::api::app::bsky::feed::get_feed_generator::ParametersData {
+                        feed: uri,
+                    }
+                    .into(),
+                )
+                .await?
+                .data
+                .view,
+        )),
+        (FeedType::List, Some(uri)) => FeedSourceInfo::List(Box::new(
+            agent
+                .api
+                .app
+                .bsky
+                .graph
+                .get_list(
+                    bsky_sdk::api::app::bsky::graph::get_list::ParametersData {
+                        cursor: None,
+                        limit: 1.try_into().ok(),
+                        list: uri,
+                    }
+                    .into(),
+                )
+                .await?
+                .data
+                .list,
+        )),
+        (FeedType::Actor, Some(actor)) => FeedSourceInfo::Author(actor),
+        (FeedType::Actor, None) => FeedSourceInfo::Own,
+        (kind @ (FeedType::Feed | FeedType::List), None) => {
+            return Err(eyre::eyre!(
+                "`uri` is required for the feeds of type {kind:?}"
+            ))
+        }
+    })
+}
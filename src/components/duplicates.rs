@@ -0,0 +1,45 @@
+use bsky_sdk::api::types::string::Cid;
+use indexmap::IndexSet;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::RwLock;
+
+/// The posts loaded in the active feed views, to find the ones shown in several columns.
+#[derive(Debug, Default)]
+pub struct Duplicates {
+    counter: AtomicU32,
+    // the active feed views of each post, in the order the post was loaded in them
+    feeds: RwLock<HashMap<Cid, IndexSet<u32>>>,
+}
+
+impl Duplicates {
+    /// A new identifier of a feed view.
+    pub fn next_id(&self) -> u32 {
+        self.counter.fetch_add(1, Ordering::SeqCst)
+    }
+    /// Replace the posts loaded in the feed view `id`.
+    pub fn update<'a>(&self, id: u32, cids: impl IntoIterator<Item = &'a Cid>) {
+        if let Ok(mut feeds) = self.feeds.write() {
+            let cids = cids.into_iter().collect::<IndexSet<_>>();
+            feeds.retain(|cid, ids| {
+                if !cids.contains(cid) {
+                    ids.shift_remove(&id);
+                }
+                !ids.is_empty()
+            });
+            for cid in cids {
+                feeds.entry(cid.clone()).or_default().insert(id);
+            }
+        }
+    }
+    /// Forget the posts of the feed view `id`, which is no longer shown.
+    pub fn remove(&self, id: u32) {
+        self.update(id, []);
+    }
+    /// The number of the feed views showing the post, if it was loaded in another one before `id`.
+    pub fn duplicates(&self, cid: &Cid, id: u32) -> Option<usize> {
+        let feeds = self.feeds.read().ok()?;
+        let ids = feeds.get(cid)?;
+        (ids.get_index_of(&id)? > 0).then_some(ids.len())
+    }
+}
@@ -1,66 +1,145 @@
 use super::column::ColumnComponent;
+use super::noteboard::NoteboardComponent;
+use super::store::LocalStore;
 use super::Component;
-use crate::config::Config;
+use crate::commands::CommandParser;
+use crate::config::{parse_style, ColumnSizing, Config, Pin};
 use crate::types::Action;
-use crate::utils::get_data_dir;
+use crate::utils::{get_config_dir, get_data_dir};
 use bsky_sdk::agent::config::Config as AgentConfig;
 use color_eyre::Result;
-use crossterm::event::KeyEvent;
-use ratatui::layout::{Alignment, Constraint, Direction, Layout, Rect};
+use crossterm::event::{KeyCode, KeyEvent, MouseEvent, MouseEventKind};
+use ratatui::layout::{Alignment, Constraint, Direction, Layout, Position, Rect};
 use ratatui::style::{Style, Stylize};
-use ratatui::widgets::{Block, BorderType};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, BorderType, Clear, List, ListState};
 use ratatui::Frame;
 use serde::{Deserialize, Serialize};
 use std::fs::{create_dir_all, File};
 use std::path::PathBuf;
+use std::sync::Arc;
 use tokio::sync::mpsc::UnboundedSender;
 
+/// Upper limit of the extra width given to a column for its unread notifications,
+/// as a multiple of the width of a column without them.
+const MAX_EXTRA_WEIGHT: i64 = 3;
+const NOTEBOARD_WIDTH: u16 = 50;
+const PINNED_COLUMN_WIDTH: u16 = 60;
+
 #[derive(Debug, Default, Serialize, Deserialize)]
 struct AppData {
     views: Vec<ViewData>,
+    #[serde(default)]
+    column_sizing: Option<ColumnSizing>,
 }
 
 #[derive(Debug, Default, Serialize, Deserialize)]
 struct ViewData {
+    /// Without the session if it is kept in the secure store.
     agent: Option<AgentConfig>,
+    /// The session encrypted with age, with `secure_store = AgeFile`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    session: Option<String>,
+}
+
+impl ViewData {
+    /// Keep the session of the column at `index` out of the app data with the secure store.
+    fn seal(config: &Config, index: usize, agent: Option<AgentConfig>) -> Result<Self> {
+        let mut view = Self {
+            agent,
+            session: None,
+        };
+        let mut signed_out = AgentConfig::default();
+        view.session =
+            config.seal_session(index, view.agent.as_mut().unwrap_or(&mut signed_out))?;
+        Ok(view)
+    }
+    /// The session of the column at `index`, read back from the secure store.
+    fn unseal(self, config: &Config, index: usize) -> Result<Option<AgentConfig>> {
+        self.agent
+            .map(|mut agent| {
+                config.unseal_session(index, &mut agent, self.session.as_deref())?;
+                Ok(agent)
+            })
+            .transpose()
+    }
 }
 
 #[derive(Default)]
 struct State {
     selected: Option<usize>,
+    is_manager_active: bool,
+    is_noteboard_active: bool,
+    command_line: Option<CommandLine>,
+}
+
+#[derive(Default)]
+struct CommandLine {
+    input: String,
+    error: Option<String>,
 }
 
 pub struct MainComponent {
     config: Config,
     action_tx: UnboundedSender<Action>,
     columns: Vec<ColumnComponent>,
+    column_styles: Vec<Style>,
+    column_pins: Vec<Option<Pin>>,
+    // the index of `config.columns` used for each column
+    column_order: Vec<usize>,
+    // chosen in the command mode, which takes precedence over the config
+    column_sizing: Option<ColumnSizing>,
+    noteboard: NoteboardComponent,
     state: State,
+    // the areas of the columns in the last draw, to find the column under the mouse
+    column_areas: Vec<(usize, Rect)>,
+    store: Arc<LocalStore>,
 }
 
 impl MainComponent {
     pub fn new(config: Config, action_tx: UnboundedSender<Action>) -> Self {
+        let store = Arc::<LocalStore>::default();
         Self {
             config,
             action_tx,
             columns: Vec::new(),
-            state: State { selected: None },
+            column_styles: Vec::new(),
+            column_pins: Vec::new(),
+            column_order: Vec::new(),
+            column_sizing: None,
+            noteboard: NoteboardComponent::load(store.clone()),
+            state: State::default(),
+            column_areas: Vec::new(),
+            store,
+        }
+    }
+    /// Stop the background tasks of all the columns, before exiting.
+    pub async fn shutdown(&mut self) {
+        for column in &mut self.columns {
+            column.shutdown().await;
         }
     }
     pub async fn save(&self) -> Result<()> {
         let mut appdata = AppData {
             views: Vec::with_capacity(self.columns.len()),
+            column_sizing: self.column_sizing.clone(),
         };
-        for view in &self.columns {
+        for (i, view) in self.columns.iter().enumerate() {
             let config = if let Some(w) = &view.watcher {
                 Some(w.agent.to_config().await)
             } else {
                 None
             };
-            appdata.views.push(ViewData { agent: config });
+            appdata.views.push(ViewData::seal(&self.config, i, config)?);
         }
         let path = Self::appdata_path()?;
         serde_json::to_writer_pretty(File::create(&path)?, &appdata)?;
         log::info!("saved appdata to: {path:?}");
+        // the order must be saved together with the appdata, which is stored by position
+        let path = Self::column_order_path()?;
+        serde_json::to_writer_pretty(File::create(&path)?, &self.column_order)?;
+        log::info!("saved column order to: {path:?}");
+        self.store.save()?;
         Ok(())
     }
     fn load() -> Result<AppData> {
@@ -69,31 +148,222 @@ impl MainComponent {
         log::info!("loaded appdata from {path:?}");
         Ok(appdata)
     }
+    /// The session of the first signed-in column, with its position in the app data.
+    pub fn saved_agent_config(config: &Config) -> Result<Option<(usize, AgentConfig)>> {
+        for (i, view) in Self::load()?.views.into_iter().enumerate() {
+            if let Some(agent) = view.unseal(config, i)? {
+                return Ok(Some((i, agent)));
+            }
+        }
+        Ok(None)
+    }
+    /// Replace the session of the column at `index`, e.g. after its tokens have been refreshed.
+    pub fn store_agent_config(config: &Config, index: usize, agent: AgentConfig) -> Result<()> {
+        let mut appdata = Self::load()?;
+        if let Some(view) = appdata.views.get_mut(index) {
+            *view = ViewData::seal(config, index, Some(agent))?;
+        }
+        serde_json::to_writer_pretty(File::create(Self::appdata_path()?)?, &appdata)?;
+        Ok(())
+    }
     fn appdata_path() -> Result<PathBuf> {
         let data_dir = get_data_dir()?;
         create_dir_all(&data_dir)?;
         Ok(data_dir.join("appdata.json"))
     }
+    fn load_column_order(num_columns: usize) -> Vec<usize> {
+        let saved = Self::column_order_path()
+            .and_then(|path| Ok(serde_json::from_reader::<_, Vec<usize>>(File::open(path)?)?))
+            .unwrap_or_else(|e| {
+                log::warn!("failed to load column order, using default: {e}");
+                Vec::new()
+            });
+        // drop the columns that no longer exist and append the new ones
+        let mut order = Vec::with_capacity(num_columns);
+        for i in saved.into_iter().chain(0..num_columns) {
+            if i < num_columns && !order.contains(&i) {
+                order.push(i);
+            }
+        }
+        order
+    }
+    fn column_order_path() -> Result<PathBuf> {
+        let config_dir = get_config_dir()?;
+        create_dir_all(&config_dir)?;
+        Ok(config_dir.join("columns.json"))
+    }
+    /// Move the selected column by `delta` positions, keeping it selected.
+    fn move_column(&mut self, delta: isize) -> Option<Action> {
+        let selected = self.state.selected?;
+        let target = selected
+            .checked_add_signed(delta)
+            .filter(|&i| i < self.columns.len())?;
+        self.columns.swap(selected, target);
+        self.column_styles.swap(selected, target);
+        self.column_pins.swap(selected, target);
+        self.column_order.swap(selected, target);
+        self.state.selected = Some(target);
+        Some(Action::Render)
+    }
+    /// Pin the selected column to the left, then to the right, then unpin it.
+    fn toggle_pin(&mut self) -> Option<Action> {
+        let pin = self.column_pins.get_mut(self.state.selected?)?;
+        *pin = match pin {
+            None => Some(Pin::Left),
+            Some(Pin::Left) => Some(Pin::Right),
+            Some(Pin::Right) => None,
+        };
+        Some(Action::Render)
+    }
+    /// The indices of the columns in the order they are drawn,
+    /// with the pinned columns at the edges.
+    fn column_layout(&self) -> Vec<usize> {
+        let pinned = |pin| {
+            (0..self.columns.len())
+                .filter(move |&i| self.column_pins.get(i).copied().flatten() == pin)
+        };
+        pinned(Some(Pin::Left))
+            .chain(pinned(None))
+            .chain(pinned(Some(Pin::Right)))
+            .collect()
+    }
+    fn column_constraints(&self) -> Vec<Constraint> {
+        match self
+            .column_sizing
+            .as_ref()
+            .unwrap_or(&self.config.ui.column_sizing)
+        {
+            ColumnSizing::Equal => self.columns.iter().map(|_| Constraint::Fill(1)).collect(),
+            ColumnSizing::Weighted => self
+                .columns
+                .iter()
+                .map(|column| {
+                    let extra = column.unread_count().clamp(0, MAX_EXTRA_WEIGHT);
+                    Constraint::Fill(1 + extra as u16)
+                })
+                .collect(),
+            ColumnSizing::Fixed(widths) => (0..self.columns.len())
+                .map(|i| {
+                    widths
+                        .get(i)
+                        .map_or(Constraint::Fill(1), |w| Constraint::Length(*w))
+                })
+                .collect(),
+        }
+    }
+    fn draw_command_line(f: &mut Frame<'_>, command_line: &CommandLine, area: Rect) {
+        let mut line = Line::from(format!(":{}", command_line.input));
+        let cursor = area.x + line.width() as u16;
+        if let Some(error) = &command_line.error {
+            line.push_span(Span::from(format!("  {error}")).red());
+        } else if !command_line.input.contains(' ') {
+            let candidates = CommandParser::complete(&command_line.input);
+            line.push_span(Span::from(format!("  {}", candidates.join(" "))).dim());
+        }
+        f.render_widget(Clear, area);
+        f.render_widget(line, area);
+        f.set_cursor(cursor.min(area.right().saturating_sub(1)), area.y);
+    }
+    fn draw_manager(&self, f: &mut Frame<'_>, area: Rect) {
+        let items = self
+            .columns
+            .iter()
+            .enumerate()
+            .map(|(i, column)| {
+                Line::from(vec![
+                    Span::from(format!("{}. ", i + 1)).dim(),
+                    Span::from(column.label()),
+                    Span::from(match self.column_pins.get(i).copied().flatten() {
+                        Some(Pin::Left) => " [pinned left]",
+                        Some(Pin::Right) => " [pinned right]",
+                        None => "",
+                    })
+                    .yellow(),
+                ])
+            })
+            .collect::<Vec<_>>();
+        let width = items
+            .iter()
+            .map(Line::width)
+            .max()
+            .unwrap_or_default()
+            .max(30) as u16
+            + 4;
+        let height = items.len() as u16 + 2;
+        let area = Rect::new(
+            area.x + area.width.saturating_sub(width) / 2,
+            area.y + area.height.saturating_sub(height) / 2,
+            width.min(area.width),
+            height.min(area.height),
+        );
+        f.render_widget(Clear, area);
+        f.render_stateful_widget(
+            List::new(items)
+                .block(Block::bordered().title("Columns (J/K to move, p to pin)"))
+                .highlight_style(Style::default().reversed()),
+            area,
+            &mut ListState::default().with_selected(self.state.selected),
+        );
+    }
 }
 
 impl Component for MainComponent {
     fn init(&mut self, rect: Rect) -> Result<()> {
-        let appdata = if let Ok(appdata) = Self::load() {
+        let mut appdata = if let Ok(appdata) = Self::load() {
             appdata
         } else {
             log::warn!("failed to load appdata, using default");
             AppData::default()
         };
+        self.column_sizing.clone_from(&appdata.column_sizing);
 
         let auto_num = usize::from(rect.width) / 75;
+        // as many columns as the configured feeds, if the number is not set
         let num_columns = self
             .config
             .num_columns
+            .or_else(|| (!self.config.feeds.is_empty()).then_some(self.config.feeds.len()))
             .map_or(auto_num, |n| n.min(auto_num));
+        if num_columns < self.config.feeds.len() {
+            log::warn!(
+                "only {num_columns} of the {} configured feeds are opened",
+                self.config.feeds.len()
+            );
+        }
 
-        for i in 0..num_columns {
-            let mut column = ColumnComponent::new(self.config.clone(), self.action_tx.clone());
-            if let Some(config) = appdata.views.get(i).and_then(|view| view.agent.as_ref()) {
+        self.column_order = Self::load_column_order(num_columns);
+        self.column_styles = self
+            .column_order
+            .iter()
+            .map(|&i| {
+                self.config
+                    .columns
+                    .get(i)
+                    .map(|column| parse_style(&column.style))
+                    .unwrap_or_default()
+            })
+            .collect();
+        self.column_pins = self
+            .column_order
+            .iter()
+            .map(|&i| self.config.columns.get(i).and_then(|column| column.pinned))
+            .collect();
+        for (i, &index) in self.column_order.iter().enumerate() {
+            let mut column = ColumnComponent::new(
+                self.config.clone(),
+                self.config.columns.get(index).cloned().unwrap_or_default(),
+                self.config.feeds.get(index).cloned(),
+                self.action_tx.clone(),
+                self.store.clone(),
+            );
+            let agent = match appdata.views.get_mut(i).map(std::mem::take) {
+                Some(view) => view.unseal(&self.config, i).unwrap_or_else(|e| {
+                    log::warn!("failed to read the session of column {i}, signing out: {e}");
+                    None
+                }),
+                None => None,
+            };
+            if let Some(config) = &agent {
                 column.init_with_config(config)?;
             } else {
                 column.init(rect)?;
@@ -106,12 +376,96 @@ impl Component for MainComponent {
         Ok(())
     }
     fn handle_key_events(&mut self, key: KeyEvent) -> Result<Option<Action>> {
+        if let Some(command_line) = self.state.command_line.as_mut() {
+            command_line.error = None;
+            match key.code {
+                KeyCode::Enter => {
+                    let column = self.state.selected.map(|i| self.columns[i].id);
+                    match CommandParser::new(column).parse(&command_line.input) {
+                        Ok(action) => {
+                            self.state.command_line = None;
+                            self.action_tx.send(Action::Render)?;
+                            return Ok(Some(action));
+                        }
+                        Err(e) => command_line.error = Some(e.to_string()),
+                    }
+                }
+                KeyCode::Tab => {
+                    let candidates = CommandParser::complete(command_line.input.trim_start());
+                    if let Some(first) = candidates.first() {
+                        // complete to the longest common prefix of the candidates
+                        let len = candidates.iter().fold(first.len(), |len, candidate| {
+                            first
+                                .bytes()
+                                .zip(candidate.bytes())
+                                .take(len)
+                                .take_while(|(a, b)| a == b)
+                                .count()
+                        });
+                        command_line.input = first[..len].to_string();
+                        if candidates.len() == 1 {
+                            command_line.input.push(' ');
+                        }
+                    }
+                }
+                KeyCode::Backspace if command_line.input.is_empty() => {
+                    self.state.command_line = None;
+                }
+                KeyCode::Backspace => {
+                    command_line.input.pop();
+                }
+                KeyCode::Esc => {
+                    self.state.command_line = None;
+                }
+                KeyCode::Char(c) => {
+                    command_line.input.push(c);
+                }
+                _ => {}
+            }
+            return Ok(Some(Action::Render));
+        }
+        if self.state.is_noteboard_active {
+            return self.noteboard.handle_key_events(key);
+        }
+        if self.state.is_manager_active {
+            return Ok(match key.code {
+                KeyCode::Char('J') => self.move_column(1),
+                KeyCode::Char('K') => self.move_column(-1),
+                KeyCode::Char('p') => self.toggle_pin(),
+                KeyCode::Down => Some(Action::NextFocus),
+                KeyCode::Up => Some(Action::PrevFocus),
+                KeyCode::Esc => Some(Action::ColumnManager),
+                _ => None,
+            });
+        }
         if let Some(selected) = self.state.selected {
             self.columns[selected].handle_key_events(key)
         } else {
             Ok(None)
         }
     }
+    fn handle_mouse_events(&mut self, mouse: MouseEvent) -> Result<Option<Action>> {
+        if self.state.command_line.is_some()
+            || self.state.is_noteboard_active
+            || self.state.is_manager_active
+        {
+            return Ok(None);
+        }
+        let position = Position::new(mouse.column, mouse.row);
+        let Some(i) = self
+            .column_areas
+            .iter()
+            .find(|(_, area)| area.contains(position))
+            .map(|(i, _)| *i)
+        else {
+            return Ok(None);
+        };
+        // clicking a column focuses it
+        if matches!(mouse.kind, MouseEventKind::Down(_)) {
+            self.state.selected = Some(i);
+        }
+        self.columns[i].handle_mouse_events(mouse)
+    }
     fn update(&mut self, action: Action) -> Result<Option<Action>> {
         match action {
             Action::NextFocus => {
@@ -134,6 +488,37 @@ impl Component for MainComponent {
                 );
                 return Ok(Some(Action::Render));
             }
+            Action::ColumnSizing(column_sizing) => {
+                self.column_sizing = Some(column_sizing);
+                return Ok(Some(Action::Render));
+            }
+            Action::Noteboard => {
+                self.state.is_noteboard_active = !self.state.is_noteboard_active;
+                if self.state.is_noteboard_active {
+                    self.noteboard.reload_comments();
+                }
+                return Ok(Some(Action::Render));
+            }
+            Action::AddNote(post_view) => {
+                self.noteboard.add(*post_view);
+                return Ok(Some(Action::Render));
+            }
+            Action::CommandMode => {
+                self.state.command_line = Some(CommandLine::default());
+                return Ok(Some(Action::Render));
+            }
+            Action::ColumnManager => {
+                self.state.is_manager_active = !self.state.is_manager_active;
+                return Ok(Some(Action::Render));
+            }
+            // every column receives the ticks, e.g. for the animations
+            Action::Tick(_) => {
+                let mut render = false;
+                for column in self.columns.iter_mut() {
+                    render |= column.update(action.clone())?.is_some();
+                }
+                return Ok(render.then_some(Action::Render));
+            }
             _ => {
                 for column in self.columns.iter_mut() {
                     if let Some(action) = column.update(action.clone())? {
@@ -145,21 +530,82 @@ impl Component for MainComponent {
         Ok(None)
     }
     fn draw(&mut self, f: &mut Frame<'_>, area: Rect) -> Result<()> {
+        self.column_areas.clear();
+        for (i, column) in self.columns.iter().enumerate() {
+            column.set_focused(self.state.selected == Some(i));
+        }
+        // the command line takes up the bottom line while it is open
+        let area = if let Some(command_line) = &self.state.command_line {
+            let layout = Layout::vertical([Constraint::Fill(1), Constraint::Length(1)]).split(area);
+            Self::draw_command_line(f, command_line, layout[1]);
+            layout[0]
+        } else {
+            area
+        };
+        // the noteboard is shown on the right side
+        let area = if self.state.is_noteboard_active {
+            let layout = Layout::horizontal([
+                Constraint::Fill(1),
+                Constraint::Length(NOTEBOARD_WIDTH.min(area.width / 2)),
+            ])
+            .split(area);
+            self.noteboard.draw(f, layout[1])?;
+            layout[0]
+        } else {
+            area
+        };
+        // a column in focus mode takes up the whole area
+        if let Some(view) = self
+            .state
+            .selected
+            .and_then(|i| self.columns.get_mut(i))
+            .filter(|view| view.is_focus_mode())
+        {
+            let block = Block::bordered()
+                .title(view.title())
+                .title_alignment(Alignment::Center)
+                .border_type(BorderType::Double)
+                .border_style(Style::default().reset().bold());
+            view.draw(f, block.inner(area))?;
+            f.render_widget(block, area);
+            self.column_areas
+                .extend(self.state.selected.map(|i| (i, area)));
+            return Ok(());
+        }
+        let order = self.column_layout();
+        let constraints = self.column_constraints();
         let layout = Layout::default()
             .direction(Direction::Horizontal)
-            .constraints(self.columns.iter().map(|_| Constraint::Fill(1)))
+            .constraints(order.iter().map(|&i| {
+                if self.column_pins.get(i).copied().flatten().is_some() {
+                    Constraint::Length(PINNED_COLUMN_WIDTH)
+                } else {
+                    constraints[i]
+                }
+            }))
             .split(area);
-        for (i, (area, view)) in layout.iter().zip(self.columns.iter_mut()).enumerate() {
+        for (area, &i) in layout.iter().zip(&order) {
+            let view = &mut self.columns[i];
+            let mut style = self.column_styles.get(i).copied().unwrap_or_default();
+            // e.g. the highlight of new posts
+            if let Some(border_style) = view.border_style() {
+                style = style.patch(border_style);
+            }
             let mut block = Block::bordered()
                 .title(view.title())
-                .title_alignment(Alignment::Center);
+                .title_alignment(Alignment::Center)
+                .border_style(style);
             if self.state.selected == Some(i) {
                 block = block
                     .border_type(BorderType::Double)
-                    .border_style(Style::default().reset().bold());
+                    .border_style(Style::default().reset().bold().patch(style));
             }
             view.draw(f, block.inner(*area))?;
             f.render_widget(block, *area);
+            self.column_areas.push((i, *area));
+        }
+        if self.state.is_manager_active {
+            self.draw_manager(f, area);
         }
         Ok(())
     }
@@ -0,0 +1,279 @@
+use super::store::LocalStore;
+use super::Component;
+use crate::backend::comments::Comment;
+use crate::types::Action;
+use crate::utils::get_data_dir;
+use bsky_sdk::api::app::bsky::feed::defs::PostView;
+use bsky_sdk::api::records::{KnownRecord, Record};
+use chrono::Local;
+use color_eyre::Result;
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::layout::Rect;
+use ratatui::style::{Style, Stylize};
+use ratatui::text::{Line, Span, Text};
+use ratatui::widgets::{Block, BorderType, Clear, List, ListItem, ListState, Padding};
+use ratatui::Frame;
+use std::fs::{create_dir_all, File};
+use std::path::PathBuf;
+use std::sync::Arc;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Tab {
+    Pinned,
+    Comments,
+}
+
+/// A side panel of the posts pinned with `p` and the private comments written with `n`,
+/// kept across restarts.
+pub struct NoteboardComponent {
+    notes: Vec<PostView>,
+    // the comments matching the query, read from the database
+    comments: Vec<Comment>,
+    tab: Tab,
+    state: ListState,
+    query: String,
+    is_searching: bool,
+    store: Arc<LocalStore>,
+}
+
+impl NoteboardComponent {
+    /// Create the noteboard with the posts pinned in the previous sessions.
+    pub fn load(store: Arc<LocalStore>) -> Self {
+        let notes = Self::read_notes().unwrap_or_else(|e| {
+            log::warn!("failed to load pinned posts: {e}");
+            Vec::new()
+        });
+        Self {
+            state: ListState::default().with_selected((!notes.is_empty()).then_some(0)),
+            notes,
+            comments: Vec::new(),
+            tab: Tab::Pinned,
+            query: String::new(),
+            is_searching: false,
+            store,
+        }
+    }
+    /// Read the comments matching the query again, as they may have been edited in a column.
+    pub fn reload_comments(&mut self) {
+        match self
+            .store
+            .comments
+            .with(|comments| comments.search(&self.query))
+        {
+            Ok(comments) => self.comments = comments,
+            Err(e) => log::warn!("failed to load comments: {e}"),
+        }
+        if self.tab == Tab::Comments {
+            self.fix_selection();
+        }
+    }
+    fn len(&self) -> usize {
+        match self.tab {
+            Tab::Pinned => self.visible_notes().count(),
+            Tab::Comments => self.comments.len(),
+        }
+    }
+    /// Pin `post_view` to the top of the noteboard, unless it is already pinned.
+    pub fn add(&mut self, post_view: PostView) {
+        if self.notes.iter().any(|note| note.uri == post_view.uri) {
+            return;
+        }
+        self.notes.insert(0, post_view);
+        self.save();
+    }
+    fn remove_selected(&mut self) {
+        if self.tab == Tab::Comments {
+            let Some(mut comment) = self
+                .state
+                .selected()
+                .and_then(|i| self.comments.get(i))
+                .cloned()
+            else {
+                return;
+            };
+            comment.text.clear();
+            if let Err(e) = self.store.comments.with(|comments| comments.save(&comment)) {
+                log::error!("failed to delete comment: {e}");
+            }
+            return self.reload_comments();
+        }
+        let Some(uri) = self
+            .state
+            .selected()
+            .and_then(|i| self.visible_notes().nth(i))
+            .map(|note| note.uri.clone())
+        else {
+            return;
+        };
+        self.notes.retain(|note| note.uri != uri);
+        self.save();
+        self.fix_selection();
+    }
+    /// The notes matching the query.
+    fn visible_notes(&self) -> impl Iterator<Item = &PostView> {
+        let query = self.query.to_lowercase();
+        self.notes.iter().filter(move |note| {
+            query.is_empty()
+                || note.author.handle.as_str().contains(&query)
+                || text(note).to_lowercase().contains(&query)
+        })
+    }
+    fn fix_selection(&mut self) {
+        let len = self.len();
+        self.state.select(match self.state.selected() {
+            _ if len == 0 => None,
+            Some(i) => Some(i.min(len - 1)),
+            None => Some(0),
+        });
+    }
+    fn save(&self) {
+        let result = Self::path().and_then(|path| {
+            serde_json::to_writer(File::create(&path)?, &self.notes)?;
+            Ok(path)
+        });
+        match result {
+            Ok(path) => log::info!("saved pinned posts to {path:?}"),
+            Err(e) => log::error!("failed to save pinned posts: {e}"),
+        }
+    }
+    fn read_notes() -> Result<Vec<PostView>> {
+        Ok(serde_json::from_reader(File::open(Self::path()?)?)?)
+    }
+    fn path() -> Result<PathBuf> {
+        let data_dir = get_data_dir()?;
+        create_dir_all(&data_dir)?;
+        Ok(data_dir.join("pinned.json"))
+    }
+}
+
+impl Component for NoteboardComponent {
+    fn handle_key_events(&mut self, key: KeyEvent) -> Result<Option<Action>> {
+        if self.is_searching {
+            match key.code {
+                KeyCode::Enter => self.is_searching = false,
+                KeyCode::Esc => {
+                    self.is_searching = false;
+                    self.query.clear();
+                }
+                KeyCode::Backspace => {
+                    self.query.pop();
+                }
+                KeyCode::Char(c) => self.query.push(c),
+                _ => return Ok(None),
+            }
+            self.reload_comments();
+            self.fix_selection();
+            return Ok(Some(Action::Render));
+        }
+        let len = self.len();
+        match key.code {
+            KeyCode::Tab => {
+                self.tab = match self.tab {
+                    Tab::Pinned => Tab::Comments,
+                    Tab::Comments => Tab::Pinned,
+                };
+                self.state.select(None);
+                self.fix_selection();
+            }
+            KeyCode::Down if len > 0 => {
+                self.state.select(Some(
+                    self.state.selected().map_or(0, |i| (i + 1).min(len - 1)),
+                ));
+            }
+            KeyCode::Up if len > 0 => {
+                self.state
+                    .select(Some(self.state.selected().map_or(0, |i| i.max(1) - 1)));
+            }
+            KeyCode::Char('d') => self.remove_selected(),
+            KeyCode::Char('/') => self.is_searching = true,
+            KeyCode::Esc => return Ok(Some(Action::Noteboard)),
+            _ => return Ok(None),
+        }
+        Ok(Some(Action::Render))
+    }
+    fn draw(&mut self, f: &mut Frame<'_>, area: Rect) -> Result<()> {
+        let width = usize::from(area.width.saturating_sub(4));
+        let items = if self.tab == Tab::Comments {
+            self.comments
+                .iter()
+                .map(|comment| {
+                    let mut lines = vec![Line::from(format!("@{}", comment.handle)).bold()];
+                    lines.extend(
+                        textwrap::wrap(&comment.post_text, width.max(1))
+                            .into_iter()
+                            .take(2)
+                            .map(|line| Line::from(line.into_owned()).dim()),
+                    );
+                    lines.extend(
+                        textwrap::wrap(&format!("📝 {}", comment.text), width.max(1))
+                            .into_iter()
+                            .map(|line| Line::from(line.into_owned())),
+                    );
+                    lines.push(Line::default());
+                    ListItem::new(Text::from(lines))
+                })
+                .collect::<Vec<_>>()
+        } else {
+            self.visible_notes()
+                .map(|note| {
+                    let mut lines = vec![Line::from(vec![
+                        Span::from(format!("@{}", note.author.handle.as_str())).bold(),
+                        Span::from(
+                            note.indexed_at
+                                .as_ref()
+                                .with_timezone(&Local)
+                                .format(" %Y-%m-%d %H:%M")
+                                .to_string(),
+                        )
+                        .green(),
+                    ])];
+                    lines.extend(
+                        textwrap::wrap(&text(note), width.max(1))
+                            .into_iter()
+                            .take(3)
+                            .map(|line| Line::from(line.into_owned())),
+                    );
+                    lines.push(Line::default());
+                    ListItem::new(Text::from(lines))
+                })
+                .collect::<Vec<_>>()
+        };
+        let mut title = vec![
+            Span::from(" Noteboard: "),
+            Span::from(match self.tab {
+                Tab::Pinned => "Pinned ",
+                Tab::Comments => "Comments ",
+            }),
+        ];
+        if self.is_searching || !self.query.is_empty() {
+            title.push(Span::from(format!("/{} ", self.query)).yellow());
+        }
+        let block = Block::bordered()
+            .border_type(BorderType::Double)
+            .title(Line::from(title))
+            .title_bottom(
+                Line::from(match self.tab {
+                    Tab::Pinned => " / search, d unpin, Tab comments ",
+                    Tab::Comments => " / search, d delete, Tab pinned ",
+                })
+                .dim(),
+            )
+            .padding(Padding::horizontal(1));
+        f.render_widget(Clear, area);
+        f.render_stateful_widget(
+            List::new(items)
+                .block(block)
+                .highlight_style(Style::default().reversed()),
+            area,
+            &mut self.state,
+        );
+        Ok(())
+    }
+}
+
+fn text(post_view: &PostView) -> String {
+    match &post_view.record {
+        Record::Known(KnownRecord::AppBskyFeedPost(record)) => record.text.clone(),
+        _ => String::new(),
+    }
+}
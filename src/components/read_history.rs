@@ -0,0 +1,61 @@
+use serde::{Deserialize, Serialize, Serializer};
+use std::collections::{HashSet, VecDeque};
+
+/// The number of posts kept in the history, the oldest ones are forgotten first.
+const MAX_ENTRIES: usize = 10_000;
+
+/// The CIDs of the read posts, saved in the order they were first read.
+#[derive(Debug, Default, Deserialize)]
+#[serde(from = "Vec<String>")]
+pub struct ReadHistory {
+    order: VecDeque<String>,
+    read: HashSet<String>,
+}
+
+impl From<Vec<String>> for ReadHistory {
+    fn from(cids: Vec<String>) -> Self {
+        let mut history = Self::default();
+        history.mark_read(cids);
+        history
+    }
+}
+
+impl Serialize for ReadHistory {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(&self.order)
+    }
+}
+
+impl ReadHistory {
+    pub fn is_read(&self, cid: &str) -> bool {
+        self.read.contains(cid)
+    }
+    pub fn mark_read(&mut self, cids: impl IntoIterator<Item = String>) {
+        for cid in cids {
+            if self.read.insert(cid.clone()) {
+                self.order.push_back(cid);
+            }
+        }
+        while self.order.len() > MAX_ENTRIES {
+            if let Some(cid) = self.order.pop_front() {
+                self.read.remove(&cid);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn forget_oldest_entries() {
+        let mut history = ReadHistory::from(vec![String::from("a"), String::from("b")]);
+        history.mark_read([String::from("a")]);
+        history.mark_read((0..MAX_ENTRIES - 1).map(|i| i.to_string()));
+        assert!(!history.is_read("a"));
+        assert!(history.is_read("b"));
+        assert_eq!(history.order.len(), MAX_ENTRIES);
+        assert_eq!(history.read.len(), MAX_ENTRIES);
+    }
+}
@@ -0,0 +1,26 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScrollPosition {
+    pub cid: String,
+    /// Used if the post is no longer in the feed.
+    pub index: usize,
+    /// `indexed_at` of the post in unix seconds, to find the closest post if it is no longer in the feed.
+    pub indexed_at: i64,
+}
+
+/// The last positions in the feeds, by the key of the feed.
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct ScrollPositions(HashMap<String, ScrollPosition>);
+
+impl ScrollPositions {
+    /// Returns the last position in the feed identified by `key`.
+    pub fn position(&self, key: &str) -> Option<ScrollPosition> {
+        self.0.get(key).cloned()
+    }
+    pub fn set_position(&mut self, key: &str, position: ScrollPosition) {
+        self.0.insert(key.to_string(), position);
+    }
+}
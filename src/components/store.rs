@@ -0,0 +1,146 @@
+use super::blocked_domains::BlockedDomains;
+use super::duplicates::Duplicates;
+use super::read_history::ReadHistory;
+use super::scroll::ScrollPositions;
+use super::suggestions::SuggestionsCache;
+use crate::backend::comments::CommentStore;
+use crate::backend::tags::TagStore;
+use crate::components::widgets::TutorialState;
+use crate::utils::get_data_dir;
+use color_eyre::{eyre, Result};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::fs::{create_dir_all, File};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::Instant;
+
+/// A value saved in a JSON file of the data directory, loaded on first use.
+pub struct Persisted<T> {
+    file_name: &'static str,
+    value: Mutex<Option<T>>,
+}
+
+impl<T> Persisted<T>
+where
+    T: Default + Serialize + DeserializeOwned,
+{
+    pub fn new(file_name: &'static str) -> Self {
+        Self {
+            file_name,
+            value: Mutex::new(None),
+        }
+    }
+    /// Access the value, which is loaded from the file on first use.
+    pub fn with<R>(&self, f: impl FnOnce(&mut T) -> R) -> Option<R> {
+        let mut value = self.value.lock().ok()?;
+        Some(f(value.get_or_insert_with(|| self.load())))
+    }
+    /// Write the value to the data directory, if it has been used.
+    pub fn save(&self) -> Result<()> {
+        let Ok(value) = self.value.lock() else {
+            return Ok(());
+        };
+        if let Some(value) = value.as_ref() {
+            let path = self.path()?;
+            serde_json::to_writer(File::create(&path)?, value)?;
+            log::info!("saved {} to {path:?}", self.file_name);
+        }
+        Ok(())
+    }
+    fn load(&self) -> T {
+        self.path()
+            .and_then(|path| Ok(serde_json::from_reader(File::open(path)?)?))
+            .unwrap_or_else(|e| {
+                log::warn!("failed to load {}: {e}", self.file_name);
+                T::default()
+            })
+    }
+    fn path(&self) -> Result<PathBuf> {
+        let data_dir = get_data_dir()?;
+        create_dir_all(&data_dir)?;
+        Ok(data_dir.join(self.file_name))
+    }
+}
+
+/// A database in the data directory, opened on first use and kept open.
+pub struct Database<T> {
+    file_name: &'static str,
+    open: fn(&Path) -> Result<T>,
+    conn: Mutex<Option<T>>,
+}
+
+impl<T> Database<T> {
+    pub fn new(file_name: &'static str, open: fn(&Path) -> Result<T>) -> Self {
+        Self {
+            file_name,
+            open,
+            conn: Mutex::new(None),
+        }
+    }
+    /// Access the database, which is opened on first use.
+    pub fn with<R>(&self, f: impl FnOnce(&T) -> Result<R>) -> Result<R> {
+        let mut guard = self
+            .conn
+            .lock()
+            .map_err(|e| eyre::eyre!("failed to lock {}: {e}", self.file_name))?;
+        let conn = match guard.take() {
+            Some(conn) => conn,
+            None => {
+                let data_dir = get_data_dir()?;
+                create_dir_all(&data_dir)?;
+                (self.open)(&data_dir.join(self.file_name))?
+            }
+        };
+        let result = f(&conn);
+        *guard = Some(conn);
+        result
+    }
+}
+
+/// The local state of the feeds, shared by the columns and saved with the app data.
+pub struct LocalStore {
+    // only kept while running
+    pub duplicates: Duplicates,
+    pub last_bell: Mutex<Option<Instant>>,
+    pub scroll_positions: Persisted<ScrollPositions>,
+    pub read_history: Persisted<ReadHistory>,
+    pub blocked_domains: Persisted<BlockedDomains>,
+    pub suggestions: Persisted<SuggestionsCache>,
+    pub langs_history: Persisted<Vec<String>>,
+    pub search_history: Persisted<Vec<String>>,
+    pub tutorial: Persisted<TutorialState>,
+    pub comments: Database<CommentStore>,
+    pub tags: Database<TagStore>,
+}
+
+impl Default for LocalStore {
+    fn default() -> Self {
+        Self {
+            duplicates: Duplicates::default(),
+            last_bell: Mutex::new(None),
+            scroll_positions: Persisted::new("scroll_positions.json"),
+            read_history: Persisted::new("read_history.json"),
+            blocked_domains: Persisted::new("blocked_domains.json"),
+            suggestions: Persisted::new("suggestions.json"),
+            langs_history: Persisted::new("langs_history.json"),
+            search_history: Persisted::new("search_history.json"),
+            tutorial: Persisted::new("tutorial.json"),
+            comments: Database::new("comments.db", CommentStore::open),
+            tags: Database::new("tags.db", TagStore::open),
+        }
+    }
+}
+
+impl LocalStore {
+    pub fn save(&self) -> Result<()> {
+        self.scroll_positions.save()?;
+        self.read_history.save()?;
+        self.blocked_domains.save()?;
+        self.suggestions.save()?;
+        self.langs_history.save()?;
+        self.search_history.save()?;
+        self.tutorial.save()?;
+        Ok(())
+    }
+}
@@ -0,0 +1,45 @@
+use crate::backend::graph::Suggestions;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// How long the fetched suggestions are shown before fetching them again.
+const REFRESH_INTERVAL: Duration = Duration::from_secs(7 * 24 * 60 * 60);
+
+/// The accounts suggested to follow, kept for a week, and the ones dismissed from them.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct SuggestionsCache {
+    // the DIDs dismissed from the suggestions
+    dismissed: HashSet<String>,
+    // seconds since the epoch
+    fetched_at: u64,
+    suggestions: Option<Suggestions>,
+}
+
+impl SuggestionsCache {
+    pub fn is_dismissed(&self, did: &str) -> bool {
+        self.dismissed.contains(did)
+    }
+    pub fn dismiss(&mut self, did: &str) {
+        self.dismissed.insert(did.to_string());
+    }
+    /// The suggestions fetched within the last week, if any.
+    pub fn cached(&self) -> Option<Suggestions> {
+        (now().saturating_sub(self.fetched_at) < REFRESH_INTERVAL.as_secs())
+            .then(|| self.suggestions.clone())
+            .flatten()
+    }
+    /// Keep the suggestions, with the time of the first fetch if they are pages of the cached ones.
+    pub fn cache(&mut self, suggestions: Suggestions, append: bool) {
+        if !append || self.suggestions.is_none() {
+            self.fetched_at = now();
+        }
+        self.suggestions = Some(suggestions);
+    }
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_secs())
+}
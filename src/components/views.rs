@@ -1,22 +1,65 @@
+mod analytics;
+mod blocked_lists;
+mod calendar;
+mod comment;
+mod created_feeds;
+mod diff;
+mod export;
 mod feed;
+mod feed_generator;
+mod feedback;
+mod focus_mode;
+mod json;
+mod known_followers;
+mod labelers;
 mod login;
 mod menu;
+mod muted_words;
 mod new_post;
+mod popular_feeds;
 mod post;
+mod quotes;
 mod root;
+mod rss;
+mod search;
+mod suggestions;
+mod text;
+mod thread;
 pub mod types;
 mod utils;
+mod word_cloud;
 
+pub use self::analytics::PostAnalyticsViewComponent;
+pub use self::blocked_lists::BlockedListsViewComponent;
+pub use self::calendar::CalendarViewComponent;
+pub use self::comment::CommentViewComponent;
+pub use self::created_feeds::CreatedFeedsViewComponent;
+pub use self::diff::DiffViewComponent;
+pub use self::export::ExportViewComponent;
 pub use self::feed::FeedViewComponent;
+pub use self::feed_generator::FeedGeneratorDetailViewComponent;
+pub use self::feedback::FeedbackViewComponent;
+pub use self::focus_mode::FocusModeViewComponent;
+pub use self::json::JsonViewComponent;
+pub use self::known_followers::KnownFollowersViewComponent;
+pub use self::labelers::LabelersViewComponent;
 pub use self::login::LoginComponent;
 pub use self::menu::MenuViewComponent;
-pub use self::new_post::NewPostViewComponent;
+pub use self::muted_words::MutedWordsViewComponent;
+pub use self::new_post::{NewPostViewComponent, PostContext};
+pub use self::popular_feeds::PopularFeedsViewComponent;
 pub use self::post::PostViewComponent;
+pub use self::quotes::QuotePostsViewComponent;
 pub use self::root::RootComponent;
+pub use self::rss::RssViewComponent;
+pub use self::search::SearchViewComponent;
+pub use self::suggestions::SuggestionsViewComponent;
+pub use self::thread::ConversationViewComponent;
 use self::types::{Action, View};
+pub use self::word_cloud::WordCloudViewComponent;
 use color_eyre::Result;
-use crossterm::event::KeyEvent;
-use ratatui::{layout::Rect, Frame};
+use crossterm::event::{KeyEvent, MouseEvent};
+use ratatui::{layout::Rect, style::Style, Frame};
 
 pub trait ViewComponent {
     fn view(&self) -> View;
@@ -31,8 +74,16 @@ pub trait ViewComponent {
         Ok(None)
     }
     #[allow(unused_variables)]
+    fn handle_mouse_events(&mut self, mouse: MouseEvent) -> Result<Option<Action>> {
+        Ok(None)
+    }
+    #[allow(unused_variables)]
     fn update(&mut self, action: Action) -> Result<Option<Action>> {
         Ok(None)
     }
     fn draw(&mut self, f: &mut Frame<'_>, area: Rect) -> Result<()>;
+    /// Style applied to the border of the column while the view is shown.
+    fn border_style(&self) -> Option<Style> {
+        None
+    }
 }
@@ -0,0 +1,165 @@
+use super::types::{Action, Data, Transition, View};
+use super::ViewComponent;
+use crate::backend::analytics::{post_analytics, PostAnalytics, Snapshot};
+use crate::backend::client::BskyAgent;
+use crate::utils::get_data_dir;
+use bsky_sdk::api::app::bsky::feed::defs::PostView;
+use bsky_sdk::api::records::{KnownRecord, Record};
+use color_eyre::Result;
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::style::{Color, Stylize};
+use ratatui::text::Line;
+use ratatui::widgets::{Block, Borders, Cell, Padding, Paragraph, Row, Table};
+use ratatui::Frame;
+use std::sync::Arc;
+use tokio::sync::mpsc::UnboundedSender;
+
+pub struct PostAnalyticsViewComponent {
+    post_view: PostView,
+    analytics: Option<PostAnalytics>,
+    action_tx: UnboundedSender<Action>,
+    agent: Arc<BskyAgent>,
+}
+
+impl PostAnalyticsViewComponent {
+    pub fn new(
+        action_tx: UnboundedSender<Action>,
+        agent: Arc<BskyAgent>,
+        post_view: PostView,
+    ) -> Self {
+        Self {
+            post_view,
+            analytics: None,
+            action_tx,
+            agent,
+        }
+    }
+    fn load(&self) {
+        let (agent, tx) = (self.agent.clone(), self.action_tx.clone());
+        let uri = self.post_view.uri.clone();
+        tokio::spawn(async move {
+            let result = match get_data_dir() {
+                Ok(dir) => post_analytics(&agent, &uri, dir.join("analytics.db")).await,
+                Err(e) => Err(e),
+            };
+            match result {
+                Ok(analytics) => {
+                    let data = Data::Analytics(Box::new(analytics));
+                    if let Err(e) = tx.send(Action::Update(Box::new(data))) {
+                        log::error!("failed to send update action: {e}");
+                    }
+                }
+                Err(e) => {
+                    log::error!("failed to get post analytics: {e}");
+                }
+            }
+        });
+    }
+    fn row<'a>(label: &'a str, snapshot: Option<&Snapshot>, current: &Snapshot) -> Row<'a> {
+        let Some(snapshot) = snapshot else {
+            return Row::new(vec![Cell::from(label), "-".into(), "-".into(), "-".into()]).dim();
+        };
+        let count = |value: i64, now: i64| {
+            if snapshot == current || value == now {
+                Cell::from(value.to_string())
+            } else {
+                Cell::from(Line::from(vec![
+                    value.to_string().into(),
+                    format!(" ({:+})", now - value).green(),
+                ]))
+            }
+        };
+        Row::new(vec![
+            Cell::from(label),
+            count(snapshot.like_count, current.like_count),
+            count(snapshot.repost_count, current.repost_count),
+            count(snapshot.reply_count, current.reply_count),
+        ])
+    }
+}
+
+impl ViewComponent for PostAnalyticsViewComponent {
+    fn view(&self) -> View {
+        View::Analytics(Box::new(self.post_view.clone()))
+    }
+    fn activate(&mut self) -> Result<()> {
+        self.load();
+        Ok(())
+    }
+    fn update(&mut self, action: Action) -> Result<Option<Action>> {
+        match action {
+            Action::Back => Ok(Some(Action::Transition(Transition::Pop))),
+            Action::Refresh => {
+                self.load();
+                Ok(None)
+            }
+            Action::Update(data) => {
+                let Data::Analytics(analytics) = data.as_ref() else {
+                    return Ok(None);
+                };
+                self.analytics = Some(analytics.as_ref().clone());
+                Ok(Some(Action::Render))
+            }
+            _ => Ok(None),
+        }
+    }
+    fn draw(&mut self, f: &mut Frame<'_>, area: Rect) -> Result<()> {
+        let text = match &self.post_view.record {
+            Record::Known(KnownRecord::AppBskyFeedPost(record)) => record.text.as_str(),
+            _ => "",
+        };
+        let header = Paragraph::new("Post analytics").bold().block(
+            Block::default()
+                .borders(Borders::BOTTOM)
+                .border_style(Color::Gray)
+                .padding(Padding::horizontal(1)),
+        );
+        let layout = Layout::vertical([
+            Constraint::Length(2),
+            Constraint::Length(2),
+            Constraint::Percentage(100),
+        ])
+        .split(area);
+        f.render_widget(header, layout[0]);
+        f.render_widget(
+            Paragraph::new(text.lines().next().unwrap_or_default())
+                .gray()
+                .block(Block::default().padding(Padding::horizontal(1))),
+            layout[1],
+        );
+        let Some(analytics) = &self.analytics else {
+            f.render_widget(
+                Paragraph::new("Loading...")
+                    .dim()
+                    .block(Block::default().padding(Padding::horizontal(1))),
+                layout[2],
+            );
+            return Ok(());
+        };
+        let rows = [("now", Some(&analytics.current))]
+            .into_iter()
+            .chain(
+                analytics
+                    .history
+                    .iter()
+                    .map(|(label, snapshot)| (*label, snapshot.as_ref())),
+            )
+            .map(|(label, snapshot)| Self::row(label, snapshot, &analytics.current))
+            .collect::<Vec<_>>();
+        f.render_widget(
+            Table::new(
+                rows,
+                [
+                    Constraint::Length(10),
+                    Constraint::Fill(1),
+                    Constraint::Fill(1),
+                    Constraint::Fill(1),
+                ],
+            )
+            .header(Row::new(vec!["", "likes", "reposts", "replies"]).bold())
+            .block(Block::default().padding(Padding::horizontal(1))),
+            layout[2],
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,190 @@
+use super::types::{Action, Data, Transition, View};
+use super::utils::profile_name_as_str;
+use super::ViewComponent;
+use crate::backend::client::BskyAgent;
+use crate::backend::types::FeedSourceInfo;
+use crate::backend::{Watch, Watcher};
+use bsky_sdk::api::app::bsky::graph::defs::ListView;
+use color_eyre::Result;
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::style::{Color, Style, Stylize};
+use ratatui::text::{Line, Span, Text};
+use ratatui::widgets::{Block, Borders, List, ListState, Padding, Paragraph};
+use ratatui::Frame;
+use std::sync::Arc;
+use tokio::sync::mpsc::UnboundedSender;
+use tokio::sync::oneshot;
+
+pub struct BlockedListsViewComponent {
+    items: Vec<ListView>,
+    state: ListState,
+    action_tx: UnboundedSender<Action>,
+    agent: Arc<BskyAgent>,
+    watcher: Box<dyn Watch<Output = Vec<ListView>>>,
+    quit: Option<oneshot::Sender<()>>,
+}
+
+impl BlockedListsViewComponent {
+    pub fn new(action_tx: UnboundedSender<Action>, watcher: Arc<Watcher>) -> Self {
+        let agent = watcher.agent.clone();
+        let watcher = Box::new(watcher.list_blocks());
+        Self {
+            items: Vec::new(),
+            state: ListState::default(),
+            action_tx,
+            agent,
+            watcher,
+            quit: None,
+        }
+    }
+}
+
+impl ViewComponent for BlockedListsViewComponent {
+    fn view(&self) -> View {
+        View::BlockedLists
+    }
+    fn activate(&mut self) -> Result<()> {
+        let (tx, mut rx) = (self.action_tx.clone(), self.watcher.subscribe());
+        let (quit_tx, mut quit_rx) = oneshot::channel();
+        self.quit = Some(quit_tx);
+        tokio::spawn(async move {
+            loop {
+                tokio::select! {
+                    changed = rx.changed() => {
+                        if changed.is_ok() {
+                            if let Err(e) = tx.send(Action::Update(Box::new(Data::ListBlocks(
+                                rx.borrow_and_update().clone(),
+                            )))) {
+                                log::error!("failed to send update action: {e}");
+                            }
+                        } else {
+                            break log::warn!("list blocks channel closed");
+                        }
+                    }
+                    _ = &mut quit_rx => {
+                        break;
+                    }
+                }
+            }
+            log::debug!("subscription finished");
+        });
+        Ok(())
+    }
+    fn deactivate(&mut self) -> Result<()> {
+        if let Some(tx) = self.quit.take() {
+            if tx.send(()).is_err() {
+                log::error!("failed to send quit signal");
+            }
+        }
+        self.watcher.unsubscribe();
+        Ok(())
+    }
+    fn update(&mut self, action: Action) -> Result<Option<Action>> {
+        match action {
+            Action::NextItem if !self.items.is_empty() => {
+                self.state.select(Some(
+                    self.state
+                        .selected()
+                        .map(|s| (s + 1).min(self.items.len() - 1))
+                        .unwrap_or_default(),
+                ));
+                return Ok(Some(Action::Render));
+            }
+            Action::PrevItem if !self.items.is_empty() => {
+                self.state.select(Some(
+                    self.state
+                        .selected()
+                        .map(|s| s.max(1) - 1)
+                        .unwrap_or_default(),
+                ));
+                return Ok(Some(Action::Render));
+            }
+            Action::Enter => {
+                if let Some(list_view) = self.state.selected().and_then(|i| self.items.get(i)) {
+                    return Ok(Some(Action::Transition(Transition::Push(Box::new(
+                        View::Feed(Box::new(FeedSourceInfo::List(Box::new(list_view.clone())))),
+                    )))));
+                }
+            }
+            Action::Delete => {
+                if let Some(uri) = self
+                    .state
+                    .selected()
+                    .and_then(|i| self.items.get(i))
+                    .and_then(|list_view| list_view.viewer.as_ref())
+                    .and_then(|viewer| viewer.blocked.clone())
+                {
+                    let (agent, tx) = (self.agent.clone(), self.action_tx.clone());
+                    tokio::spawn(async move {
+                        match agent.delete_record(uri).await {
+                            Ok(_) => {
+                                log::info!("deleted list block record");
+                                tx.send(Action::Refresh).ok();
+                            }
+                            Err(e) => {
+                                log::error!("failed to delete list block record: {e}");
+                            }
+                        }
+                    });
+                }
+            }
+            Action::Back => return Ok(Some(Action::Transition(Transition::Pop))),
+            Action::Refresh => {
+                self.watcher.refresh();
+            }
+            Action::Update(data) => {
+                let Data::ListBlocks(lists) = data.as_ref() else {
+                    return Ok(None);
+                };
+                self.items.clone_from(lists);
+                self.state.select(match self.state.selected() {
+                    _ if self.items.is_empty() => None,
+                    Some(s) => Some(s.min(self.items.len() - 1)),
+                    None => Some(0),
+                });
+                return Ok(Some(Action::Render));
+            }
+            _ => {}
+        }
+        Ok(None)
+    }
+    fn draw(&mut self, f: &mut Frame<'_>, area: Rect) -> Result<()> {
+        let header = Paragraph::new("Blocked lists").bold().block(
+            Block::default()
+                .borders(Borders::BOTTOM)
+                .border_style(Color::Gray)
+                .padding(Padding::horizontal(1)),
+        );
+        let items = self
+            .items
+            .iter()
+            .map(|list_view| {
+                Text::from(vec![
+                    Line::from(vec![
+                        Span::from(list_view.name.as_str()).bold(),
+                        Span::from(" "),
+                        Span::from(format!("by {}", profile_name_as_str(&list_view.creator)))
+                            .gray(),
+                    ]),
+                    Line::from(format!(
+                        "  {} members",
+                        list_view.list_item_count.unwrap_or_default()
+                    ))
+                    .dim(),
+                ])
+            })
+            .collect::<Vec<_>>();
+
+        let layout =
+            Layout::vertical([Constraint::Length(2), Constraint::Percentage(100)]).split(area);
+        f.render_widget(header, layout[0]);
+        f.render_stateful_widget(
+            List::new(items)
+                .highlight_style(Style::default().reset().reversed())
+                .block(Block::default().padding(Padding::horizontal(1))),
+            layout[1],
+            &mut self.state,
+        );
+        Ok(())
+    }
+}
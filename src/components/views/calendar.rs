@@ -0,0 +1,170 @@
+use super::types::{Action, Transition, View};
+use super::ViewComponent;
+use chrono::{Datelike, Days, Months, NaiveDate};
+use color_eyre::Result;
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::style::{Color, Style, Stylize};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Padding, Paragraph};
+use ratatui::Frame;
+use std::collections::BTreeMap;
+use tokio::sync::mpsc::UnboundedSender;
+
+const WEEKDAYS: [&str; 7] = ["Mo", "Tu", "We", "Th", "Fr", "Sa", "Su"];
+
+/// A month calendar of the loaded posts, to show only the posts of a day in the feed.
+pub struct CalendarViewComponent {
+    action_tx: UnboundedSender<Action>,
+    // the number of loaded posts of each day
+    counts: BTreeMap<NaiveDate, usize>,
+    selected: NaiveDate,
+}
+
+impl CalendarViewComponent {
+    pub fn new(
+        action_tx: UnboundedSender<Action>,
+        counts: BTreeMap<NaiveDate, usize>,
+        selected: NaiveDate,
+    ) -> Self {
+        Self {
+            action_tx,
+            counts,
+            selected,
+        }
+    }
+    fn select(&mut self, date: Option<NaiveDate>) -> Option<Action> {
+        self.selected = date?;
+        Some(Action::Render)
+    }
+    /// Close the calendar and apply the filter to the feed revealed by closing it.
+    fn filter(&self, date: Option<NaiveDate>) -> Result<Option<Action>> {
+        self.action_tx.send(Action::FilterDate(date))?;
+        Ok(Some(Action::Transition(Transition::Pop)))
+    }
+    fn day_style(count: usize) -> Style {
+        match count {
+            0 => Style::default().dark_gray(),
+            1..=4 => Style::default().green(),
+            5..=19 => Style::default().yellow(),
+            _ => Style::default().red().bold(),
+        }
+    }
+    fn month_lines(&self) -> Vec<Line<'static>> {
+        let first = self.selected.with_day(1).unwrap_or(self.selected);
+        let days = (first + Months::new(1))
+            .signed_duration_since(first)
+            .num_days() as u32;
+        let offset = first.weekday().num_days_from_monday();
+        let border = |left: &str, middle: &str, right: &str| {
+            Line::from(format!("{left}{}{right}", ["────"; 7].join(middle))).gray()
+        };
+        let mut lines = vec![
+            border("┌", "┬", "┐"),
+            Line::from(
+                WEEKDAYS
+                    .iter()
+                    .map(|weekday| format!("│ {weekday} "))
+                    .collect::<String>()
+                    + "│",
+            )
+            .bold(),
+            border("├", "┼", "┤"),
+        ];
+        for week in 0..(offset + days).div_ceil(7) {
+            let mut spans = Vec::new();
+            for weekday in 0..7 {
+                spans.push(Span::from("│ ").gray());
+                let day = (week * 7 + weekday + 1).checked_sub(offset);
+                match day.filter(|day| (1..=days).contains(day)) {
+                    Some(day) => {
+                        let date = first.with_day(day).unwrap_or(first);
+                        let count = self.counts.get(&date).copied().unwrap_or_default();
+                        let mut style = Self::day_style(count);
+                        if date == self.selected {
+                            style = style.reversed();
+                        }
+                        spans.push(Span::styled(format!("{day:>2}"), style));
+                    }
+                    None => spans.push(Span::from("  ")),
+                }
+                spans.push(Span::from(" "));
+            }
+            spans.push(Span::from("│").gray());
+            lines.push(Line::from(spans));
+        }
+        lines.push(border("└", "┴", "┘"));
+        lines
+    }
+}
+
+impl ViewComponent for CalendarViewComponent {
+    fn view(&self) -> View {
+        View::Calendar(Box::new((self.counts.clone(), self.selected)))
+    }
+    fn handle_key_events(&mut self, key: KeyEvent) -> Result<Option<Action>> {
+        Ok(match key.code {
+            KeyCode::Left => self.select(self.selected.checked_sub_days(Days::new(1))),
+            KeyCode::Right => self.select(self.selected.checked_add_days(Days::new(1))),
+            KeyCode::PageUp => self.select(self.selected.checked_sub_months(Months::new(1))),
+            KeyCode::PageDown => self.select(self.selected.checked_add_months(Months::new(1))),
+            KeyCode::Char('x') => return self.filter(None),
+            KeyCode::Esc => Some(Action::Back),
+            _ => None,
+        })
+    }
+    fn update(&mut self, action: Action) -> Result<Option<Action>> {
+        Ok(match action {
+            Action::NextItem => self.select(self.selected.checked_add_days(Days::new(7))),
+            Action::PrevItem => self.select(self.selected.checked_sub_days(Days::new(7))),
+            Action::Enter => return self.filter(Some(self.selected)),
+            Action::Back | Action::Calendar => Some(Action::Transition(Transition::Pop)),
+            _ => None,
+        })
+    }
+    fn draw(&mut self, f: &mut Frame<'_>, area: Rect) -> Result<()> {
+        let count = self.counts.get(&self.selected).copied().unwrap_or_default();
+        let lines = [
+            vec![
+                Line::from(self.selected.format("%B %Y").to_string()).bold(),
+                Line::default(),
+            ],
+            self.month_lines(),
+            vec![
+                Line::default(),
+                Line::from(format!(
+                    "{}: {count} loaded posts",
+                    self.selected.format("%Y-%m-%d")
+                )),
+                Line::from(vec![
+                    Span::from("■").green(),
+                    Span::from(" 1-4  "),
+                    Span::from("■").yellow(),
+                    Span::from(" 5-19  "),
+                    Span::from("■").red(),
+                    Span::from(" 20+"),
+                ]),
+                Line::default(),
+                Line::from("Enter: show only this day, x: show all days").dim(),
+                Line::from("←/→: day, ↑/↓: week, PageUp/PageDown: month").dim(),
+            ],
+        ]
+        .concat();
+        let layout =
+            Layout::vertical([Constraint::Length(2), Constraint::Percentage(100)]).split(area);
+        f.render_widget(
+            Paragraph::new("Calendar of the loaded posts").bold().block(
+                Block::default()
+                    .borders(Borders::BOTTOM)
+                    .border_style(Color::Gray)
+                    .padding(Padding::horizontal(1)),
+            ),
+            layout[0],
+        );
+        f.render_widget(
+            Paragraph::new(lines).block(Block::default().padding(Padding::uniform(1))),
+            layout[1],
+        );
+        Ok(())
+    }
+}
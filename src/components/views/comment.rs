@@ -0,0 +1,152 @@
+use super::types::{Action, Transition, View};
+use super::utils::profile_name;
+use super::ViewComponent;
+use crate::backend::comments::Comment;
+use crate::components::store::LocalStore;
+use bsky_sdk::api::app::bsky::feed::defs::PostView;
+use bsky_sdk::api::records::{KnownRecord, Record};
+use color_eyre::Result;
+use crossterm::event::KeyEvent;
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::style::{Color, Style, Stylize};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Padding, Paragraph, Wrap};
+use ratatui::Frame;
+use std::sync::Arc;
+use tui_textarea::TextArea;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Focus {
+    Text,
+    Save,
+}
+
+/// Edits the private comment on a post.
+pub struct CommentViewComponent {
+    post_view: PostView,
+    store: Arc<LocalStore>,
+    textarea: TextArea<'static>,
+    focus: Focus,
+}
+
+impl CommentViewComponent {
+    pub fn new(post_view: PostView, store: Arc<LocalStore>) -> Self {
+        let lines = store
+            .comments
+            .with(|comments| comments.get(&post_view.uri))
+            .unwrap_or_else(|e| {
+                log::warn!("failed to load comment: {e}");
+                None
+            })
+            .map(|comment| comment.text.lines().map(String::from).collect())
+            .unwrap_or_default();
+        let mut textarea = TextArea::new(lines);
+        textarea.set_block(Block::bordered().title("Comment (only saved locally)"));
+        textarea.set_cursor_line_style(Style::default());
+        Self {
+            post_view,
+            store,
+            textarea,
+            focus: Focus::Text,
+        }
+    }
+    fn post_text(&self) -> &str {
+        match &self.post_view.record {
+            Record::Known(KnownRecord::AppBskyFeedPost(record)) => &record.text,
+            _ => "",
+        }
+    }
+    fn save(&self) {
+        let comment = Comment {
+            uri: self.post_view.uri.clone(),
+            cid: self.post_view.cid.as_ref().to_string(),
+            handle: self.post_view.author.handle.as_str().to_string(),
+            post_text: self.post_text().to_string(),
+            text: self.textarea.lines().join("\n"),
+        };
+        if let Err(e) = self.store.comments.with(|comments| comments.save(&comment)) {
+            log::error!("failed to save comment: {e}");
+        }
+    }
+}
+
+impl ViewComponent for CommentViewComponent {
+    fn view(&self) -> View {
+        View::Comment(Box::new(self.post_view.clone()))
+    }
+    fn handle_key_events(&mut self, key: KeyEvent) -> Result<Option<Action>> {
+        if self.focus == Focus::Text {
+            let cursor = self.textarea.cursor();
+            if self.textarea.input(key) || self.textarea.cursor() != cursor {
+                return Ok(Some(Action::Render));
+            }
+        }
+        Ok(None)
+    }
+    fn update(&mut self, action: Action) -> Result<Option<Action>> {
+        match action {
+            Action::NextItem | Action::PrevItem => {
+                self.focus = match self.focus {
+                    Focus::Text => Focus::Save,
+                    Focus::Save => Focus::Text,
+                };
+                self.textarea
+                    .set_cursor_style(if self.focus == Focus::Text {
+                        Style::default().reversed()
+                    } else {
+                        Style::default()
+                    });
+                Ok(Some(Action::Render))
+            }
+            Action::Enter if self.focus == Focus::Save => {
+                self.save();
+                Ok(Some(Action::Transition(Transition::Pop)))
+            }
+            Action::Back => Ok(Some(Action::Transition(Transition::Pop))),
+            _ => Ok(None),
+        }
+    }
+    fn draw(&mut self, f: &mut Frame<'_>, area: Rect) -> Result<()> {
+        let layout = Layout::vertical([
+            Constraint::Length(2),
+            Constraint::Length(5),
+            Constraint::Length(10),
+            Constraint::Length(1),
+        ])
+        .split(area);
+
+        let mut save = Line::from("Save (an empty comment is deleted)")
+            .centered()
+            .blue();
+        if self.focus == Focus::Save {
+            save = save.reversed();
+        }
+        f.render_widget(
+            Paragraph::new(Line::from(
+                [
+                    vec![Span::from("Comment on the post by ")],
+                    profile_name(&self.post_view.author),
+                ]
+                .concat(),
+            ))
+            .bold()
+            .block(
+                Block::default()
+                    .borders(Borders::BOTTOM)
+                    .border_style(Color::Gray)
+                    .padding(Padding::horizontal(1)),
+            ),
+            layout[0],
+        );
+        f.render_widget(
+            Paragraph::new(self.post_text())
+                .gray()
+                .wrap(Wrap { trim: true })
+                .block(Block::default().padding(Padding::horizontal(1))),
+            layout[1],
+        );
+        f.render_widget(self.textarea.widget(), layout[2]);
+        f.render_widget(save, layout[3]);
+        Ok(())
+    }
+}
@@ -0,0 +1,189 @@
+use super::types::{Action, Data, Transition, View};
+use super::utils::profile_name;
+use super::ViewComponent;
+use crate::backend::types::FeedSourceInfo;
+use crate::backend::{Watch, Watcher};
+use bsky_sdk::api::app::bsky::actor::defs::ProfileViewBasic;
+use bsky_sdk::api::app::bsky::feed::defs::GeneratorView;
+use color_eyre::Result;
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::style::{Color, Style, Stylize};
+use ratatui::text::{Line, Span, Text};
+use ratatui::widgets::{Block, Borders, List, ListState, Padding, Paragraph};
+use ratatui::Frame;
+use std::sync::Arc;
+use tokio::sync::mpsc::UnboundedSender;
+use tokio::sync::oneshot;
+
+pub struct CreatedFeedsViewComponent {
+    profile: ProfileViewBasic,
+    items: Vec<GeneratorView>,
+    state: ListState,
+    action_tx: UnboundedSender<Action>,
+    watcher: Box<dyn Watch<Output = Vec<GeneratorView>>>,
+    quit: Option<oneshot::Sender<()>>,
+}
+
+impl CreatedFeedsViewComponent {
+    pub fn new(
+        action_tx: UnboundedSender<Action>,
+        watcher: Arc<Watcher>,
+        profile: ProfileViewBasic,
+    ) -> Self {
+        let watcher = Box::new(watcher.created_feeds(profile.did.clone()));
+        Self {
+            profile,
+            items: Vec::new(),
+            state: ListState::default(),
+            action_tx,
+            watcher,
+            quit: None,
+        }
+    }
+}
+
+impl ViewComponent for CreatedFeedsViewComponent {
+    fn view(&self) -> View {
+        View::CreatedFeeds(Box::new(self.profile.clone()))
+    }
+    fn activate(&mut self) -> Result<()> {
+        let (tx, mut rx) = (self.action_tx.clone(), self.watcher.subscribe());
+        let (quit_tx, mut quit_rx) = oneshot::channel();
+        self.quit = Some(quit_tx);
+        tokio::spawn(async move {
+            loop {
+                tokio::select! {
+                    changed = rx.changed() => {
+                        if changed.is_ok() {
+                            if let Err(e) = tx.send(Action::Update(Box::new(Data::CreatedFeeds(
+                                rx.borrow_and_update().clone(),
+                            )))) {
+                                log::error!("failed to send update action: {e}");
+                            }
+                        } else {
+                            break log::warn!("created feeds channel closed");
+                        }
+                    }
+                    _ = &mut quit_rx => {
+                        break;
+                    }
+                }
+            }
+            log::debug!("subscription finished");
+        });
+        Ok(())
+    }
+    fn deactivate(&mut self) -> Result<()> {
+        if let Some(tx) = self.quit.take() {
+            if tx.send(()).is_err() {
+                log::error!("failed to send quit signal");
+            }
+        }
+        self.watcher.unsubscribe();
+        Ok(())
+    }
+    fn update(&mut self, action: Action) -> Result<Option<Action>> {
+        match action {
+            Action::NextItem if !self.items.is_empty() => {
+                self.state.select(Some(
+                    self.state
+                        .selected()
+                        .map(|s| (s + 1).min(self.items.len() - 1))
+                        .unwrap_or_default(),
+                ));
+                return Ok(Some(Action::Render));
+            }
+            Action::PrevItem if !self.items.is_empty() => {
+                self.state.select(Some(
+                    self.state
+                        .selected()
+                        .map(|s| s.max(1) - 1)
+                        .unwrap_or_default(),
+                ));
+                return Ok(Some(Action::Render));
+            }
+            Action::Enter => {
+                if let Some(generator_view) = self.state.selected().and_then(|i| self.items.get(i))
+                {
+                    return Ok(Some(Action::Transition(Transition::Push(Box::new(
+                        View::Feed(Box::new(FeedSourceInfo::Feed(Box::new(
+                            generator_view.clone(),
+                        )))),
+                    )))));
+                }
+            }
+            Action::Info => {
+                if let Some(generator_view) = self.state.selected().and_then(|i| self.items.get(i))
+                {
+                    return Ok(Some(Action::Transition(Transition::Push(Box::new(
+                        View::FeedGenerator(generator_view.uri.clone()),
+                    )))));
+                }
+            }
+            Action::Back | Action::Feeds => {
+                return Ok(Some(Action::Transition(Transition::Pop)));
+            }
+            Action::Refresh => {
+                self.watcher.refresh();
+            }
+            Action::Update(data) => {
+                let Data::CreatedFeeds(feeds) = data.as_ref() else {
+                    return Ok(None);
+                };
+                self.items.clone_from(feeds);
+                if self.state.selected().is_none() && !self.items.is_empty() {
+                    self.state.select(Some(0));
+                }
+                return Ok(Some(Action::Render));
+            }
+            _ => {}
+        }
+        Ok(None)
+    }
+    fn draw(&mut self, f: &mut Frame<'_>, area: Rect) -> Result<()> {
+        let header = Paragraph::new(Line::from(
+            [vec![Span::from("Feeds by ")], profile_name(&self.profile)].concat(),
+        ))
+        .bold()
+        .block(
+            Block::default()
+                .borders(Borders::BOTTOM)
+                .border_style(Color::Gray)
+                .padding(Padding::horizontal(1)),
+        );
+        let items = self
+            .items
+            .iter()
+            .map(|generator_view| {
+                Text::from(vec![
+                    Line::from(vec![
+                        Span::from(generator_view.display_name.as_str()).bold(),
+                        Span::from(" "),
+                        Span::from(format!(
+                            "{} likes",
+                            generator_view.like_count.unwrap_or_default()
+                        ))
+                        .gray(),
+                    ]),
+                    Line::from(format!(
+                        "  {}",
+                        generator_view.description.as_deref().unwrap_or_default()
+                    ))
+                    .dim(),
+                ])
+            })
+            .collect::<Vec<_>>();
+
+        let layout =
+            Layout::vertical([Constraint::Length(2), Constraint::Percentage(100)]).split(area);
+        f.render_widget(header, layout[0]);
+        f.render_stateful_widget(
+            List::new(items)
+                .highlight_style(Style::default().reset().reversed())
+                .block(Block::default().padding(Padding::horizontal(1))),
+            layout[1],
+            &mut self.state,
+        );
+        Ok(())
+    }
+}
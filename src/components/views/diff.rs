@@ -0,0 +1,178 @@
+use super::types::{Action, Transition, View};
+use super::utils::profile_name;
+use super::ViewComponent;
+use bsky_sdk::api::app::bsky::feed::defs::PostView;
+use bsky_sdk::api::records::{KnownRecord, Record};
+use color_eyre::Result;
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::style::{Color, Stylize};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Padding, Paragraph};
+use ratatui::Frame;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Change {
+    Unchanged,
+    Removed,
+    Added,
+}
+
+/// Shows the previous and the current text of an edited post side by side.
+pub struct DiffViewComponent {
+    previous: String,
+    post_view: PostView,
+}
+
+impl DiffViewComponent {
+    pub fn new(previous: String, post_view: PostView) -> Self {
+        Self {
+            previous,
+            post_view,
+        }
+    }
+    fn current(&self) -> &str {
+        match &self.post_view.record {
+            Record::Known(KnownRecord::AppBskyFeedPost(record)) => &record.text,
+            _ => "",
+        }
+    }
+}
+
+impl ViewComponent for DiffViewComponent {
+    fn view(&self) -> View {
+        View::Diff(Box::new((self.previous.clone(), self.post_view.clone())))
+    }
+    fn handle_key_events(&mut self, key: KeyEvent) -> Result<Option<Action>> {
+        Ok(match key.code {
+            KeyCode::Esc => Some(Action::Back),
+            _ => None,
+        })
+    }
+    fn update(&mut self, action: Action) -> Result<Option<Action>> {
+        Ok(match action {
+            Action::Back | Action::Diff => Some(Action::Transition(Transition::Pop)),
+            _ => None,
+        })
+    }
+    fn draw(&mut self, f: &mut Frame<'_>, area: Rect) -> Result<()> {
+        let header = Paragraph::new(Line::from(
+            [
+                vec![Span::from("Changes of the post by ")],
+                profile_name(&self.post_view.author),
+            ]
+            .concat(),
+        ))
+        .bold()
+        .block(
+            Block::default()
+                .borders(Borders::BOTTOM)
+                .border_style(Color::Gray)
+                .padding(Padding::horizontal(1)),
+        );
+        let layout =
+            Layout::vertical([Constraint::Length(2), Constraint::Percentage(100)]).split(area);
+        let sides = Layout::horizontal([Constraint::Percentage(50); 2]).split(layout[1]);
+        let width = usize::from(sides[0].width.saturating_sub(3)).max(1);
+        let (mut old, mut new) = (Vec::new(), Vec::new());
+        for (change, line) in line_diff(&self.previous, self.current()) {
+            // keep both sides aligned, padding the side without the line
+            let mut wrapped = textwrap::wrap(line, width)
+                .into_iter()
+                .map(|s| Line::from(s.into_owned()))
+                .collect::<Vec<_>>();
+            if wrapped.is_empty() {
+                wrapped.push(Line::default());
+            }
+            let len = wrapped.len();
+            match change {
+                Change::Unchanged => {
+                    old.extend(wrapped.clone());
+                    new.extend(wrapped);
+                }
+                Change::Removed => {
+                    old.extend(wrapped.into_iter().map(|line| line.red()));
+                    new.extend((0..len).map(|_| Line::default()));
+                }
+                Change::Added => {
+                    old.extend((0..len).map(|_| Line::default()));
+                    new.extend(wrapped.into_iter().map(|line| line.green()));
+                }
+            }
+        }
+        f.render_widget(header, layout[0]);
+        f.render_widget(
+            Paragraph::new(old).block(
+                Block::default()
+                    .title("Previous")
+                    .borders(Borders::RIGHT)
+                    .padding(Padding::horizontal(1)),
+            ),
+            sides[0],
+        );
+        f.render_widget(
+            Paragraph::new(new).block(
+                Block::default()
+                    .title("Current")
+                    .padding(Padding::horizontal(1)),
+            ),
+            sides[1],
+        );
+        Ok(())
+    }
+}
+
+/// Compare the texts line by line, using the longest common subsequence of the lines.
+fn line_diff<'a>(old: &'a str, new: &'a str) -> Vec<(Change, &'a str)> {
+    let (old, new) = (
+        old.lines().collect::<Vec<_>>(),
+        new.lines().collect::<Vec<_>>(),
+    );
+    // lcs[i][j] is the length of the longest common subsequence of old[i..] and new[j..]
+    let mut lcs = vec![vec![0; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lcs[i][j] = if old[i] == new[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+    let (mut i, mut j, mut ret) = (0, 0, Vec::new());
+    while i < old.len() && j < new.len() {
+        if old[i] == new[j] {
+            ret.push((Change::Unchanged, old[i]));
+            (i, j) = (i + 1, j + 1);
+        } else if lcs[i + 1][j] >= lcs[i][j + 1] {
+            ret.push((Change::Removed, old[i]));
+            i += 1;
+        } else {
+            ret.push((Change::Added, new[j]));
+            j += 1;
+        }
+    }
+    ret.extend(old[i..].iter().map(|line| (Change::Removed, *line)));
+    ret.extend(new[j..].iter().map(|line| (Change::Added, *line)));
+    ret
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn diff_lines() {
+        assert_eq!(
+            line_diff("a\nb\nc", "a\nc\nd"),
+            vec![
+                (Change::Unchanged, "a"),
+                (Change::Removed, "b"),
+                (Change::Unchanged, "c"),
+                (Change::Added, "d"),
+            ]
+        );
+        assert_eq!(line_diff("", "a"), vec![(Change::Added, "a")]);
+        assert_eq!(line_diff("a", ""), vec![(Change::Removed, "a")]);
+    }
+}
@@ -0,0 +1,213 @@
+use super::types::{Action, Transition, View};
+use super::ViewComponent;
+use crate::backend::export::{export_feed, ExportFormat};
+use crate::components::widgets::TextInput;
+use bsky_sdk::api::app::bsky::feed::defs::FeedViewPost;
+use color_eyre::Result;
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::style::{Color, Style, Stylize};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Padding, Paragraph};
+use ratatui::Frame;
+use std::path::{Path, PathBuf};
+
+/// The file name suggested for the export, with the extension of the format.
+const DEFAULT_FILE_STEM: &str = "tuisky-feed";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Focus {
+    Format,
+    Path,
+    Export,
+}
+
+impl Focus {
+    fn next(&self) -> Self {
+        match self {
+            Self::Format => Self::Path,
+            Self::Path => Self::Export,
+            Self::Export => Self::Format,
+        }
+    }
+    fn prev(&self) -> Self {
+        match self {
+            Self::Format => Self::Export,
+            Self::Path => Self::Format,
+            Self::Export => Self::Path,
+        }
+    }
+}
+
+/// Asks for the format and the path to export the visible posts of a feed.
+pub struct ExportViewComponent {
+    posts: Vec<FeedViewPost>,
+    format: ExportFormat,
+    path: TextInput,
+    focus: Focus,
+    // the result of the last export
+    status: Option<Result<PathBuf, String>>,
+}
+
+impl ExportViewComponent {
+    pub fn new(posts: Vec<FeedViewPost>) -> Self {
+        let mut path = TextInput::default();
+        path.set_value(&format!(
+            "{DEFAULT_FILE_STEM}.{}",
+            ExportFormat::Markdown.extension()
+        ));
+        path.set_block(Block::bordered().title("Path").dim());
+        path.set_cursor_style(Style::default());
+        Self {
+            posts,
+            format: ExportFormat::Markdown,
+            path,
+            focus: Focus::Format,
+            status: None,
+        }
+    }
+    fn update_focus(&mut self, focus: Focus) {
+        self.focus = focus;
+        let focused = self.focus == Focus::Path;
+        self.path.set_cursor_style(if focused {
+            Style::default().reversed()
+        } else {
+            Style::default()
+        });
+        if let Some(block) = self.path.block().cloned() {
+            self.path
+                .set_block(if focused { block.reset() } else { block.dim() });
+        }
+    }
+    fn toggle_format(&mut self) {
+        let previous = self.format;
+        self.format = match self.format {
+            ExportFormat::Markdown => ExportFormat::Html,
+            ExportFormat::Html => ExportFormat::Markdown,
+        };
+        // keep the extension in sync, unless it has been changed by the user
+        let path = PathBuf::from(self.path.value());
+        if path.extension().and_then(|ext| ext.to_str()) == Some(previous.extension()) {
+            self.path.set_value(
+                &path
+                    .with_extension(self.format.extension())
+                    .to_string_lossy(),
+            );
+        }
+    }
+    fn export(&mut self) {
+        let value = self.path.value();
+        let path = Path::new(value.trim());
+        self.status = Some(if path.as_os_str().is_empty() {
+            Err(String::from("the path is empty"))
+        } else {
+            export_feed(&self.posts, self.format, path)
+                .map(|()| path.to_path_buf())
+                .map_err(|e| e.to_string())
+        });
+    }
+}
+
+impl ViewComponent for ExportViewComponent {
+    fn view(&self) -> View {
+        View::Export(self.posts.clone())
+    }
+    fn handle_key_events(&mut self, key: KeyEvent) -> Result<Option<Action>> {
+        match self.focus {
+            Focus::Format => {
+                if matches!(
+                    key.code,
+                    KeyCode::Left | KeyCode::Right | KeyCode::Char(' ')
+                ) {
+                    self.toggle_format();
+                    return Ok(Some(Action::Render));
+                }
+            }
+            Focus::Path => {
+                if key.code != KeyCode::Enter && self.path.input(key) {
+                    return Ok(Some(Action::Render));
+                }
+            }
+            Focus::Export => {}
+        }
+        Ok(None)
+    }
+    fn update(&mut self, action: Action) -> Result<Option<Action>> {
+        Ok(match action {
+            Action::NextItem => {
+                self.update_focus(self.focus.next());
+                Some(Action::Render)
+            }
+            Action::PrevItem => {
+                self.update_focus(self.focus.prev());
+                Some(Action::Render)
+            }
+            Action::Enter if self.focus == Focus::Export => {
+                self.export();
+                Some(Action::Render)
+            }
+            Action::Back | Action::Export => Some(Action::Transition(Transition::Pop)),
+            _ => None,
+        })
+    }
+    fn draw(&mut self, f: &mut Frame<'_>, area: Rect) -> Result<()> {
+        let layout = Layout::vertical([
+            Constraint::Length(2),
+            Constraint::Length(2),
+            Constraint::Length(3),
+            Constraint::Length(2),
+            Constraint::Length(2),
+        ])
+        .split(area);
+
+        f.render_widget(
+            Paragraph::new(format!("Export {} posts", self.posts.len()))
+                .bold()
+                .block(
+                    Block::default()
+                        .borders(Borders::BOTTOM)
+                        .border_style(Color::Gray)
+                        .padding(Padding::horizontal(1)),
+                ),
+            layout[0],
+        );
+        let option = |format: ExportFormat, label: &'static str| {
+            let span = Span::from(format!(
+                "({}) {label}",
+                if self.format == format { "x" } else { " " }
+            ));
+            if self.focus == Focus::Format && self.format == format {
+                span.reversed()
+            } else {
+                span
+            }
+        };
+        f.render_widget(
+            Paragraph::new(Line::from(vec![
+                Span::from("Format: "),
+                option(ExportFormat::Markdown, "Markdown"),
+                Span::from("  "),
+                option(ExportFormat::Html, "HTML"),
+            ]))
+            .block(Block::default().padding(Padding::horizontal(1))),
+            layout[1],
+        );
+        f.render_widget(&self.path, layout[2]);
+        let mut export = Line::from("Export").centered().blue();
+        if self.focus == Focus::Export {
+            export = export.reversed();
+        }
+        f.render_widget(export, layout[3]);
+        if let Some(status) = &self.status {
+            f.render_widget(
+                match status {
+                    Ok(path) => Line::from(format!("Exported to {}", path.display())).green(),
+                    Err(e) => Line::from(format!("Failed to export: {e}")).red(),
+                }
+                .centered(),
+                layout[4],
+            );
+        }
+        Ok(())
+    }
+}
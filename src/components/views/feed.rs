@@ -1,291 +1,3424 @@
+mod filters;
+mod preview;
+mod stats;
+mod visual;
+
+use self::filters::FeedFilters;
+use self::preview::SplitPreview;
+use self::stats::{FeedAnalytics, FeedStats};
+use self::visual::VisualMode;
+use super::post::PostViewComponent;
+use super::text::{link_card_lines, text_lines};
 use super::types::{Action, Data, Transition, View};
-use super::utils::{counts, profile_name, profile_name_as_str};
+use super::utils::{
+    counts, external_uri, find_next_by_author, format_duration, format_timestamp, heat_map,
+    link_domain, mentions, post_url, profile_name, profile_name_as_str, record_url,
+    PostHeightEstimator, ScrollAccelerator,
+};
 use super::ViewComponent;
+use crate::backend::analytics::record_posts;
+use crate::backend::client::BskyAgent;
+use crate::backend::comments::CommentStore;
+use crate::backend::discover::{request_less, DISCOVER_SERVICE};
+use crate::backend::graph::{add_to_list, lists, mutual_follows};
+use crate::backend::posts::{raw_record, replied_uris, top_repliers};
+use crate::backend::search::SearchQuery;
+use crate::backend::tags::{parse_tags, TagStore};
+use crate::backend::translation::{translate, Config as TranslationConfig, Translation};
 use crate::backend::types::FeedSourceInfo;
-use crate::backend::{Watch, Watcher};
+use crate::backend::{PostHistory, Watch, Watcher};
+use crate::components::scroll::ScrollPosition;
+use crate::components::store::LocalStore;
+use crate::components::widgets::{
+    ContextMenu, TextInput, TutorialAreas, TutorialOverlay, TutorialState, TutorialStep,
+    TutorialTarget,
+};
+use crate::config::{
+    ColumnAction, ColumnConfig, Config, FeedConfig, Keybindings, ReplyContext, TimestampFormat,
+};
+use crate::tui;
+use crate::utils::{get_data_dir, open_url};
+use bsky_sdk::api;
+use bsky_sdk::api::app::bsky::embed::record::ViewRecordRefs;
+use bsky_sdk::api::app::bsky::embed::record_with_media::ViewMediaRefs;
 use bsky_sdk::api::app::bsky::feed::defs::{
-    FeedViewPost, FeedViewPostReasonRefs, PostViewEmbedRefs, ReplyRefParentRefs,
+    FeedViewPost, FeedViewPostReasonRefs, PostView, PostViewEmbedRefs, ReplyRefParentRefs,
+    ReplyRefRootRefs,
 };
+use bsky_sdk::api::app::bsky::feed::get_post_thread::OutputThreadRefs;
+use bsky_sdk::api::app::bsky::feed::post::RecordData;
+use bsky_sdk::api::app::bsky::graph::defs::ListView;
+use bsky_sdk::api::com::atproto::moderation::defs;
 use bsky_sdk::api::records::{KnownRecord, Record};
+use bsky_sdk::api::types::string::{Cid, Datetime, Did};
 use bsky_sdk::api::types::Union;
-use chrono::Local;
+use chrono::{DateTime, Local, NaiveDate, TimeDelta, Utc};
 use color_eyre::Result;
-use ratatui::layout::{Constraint, Layout, Rect};
+use crossterm::event::{KeyCode, KeyEvent, MouseButton, MouseEvent, MouseEventKind};
+use indexmap::IndexMap;
+use ratatui::layout::{Constraint, Layout, Position, Rect};
 use ratatui::style::{Color, Style, Stylize};
 use ratatui::text::{Line, Span, Text};
-use ratatui::widgets::{Block, Borders, List, ListState, Padding, Paragraph};
+use ratatui::widgets::{
+    Block, Borders, Cell, Clear, List, ListItem, ListState, Padding, Paragraph, Row, Scrollbar,
+    ScrollbarOrientation, ScrollbarState, Table,
+};
 use ratatui::Frame;
-use std::sync::Arc;
-use textwrap::Options;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::io::Write;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant};
 use tokio::sync::mpsc::UnboundedSender;
 use tokio::sync::oneshot;
 
+/// Difference between the creation and the indexing of a post, above which it is marked as edited.
+const EDITED_THRESHOLD: TimeDelta = TimeDelta::minutes(10);
+/// How long the border of the column is highlighted after new posts arrive.
+const FLASH_DURATION: Duration = Duration::from_millis(500);
+/// How long a status message is shown in the header.
+const STATUS_DURATION: Duration = Duration::from_secs(2);
+/// How long a post must stay selected to be marked as read.
+const READ_DWELL: Duration = Duration::from_secs(1);
+/// The height of a placeholder row shown while the feed is loading, including the blank line.
+const PLACEHOLDER_HEIGHT: u16 = 5;
+/// The number of the authors shown in the leaderboard of the repliers.
+const TOP_REPLIERS: usize = 5;
+/// The number of the frames of the animation of a post dismissed as not interesting.
+const DISMISS_FRAMES: u32 = 3;
+/// How long each frame of the dismissal animation is shown.
+const DISMISS_FRAME_DURATION: Duration = Duration::from_millis(150);
+/// The background of every other post with `zebra_stripe`, near-black on dark terminals.
+const ZEBRA_STRIPE: Color = Color::Indexed(234);
+/// The local tag added to the posts bookmarked in visual mode.
+const BOOKMARK_TAG: &str = "bookmark";
+/// The type of the embedded videos, which are not known to the API types.
+const VIDEO_VIEW_TYPE: &str = "app.bsky.embed.video#view";
+
+/// The handles of the authors and their numbers of replies, from the most active.
+type Repliers = Vec<(String, usize)>;
+
+/// The actions of the context menu of a post.
+#[derive(Debug, Clone)]
+enum PostMenuItem {
+    Open,
+    Like,
+    Unlike(String),
+    Repost,
+    Quote,
+    Reply,
+    Conversation,
+    Quotes,
+    Profile,
+    CopyLink,
+    OpenInBrowser,
+    Report,
+    // one of the reason types of `com.atproto.moderation.defs`
+    ReportAs(&'static str),
+    Delete,
+    ConfirmDelete,
+    // the label with the number of the own posts selected in visual mode
+    ConfirmDeleteSelection(String),
+    // the URI and the name of a list, for the authors of the posts selected in visual mode
+    AddToList(String, String),
+    Cancel,
+}
+
+impl PostMenuItem {
+    fn label(&self) -> &str {
+        match self {
+            Self::Open => "Open",
+            Self::Like => "Like",
+            Self::Unlike(_) => "Unlike",
+            Self::Repost => "Repost",
+            Self::Quote => "Quote",
+            Self::Reply => "Reply",
+            Self::Conversation => "View thread",
+            Self::Quotes => "View quotes",
+            Self::Profile => "View profile",
+            Self::CopyLink => "Copy link",
+            Self::OpenInBrowser => "Open in browser",
+            Self::Report => "Report...",
+            Self::ReportAs(defs::REASON_SPAM) => "Spam",
+            Self::ReportAs(defs::REASON_MISLEADING) => "Misleading",
+            Self::ReportAs(defs::REASON_SEXUAL) => "Unwanted sexual content",
+            Self::ReportAs(defs::REASON_RUDE) => "Rude or harassing",
+            Self::ReportAs(defs::REASON_VIOLATION) => "Violates the rules",
+            Self::ReportAs(_) => "Other",
+            Self::Delete => "Delete...",
+            Self::ConfirmDelete => "Delete the post",
+            Self::ConfirmDeleteSelection(label) => label,
+            Self::AddToList(_, name) => name,
+            Self::Cancel => "Cancel",
+        }
+    }
+}
+
 pub struct FeedViewComponent {
+    feed: Vec<FeedViewPost>,
     items: Vec<FeedViewPost>,
     state: ListState,
+    heights: PostHeightEstimator,
     action_tx: UnboundedSender<Action>,
     feed_info: FeedSourceInfo,
     watcher: Box<dyn Watch<Output = Vec<FeedViewPost>>>,
     quit: Option<oneshot::Sender<()>>,
+    tacked: Option<Cid>,
+    agent: Arc<BskyAgent>,
+    mutuals: Arc<RwLock<HashMap<Did, bool>>>,
+    browser_command: Option<String>,
+    pds_browser_url: Option<String>,
+    dev: bool,
+    zebra_stripe: bool,
+    threads: Arc<Watcher>,
+    preview: SplitPreview,
+    // the leaderboard of the repliers of the selected post while it is open, `None` until fetched
+    repliers: Option<Arc<RwLock<Option<Repliers>>>>,
+    filters: FeedFilters,
+    expanded: HashSet<Cid>,
+    tutorial: Option<TutorialOverlay>,
+    // the context menu of a post, opened with a right-click
+    context_menu: Option<(ContextMenu, Vec<PostMenuItem>)>,
+    // the area of the list in the last draw, to find the post under the mouse
+    list_area: Rect,
+    did: Option<Did>,
+    compact: bool,
+    timestamp_format: TimestampFormat,
+    header_collapsed: bool,
+    analytics: FeedAnalytics,
+    live_mode: bool,
+    // the minimum interval between the bells, if the bell is enabled
+    bell_interval: Option<Duration>,
+    // the bell for the new posts only rings in the focused column
+    column_focused: Arc<AtomicBool>,
+    // the interval of the bell for the posts mentioning the account
+    mention_bell_interval: Option<Duration>,
+    accessible: bool,
+    translation: Option<TranslationConfig>,
+    translations: Arc<RwLock<HashMap<(Cid, String), Translation>>>,
+    history: PostHistory,
+    // the URIs of the posts with a private comment
+    commented: HashSet<String>,
+    // the local tags of the posts by their CID, and the input of the tags of the selected post
+    tags: HashMap<Cid, BTreeSet<String>>,
+    tag_input: Option<TextInput>,
+    visual: VisualMode,
+    // the URIs of the posts and the threads replied to by the user
+    replied: Arc<RwLock<HashSet<String>>>,
+    // the position saved in the previous run, restored on the first update
+    restore: Option<ScrollPosition>,
+    store: Arc<LocalStore>,
+    last_update_flash: Option<Instant>,
+    status: Option<(String, Instant)>,
+    reply_context: ReplyContext,
+    thread_depth: u8,
+    background: Option<Color>,
+    // the parents of the replies which are not included in the feed, by URI
+    parents: Arc<RwLock<HashMap<String, PostView>>>,
+    requested_parents: HashSet<String>,
+    // the selected post and since when
+    focused: Option<(Cid, Instant)>,
+    // the post whose time the selected posts are compared with
+    compare_anchor: Option<(Cid, DateTime<Local>)>,
+    scroll_accelerator: ScrollAccelerator,
+    digest_mode: bool,
+    // to find the posts shown in the other columns
+    id: u32,
+    // the posts being removed once the feed generator got the feedback, and since when
+    dismissed: Arc<RwLock<HashMap<Cid, Instant>>>,
+    // false until the first update, to show the placeholders
+    loaded: bool,
+    // the frame of the placeholder animation, advanced on each tick
+    shimmer: usize,
+    // group the posts of the same thread, collapsed to the most recent one unless expanded
+    group_threads: bool,
+    expanded_threads: HashSet<String>,
+    // the number of the posts in the thread of each visible post, if grouped with others
+    thread_sizes: HashMap<Cid, usize>,
+}
+
+/// The URI of the post replied to by `post_view`.
+fn parent_uri(post_view: &PostView) -> Option<&str> {
+    match &post_view.record {
+        Record::Known(KnownRecord::AppBskyFeedPost(record)) => {
+            record.reply.as_ref().map(|reply| reply.parent.uri.as_str())
+        }
+        _ => None,
+    }
+}
+
+/// What is shown of the parent of a reply, above the reply.
+pub(super) enum ReplyParent<'a> {
+    None,
+    Author(&'a PostView),
+    Post(&'a PostView),
+    /// The parents up to the thread depth, from the nearest one.
+    Chain(Vec<&'a PostView>),
+}
+
+impl<'a> ReplyParent<'a> {
+    /// `fetched` is used if the parent is not included in the feed.
+    pub(super) fn new(
+        feed_view_post: &'a FeedViewPost,
+        reply_context: ReplyContext,
+        fetched: Option<&'a PostView>,
+    ) -> Self {
+        let included = feed_view_post
+            .reply
+            .as_ref()
+            .and_then(|reply| match &reply.parent {
+                Union::Refs(ReplyRefParentRefs::PostView(post_view)) => Some(&**post_view),
+                _ => None,
+            });
+        match reply_context {
+            ReplyContext::Auto => included.map_or(Self::None, Self::Author),
+            ReplyContext::Always => included.or(fetched).map_or(Self::None, Self::Post),
+            ReplyContext::Never => Self::None,
+        }
+    }
 }
 
 impl FeedViewComponent {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         action_tx: UnboundedSender<Action>,
         watcher: Arc<Watcher>,
         feed_info: FeedSourceInfo,
+        config: &Config,
+        column_config: &ColumnConfig,
+        feed_config: Option<&FeedConfig>,
+        did: Option<Did>,
+        store: Arc<LocalStore>,
+        column_focused: Arc<AtomicBool>,
     ) -> Self {
-        let watcher = Box::new(watcher.feed(feed_info.clone()));
+        let (agent, history) = (watcher.agent.clone(), watcher.history.clone());
+        let digest_mode = feed_config.is_some_and(|feed| feed.digest_mode);
+        let threads = watcher.clone();
+        let watcher = if digest_mode {
+            watcher.digest_feed_source(feed_info.clone())
+        } else {
+            watcher.feed_source(feed_info.clone())
+        };
+        let restore = store
+            .scroll_positions
+            .with(|positions| positions.position(&feed_info.key()))
+            .flatten();
         Self {
+            feed: Vec::new(),
             items: Vec::new(),
             state: ListState::default(),
+            heights: PostHeightEstimator::default(),
             action_tx,
             feed_info,
             watcher,
             quit: None,
+            tacked: None,
+            agent,
+            mutuals: Arc::new(RwLock::new(HashMap::new())),
+            browser_command: config.browser_command.clone(),
+            pds_browser_url: config.pds_browser_url.clone(),
+            dev: config.dev,
+            zebra_stripe: config.ui.zebra_stripe,
+            threads,
+            preview: SplitPreview::new(config.ui.split_view),
+            repliers: None,
+            filters: FeedFilters {
+                only_with_media: column_config.only_with_media,
+                max_age_hours: column_config.max_age_hours,
+                configured_max_age_hours: column_config.max_age_hours,
+                languages: feed_config.and_then(|feed| feed.filter_languages.clone()),
+                show_untagged: feed_config.map_or(true, |feed| feed.show_untagged),
+                hide_duplicates: config.ui.hide_cross_column_duplicates,
+                blocked_domains: config.blocked_domains.clone(),
+                ..FeedFilters::default()
+            },
+            expanded: HashSet::new(),
+            context_menu: None,
+            list_area: Rect::default(),
+            tutorial: if config.ui.tutorial_seen {
+                None
+            } else {
+                store
+                    .tutorial
+                    .with(|state| {
+                        TutorialOverlay::first_run(tutorial_steps(&config.keybindings), state)
+                    })
+                    .flatten()
+            },
+            did,
+            compact: config.ui.compact_feed,
+            timestamp_format: config.ui.timestamp_format,
+            header_collapsed: false,
+            analytics: FeedAnalytics::default(),
+            live_mode: false,
+            bell_interval: config
+                .ui
+                .bell_on_new_posts
+                .then(|| Duration::from_secs(config.ui.bell_min_interval_secs)),
+            column_focused,
+            mention_bell_interval: config
+                .ui
+                .bell_on_mention
+                .then(|| Duration::from_secs(config.ui.bell_min_interval_secs)),
+            accessible: config.ui.accessible,
+            translation: config.translation.clone(),
+            translations: Arc::new(RwLock::new(HashMap::new())),
+            history,
+            commented: HashSet::new(),
+            tags: HashMap::new(),
+            tag_input: None,
+            visual: VisualMode::default(),
+            replied: Arc::new(RwLock::new(HashSet::new())),
+            restore,
+            last_update_flash: None,
+            status: None,
+            reply_context: column_config.show_reply_context,
+            thread_depth: column_config.thread_depth,
+            background: column_config.background,
+            parents: Arc::new(RwLock::new(HashMap::new())),
+            requested_parents: HashSet::new(),
+            focused: None,
+            compare_anchor: None,
+            scroll_accelerator: ScrollAccelerator::default(),
+            digest_mode,
+            id: store.duplicates.next_id(),
+            store,
+            dismissed: Arc::new(RwLock::new(HashMap::new())),
+            loaded: false,
+            shimmer: 0,
+            group_threads: false,
+            expanded_threads: HashSet::new(),
+            thread_sizes: HashMap::new(),
         }
     }
-    fn lines(feed_view_post: &FeedViewPost, area: Rect) -> Option<Vec<Line>> {
-        let Record::Known(KnownRecord::AppBskyFeedPost(record)) = &feed_view_post.post.record
+    fn reply_parent<'a>(
+        &self,
+        feed_view_post: &'a FeedViewPost,
+        parents: &'a HashMap<String, PostView>,
+    ) -> ReplyParent<'a> {
+        let fetched = parent_uri(&feed_view_post.post).and_then(|uri| parents.get(uri));
+        if self.thread_depth <= 1 || self.reply_context == ReplyContext::Never {
+            return ReplyParent::new(feed_view_post, self.reply_context, fetched);
+        }
+        let ReplyParent::Post(parent) =
+            ReplyParent::new(feed_view_post, ReplyContext::Always, fetched)
         else {
-            return None;
+            return ReplyParent::None;
         };
-        let mut lines = Vec::new();
-        {
-            let mut spans = [
-                vec![
-                    Span::from(
-                        feed_view_post
-                            .post
-                            .indexed_at
-                            .as_ref()
-                            .with_timezone(&Local)
-                            .format("%Y-%m-%d %H:%M:%S %z")
-                            .to_string(),
-                    )
-                    .green(),
-                    Span::from(": "),
-                ],
-                profile_name(&feed_view_post.post.author),
-            ]
-            .concat();
-            if let Some(labels) = feed_view_post
-                .post
-                .author
-                .labels
-                .as_ref()
-                .filter(|v| !v.is_empty())
-            {
-                spans.push(Span::from(" "));
-                spans.push(format!("[{} labels]", labels.len()).magenta());
-            }
-            lines.push(Line::from(spans));
+        let mut chain = vec![parent];
+        while chain.len() < usize::from(self.thread_depth) {
+            let Some(next) = chain
+                .last()
+                .and_then(|post_view| parent_uri(post_view))
+                .and_then(|uri| parents.get(uri))
+            else {
+                break;
+            };
+            chain.push(next);
         }
-        if let Some(Union::Refs(FeedViewPostReasonRefs::ReasonRepost(repost))) =
-            &feed_view_post.reason
+        ReplyParent::Chain(chain)
+    }
+    /// Fetch the parents of the replies which are not included in the feed, all at once.
+    ///
+    /// With a thread depth, the parents of the parents are fetched level by level.
+    fn fetch_parents(&mut self) {
+        let chained = self.thread_depth > 1;
+        if self.reply_context == ReplyContext::Never
+            || (self.reply_context == ReplyContext::Auto && !chained)
         {
-            lines.push(
-                Line::from(format!("  Reposted by {}", profile_name_as_str(&repost.by))).blue(),
-            );
+            return;
         }
-        if let Some(reply) = &feed_view_post.reply {
-            if let Union::Refs(ReplyRefParentRefs::PostView(post_view)) = &reply.parent {
-                lines.push(Line::from(
-                    [
-                        vec![Span::from("  Reply to ").blue()],
-                        profile_name(&post_view.author),
-                    ]
-                    .concat(),
-                ));
+        // the parents included in the feed are the first level of their chains
+        let mut next = Vec::new();
+        if chained {
+            if let Ok(mut parents) = self.parents.write() {
+                for feed_view_post in &self.feed {
+                    if let Some(Union::Refs(ReplyRefParentRefs::PostView(post_view))) =
+                        feed_view_post.reply.as_ref().map(|reply| &reply.parent)
+                    {
+                        if let Some(uri) = parent_uri(post_view) {
+                            if !parents.contains_key(uri)
+                                && self.requested_parents.insert(uri.to_string())
+                            {
+                                next.push((uri.to_string(), 2));
+                            }
+                        }
+                        parents.insert(post_view.uri.clone(), (**post_view).clone());
+                    }
+                }
             }
         }
-        lines.extend(
-            textwrap::wrap(
-                &record.text,
-                Options::new(usize::from(area.width) - 2)
-                    .initial_indent("  ")
-                    .subsequent_indent("  "),
-            )
+        let uris = self
+            .feed
             .iter()
-            .map(|s| Line::from(s.to_string())),
-        );
-        if let Some(embed) = &feed_view_post.post.embed {
-            let content = match embed {
-                Union::Refs(PostViewEmbedRefs::AppBskyEmbedImagesView(images)) => {
-                    format!("{} images", images.images.len())
+            .filter(|feed_view_post| {
+                !matches!(
+                    feed_view_post.reply.as_ref().map(|reply| &reply.parent),
+                    Some(Union::Refs(ReplyRefParentRefs::PostView(_)))
+                )
+            })
+            .filter_map(|feed_view_post| match &feed_view_post.post.record {
+                Record::Known(KnownRecord::AppBskyFeedPost(record)) => {
+                    record.reply.as_ref().map(|reply| reply.parent.uri.clone())
                 }
-                Union::Refs(PostViewEmbedRefs::AppBskyEmbedExternalView(_)) => {
-                    String::from("external")
-                }
-                Union::Refs(PostViewEmbedRefs::AppBskyEmbedRecordView(_)) => String::from("record"),
-                Union::Refs(PostViewEmbedRefs::AppBskyEmbedRecordWithMediaView(_)) => {
-                    String::from("recordWithMedia")
-                }
-                _ => String::from("unknown"),
-            };
-            lines.push(Line::from(format!("  Embedded {content}")).yellow());
+                _ => None,
+            })
+            .filter(|uri| self.requested_parents.insert(uri.clone()))
+            .map(|uri| (uri, 1))
+            .chain(next)
+            .collect::<Vec<_>>();
+        if uris.is_empty() {
+            return;
         }
-        lines.push(Line::from(
-            [vec![Span::from("  ")], counts(&feed_view_post.post, 5)].concat(),
-        ));
-        Some(lines)
-    }
-}
-
-impl ViewComponent for FeedViewComponent {
-    fn view(&self) -> View {
-        View::Feed(Box::new(self.feed_info.clone()))
-    }
-    fn activate(&mut self) -> Result<()> {
-        let (tx, mut rx) = (self.action_tx.clone(), self.watcher.subscribe());
-        let (quit_tx, mut quit_rx) = oneshot::channel();
-        self.quit = Some(quit_tx);
+        let (agent, cache, parents, tx, depth) = (
+            self.agent.clone(),
+            self.threads.posts.clone(),
+            self.parents.clone(),
+            self.action_tx.clone(),
+            self.thread_depth,
+        );
         tokio::spawn(async move {
-            loop {
-                tokio::select! {
-                    changed = rx.changed() => {
-                        match changed {
-                            Ok(()) => {
-                                if let Err(e) = tx.send(Action::Update(Box::new(Data::Feed(
-                                    rx.borrow_and_update().clone(),
-                                )))) {
-                                    log::error!("failed to send update action: {e}");
-                                }
-                            }
-                            Err(e) => {
-                                log::warn!("changed channel error: {e}");
-                                break;
+            let mut levels = uris.into_iter().collect::<HashMap<_, _>>();
+            while !levels.is_empty() {
+                let uris = levels.keys().cloned().collect::<Vec<_>>();
+                let posts = match cache.posts(&agent, &uris).await {
+                    Ok(posts) => posts,
+                    Err(e) => {
+                        log::warn!("failed to get the parents of the replies: {e}");
+                        break;
+                    }
+                };
+                let mut next = HashMap::new();
+                if let Ok(mut parents) = parents.write() {
+                    for post_view in posts {
+                        let level = levels.get(&post_view.uri).copied().unwrap_or(depth);
+                        if let Some(uri) = parent_uri(&post_view) {
+                            if level < depth && !parents.contains_key(uri) {
+                                next.insert(uri.to_string(), level + 1);
                             }
                         }
+                        parents.insert(post_view.uri.clone(), post_view);
                     }
-                    _ = &mut quit_rx => {
-                        break;
-                    }
                 }
+                if let Err(e) = tx.send(Action::Render) {
+                    log::error!("failed to send render action: {e}");
+                }
+                levels = next;
             }
-            log::debug!("subscription finished");
         });
-        Ok(())
     }
-    fn deactivate(&mut self) -> Result<()> {
-        if let Some(tx) = self.quit.take() {
-            if tx.send(()).is_err() {
-                log::error!("failed to send quit signal");
+    /// Highlight the border of the column, and render again once the highlight is over.
+    fn flash(&mut self) {
+        self.last_update_flash = Some(Instant::now());
+        let tx = self.action_tx.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(FLASH_DURATION).await;
+            if let Err(e) = tx.send(Action::Render) {
+                log::error!("failed to send render action: {e}");
             }
+        });
+    }
+    /// The service which receives the interactions with the posts of the feed, if it accepts them.
+    fn interactions_service(&self) -> Option<String> {
+        match &self.feed_info {
+            FeedSourceInfo::Suggested => Some(String::from(DISCOVER_SERVICE)),
+            FeedSourceInfo::Feed(generator_view) => generator_view
+                .accepts_interactions
+                .unwrap_or_default()
+                .then(|| generator_view.did.to_string()),
+            _ => None,
         }
-        self.watcher.unsubscribe();
-        Ok(())
     }
-    fn update(&mut self, action: Action) -> Result<Option<Action>> {
-        match action {
-            Action::NextItem if !self.items.is_empty() => {
-                self.state.select(Some(
-                    self.state
-                        .selected()
-                        .map(|s| (s + 1).min(self.items.len() - 1))
-                        .unwrap_or_default(),
+    /// Send the feedback of the post, and animate its removal once the feed generator got it.
+    fn request_less(&self, service: String, feed_view_post: FeedViewPost) {
+        let (agent, tx) = (self.agent.clone(), self.action_tx.clone());
+        let dismissed = self.dismissed.clone();
+        tokio::spawn(async move {
+            if let Err(e) = request_less(&agent, &service, &feed_view_post).await {
+                return log::warn!("failed to send the feedback of the post: {e}");
+            }
+            if let Ok(mut dismissed) = dismissed.write() {
+                dismissed.insert(feed_view_post.post.cid.clone(), Instant::now());
+            }
+            // the last render removes the post
+            for _ in 0..=DISMISS_FRAMES {
+                if let Err(e) = tx.send(Action::Render) {
+                    return log::error!("failed to send render action: {e}");
+                }
+                tokio::time::sleep(DISMISS_FRAME_DURATION).await;
+            }
+        });
+    }
+    /// The frame of the dismissal animation of the post, if it is being removed.
+    fn dismissal_frame(&self, cid: &Cid) -> Option<u32> {
+        let dismissed = self.dismissed.read().ok()?;
+        let frame = dismissed.get(cid)?.elapsed().as_millis() / DISMISS_FRAME_DURATION.as_millis();
+        u32::try_from(frame)
+            .ok()
+            .filter(|frame| *frame < DISMISS_FRAMES)
+    }
+    /// Hide the posts whose dismissal animation is over.
+    fn remove_dismissed(&mut self) {
+        let done = match self.dismissed.write() {
+            Ok(mut dismissed) => {
+                let done = dismissed
+                    .iter()
+                    .filter(|(_, since)| since.elapsed() >= DISMISS_FRAME_DURATION * DISMISS_FRAMES)
+                    .map(|(cid, _)| cid.clone())
+                    .collect::<Vec<_>>();
+                for cid in &done {
+                    dismissed.remove(cid);
+                }
+                done
+            }
+            Err(_) => return,
+        };
+        if done.is_empty() {
+            return;
+        }
+        self.filters.not_interested.extend(done);
+        let index = self.state.selected();
+        self.update_items();
+        // the next post takes the place of the hidden one
+        self.state.select(
+            index
+                .filter(|_| !self.items.is_empty())
+                .map(|i| i.min(self.items.len() - 1)),
+        );
+    }
+    /// The dates of the oldest and the newest posts in view, from the offset of the list.
+    fn date_range(&self, height: usize) -> Paragraph<'static> {
+        let offset = self.state.offset();
+        let visible = self.items.get(self.heights.visible(offset, height));
+        let dates = visible.into_iter().flatten().map(|feed_view_post| {
+            feed_view_post
+                .post
+                .indexed_at
+                .as_ref()
+                .with_timezone(&Local)
+        });
+        let mut line = match (dates.clone().min(), dates.max()) {
+            (Some(oldest), Some(newest)) => Line::from(format!(
+                "Showing posts from {} to {}",
+                oldest.format("%Y-%m-%d %H:%M"),
+                newest.format("%Y-%m-%d %H:%M")
+            )),
+            _ => Line::default(),
+        };
+        // nothing newer above the top post
+        if offset == 0 {
+            line.push_span(Span::from("  ↑ end of feed").dim());
+        }
+        Paragraph::new(line.gray()).block(
+            Block::default()
+                .borders(Borders::BOTTOM)
+                .border_style(Style::default().dark_gray())
+                .padding(Padding::horizontal(1)),
+        )
+    }
+    /// The blocked domain of the link card of the post, unless the post has been revealed.
+    fn hidden_domain<'a>(&self, feed_view_post: &'a FeedViewPost) -> Option<&'a str> {
+        if self.filters.revealed.contains(&feed_view_post.post.cid) {
+            return None;
+        }
+        external_uri(&feed_view_post.post)
+            .map(link_domain)
+            .filter(|domain| {
+                self.store
+                    .blocked_domains
+                    .with(|blocked| blocked.is_blocked(domain, &self.filters.blocked_domains))
+                    .unwrap_or_default()
+            })
+    }
+    /// The items of the context menu of the post, depending on its viewer state and author.
+    fn post_menu_items(&self, post_view: &PostView) -> Vec<PostMenuItem> {
+        let mut items = vec![
+            PostMenuItem::Open,
+            match post_view
+                .viewer
+                .as_ref()
+                .and_then(|viewer| viewer.like.clone())
+            {
+                Some(uri) => PostMenuItem::Unlike(uri),
+                None => PostMenuItem::Like,
+            },
+            PostMenuItem::Repost,
+            PostMenuItem::Quote,
+            PostMenuItem::Reply,
+            PostMenuItem::Conversation,
+            PostMenuItem::Quotes,
+            PostMenuItem::Profile,
+            PostMenuItem::CopyLink,
+            PostMenuItem::OpenInBrowser,
+        ];
+        if self.did.as_ref() == Some(&post_view.author.did) {
+            items.push(PostMenuItem::Delete);
+        } else {
+            items.push(PostMenuItem::Report);
+        }
+        items
+    }
+    /// Replace the context menu with the items of a step of the selected item, e.g. a confirmation.
+    fn open_post_submenu(&mut self, items: Vec<PostMenuItem>, position: Position) -> Action {
+        let labels = items.iter().map(|item| item.label().to_string()).collect();
+        self.context_menu = Some((ContextMenu::new(labels, position), items));
+        Action::Render
+    }
+    /// Run the item of the context menu, opened at `position`, on the selected post.
+    fn run_post_menu_item(&mut self, item: &PostMenuItem, position: Position) -> Option<Action> {
+        match item {
+            PostMenuItem::AddToList(uri, name) => {
+                return Some(self.add_selection_to_list(uri.clone(), name));
+            }
+            PostMenuItem::ConfirmDeleteSelection(_) => return Some(self.delete_selection()),
+            _ => {}
+        }
+        let post_view = self
+            .state
+            .selected()
+            .and_then(|i| self.items.get(i))
+            .map(|feed_view_post| feed_view_post.post.clone())?;
+        let (agent, tx) = (self.agent.clone(), self.action_tx.clone());
+        let subject = api::com::atproto::repo::strong_ref::MainData {
+            cid: post_view.cid.clone(),
+            uri: post_view.uri.clone(),
+        };
+        if matches!(
+            item,
+            PostMenuItem::Like
+                | PostMenuItem::Unlike(_)
+                | PostMenuItem::Repost
+                | PostMenuItem::ConfirmDelete
+        ) {
+            self.threads.posts.evict(&post_view.uri);
+        }
+        match item {
+            PostMenuItem::Open => return Some(Action::Enter),
+            PostMenuItem::Quotes => return Some(Action::Quotes),
+            PostMenuItem::OpenInBrowser => return Some(Action::Open),
+            // the items of visual mode are handled above, as they are not about the selected post
+            PostMenuItem::Cancel
+            | PostMenuItem::AddToList(..)
+            | PostMenuItem::ConfirmDeleteSelection(_) => {}
+            PostMenuItem::Quote => {
+                return Some(Action::Transition(Transition::Push(Box::new(View::Quote(
+                    Box::new(post_view),
+                )))));
+            }
+            PostMenuItem::Reply => {
+                return Some(Action::Transition(Transition::Push(Box::new(View::Reply(
+                    Box::new(post_view),
+                )))));
+            }
+            PostMenuItem::Profile => {
+                return Some(Action::Transition(Transition::Push(Box::new(View::Feed(
+                    Box::new(FeedSourceInfo::Author(post_view.author.did.to_string())),
+                )))));
+            }
+            PostMenuItem::Report => {
+                let reasons = [
+                    defs::REASON_SPAM,
+                    defs::REASON_MISLEADING,
+                    defs::REASON_SEXUAL,
+                    defs::REASON_RUDE,
+                    defs::REASON_VIOLATION,
+                    defs::REASON_OTHER,
+                ];
+                return Some(self.open_post_submenu(
+                    reasons.into_iter().map(PostMenuItem::ReportAs).collect(),
+                    position,
                 ));
-                return Ok(Some(Action::Render));
             }
-            Action::PrevItem if !self.items.is_empty() => {
-                self.state.select(Some(
-                    self.state
-                        .selected()
-                        .map(|s| s.max(1) - 1)
-                        .unwrap_or_default(),
+            PostMenuItem::ReportAs(reason_type) => {
+                let input = api::com::atproto::moderation::create_report::InputData {
+                    reason: None,
+                    reason_type: reason_type.to_string(),
+                    subject: Union::Refs(
+                        api::com::atproto::moderation::create_report::InputSubjectRefs::ComAtprotoRepoStrongRefMain(
+                            Box::new(subject.into()),
+                        ),
+                    ),
+                };
+                tokio::spawn(async move {
+                    match agent
+                        .api
+                        .com
+                        .atproto
+                        .moderation
+                        .create_report(input.into())
+                        .await
+                    {
+                        Ok(output) => log::info!("created report: {}", output.id),
+                        Err(e) => log::error!("failed to create report: {e}"),
+                    }
+                });
+                self.show_status(String::from("Reported the post"));
+                return Some(Action::Render);
+            }
+            PostMenuItem::Delete => {
+                return Some(self.open_post_submenu(
+                    vec![PostMenuItem::ConfirmDelete, PostMenuItem::Cancel],
+                    position,
                 ));
-                return Ok(Some(Action::Render));
             }
-            Action::Enter => {
-                if let Some(feed_view_post) = self.state.selected().and_then(|i| self.items.get(i))
-                {
-                    return Ok(Some(Action::Transition(Transition::Push(Box::new(
-                        View::Post(Box::new((
-                            feed_view_post.post.clone(),
-                            feed_view_post
-                                .reply
-                                .as_ref()
-                                .and_then(|reply| match &reply.parent {
-                                    Union::Refs(ReplyRefParentRefs::PostView(post_view)) => {
-                                        Some(post_view.as_ref().clone())
-                                    }
-                                    _ => None,
-                                }),
-                        ))),
-                    )))));
-                }
+            PostMenuItem::Conversation => {
+                return Some(Action::Transition(Transition::Push(Box::new(
+                    View::Conversation(post_view.uri.clone()),
+                ))));
             }
-            Action::Back => return Ok(Some(Action::Transition(Transition::Pop))),
-            Action::Refresh => {
-                self.watcher.refresh();
+            PostMenuItem::CopyLink => {
+                let url = post_url(&post_view)?;
+                match arboard::Clipboard::new().and_then(|mut clipboard| clipboard.set_text(&url)) {
+                    Ok(()) => self.show_status(String::from("Copied the link of the post")),
+                    Err(e) => log::warn!("failed to copy the link to clipboard: {e}"),
+                }
+                return Some(Action::Render);
             }
-            Action::Update(data) => {
-                let Data::Feed(feed) = data.as_ref() else {
-                    return Ok(None);
+            PostMenuItem::Like => {
+                let record_data = api::app::bsky::feed::like::RecordData {
+                    created_at: Datetime::now(),
+                    subject: subject.into(),
                 };
-                log::debug!("update feed view: {}", feed.len());
-                // TODO: update state.selected
-                let select = if let Some(cid) = self
-                    .state
-                    .selected()
-                    .and_then(|i| self.items.get(i))
-                    .map(|feed_view_post| feed_view_post.post.cid.as_ref())
-                {
-                    feed.iter()
-                        .position(|feed_view_post| feed_view_post.post.cid.as_ref() == cid)
-                } else {
-                    None
+                tokio::spawn(async move {
+                    match agent.create_record(record_data).await {
+                        Ok(_) => {
+                            tx.send(Action::Refresh).ok();
+                        }
+                        Err(e) => log::error!("failed to create like record: {e}"),
+                    }
+                });
+            }
+            PostMenuItem::Repost => {
+                let record_data = api::app::bsky::feed::repost::RecordData {
+                    created_at: Datetime::now(),
+                    subject: subject.into(),
                 };
-                self.items.clone_from(feed);
-                self.state.select(select);
-                return Ok(Some(Action::Render));
+                tokio::spawn(async move {
+                    match agent.create_record(record_data).await {
+                        Ok(_) => {
+                            tx.send(Action::Refresh).ok();
+                        }
+                        Err(e) => log::error!("failed to create repost record: {e}"),
+                    }
+                });
+            }
+            PostMenuItem::Unlike(uri) => {
+                let at_uri = uri.clone();
+                tokio::spawn(async move {
+                    match agent.delete_record(at_uri).await {
+                        Ok(_) => {
+                            tx.send(Action::Refresh).ok();
+                        }
+                        Err(e) => log::error!("failed to delete like record: {e}"),
+                    }
+                });
+            }
+            PostMenuItem::ConfirmDelete => {
+                let at_uri = post_view.uri.clone();
+                tokio::spawn(async move {
+                    match agent.delete_record(at_uri).await {
+                        Ok(_) => {
+                            tx.send(Action::Refresh).ok();
+                        }
+                        Err(e) => log::error!("failed to delete record: {e}"),
+                    }
+                });
             }
-            _ => {}
         }
-        Ok(None)
+        None
     }
-    fn draw(&mut self, f: &mut Frame<'_>, area: Rect) -> Result<()> {
-        let header = Paragraph::new(match &self.feed_info {
-            FeedSourceInfo::Feed(generator_view) => Line::from(vec![
-                Span::from(generator_view.display_name.clone()).bold(),
-                Span::from(" "),
-                Span::from(format!(
-                    "by {}",
-                    profile_name_as_str(&generator_view.creator)
-                ))
-                .gray(),
-            ]),
-            FeedSourceInfo::List(list_view) => Line::from(vec![
-                Span::from(list_view.name.clone()).bold(),
-                Span::from(" "),
-                Span::from(format!("by {}", profile_name_as_str(&list_view.creator))).gray(),
-            ]),
-            FeedSourceInfo::Timeline(_) => Line::from("Following").bold(),
-        })
-        .bold()
-        .block(
-            Block::default()
-                .borders(Borders::BOTTOM)
-                .border_style(Color::Gray)
-                .padding(Padding::horizontal(1)),
-        );
-        let mut items = Vec::new();
-        for feed_view_post in &self.items {
-            if let Some(lines) = Self::lines(feed_view_post, area) {
-                items.push(Text::from(lines));
+    /// Show the message in the header for a while, and render again once it is over.
+    fn show_status(&mut self, message: String) {
+        self.status = Some((message, Instant::now()));
+        let tx = self.action_tx.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(STATUS_DURATION).await;
+            if let Err(e) = tx.send(Action::Render) {
+                log::error!("failed to send render action: {e}");
             }
+        });
+    }
+    /// Select the saved post, or the post closest in time if it is no longer in the feed.
+    fn restore_position(&mut self, position: &ScrollPosition) {
+        let index = self
+            .items
+            .iter()
+            .position(|feed_view_post| feed_view_post.post.cid.as_ref().to_string() == position.cid)
+            .or_else(|| {
+                self.items
+                    .iter()
+                    .enumerate()
+                    .min_by_key(|(_, feed_view_post)| {
+                        (feed_view_post.post.indexed_at.as_ref().timestamp() - position.indexed_at)
+                            .abs()
+                    })
+                    .map(|(i, _)| i)
+            })
+            .unwrap_or(position.index);
+        self.state.select(Some(index.min(self.items.len() - 1)));
+    }
+    /// Remember the selected post, to be saved on exit.
+    fn remember_position(&self) {
+        if let Some((index, feed_view_post)) = self
+            .state
+            .selected()
+            .and_then(|i| self.items.get(i).map(|feed_view_post| (i, feed_view_post)))
+        {
+            let position = ScrollPosition {
+                cid: feed_view_post.post.cid.as_ref().to_string(),
+                index,
+                indexed_at: feed_view_post.post.indexed_at.as_ref().timestamp(),
+            };
+            self.store.scroll_positions.with(|positions| {
+                positions.set_position(&self.feed_info.key(), position);
+            });
         }
-
-        let layout =
-            Layout::vertical([Constraint::Length(2), Constraint::Percentage(100)]).split(area);
-        f.render_widget(header, layout[0]);
-        f.render_stateful_widget(
+    }
+    /// Mark the selected post as read once it has been selected for `READ_DWELL`.
+    fn update_read(&mut self) {
+        let selected = self
+            .state
+            .selected()
+            .and_then(|i| self.items.get(i))
+            .map(|feed_view_post| &feed_view_post.post.cid);
+        if let Some((cid, since)) = &self.focused {
+            if since.elapsed() >= READ_DWELL {
+                self.store
+                    .read_history
+                    .with(|history| history.mark_read([cid.as_ref().to_string()]));
+            }
+            if Some(cid) == selected {
+                return;
+            }
+        }
+        self.focused = selected.map(|cid| (cid.clone(), Instant::now()));
+    }
+    /// Show the time between the anchor and the selected post, if another post is selected.
+    fn compare_with_anchor(&mut self) {
+        let Some((anchor, anchor_time)) = &self.compare_anchor else {
+            return;
+        };
+        let Some(post_view) = self
+            .state
+            .selected()
+            .and_then(|i| self.items.get(i))
+            .map(|feed_view_post| &feed_view_post.post)
+            .filter(|post_view| &post_view.cid != anchor)
+        else {
+            return;
+        };
+        let delta = post_view.indexed_at.as_ref().with_timezone(&Local) - *anchor_time;
+        self.show_status(format!(
+            "Δt: {} between selected posts",
+            format_duration(delta)
+        ));
+    }
+    /// A grey row mimicking the layout of a post: a header, two lines of text and the counts.
+    fn placeholder(width: usize, frame: usize) -> Text<'static> {
+        let bar = |percent: usize| {
+            Line::from(
+                (0..width * percent / 100)
+                    .map(|i| {
+                        if (i / 4 + frame) % 2 == 0 {
+                            '░'
+                        } else {
+                            '▒'
+                        }
+                    })
+                    .collect::<String>(),
+            )
+            .dark_gray()
+        };
+        Text::from(vec![bar(40), bar(90), bar(70), bar(30), Line::default()])
+    }
+    /// Translate the text of the selected post in the background, unless it is already translated.
+    fn translate_selected(&self) {
+        let Some(config) = self.translation.clone() else {
+            log::warn!("translation is not configured");
+            return;
+        };
+        let Some(post_view) = self
+            .state
+            .selected()
+            .and_then(|i| self.items.get(i))
+            .map(|feed_view_post| &feed_view_post.post)
+        else {
+            return;
+        };
+        let Record::Known(KnownRecord::AppBskyFeedPost(record)) = &post_view.record else {
+            return;
+        };
+        let key = (post_view.cid.clone(), config.target.clone());
+        if self
+            .translations
+            .read()
+            .is_ok_and(|translations| translations.contains_key(&key))
+        {
+            return;
+        }
+        let source = record
+            .langs
+            .as_ref()
+            .and_then(|langs| langs.first())
+            .map(|lang| lang.as_ref().as_str().to_string());
+        if source.as_deref() == Some(config.target.as_str()) {
+            return;
+        }
+        let (text, translations, tx) = (
+            record.text.clone(),
+            self.translations.clone(),
+            self.action_tx.clone(),
+        );
+        tokio::spawn(async move {
+            match translate(&config, &text, source.as_deref()).await {
+                Ok(translation) => {
+                    if let Ok(mut translations) = translations.write() {
+                        translations.insert(key, translation);
+                    }
+                    if let Err(e) = tx.send(Action::Render) {
+                        log::error!("failed to send render action: {e}");
+                    }
+                }
+                Err(e) => {
+                    log::warn!("failed to translate post: {e}");
+                }
+            }
+        });
+    }
+    fn own_posts(&self) -> impl Iterator<Item = &FeedViewPost> {
+        self.feed.iter().filter(|feed_view_post| {
+            Some(&feed_view_post.post.author.did) == self.did.as_ref()
+                && feed_view_post.reason.is_none()
+        })
+    }
+    fn update_preview(&mut self) -> Result<()> {
+        let selected = self
+            .state
+            .selected()
+            .and_then(|i| self.items.get(i))
+            .map(|feed_view_post| feed_view_post.post.uri.clone());
+        self.preview
+            .update(selected, &self.action_tx, &self.threads)
+    }
+    /// The posts selected in visual mode, in the order of the feed.
+    fn selected_posts(&self) -> Vec<&PostView> {
+        self.items
+            .iter()
+            .filter(|feed_view_post| self.visual.selection.contains(&feed_view_post.post.cid))
+            .map(|feed_view_post| &feed_view_post.post)
+            .collect()
+    }
+    /// Copy the links of the selected posts, one per line.
+    fn yank_selection(&mut self) -> Action {
+        let urls = self
+            .selected_posts()
+            .into_iter()
+            .filter_map(post_url)
+            .collect::<Vec<_>>();
+        match arboard::Clipboard::new()
+            .and_then(|mut clipboard| clipboard.set_text(urls.join("\n")))
+        {
+            Ok(()) => self.show_status(format!("Copied {} links", urls.len())),
+            Err(e) => log::warn!("failed to copy the links to clipboard: {e}"),
+        }
+        self.visual.exit();
+        Action::Render
+    }
+    /// The URIs of the selected posts of the logged-in user.
+    fn own_selected_posts(&self) -> Vec<String> {
+        self.selected_posts()
+            .into_iter()
+            .filter(|post_view| Some(&post_view.author.did) == self.did.as_ref())
+            .map(|post_view| post_view.uri.clone())
+            .collect()
+    }
+    /// Ask to delete the selected posts of the logged-in user, as with a single post.
+    fn confirm_delete_selection(&mut self) -> Action {
+        let count = self.own_selected_posts().len();
+        if count == 0 {
+            self.show_status(String::from("No own posts selected"));
+            return Action::Render;
+        }
+        let label = format!(
+            "Delete {count} {}",
+            if count == 1 { "post" } else { "posts" }
+        );
+        self.open_post_submenu(
+            vec![
+                PostMenuItem::ConfirmDeleteSelection(label),
+                PostMenuItem::Cancel,
+            ],
+            Position::new(self.list_area.x + 2, self.list_area.y + 1),
+        )
+    }
+    /// Delete the selected posts of the logged-in user, and skip the others.
+    fn delete_selection(&mut self) -> Action {
+        let uris = self.own_selected_posts();
+        self.show_status(format!("Deleting {} own posts", uris.len()));
+        for uri in &uris {
+            self.threads.posts.evict(uri);
+        }
+        let (agent, tx) = (self.agent.clone(), self.action_tx.clone());
+        tokio::spawn(async move {
+            for uri in uris {
+                if let Err(e) = agent.delete_record(uri).await {
+                    log::error!("failed to delete record: {e}");
+                }
+            }
+            tx.send(Action::Refresh).ok();
+        });
+        self.visual.exit();
+        Action::Render
+    }
+    /// Add the local `bookmark` tag to the selected posts.
+    fn bookmark_selection(&mut self) -> Action {
+        let cids = self
+            .selected_posts()
+            .into_iter()
+            .map(|post_view| post_view.cid.clone())
+            .collect::<Vec<_>>();
+        for cid in &cids {
+            let mut tags = self.tags.get(cid).cloned().unwrap_or_default();
+            tags.insert(String::from(BOOKMARK_TAG));
+            self.save_tags(cid.clone(), tags);
+        }
+        self.show_status(format!("Bookmarked {} posts", cids.len()));
+        self.visual.exit();
+        Action::Render
+    }
+    /// Fetch the lists of the logged-in user, to pick the one for the selected posts.
+    fn pick_list_for_selection(&mut self) -> Action {
+        let Some(did) = self.did.clone() else {
+            return Action::Render;
+        };
+        self.show_status(String::from("Loading the lists"));
+        let (agent, tx) = (self.agent.clone(), self.action_tx.clone());
+        tokio::spawn(async move {
+            match lists(&agent, &did).await {
+                Ok(lists) => {
+                    if let Err(e) = tx.send(Action::Update(Box::new(Data::Lists(lists)))) {
+                        log::error!("failed to send update action: {e}");
+                    }
+                }
+                Err(e) => log::warn!("failed to get the lists: {e}"),
+            }
+        });
+        Action::Render
+    }
+    /// Open the menu of the fetched lists, if the posts are still selected.
+    fn open_list_menu(&mut self, lists: &[ListView]) -> Action {
+        if !self.visual.active {
+            return Action::Render;
+        }
+        if lists.is_empty() {
+            self.show_status(String::from("No lists to add to"));
+            return Action::Render;
+        }
+        let items = lists
+            .iter()
+            .map(|list| PostMenuItem::AddToList(list.uri.clone(), list.name.clone()))
+            .collect();
+        self.status = None;
+        self.open_post_submenu(
+            items,
+            Position::new(self.list_area.x + 2, self.list_area.y + 1),
+        )
+    }
+    /// Add the authors of the selected posts to the list at `uri`.
+    fn add_selection_to_list(&mut self, uri: String, name: &str) -> Action {
+        let mut authors = Vec::new();
+        for post_view in self.selected_posts() {
+            if !authors.contains(&post_view.author.did) {
+                authors.push(post_view.author.did.clone());
+            }
+        }
+        self.show_status(format!("Adding {} accounts to {name}", authors.len()));
+        let agent = self.agent.clone();
+        tokio::spawn(async move {
+            let added = add_to_list(&agent, &uri, authors).await;
+            log::info!("added {added} accounts to {uri}");
+        });
+        self.visual.exit();
+        Action::Render
+    }
+    /// Store the tags of the post, replacing its previous ones.
+    fn save_tags(&mut self, cid: Cid, tags: BTreeSet<String>) {
+        match self
+            .store
+            .tags
+            .with(|store| store.set(&cid.as_ref().to_string(), &tags))
+        {
+            Ok(()) => {
+                if tags.is_empty() {
+                    self.tags.remove(&cid);
+                } else {
+                    self.tags.insert(cid, tags);
+                }
+                if self.filters.tag.is_some() {
+                    self.update_items();
+                }
+            }
+            Err(e) => log::warn!("failed to save tags: {e}"),
+        }
+    }
+    /// Update the items from the feed, keeping the selected post if it is still visible.
+    fn update_items(&mut self) {
+        let selected = self
+            .state
+            .selected()
+            .and_then(|i| self.items.get(i))
+            .map(|feed_view_post| feed_view_post.post.cid.clone());
+        // only for the view, the cached feed keeps the old posts
+        let cutoff = self.filters.cutoff(Utc::now());
+        self.items = self
+            .feed
+            .iter()
+            .filter(|feed_view_post| {
+                self.filters.matches(
+                    feed_view_post,
+                    cutoff,
+                    self.tags.get(&feed_view_post.post.cid),
+                )
+            })
+            // rendered above the items instead
+            .filter(|feed_view_post| self.tacked.as_ref() != Some(&feed_view_post.post.cid))
+            .filter(|feed_view_post| {
+                !self.filters.hide_duplicates
+                    || self
+                        .store
+                        .duplicates
+                        .duplicates(&feed_view_post.post.cid, self.id)
+                        .is_none()
+            })
+            .cloned()
+            .collect();
+        self.thread_sizes.clear();
+        if self.group_threads {
+            self.group_items();
+        }
+        self.analytics.stats = FeedStats::new(&self.items);
+        self.state.select(selected.and_then(|cid| {
+            self.items
+                .iter()
+                .position(|feed_view_post| feed_view_post.post.cid == cid)
+        }));
+    }
+    /// Group the items by the root of their threads, in the order of the first post of each thread.
+    fn group_items(&mut self) {
+        let mut threads = IndexMap::<String, Vec<FeedViewPost>>::new();
+        for feed_view_post in self.items.drain(..) {
+            threads
+                .entry(thread_root(&feed_view_post).to_string())
+                .or_default()
+                .push(feed_view_post);
+        }
+        for (root, mut posts) in threads {
+            posts.sort_by(|a, b| a.post.indexed_at.as_ref().cmp(b.post.indexed_at.as_ref()));
+            if posts.len() > 1 {
+                self.thread_sizes.extend(
+                    posts
+                        .iter()
+                        .map(|feed_view_post| (feed_view_post.post.cid.clone(), posts.len())),
+                );
+                if !self.expanded_threads.contains(&root) {
+                    self.items.extend(posts.pop());
+                    continue;
+                }
+            }
+            self.items.extend(posts);
+        }
+    }
+    /// Fetch the posts replied to by the user, to be marked in the feed.
+    fn fetch_replied(&self) {
+        let Some(did) = self.did.clone() else {
+            return;
+        };
+        let (agent, replied, tx) = (
+            self.agent.clone(),
+            self.replied.clone(),
+            self.action_tx.clone(),
+        );
+        tokio::spawn(async move {
+            match replied_uris(&agent, &did).await {
+                Ok(uris) => {
+                    if let Ok(mut replied) = replied.write() {
+                        *replied = uris;
+                    }
+                    if let Err(e) = tx.send(Action::Render) {
+                        log::error!("failed to send render action: {e}");
+                    }
+                }
+                Err(e) => log::warn!("failed to get the replies: {e}"),
+            }
+        });
+    }
+    fn update_mutuals(&self) {
+        let Ok(mut mutuals) = self.mutuals.write() else {
+            return;
+        };
+        let mut others = Vec::new();
+        for feed_view_post in &self.items {
+            let did = &feed_view_post.post.author.did;
+            if !mutuals.contains_key(did) {
+                // mark as requested so that the same DID is not queried again
+                mutuals.insert(did.clone(), false);
+                others.push(did.clone());
+            }
+        }
+        if others.is_empty() {
+            return;
+        }
+        let (agent, mutuals, tx) = (
+            self.agent.clone(),
+            self.mutuals.clone(),
+            self.action_tx.clone(),
+        );
+        tokio::spawn(async move {
+            match mutual_follows(&agent, &others).await {
+                Ok(result) => {
+                    if let Ok(mut mutuals) = mutuals.write() {
+                        mutuals.extend(result);
+                    }
+                    if let Err(e) = tx.send(Action::Render) {
+                        log::error!("failed to send render action: {e}");
+                    }
+                }
+                Err(e) => {
+                    log::warn!("failed to get relationships: {e}");
+                }
+            }
+        });
+    }
+    /// Render a post on a single line: `[HH:MM] @handle: text… 💬N 🔁N 🩷N`.
+    fn compact_line(feed_view_post: &FeedViewPost) -> Option<Line<'_>> {
+        let Record::Known(KnownRecord::AppBskyFeedPost(record)) = &feed_view_post.post.record
+        else {
+            return None;
+        };
+        let post = &feed_view_post.post;
+        let mut text = record.text.replace('\n', " ");
+        if let Some((i, _)) = text.char_indices().nth(60) {
+            text.truncate(i);
+            text.push('…');
+        }
+        let mut spans = vec![Span::from(format!(
+            "[{}] ",
+            format_timestamp(post.indexed_at.as_ref(), TimestampFormat::TimeOnly)
+        ))
+        .green()];
+        if matches!(
+            &feed_view_post.reason,
+            Some(Union::Refs(FeedViewPostReasonRefs::ReasonRepost(_)))
+        ) {
+            spans.push(Span::from("🔁 ").blue());
+        }
+        spans.extend([
+            Span::from(format!("@{}", post.author.handle.as_str())).bold(),
+            Span::from(": "),
+            Span::from(text),
+            Span::from(format!(
+                " 💬{} 🔁{} 🩷{}",
+                post.reply_count.unwrap_or_default(),
+                post.repost_count.unwrap_or_default(),
+                post.like_count.unwrap_or_default()
+            ))
+            .dim(),
+        ]);
+        Some(Line::from(spans))
+    }
+    /// Render a post as a block of plain text, without colors and symbols.
+    fn accessible_lines<'a>(
+        feed_view_post: &'a FeedViewPost,
+        width: usize,
+        mutual: bool,
+        reply_parent: ReplyParent<'_>,
+        timestamp_format: TimestampFormat,
+    ) -> Vec<Line<'a>> {
+        let post = &feed_view_post.post;
+        let mut lines = vec![format!(
+            "{} @{}{}, {}",
+            profile_name_as_str(&post.author),
+            post.author.handle.as_str(),
+            if mutual { " (mutual)" } else { "" },
+            format_timestamp(post.indexed_at.as_ref(), timestamp_format)
+        )];
+        if let Some(Union::Refs(FeedViewPostReasonRefs::ReasonRepost(repost))) =
+            &feed_view_post.reason
+        {
+            lines.push(format!("Reposted by {}", profile_name_as_str(&repost.by)));
+        }
+        match reply_parent {
+            ReplyParent::None => {}
+            ReplyParent::Author(post_view) => {
+                lines.push(format!(
+                    "Reply to {}",
+                    profile_name_as_str(&post_view.author)
+                ));
+            }
+            ReplyParent::Post(post_view) => {
+                let text = match &post_view.record {
+                    Record::Known(KnownRecord::AppBskyFeedPost(record)) => record.text.as_str(),
+                    _ => "",
+                };
+                lines.extend(
+                    textwrap::wrap(
+                        &format!(
+                            "Reply to {}: {text}",
+                            profile_name_as_str(&post_view.author)
+                        ),
+                        width.max(1),
+                    )
+                    .into_iter()
+                    .map(|line| line.into_owned()),
+                );
+            }
+            ReplyParent::Chain(chain) => {
+                for (depth, post_view) in chain.iter().rev().enumerate() {
+                    let text = match &post_view.record {
+                        Record::Known(KnownRecord::AppBskyFeedPost(record)) => record.text.as_str(),
+                        _ => "",
+                    };
+                    lines.extend(
+                        textwrap::wrap(
+                            &format!(
+                                "{}{}: {text}",
+                                if depth == 0 {
+                                    "Thread of "
+                                } else {
+                                    "Reply by "
+                                },
+                                profile_name_as_str(&post_view.author)
+                            ),
+                            width.max(1),
+                        )
+                        .into_iter()
+                        .map(|line| line.into_owned()),
+                    );
+                }
+            }
+        }
+        if let Record::Known(KnownRecord::AppBskyFeedPost(record)) = &post.record {
+            lines.extend(
+                textwrap::wrap(&record.text, width.max(1))
+                    .into_iter()
+                    .map(|line| line.into_owned()),
+            );
+        }
+        let (media, record) = match &post.embed {
+            Some(Union::Refs(PostViewEmbedRefs::AppBskyEmbedImagesView(images))) => (
+                Some(
+                    images
+                        .images
+                        .iter()
+                        .map(|image| image.alt.clone())
+                        .collect(),
+                ),
+                None,
+            ),
+            Some(Union::Refs(PostViewEmbedRefs::AppBskyEmbedExternalView(external))) => (
+                Some(vec![format!(
+                    "Link: {} {}",
+                    external.external.title, external.external.uri
+                )]),
+                None,
+            ),
+            Some(Union::Refs(PostViewEmbedRefs::AppBskyEmbedRecordView(record))) => {
+                (None, Some(&record.record))
+            }
+            Some(Union::Refs(PostViewEmbedRefs::AppBskyEmbedRecordWithMediaView(view))) => (
+                match &view.media {
+                    Union::Refs(ViewMediaRefs::AppBskyEmbedImagesView(images)) => Some(
+                        images
+                            .images
+                            .iter()
+                            .map(|image| image.alt.clone())
+                            .collect(),
+                    ),
+                    _ => None,
+                },
+                Some(&view.record.record),
+            ),
+            _ => (None, None),
+        };
+        for alt in media.unwrap_or_default() {
+            lines.push(if alt.starts_with("Link: ") {
+                alt
+            } else if alt.is_empty() {
+                String::from("Image")
+            } else {
+                format!("Image: {alt}")
+            });
+        }
+        if let Some(Union::Refs(ViewRecordRefs::ViewRecord(view_record))) = record {
+            if let Record::Known(KnownRecord::AppBskyFeedPost(quoted)) = &view_record.value {
+                lines.extend(
+                    textwrap::wrap(
+                        &format!(
+                            "Quote from @{}: {}",
+                            view_record.author.handle.as_str(),
+                            quoted.text
+                        ),
+                        width.max(1),
+                    )
+                    .into_iter()
+                    .map(|line| line.into_owned()),
+                );
+            }
+        }
+        lines.push(format!(
+            "R:{} RT:{} L:{}",
+            post.reply_count.unwrap_or_default(),
+            post.repost_count.unwrap_or_default(),
+            post.like_count.unwrap_or_default()
+        ));
+        lines.push(String::from("---"));
+        lines.into_iter().map(Line::from).collect()
+    }
+    #[allow(clippy::too_many_arguments)]
+    pub(super) fn lines<'a>(
+        feed_view_post: &'a FeedViewPost,
+        area: Rect,
+        mutual: bool,
+        commented: bool,
+        expanded: bool,
+        translation: Option<&Translation>,
+        reply_parent: ReplyParent<'a>,
+        timestamp_format: TimestampFormat,
+    ) -> Option<Vec<Line<'a>>> {
+        let Record::Known(KnownRecord::AppBskyFeedPost(record)) = &feed_view_post.post.record
+        else {
+            return None;
+        };
+        let mut lines = Vec::new();
+        {
+            let mut spans = [
+                vec![
+                    Span::from(format_timestamp(
+                        feed_view_post.post.indexed_at.as_ref(),
+                        timestamp_format,
+                    ))
+                    .green(),
+                    Span::from(": "),
+                ],
+                profile_name(&feed_view_post.post.author),
+            ]
+            .concat();
+            if mutual {
+                spans.push(Span::from(" "));
+                spans.push(Span::from("⇄").cyan());
+            }
+            if commented {
+                spans.push(Span::from(" 📝"));
+            }
+            if is_edited(record, &feed_view_post.post) {
+                spans.push(Span::from(" "));
+                spans.push(Span::from("[edited]").yellow());
+            }
+            if let Some(labels) = feed_view_post
+                .post
+                .author
+                .labels
+                .as_ref()
+                .filter(|v| !v.is_empty())
+            {
+                spans.push(Span::from(" "));
+                spans.push(format!("[{} labels]", labels.len()).magenta());
+            }
+            lines.push(Line::from(spans));
+        }
+        if let Some(Union::Refs(FeedViewPostReasonRefs::ReasonRepost(repost))) =
+            &feed_view_post.reason
+        {
+            lines.push(
+                Line::from(format!("  Reposted by {}", profile_name_as_str(&repost.by))).blue(),
+            );
+        }
+        if let ReplyParent::Author(post_view) | ReplyParent::Post(post_view) = reply_parent {
+            lines.push(Line::from(
+                [
+                    vec![Span::from("  Reply to ").blue()],
+                    profile_name(&post_view.author),
+                ]
+                .concat(),
+            ));
+            if let (
+                ReplyParent::Post(_),
+                Record::Known(KnownRecord::AppBskyFeedPost(parent_record)),
+            ) = (&reply_parent, &post_view.record)
+            {
+                lines.extend(
+                    text_lines(
+                        &parent_record.text,
+                        usize::from(area.width).saturating_sub(4),
+                        "  │ ",
+                    )
+                    .into_iter()
+                    .map(|line| line.dim()),
+                );
+            }
+        }
+        if let ReplyParent::Chain(chain) = &reply_parent {
+            // from the root of the chain, indented one more level for each reply
+            for (depth, post_view) in chain.iter().rev().enumerate() {
+                let indent = "  ".repeat(depth + 1);
+                lines.push(Line::from(
+                    [
+                        vec![Span::from(indent.clone())],
+                        profile_name(&post_view.author),
+                    ]
+                    .concat(),
+                ));
+                if let Record::Known(KnownRecord::AppBskyFeedPost(parent_record)) =
+                    &post_view.record
+                {
+                    lines.extend(
+                        text_lines(
+                            &parent_record.text,
+                            usize::from(area.width).saturating_sub(indent.len() + 4),
+                            &format!("{indent}│ "),
+                        )
+                        .into_iter()
+                        .map(|line| line.dim()),
+                    );
+                }
+            }
+        }
+        lines.extend(text_lines(&record.text, usize::from(area.width) - 2, "  "));
+        if let Some(translation) = translation {
+            lines.push(Line::from(format!("  [🌐 translated from {}]", translation.source)).cyan());
+            lines.extend(text_lines(
+                &translation.text,
+                usize::from(area.width) - 2,
+                "  ",
+            ));
+        }
+        if let Some(Union::Refs(PostViewEmbedRefs::AppBskyEmbedRecordWithMediaView(
+            record_with_media,
+        ))) = &feed_view_post.post.embed
+        {
+            let width = area.width.saturating_sub(4);
+            let mut embed_lines = match &record_with_media.media {
+                Union::Refs(ViewMediaRefs::AppBskyEmbedImagesView(images)) => {
+                    PostViewComponent::images_lines(images)
+                }
+                Union::Refs(ViewMediaRefs::AppBskyEmbedExternalView(external)) => link_card_lines(
+                    &external.external.title,
+                    &external.external.description,
+                    &external.external.uri,
+                    usize::from(width),
+                    "",
+                ),
+                _ => Vec::new(),
+            };
+            embed_lines.push(Line::from("─".repeat(usize::from(width))).gray());
+            embed_lines.extend(PostViewComponent::record_lines(
+                &record_with_media.record,
+                width,
+            ));
+            lines.extend(embed_lines.into_iter().map(|line| {
+                Line::from([vec![Span::from("  ")], line.spans].concat()).style(line.style)
+            }));
+        } else if let Some(Union::Refs(PostViewEmbedRefs::AppBskyEmbedRecordView(record))) =
+            &feed_view_post.post.embed
+        {
+            let width = area.width.saturating_sub(4);
+            let embed_lines = match &record.record {
+                // quoted posts are collapsed to their first line unless expanded
+                Union::Refs(ViewRecordRefs::ViewRecord(view_record)) if !expanded => {
+                    match &view_record.value {
+                        Record::Known(KnownRecord::AppBskyFeedPost(quoted)) => {
+                            let wrapped = textwrap::wrap(&quoted.text, usize::from(width));
+                            let mut first =
+                                wrapped.first().map(|s| s.to_string()).unwrap_or_default();
+                            if wrapped.len() > 1 {
+                                first.push('…');
+                            }
+                            vec![Line::from(
+                                [
+                                    vec![Span::from("❝ ").gray()],
+                                    profile_name(&view_record.author),
+                                    vec![Span::from(": "), Span::from(first).gray()],
+                                ]
+                                .concat(),
+                            )]
+                        }
+                        _ => Vec::new(),
+                    }
+                }
+                _ => PostViewComponent::record_lines(record, width),
+            };
+            if embed_lines.is_empty() {
+                lines.push(Line::from("  Embedded record").yellow());
+            }
+            lines.extend(embed_lines.into_iter().map(|line| {
+                Line::from([vec![Span::from("  ")], line.spans].concat()).style(line.style)
+            }));
+        } else if let Some(Union::Refs(PostViewEmbedRefs::AppBskyEmbedExternalView(external))) =
+            &feed_view_post.post.embed
+        {
+            lines.extend(link_card_lines(
+                &external.external.title,
+                &external.external.description,
+                &external.external.uri,
+                usize::from(area.width) - 2,
+                "  ",
+            ));
+        } else if let Some(embed) = &feed_view_post.post.embed {
+            let content = match embed {
+                Union::Refs(PostViewEmbedRefs::AppBskyEmbedImagesView(images)) => {
+                    format!("{} images", images.images.len())
+                }
+                _ => String::from("unknown"),
+            };
+            lines.push(Line::from(format!("  Embedded {content}")).yellow());
+        }
+        lines.push(Line::from(
+            [vec![Span::from("  ")], counts(&feed_view_post.post, 5)].concat(),
+        ));
+        Some(lines)
+    }
+}
+
+impl ViewComponent for FeedViewComponent {
+    fn view(&self) -> View {
+        View::Feed(Box::new(self.feed_info.clone()))
+    }
+    fn handle_key_events(&mut self, key: KeyEvent) -> Result<Option<Action>> {
+        if let Some((menu, items)) = self.context_menu.as_mut() {
+            match key.code {
+                KeyCode::Down => menu.next(),
+                KeyCode::Up => menu.prev(),
+                KeyCode::Enter => {
+                    let item = menu.selected().and_then(|i| items.get(i)).cloned();
+                    let position = menu.position();
+                    self.context_menu = None;
+                    return Ok(item
+                        .and_then(|item| self.run_post_menu_item(&item, position))
+                        .or(Some(Action::Render)));
+                }
+                KeyCode::Esc => self.context_menu = None,
+                _ => {}
+            }
+            // the other keys are ignored while the menu is open
+            return Ok(Some(Action::Render));
+        }
+        if let Some(input) = self.tag_input.as_mut() {
+            match key.code {
+                KeyCode::Enter => {
+                    let tags = parse_tags(&input.value());
+                    self.tag_input = None;
+                    if let Some(cid) = self
+                        .state
+                        .selected()
+                        .and_then(|i| self.items.get(i))
+                        .map(|feed_view_post| feed_view_post.post.cid.clone())
+                    {
+                        self.save_tags(cid, tags);
+                    }
+                }
+                KeyCode::Esc => self.tag_input = None,
+                _ => {
+                    input.input(key);
+                }
+            }
+            // the other keys are typed in the input
+            return Ok(Some(Action::Render));
+        }
+        if self.visual.active {
+            match key.code {
+                KeyCode::Char(' ') => {
+                    if let Some(cid) = self
+                        .state
+                        .selected()
+                        .and_then(|i| self.items.get(i))
+                        .map(|feed_view_post| feed_view_post.post.cid.clone())
+                    {
+                        self.visual.toggle(cid);
+                    }
+                    return Ok(Some(Action::Render));
+                }
+                KeyCode::Char('y') => return Ok(Some(self.yank_selection())),
+                KeyCode::Char('d') => return Ok(Some(self.confirm_delete_selection())),
+                KeyCode::Char('b') => return Ok(Some(self.bookmark_selection())),
+                KeyCode::Char('+') => return Ok(Some(self.pick_list_for_selection())),
+                KeyCode::Esc => {
+                    self.visual.exit();
+                    return Ok(Some(Action::Render));
+                }
+                // the other keys move the selection as usual
+                _ => {}
+            }
+        }
+        if let Some(tutorial) = self.tutorial.as_mut() {
+            match key.code {
+                KeyCode::Char(' ') if tutorial.next() => return Ok(Some(Action::Render)),
+                KeyCode::Char(' ') | KeyCode::Esc => {
+                    self.tutorial = None;
+                    self.store.tutorial.with(TutorialState::mark_seen);
+                    return Ok(Some(Action::Render));
+                }
+                _ => {}
+            }
+        }
+        Ok(match key.code {
+            KeyCode::Esc if self.repliers.is_some() => {
+                self.repliers = None;
+                Some(Action::Render)
+            }
+            KeyCode::Esc if self.compare_anchor.is_some() => {
+                self.compare_anchor = None;
+                self.status = None;
+                Some(Action::Render)
+            }
+            KeyCode::Esc if self.filters.author_focus.is_some() => Some(Action::FocusAuthor),
+            KeyCode::Esc if self.filters.tag.is_some() => Some(Action::FilterTag(None)),
+            _ => None,
+        })
+    }
+    fn handle_mouse_events(&mut self, mouse: MouseEvent) -> Result<Option<Action>> {
+        let position = Position::new(mouse.column, mouse.row);
+        if let Some((menu, items)) = &self.context_menu {
+            if mouse.kind != MouseEventKind::Down(MouseButton::Left) {
+                return Ok(None);
+            }
+            // clicking outside of the menu dismisses it
+            let item = menu.item_at(position).and_then(|i| items.get(i)).cloned();
+            let position = menu.position();
+            self.context_menu = None;
+            return Ok(item
+                .and_then(|item| self.run_post_menu_item(&item, position))
+                .or(Some(Action::Render)));
+        }
+        Ok(match mouse.kind {
+            MouseEventKind::ScrollDown => Some(Action::NextItem),
+            MouseEventKind::ScrollUp => Some(Action::PrevItem),
+            MouseEventKind::Down(MouseButton::Right) if self.list_area.contains(position) => {
+                let row = self.heights.top_row(self.state.offset())
+                    + usize::from(position.y - self.list_area.y);
+                let Some((index, feed_view_post)) = self
+                    .heights
+                    .index_at(row)
+                    .and_then(|i| self.items.get(i).map(|feed_view_post| (i, feed_view_post)))
+                else {
+                    return Ok(None);
+                };
+                let items = self.post_menu_items(&feed_view_post.post);
+                let labels = items.iter().map(|item| item.label().to_string()).collect();
+                self.state.select(Some(index));
+                self.context_menu = Some((ContextMenu::new(labels, position), items));
+                Some(Action::Render)
+            }
+            _ => None,
+        })
+    }
+    fn activate(&mut self) -> Result<()> {
+        // reloaded every time, as the comments may have been edited in another view
+        match self.store.comments.with(CommentStore::uris) {
+            Ok(uris) => self.commented = uris,
+            Err(e) => log::warn!("failed to load comments: {e}"),
+        }
+        match self.store.tags.with(TagStore::all) {
+            Ok(tags) => {
+                self.tags = tags
+                    .into_iter()
+                    .filter_map(|(cid, tags)| Some((cid.parse().ok()?, tags)))
+                    .collect();
+            }
+            Err(e) => log::warn!("failed to load tags: {e}"),
+        }
+        // also reloaded every time, for the replies posted in the other views
+        self.fetch_replied();
+        // removed while deactivated
+        self.store.duplicates.update(
+            self.id,
+            self.feed
+                .iter()
+                .map(|feed_view_post| &feed_view_post.post.cid),
+        );
+        let (tx, mut rx) = (self.action_tx.clone(), self.watcher.subscribe());
+        let (quit_tx, mut quit_rx) = oneshot::channel();
+        self.quit = Some(quit_tx);
+        tokio::spawn(async move {
+            loop {
+                tokio::select! {
+                    changed = rx.changed() => {
+                        match changed {
+                            Ok(()) => {
+                                if let Err(e) = tx.send(Action::Update(Box::new(Data::Feed(
+                                    rx.borrow_and_update().clone(),
+                                )))) {
+                                    log::error!("failed to send update action: {e}");
+                                }
+                            }
+                            Err(e) => {
+                                log::warn!("changed channel error: {e}");
+                                break;
+                            }
+                        }
+                    }
+                    _ = &mut quit_rx => {
+                        break;
+                    }
+                }
+            }
+            log::debug!("subscription finished");
+        });
+        Ok(())
+    }
+    fn deactivate(&mut self) -> Result<()> {
+        if let Some(tx) = self.quit.take() {
+            if tx.send(()).is_err() {
+                log::error!("failed to send quit signal");
+            }
+        }
+        self.watcher.unsubscribe();
+        self.store.duplicates.remove(self.id);
+        self.preview.deactivate()
+    }
+    fn update(&mut self, action: Action) -> Result<Option<Action>> {
+        match action {
+            Action::NextItem if !self.items.is_empty() => {
+                let step = self.scroll_accelerator.step(true, Instant::now());
+                self.state.select(Some(
+                    self.state
+                        .selected()
+                        .map(|s| (s + step).min(self.items.len() - 1))
+                        .unwrap_or_default(),
+                ));
+                self.remember_position();
+                self.update_read();
+                self.compare_with_anchor();
+                return Ok(Some(Action::Render));
+            }
+            Action::PrevItem if !self.items.is_empty() => {
+                let step = self.scroll_accelerator.step(false, Instant::now());
+                self.state.select(Some(
+                    self.state
+                        .selected()
+                        .map(|s| s.saturating_sub(step))
+                        .unwrap_or_default(),
+                ));
+                self.remember_position();
+                self.update_read();
+                self.compare_with_anchor();
+                return Ok(Some(Action::Render));
+            }
+            Action::Enter => {
+                if let Some(feed_view_post) = self.state.selected().and_then(|i| self.items.get(i))
+                {
+                    return Ok(Some(Action::Transition(Transition::Push(Box::new(
+                        View::Post(Box::new((
+                            feed_view_post.post.clone(),
+                            feed_view_post
+                                .reply
+                                .as_ref()
+                                .and_then(|reply| match &reply.parent {
+                                    Union::Refs(ReplyRefParentRefs::PostView(post_view)) => {
+                                        Some(post_view.as_ref().clone())
+                                    }
+                                    _ => None,
+                                }),
+                        ))),
+                    )))));
+                }
+            }
+            Action::Back => return Ok(Some(Action::Transition(Transition::Pop))),
+            Action::FocusMode => {
+                if let Some(feed_view_post) = self.state.selected().and_then(|i| self.items.get(i))
+                {
+                    return Ok(Some(Action::Transition(Transition::Push(Box::new(
+                        View::FocusMode(Box::new(feed_view_post.post.clone())),
+                    )))));
+                }
+            }
+            Action::Open => {
+                if let Some(url) = self
+                    .state
+                    .selected()
+                    .and_then(|i| self.items.get(i))
+                    .and_then(|feed_view_post| post_url(&feed_view_post.post))
+                {
+                    open_url(&url, self.browser_command.as_deref());
+                }
+            }
+            Action::Note => {
+                if let Some(feed_view_post) = self.state.selected().and_then(|i| self.items.get(i))
+                {
+                    return Ok(Some(Action::AddNote(Box::new(feed_view_post.post.clone()))));
+                }
+            }
+            Action::Quotes => {
+                if let Some(feed_view_post) = self.state.selected().and_then(|i| self.items.get(i))
+                {
+                    return Ok(Some(Action::Transition(Transition::Push(Box::new(
+                        View::Quotes(Box::new(feed_view_post.post.clone())),
+                    )))));
+                }
+            }
+            Action::Comment => {
+                if let Some(feed_view_post) = self.state.selected().and_then(|i| self.items.get(i))
+                {
+                    return Ok(Some(Action::Transition(Transition::Push(Box::new(
+                        View::Comment(Box::new(feed_view_post.post.clone())),
+                    )))));
+                }
+            }
+            Action::Diff => {
+                if let Some(post_view) = self
+                    .state
+                    .selected()
+                    .and_then(|i| self.items.get(i))
+                    .map(|feed_view_post| &feed_view_post.post)
+                {
+                    if let Some(previous) = self.history.previous(&post_view.uri) {
+                        return Ok(Some(Action::Transition(Transition::Push(Box::new(
+                            View::Diff(Box::new((previous, post_view.clone()))),
+                        )))));
+                    }
+                }
+            }
+            Action::SearchAuthor => {
+                if let Some(feed_view_post) = self.state.selected().and_then(|i| self.items.get(i))
+                {
+                    return Ok(Some(Action::Transition(Transition::Push(Box::new(
+                        View::Search(Box::new(SearchQuery::by_author(
+                            feed_view_post.post.author.handle.to_string(),
+                        ))),
+                    )))));
+                }
+            }
+            Action::Feeds => {
+                if let Some(feed_view_post) = self.state.selected().and_then(|i| self.items.get(i))
+                {
+                    return Ok(Some(Action::Transition(Transition::Push(Box::new(
+                        View::CreatedFeeds(Box::new(feed_view_post.post.author.clone())),
+                    )))));
+                }
+            }
+            Action::Tack => {
+                // the tacked post is not in the items, so tacking the selected one always replaces it
+                let selected = self.state.selected();
+                self.tacked = selected
+                    .and_then(|i| self.items.get(i))
+                    .map(|feed_view_post| feed_view_post.post.cid.clone());
+                self.update_items();
+                // the next post takes the place of the tacked one
+                if let Some(i) = selected.filter(|_| !self.items.is_empty()) {
+                    self.state.select(Some(i.min(self.items.len() - 1)));
+                }
+                return Ok(Some(Action::Render));
+            }
+            Action::Untack if self.tacked.is_some() => {
+                self.tacked = None;
+                self.update_items();
+                return Ok(Some(Action::Render));
+            }
+            Action::Refresh => {
+                self.watcher.refresh();
+            }
+            Action::Analytics => {
+                if let Some(feed_view_post) = self
+                    .state
+                    .selected()
+                    .and_then(|i| self.items.get(i))
+                    .filter(|feed_view_post| {
+                        Some(&feed_view_post.post.author.did) == self.did.as_ref()
+                    })
+                {
+                    return Ok(Some(Action::Transition(Transition::Push(Box::new(
+                        View::Analytics(Box::new(feed_view_post.post.clone())),
+                    )))));
+                }
+            }
+            Action::Mutuals => {
+                let feed_info = match &self.feed_info {
+                    FeedSourceInfo::Timeline(algorithm) => {
+                        FeedSourceInfo::Mutuals(algorithm.clone())
+                    }
+                    FeedSourceInfo::Mutuals(algorithm) => {
+                        FeedSourceInfo::Timeline(algorithm.clone())
+                    }
+                    _ => return Ok(None),
+                };
+                return Ok(Some(Action::Transition(Transition::Replace(Box::new(
+                    View::Feed(Box::new(feed_info)),
+                )))));
+            }
+            Action::Translate => {
+                self.translate_selected();
+            }
+            Action::Live => {
+                self.live_mode = !self.live_mode;
+                return Ok(Some(Action::Render));
+            }
+            Action::Header => {
+                self.header_collapsed = !self.header_collapsed;
+                return Ok(Some(Action::Render));
+            }
+            Action::Compact => {
+                self.compact = !self.compact;
+                return Ok(Some(Action::Render));
+            }
+            Action::TimestampFormat => {
+                self.timestamp_format = self.timestamp_format.next();
+                return Ok(Some(Action::Render));
+            }
+            Action::PdsRecord => {
+                if let Some(post_view) = self
+                    .state
+                    .selected()
+                    .and_then(|i| self.items.get(i))
+                    .map(|feed_view_post| &feed_view_post.post)
+                {
+                    match &self.pds_browser_url {
+                        Some(base) => {
+                            if let Some(url) = record_url(base, post_view) {
+                                open_url(&url, self.browser_command.as_deref());
+                            }
+                        }
+                        None => {
+                            match arboard::Clipboard::new()
+                                .and_then(|mut clipboard| clipboard.set_text(&post_view.uri))
+                            {
+                                Ok(()) => self.show_status(String::from("Copied the AT URI")),
+                                Err(e) => log::warn!("failed to copy the AT URI to clipboard: {e}"),
+                            }
+                            return Ok(Some(Action::Render));
+                        }
+                    }
+                }
+            }
+            Action::Visual => {
+                if self.visual.active {
+                    self.visual.exit();
+                } else {
+                    self.visual.active = true;
+                }
+                return Ok(Some(Action::Render));
+            }
+            Action::TagPost => {
+                if let Some(feed_view_post) = self.state.selected().and_then(|i| self.items.get(i))
+                {
+                    let mut input = TextInput::default();
+                    input.set_block(Block::bordered().title("Tags (comma-separated)"));
+                    input.set_cursor_style(Style::default().reversed());
+                    if let Some(tags) = self.tags.get(&feed_view_post.post.cid) {
+                        input.set_value(&tags.iter().cloned().collect::<Vec<_>>().join(", "));
+                    }
+                    self.tag_input = Some(input);
+                    return Ok(Some(Action::Render));
+                }
+            }
+            Action::FilterTag(tag) => {
+                self.filters.tag = tag;
+                self.update_items();
+                if !self.items.is_empty() {
+                    self.state.select(Some(0));
+                }
+                return Ok(Some(Action::Render));
+            }
+            Action::CopyCid => {
+                if let Some(feed_view_post) = self.state.selected().and_then(|i| self.items.get(i))
+                {
+                    let cid = feed_view_post.post.cid.as_ref().to_string();
+                    match arboard::Clipboard::new()
+                        .and_then(|mut clipboard| clipboard.set_text(cid))
+                    {
+                        Ok(()) => self.show_status(String::from("CID copied")),
+                        Err(e) => log::warn!("failed to copy the CID to clipboard: {e}"),
+                    }
+                    return Ok(Some(Action::Render));
+                }
+            }
+            Action::Repliers => {
+                if self.repliers.take().is_some() {
+                    return Ok(Some(Action::Render));
+                }
+                if let Some(uri) = self
+                    .state
+                    .selected()
+                    .and_then(|i| self.items.get(i))
+                    .map(|feed_view_post| feed_view_post.post.uri.clone())
+                {
+                    let repliers = Arc::new(RwLock::new(None));
+                    self.repliers = Some(repliers.clone());
+                    let (agent, tx) = (self.agent.clone(), self.action_tx.clone());
+                    tokio::spawn(async move {
+                        match top_repliers(&agent, &uri, TOP_REPLIERS).await {
+                            Ok(top) => {
+                                if let Ok(mut repliers) = repliers.write() {
+                                    *repliers = Some(top);
+                                }
+                                tx.send(Action::Render).ok();
+                            }
+                            Err(e) => log::warn!("failed to get the thread: {e}"),
+                        }
+                    });
+                    return Ok(Some(Action::Render));
+                }
+            }
+            Action::RawRecord if self.dev => {
+                if let Some(uri) = self
+                    .state
+                    .selected()
+                    .and_then(|i| self.items.get(i))
+                    .map(|feed_view_post| feed_view_post.post.uri.clone())
+                {
+                    let (agent, tx) = (self.agent.clone(), self.action_tx.clone());
+                    tokio::spawn(async move {
+                        let json = match raw_record(&agent, &uri).await {
+                            Ok(output) => serde_json::to_string_pretty(&output),
+                            Err(e) => return log::error!("failed to get the record: {e}"),
+                        };
+                        match json {
+                            Ok(json) => {
+                                tx.send(Action::Transition(Transition::Push(Box::new(
+                                    View::RawRecord(Box::new((uri, json))),
+                                ))))
+                                .ok();
+                            }
+                            Err(e) => log::error!("failed to serialize the record: {e}"),
+                        }
+                    });
+                }
+            }
+            Action::CompareAnchor => {
+                if let Some(post_view) = self
+                    .state
+                    .selected()
+                    .and_then(|i| self.items.get(i))
+                    .map(|feed_view_post| &feed_view_post.post)
+                {
+                    self.compare_anchor = Some((
+                        post_view.cid.clone(),
+                        post_view.indexed_at.as_ref().with_timezone(&Local),
+                    ));
+                    self.show_status(String::from(
+                        "Select another post to compare (Esc to clear)",
+                    ));
+                    return Ok(Some(Action::Render));
+                }
+            }
+            Action::FeedStats => {
+                self.analytics.show_stats = !self.analytics.show_stats;
+                return Ok(Some(Action::Render));
+            }
+            Action::Expand
+                if self
+                    .state
+                    .selected()
+                    .and_then(|i| self.items.get(i))
+                    .is_some_and(|feed_view_post| self.hidden_domain(feed_view_post).is_some()) =>
+            {
+                if let Some(feed_view_post) = self.state.selected().and_then(|i| self.items.get(i))
+                {
+                    self.filters
+                        .revealed
+                        .insert(feed_view_post.post.cid.clone());
+                }
+                return Ok(Some(Action::Render));
+            }
+            Action::BlockDomain => {
+                let Some(domain) = self
+                    .state
+                    .selected()
+                    .and_then(|i| self.items.get(i))
+                    .and_then(|feed_view_post| external_uri(&feed_view_post.post))
+                    .map(|uri| link_domain(uri).to_lowercase())
+                else {
+                    self.show_status(String::from("No link card in the selected post"));
+                    return Ok(Some(Action::Render));
+                };
+                if self.filters.pending_block.take().as_ref() == Some(&domain) {
+                    self.store
+                        .blocked_domains
+                        .with(|blocked| blocked.block(&domain));
+                    self.show_status(format!("Hiding the posts linking to {domain}"));
+                } else {
+                    self.show_status(format!(
+                        "Press X again to hide the posts linking to {domain}"
+                    ));
+                    self.filters.pending_block = Some(domain);
+                }
+                return Ok(Some(Action::Render));
+            }
+            Action::Expand if self.group_threads => {
+                if let Some(feed_view_post) = self
+                    .state
+                    .selected()
+                    .and_then(|i| self.items.get(i))
+                    .filter(|feed_view_post| {
+                        self.thread_sizes.contains_key(&feed_view_post.post.cid)
+                    })
+                {
+                    let root = thread_root(feed_view_post).to_string();
+                    if !self.expanded_threads.remove(&root) {
+                        self.expanded_threads.insert(root);
+                    }
+                    self.update_items();
+                    return Ok(Some(Action::Render));
+                }
+            }
+            Action::Expand => {
+                if let Some(feed_view_post) = self.state.selected().and_then(|i| self.items.get(i))
+                {
+                    if !self.expanded.remove(&feed_view_post.post.cid) {
+                        self.expanded.insert(feed_view_post.post.cid.clone());
+                    }
+                    return Ok(Some(Action::Render));
+                }
+            }
+            Action::NextByAuthor | Action::PrevByAuthor => {
+                let Some((current, feed_view_post)) = self
+                    .state
+                    .selected()
+                    .and_then(|i| self.items.get(i).map(|feed_view_post| (i, feed_view_post)))
+                else {
+                    return Ok(None);
+                };
+                let author = &feed_view_post.post.author;
+                match find_next_by_author(
+                    &self.items,
+                    current,
+                    &author.did,
+                    matches!(action, Action::NextByAuthor),
+                ) {
+                    Some(index) => {
+                        self.state.select(Some(index));
+                        self.remember_position();
+                        self.update_read();
+                    }
+                    None => {
+                        let message = format!("No more posts by @{}", author.handle.as_str());
+                        self.show_status(message);
+                    }
+                }
+                return Ok(Some(Action::Render));
+            }
+            Action::FocusAuthor => {
+                if self.filters.author_focus.take().is_none() {
+                    let Some(author) = self
+                        .state
+                        .selected()
+                        .and_then(|i| self.items.get(i))
+                        .map(|feed_view_post| &feed_view_post.post.author)
+                    else {
+                        return Ok(None);
+                    };
+                    self.filters.author_focus =
+                        Some((author.did.clone(), author.handle.as_str().to_string()));
+                }
+                self.update_items();
+                return Ok(Some(Action::Render));
+            }
+            Action::ExternalLinks => {
+                // back to the feed below, if this is already filtered
+                if matches!(self.feed_info, FeedSourceInfo::ExternalLinks(_)) {
+                    return Ok(Some(Action::Transition(Transition::Pop)));
+                }
+                return Ok(Some(Action::Transition(Transition::Push(Box::new(
+                    View::Feed(Box::new(FeedSourceInfo::ExternalLinks(Box::new(
+                        self.feed_info.clone(),
+                    )))),
+                )))));
+            }
+            Action::MaxAge => {
+                self.filters.toggle_max_age();
+                self.update_items();
+                return Ok(Some(Action::Render));
+            }
+            Action::GroupThreads => {
+                self.group_threads = !self.group_threads;
+                self.update_items();
+                return Ok(Some(Action::Render));
+            }
+            Action::Media => {
+                self.filters.only_with_media = !self.filters.only_with_media;
+                self.update_items();
+                return Ok(Some(Action::Render));
+            }
+            Action::Export if !self.items.is_empty() => {
+                return Ok(Some(Action::Transition(Transition::Push(Box::new(
+                    View::Export(self.items.clone()),
+                )))));
+            }
+            Action::WordCloud => {
+                return Ok(Some(Action::Transition(Transition::Push(Box::new(
+                    View::WordCloud(Box::new(self.feed_info.clone())),
+                )))));
+            }
+            Action::Calendar => {
+                let mut counts = BTreeMap::new();
+                for feed_view_post in &self.feed {
+                    *counts.entry(local_date(&feed_view_post.post)).or_default() += 1;
+                }
+                let selected = self
+                    .state
+                    .selected()
+                    .and_then(|i| self.items.get(i))
+                    .map(|feed_view_post| local_date(&feed_view_post.post))
+                    .unwrap_or_else(|| Local::now().date_naive());
+                return Ok(Some(Action::Transition(Transition::Push(Box::new(
+                    View::Calendar(Box::new((counts, selected))),
+                )))));
+            }
+            Action::NotInterested => {
+                let Some(service) = self.interactions_service() else {
+                    self.show_status(String::from("The feed does not accept interactions"));
+                    return Ok(Some(Action::Render));
+                };
+                if let Some(feed_view_post) = self
+                    .state
+                    .selected()
+                    .and_then(|i| self.items.get(i))
+                    .cloned()
+                {
+                    self.request_less(service, feed_view_post);
+                }
+            }
+            Action::MarkAllRead => {
+                self.store.read_history.with(|history| {
+                    history.mark_read(
+                        self.items
+                            .iter()
+                            .map(|feed_view_post| feed_view_post.post.cid.as_ref().to_string()),
+                    );
+                });
+                return Ok(Some(Action::Render));
+            }
+            Action::FilterDate(date) => {
+                self.filters.date = date;
+                self.update_items();
+                if !self.items.is_empty() {
+                    self.state.select(Some(0));
+                }
+                return Ok(Some(Action::Render));
+            }
+            Action::Tick(_) if !self.loaded => {
+                self.shimmer = self.shimmer.wrapping_add(1);
+                return Ok(Some(Action::Render));
+            }
+            Action::Update(data) => {
+                if let Data::PostThread(Union::Refs(
+                    OutputThreadRefs::AppBskyFeedDefsThreadViewPost(thread_view),
+                )) = data.as_ref()
+                {
+                    let uri = thread_view.post.uri.clone();
+                    return self.preview.update_thread(&uri, data);
+                }
+                if let Data::Lists(lists) = data.as_ref() {
+                    return Ok(Some(self.open_list_menu(lists)));
+                }
+                let Data::Feed(feed) = data.as_ref() else {
+                    return Ok(None);
+                };
+                log::debug!("update feed view: {}", feed.len());
+                self.loaded = true;
+                // not on the first load
+                if !self.feed.is_empty() && feed.len() > self.feed.len() {
+                    self.flash();
+                    if let Some(interval) = self
+                        .bell_interval
+                        .filter(|_| self.live_mode && self.column_focused.load(Ordering::Relaxed))
+                    {
+                        ring_bell(&self.store, interval);
+                    }
+                }
+                if let (Some(interval), Some(did)) = (
+                    self.mention_bell_interval.filter(|_| !self.feed.is_empty()),
+                    &self.did,
+                ) {
+                    let known = self
+                        .feed
+                        .iter()
+                        .map(|feed_view_post| &feed_view_post.post.cid)
+                        .collect::<HashSet<_>>();
+                    if feed.iter().any(|feed_view_post| {
+                        !known.contains(&feed_view_post.post.cid)
+                            && mentions(&feed_view_post.post, did)
+                    }) {
+                        ring_bell(&self.store, interval);
+                    }
+                }
+                self.feed.clone_from(feed);
+                self.analytics.update_hourly_buckets(&self.feed);
+                self.store.duplicates.update(
+                    self.id,
+                    self.feed
+                        .iter()
+                        .map(|feed_view_post| &feed_view_post.post.cid),
+                );
+                self.update_items();
+                // keep the newest post in view
+                if self.live_mode && !self.items.is_empty() {
+                    self.state.select(Some(0));
+                } else if !self.items.is_empty() {
+                    if let Some(position) = self.restore.take() {
+                        self.restore_position(&position);
+                    }
+                }
+                self.remember_position();
+                self.update_read();
+                self.update_mutuals();
+                self.fetch_parents();
+                let own_posts = self
+                    .own_posts()
+                    .map(|feed_view_post| feed_view_post.post.clone())
+                    .collect::<Vec<_>>();
+                if !own_posts.is_empty() {
+                    if let Ok(dir) = get_data_dir() {
+                        record_posts(own_posts, dir.join("analytics.db"));
+                    }
+                }
+                return Ok(Some(Action::Render));
+            }
+            _ => {}
+        }
+        Ok(None)
+    }
+    fn border_style(&self) -> Option<Style> {
+        self.last_update_flash
+            .filter(|flashed_at| flashed_at.elapsed() < FLASH_DURATION)
+            .map(|_| Style::default().fg(Color::Green))
+    }
+    fn draw(&mut self, f: &mut Frame<'_>, area: Rect) -> Result<()> {
+        self.update_read();
+        self.remove_dismissed();
+        let area = if self.preview.enabled {
+            self.update_preview()?;
+            let layout =
+                Layout::horizontal([Constraint::Percentage(40), Constraint::Percentage(60)])
+                    .split(area);
+            let block = Block::default()
+                .borders(Borders::LEFT)
+                .border_style(Style::default().fg(Color::Gray));
+            let inner = block.inner(layout[1]);
+            f.render_widget(block, layout[1]);
+            self.preview.draw(f, inner)?;
+            layout[0]
+        } else {
+            area
+        };
+        let mut title = match &self.feed_info {
+            FeedSourceInfo::Feed(generator_view) | FeedSourceInfo::Skeleton(generator_view) => {
+                Line::from(vec![
+                    Span::from(generator_view.display_name.clone()).bold(),
+                    Span::from(" "),
+                    Span::from(format!(
+                        "by {}",
+                        profile_name_as_str(&generator_view.creator)
+                    ))
+                    .gray(),
+                ])
+            }
+            FeedSourceInfo::List(list_view) => Line::from(vec![
+                Span::from(list_view.name.clone()).bold(),
+                Span::from(" "),
+                Span::from(format!("by {}", profile_name_as_str(&list_view.creator))).gray(),
+            ]),
+            FeedSourceInfo::Timeline(_) => Line::from("Following").bold(),
+            FeedSourceInfo::Mutuals(_) => Line::from(vec![
+                Span::from("Following").bold(),
+                Span::from(" [mutuals]").cyan(),
+            ]),
+            FeedSourceInfo::Activity(_) => Line::from("Repost activity").bold(),
+            FeedSourceInfo::Own => Line::from("Own posts").bold(),
+            FeedSourceInfo::Author(actor) => Line::from(actor.as_str()).bold(),
+            FeedSourceInfo::Suggested => Line::from("Discover").bold(),
+            FeedSourceInfo::Combined(feed_infos) => Line::from(vec![
+                Span::from("All pinned feeds").bold(),
+                Span::from(format!(" ({} feeds)", feed_infos.len())).gray(),
+            ]),
+            FeedSourceInfo::ExternalLinks(feed_info) => Line::from(vec![
+                Span::from(feed_info.label()).bold(),
+                Span::from(" [links only]").cyan(),
+            ]),
+            FeedSourceInfo::Conversation(uri) => Line::from(vec![
+                Span::from("Replies").bold(),
+                Span::from(format!(" to {uri}")).gray(),
+            ]),
+        };
+        if matches!(self.feed_info, FeedSourceInfo::Skeleton(_)) {
+            title.push_span(Span::from(" [skeleton]").magenta());
+        }
+        if self.filters.only_with_media {
+            title.push_span(Span::from(" [media only]").cyan());
+        }
+        if let Some((_, handle)) = &self.filters.author_focus {
+            title.push_span(Span::from(format!(" [focused: @{handle}]")).red());
+        }
+        if let Some(hours) = self.filters.max_age_hours {
+            title.push_span(Span::from(format!(" [< {hours} h]")).cyan());
+        }
+        if self.live_mode {
+            title.push_span(Span::from(" [LIVE]").red());
+        }
+        if self.group_threads {
+            title.push_span(Span::from(" [threads]").cyan());
+        }
+        if self.compare_anchor.is_some() {
+            title.push_span(Span::from(" [Δt]").cyan());
+        }
+        if self.digest_mode {
+            let next = Local::now() + TimeDelta::hours(1);
+            title.push_span(
+                Span::from(format!(" [digest: next at {}:00]", next.format("%H"))).cyan(),
+            );
+        }
+        if self.visual.active {
+            title.push_span(
+                Span::from(format!(
+                    " [VISUAL: {} selected]",
+                    self.visual.selection.len()
+                ))
+                .magenta(),
+            );
+        }
+        if let Some(tag) = &self.filters.tag {
+            title.push_span(Span::from(format!(" [tag: {tag}]")).cyan());
+        }
+        if let Some(languages) = &self.filters.languages {
+            title.push_span(Span::from(format!(" [lang: {}]", languages.join(", "))).cyan());
+        }
+        if let Some((message, _)) = self
+            .status
+            .as_ref()
+            .filter(|(_, shown_at)| shown_at.elapsed() < STATUS_DURATION)
+        {
+            title.push_span(Span::from(format!(" {message}")).yellow());
+        }
+        if let Some(date) = self.filters.date {
+            title.push_span(Span::from(format!(" [{date}]")).cyan());
+        }
+        if self.accessible {
+            title = Line::from(
+                title
+                    .spans
+                    .into_iter()
+                    .map(|span| span.content)
+                    .collect::<String>(),
+            );
+        }
+        let mut header_block = Block::default()
+            .borders(Borders::BOTTOM)
+            .border_style(if self.accessible {
+                Style::default()
+            } else {
+                Style::default().fg(Color::Gray)
+            })
+            .padding(Padding::horizontal(1));
+        if let Some(color) = self.background {
+            header_block = header_block.style(Style::default().bg(color));
+        }
+        // not read out by the screen readers
+        let show_heat_map = self.loaded && !self.accessible;
+        let mut header = Text::from(title).bold();
+        if show_heat_map {
+            header.push_line(heat_map(&self.analytics.hourly_buckets));
+        }
+        let header = Paragraph::new(header).block(header_block);
+        // borrowed while the items are built, the fetches only lock them to insert the results
+        let mutuals = self.mutuals.read().ok();
+        let is_mutual = |feed_view_post: &FeedViewPost| {
+            mutuals
+                .as_ref()
+                .and_then(|mutuals| mutuals.get(&feed_view_post.post.author.did))
+                .copied()
+                .unwrap_or_default()
+        };
+        let translations = self.translations.read().ok();
+        let translation = |feed_view_post: &FeedViewPost| {
+            self.translation
+                .as_ref()
+                .zip(translations.as_ref())
+                .and_then(|(config, translations)| {
+                    translations.get(&(feed_view_post.post.cid.clone(), config.target.clone()))
+                })
+        };
+        let no_parents = HashMap::new();
+        let parents = self.parents.read().ok();
+        let parents = parents.as_deref().unwrap_or(&no_parents);
+        let reply_parent = |feed_view_post| self.reply_parent(feed_view_post, parents);
+        let replied = self.replied.read().ok();
+        let replied = |feed_view_post: &FeedViewPost| {
+            replied
+                .as_ref()
+                .is_some_and(|replied| replied.contains(&feed_view_post.post.uri))
+        };
+        let mut items = Vec::new();
+        if !self.loaded && !self.accessible {
+            items.extend(
+                (0..area.height.saturating_sub(2) / PLACEHOLDER_HEIGHT).map(|_| {
+                    Self::placeholder(usize::from(area.width.saturating_sub(2)), self.shimmer)
+                }),
+            );
+        }
+        let mut prev_date = None;
+        // the indices of the items tinted with the background, counted by the visible posts only
+        let mut tinted = HashSet::new();
+        let mut post_index = 0;
+        for feed_view_post in &self.items {
+            if let Some(domain) = self.hidden_domain(feed_view_post) {
+                items.push(Text::from(
+                    Line::from(format!("[domain hidden] {domain} (e to reveal)")).dark_gray(),
+                ));
+                continue;
+            }
+            let mentioned = self
+                .did
+                .as_ref()
+                .is_some_and(|did| mentions(&feed_view_post.post, did));
+            if self.accessible {
+                let mut lines = Self::accessible_lines(
+                    feed_view_post,
+                    usize::from(area.width.saturating_sub(2)),
+                    is_mutual(feed_view_post),
+                    reply_parent(feed_view_post),
+                    self.timestamp_format,
+                );
+                if mentioned {
+                    lines.insert(0, Line::from("Mentions you"));
+                }
+                items.push(Text::from(lines));
+                continue;
+            }
+            let thread_size = self.thread_sizes.get(&feed_view_post.post.cid).copied();
+            // leave room for the gutter of the thread or the mention
+            let area = if thread_size.is_some() || mentioned {
+                Rect {
+                    width: area.width.saturating_sub(2),
+                    ..area
+                }
+            } else {
+                area
+            };
+            let mut text = if self.compact {
+                Self::compact_line(feed_view_post).map(Text::from)
+            } else {
+                Self::lines(
+                    feed_view_post,
+                    area,
+                    is_mutual(feed_view_post),
+                    self.commented.contains(&feed_view_post.post.uri),
+                    self.expanded.contains(&feed_view_post.post.cid),
+                    translation(feed_view_post),
+                    reply_parent(feed_view_post),
+                    self.timestamp_format,
+                )
+                .map(Text::from)
+            };
+            // before the author
+            if mentioned {
+                if let Some(line) = text.as_mut().and_then(|text| text.lines.first_mut()) {
+                    line.spans.insert(0, Span::from("📣 "));
+                }
+            }
+            if let Some(tags) = self.tags.get(&feed_view_post.post.cid) {
+                if let Some(line) = text.as_mut().and_then(|text| text.lines.first_mut()) {
+                    let tags = tags.iter().cloned().collect::<Vec<_>>().join(", ");
+                    line.spans.push(Span::from(format!(" [🏷 {tags}]")).yellow());
+                }
+            }
+            // next to the counts, on the last line
+            if replied(feed_view_post) {
+                if let Some(line) = text.as_mut().and_then(|text| text.lines.last_mut()) {
+                    line.spans.push(Span::from(" ↩").gray());
+                }
+            }
+            if matches!(self.feed_info, FeedSourceInfo::Activity(_)) {
+                if let (Some(text), Some(line)) = (text.as_mut(), activity_line(feed_view_post)) {
+                    text.lines.insert(0, line);
+                }
+            }
+            if self.feed_info == FeedSourceInfo::Suggested {
+                if let Some(line) = text.as_mut().and_then(|text| text.lines.first_mut()) {
+                    line.spans.push(Span::from(" [suggested]").cyan());
+                }
+            }
+            if let Some(columns) = self
+                .store
+                .duplicates
+                .duplicates(&feed_view_post.post.cid, self.id)
+            {
+                if let Some(line) = text.as_mut().and_then(|text| text.lines.first_mut()) {
+                    line.spans
+                        .push(Span::from(format!(" [×{columns}]")).magenta());
+                }
+            }
+            if let (Some(text), Some(size)) = (text.as_mut(), thread_size) {
+                if !self.expanded_threads.contains(thread_root(feed_view_post)) {
+                    text.lines.push(
+                        Line::from(format!("  ⋯ {} more posts in the thread", size - 1))
+                            .dark_gray(),
+                    );
+                }
+            }
+            // a single gutter for both, highlighted for the mentions
+            if let Some(text) = text.as_mut().filter(|_| thread_size.is_some() || mentioned) {
+                let gutter = if mentioned {
+                    Span::from("┃ ").light_yellow()
+                } else {
+                    Span::from("│ ").dark_gray()
+                };
+                for line in &mut text.lines {
+                    line.spans.insert(0, gutter.clone());
+                }
+            }
+            // the separator is a part of the first post of the date, to keep the indices of the items
+            let date = local_date(&feed_view_post.post);
+            if let Some(text) = text
+                .as_mut()
+                .filter(|_| prev_date.is_some_and(|d| d != date))
+            {
+                let label = format!("─── {date} ");
+                text.lines.insert(
+                    0,
+                    Line::from(format!(
+                        "{label}{}",
+                        "─".repeat(
+                            usize::from(area.width).saturating_sub(label.chars().count() + 2)
+                        )
+                    ))
+                    .dark_gray(),
+                );
+            }
+            prev_date = Some(date);
+            if self
+                .store
+                .read_history
+                .with(|history| history.is_read(&feed_view_post.post.cid.as_ref().to_string()))
+                .unwrap_or_default()
+            {
+                text = text.map(|text| text.patch_style(Style::default().dim()));
+            }
+            // between the posts tinted with the background of the column
+            if self.zebra_stripe && post_index % 2 == 1 {
+                text = text.map(|text| text.patch_style(Style::default().bg(ZEBRA_STRIPE)));
+            }
+            if self.visual.selection.contains(&feed_view_post.post.cid) {
+                text = text.map(|text| text.patch_style(Style::default().bg(Color::DarkGray)));
+            }
+            if let Some(frame) = self.dismissal_frame(&feed_view_post.post.cid) {
+                text = text.map(|text| dismissed_text(&text, frame));
+            }
+            if text.is_some() {
+                if post_index % 2 == 0 {
+                    tinted.insert(items.len());
+                }
+                post_index += 1;
+            }
+            items.extend(text);
+        }
+        // the tacked post is always rendered first, and left out of the items while tacked
+        let tacked = self
+            .tacked
+            .as_ref()
+            .and_then(|cid| {
+                self.feed
+                    .iter()
+                    .find(|feed_view_post| &feed_view_post.post.cid == cid)
+            })
+            .and_then(|feed_view_post| {
+                if self.accessible {
+                    return Some(Self::accessible_lines(
+                        feed_view_post,
+                        usize::from(area.width.saturating_sub(2)),
+                        is_mutual(feed_view_post),
+                        reply_parent(feed_view_post),
+                        self.timestamp_format,
+                    ));
+                }
+                Self::lines(
+                    feed_view_post,
+                    area,
+                    is_mutual(feed_view_post),
+                    self.commented.contains(&feed_view_post.post.uri),
+                    self.expanded.contains(&feed_view_post.post.cid),
+                    translation(feed_view_post),
+                    reply_parent(feed_view_post),
+                    self.timestamp_format,
+                )
+            });
+
+        let show_range = !self.header_collapsed && self.loaded && !self.items.is_empty();
+        let layout = Layout::vertical([
+            Constraint::Length(match (self.header_collapsed, show_heat_map) {
+                (true, _) => 0,
+                (false, true) => 3,
+                (false, false) => 2,
+            }),
+            Constraint::Length(tacked.as_ref().map_or(0, |lines| lines.len() as u16 + 2)),
+            Constraint::Length(if show_range { 2 } else { 0 }),
+            Constraint::Percentage(100),
+            Constraint::Length(u16::from(self.analytics.show_stats)),
+        ])
+        .split(area);
+        f.render_widget(header, layout[0]);
+        if let Some(lines) = tacked {
+            f.render_widget(
+                Paragraph::new(lines).block(Block::bordered().title("Tacked").border_style(
+                    if self.accessible {
+                        Style::default()
+                    } else {
+                        Style::default().reversed()
+                    },
+                )),
+                layout[1],
+            );
+        }
+        self.heights.update(&items);
+        // every other post, as the terminals cannot blend the colors
+        let items = items.into_iter().enumerate().map(|(i, text)| {
+            let item = ListItem::new(text);
+            match self.background {
+                Some(color) if tinted.contains(&i) => item.style(Style::default().bg(color)),
+                _ => item,
+            }
+        });
+        let mut list_block = Block::default().padding(Padding::horizontal(1));
+        if let Some(color) = self.background {
+            list_block = list_block.style(Style::default().bg(color));
+        }
+        f.render_stateful_widget(
             List::new(items)
                 .highlight_style(Style::default().reset().reversed())
-                .block(Block::default().padding(Padding::horizontal(1))),
-            layout[1],
+                .block(list_block),
+            layout[3],
             &mut self.state,
         );
+        // in the right padding, by the rows of the posts
+        let (total, viewport) = (self.heights.total(), usize::from(layout[3].height));
+        if !self.accessible && total > viewport {
+            let top_row = self
+                .heights
+                .top_row(self.state.selected().unwrap_or(self.state.offset()));
+            let mut scrollbar_state = ScrollbarState::new(total.saturating_sub(viewport))
+                .position(top_row.min(total.saturating_sub(viewport)))
+                .viewport_content_length(viewport);
+            f.render_stateful_widget(
+                Scrollbar::new(ScrollbarOrientation::VerticalRight)
+                    .begin_symbol(None)
+                    .end_symbol(None)
+                    .style(Style::default().dark_gray()),
+                layout[3],
+                &mut scrollbar_state,
+            );
+        }
+        // over the first and the last rows of the list, next to the scrollbar
+        let list = layout[3];
+        if self.loaded && list.height > 1 && list.width > 2 {
+            let visible = self
+                .heights
+                .visible(self.state.offset(), viewport)
+                .end
+                .min(self.items.len());
+            let (above, below) = (self.state.offset(), self.items.len() - visible);
+            let row = |y| Rect::new(list.x, y, list.width - 2, 1);
+            if above > 0 {
+                f.render_widget(
+                    Line::from(format!("[{above} above]"))
+                        .dark_gray()
+                        .right_aligned(),
+                    row(list.y),
+                );
+            }
+            if below > 0 {
+                f.render_widget(
+                    Line::from(format!("[{below} below]"))
+                        .dark_gray()
+                        .right_aligned(),
+                    row(list.bottom() - 1),
+                );
+            }
+        }
+        // rendered after the list, to know the posts in view
+        if show_range {
+            f.render_widget(self.date_range(viewport), layout[2]);
+        }
+        if self.analytics.show_stats {
+            let mut stats = format!(
+                "{} posts · avg {} chars",
+                self.analytics.stats.posts, self.analytics.stats.average_length
+            );
+            if let Some(handle) = &self.analytics.stats.top_poster {
+                stats.push_str(&format!(" · top poster: @{handle}"));
+            }
+            f.render_widget(Line::from(stats).dark_gray().centered(), layout[4]);
+        }
+        // the initial letter of the feed in the left padding identifies the collapsed header
+        if self.header_collapsed && area.height > 0 {
+            let name = match &self.feed_info {
+                FeedSourceInfo::Feed(generator_view) | FeedSourceInfo::Skeleton(generator_view) => {
+                    generator_view.display_name.as_str()
+                }
+                FeedSourceInfo::List(list_view) => list_view.name.as_str(),
+                FeedSourceInfo::Timeline(_)
+                | FeedSourceInfo::Mutuals(_)
+                | FeedSourceInfo::Activity(_) => "Following",
+                FeedSourceInfo::Own => "Own posts",
+                FeedSourceInfo::Author(actor) => actor.as_str(),
+                FeedSourceInfo::Suggested => "Discover",
+                FeedSourceInfo::Combined(_) => "All pinned feeds",
+                FeedSourceInfo::ExternalLinks(_) => "Links",
+                FeedSourceInfo::Conversation(_) => "Replies",
+            };
+            f.render_widget(
+                Span::from(name.chars().next().map(String::from).unwrap_or_default())
+                    .bold()
+                    .reversed(),
+                Rect::new(area.x, area.y, 1, 1),
+            );
+        }
+        self.list_area = layout[3];
+        if let Some(repliers) = &self.repliers {
+            let repliers = repliers.read().ok().and_then(|repliers| repliers.clone());
+            let rows = match &repliers {
+                Some(repliers) if repliers.is_empty() => vec![Row::new(vec!["No replies"]).dim()],
+                Some(repliers) => repliers
+                    .iter()
+                    .map(|(handle, count)| {
+                        Row::new(vec![
+                            Cell::from(format!("@{handle}")),
+                            Cell::from(count.to_string()),
+                        ])
+                    })
+                    .collect(),
+                None => vec![Row::new(vec!["Loading..."]).dim()],
+            };
+            let list = layout[3];
+            let (width, height) = (list.width.min(40), (rows.len() as u16 + 3).min(list.height));
+            let popup = Rect::new(list.x + (list.width - width) / 2, list.y, width, height);
+            f.render_widget(Clear, popup);
+            f.render_widget(
+                Table::new(rows, [Constraint::Fill(1), Constraint::Length(7)])
+                    .header(Row::new(vec!["author", "replies"]).bold())
+                    .block(Block::bordered().title("Top repliers")),
+                popup,
+            );
+        }
+        if let Some(input) = &self.tag_input {
+            let list = layout[3];
+            let (width, height) = (list.width.min(40), list.height.min(3));
+            let popup = Rect::new(list.x + (list.width - width) / 2, list.y, width, height);
+            f.render_widget(Clear, popup);
+            f.render_widget(input, popup);
+        }
+        if let Some((menu, _)) = self.context_menu.as_mut() {
+            menu.draw(f, area);
+        }
+        if let Some(tutorial) = &self.tutorial {
+            let list = layout[3];
+            let (offset, selected) = (
+                self.state.offset(),
+                self.state.selected().unwrap_or(self.state.offset()),
+            );
+            let top = self.heights.top_row(selected) as i64 - self.heights.top_row(offset) as i64;
+            let height = self.heights.top_row(selected + 1) - self.heights.top_row(selected);
+            let selected_post = u16::try_from(top)
+                .ok()
+                .filter(|top| *top < list.height)
+                .map(|top| {
+                    let height = u16::try_from(height).unwrap_or(u16::MAX);
+                    Rect::new(
+                        list.x,
+                        list.y + top,
+                        list.width,
+                        height.min(list.height - top),
+                    )
+                })
+                .unwrap_or_default();
+            tutorial.draw(
+                f,
+                &TutorialAreas {
+                    header: layout[0],
+                    feed: list,
+                    selected_post,
+                },
+            );
+        }
         Ok(())
     }
 }
+
+/// Ring the terminal bell, unless it has been rung by any column within `interval`.
+fn ring_bell(store: &LocalStore, interval: Duration) {
+    let Ok(mut last) = store.last_bell.lock() else {
+        return;
+    };
+    if last.is_some_and(|last| last.elapsed() < interval) {
+        return;
+    }
+    *last = Some(Instant::now());
+    let mut stdout = tui::io();
+    if let Err(e) = write!(stdout, "\x07").and_then(|()| stdout.flush()) {
+        log::warn!("failed to ring the bell: {e}");
+    }
+}
+
+/// The steps of the tutorial, with the keys bound to the actions.
+fn tutorial_steps(keybindings: &Keybindings) -> Vec<TutorialStep> {
+    let keys = |action: ColumnAction| {
+        let mut keys = keybindings
+            .column
+            .iter()
+            .filter(|(_, a)| **a == action)
+            .map(|(k, _)| k)
+            .collect::<Vec<_>>();
+        keys.sort();
+        keys.iter()
+            .filter_map(|k| serde_json::to_string(k).ok())
+            .map(|s| s.trim_matches('"').to_string())
+            .collect::<Vec<_>>()
+            .join("/")
+    };
+    let compose = match keys(ColumnAction::NewPost) {
+        new_post if new_post.is_empty() => format!(
+            "{} opens the menu, where New Post writes a post from this account.",
+            keys(ColumnAction::Menu)
+        ),
+        new_post => format!("{new_post} writes a new post from this account."),
+    };
+    vec![
+        TutorialStep {
+            title: "Navigate",
+            text: format!(
+                "{} and {} select the next and the previous post.",
+                keys(ColumnAction::NextItem),
+                keys(ColumnAction::PrevItem)
+            ),
+            target: TutorialTarget::Feed,
+        },
+        TutorialStep {
+            title: "Open a post",
+            text: format!(
+                "{} opens the selected post, with its details and actions.",
+                keys(ColumnAction::Enter)
+            ),
+            target: TutorialTarget::SelectedPost,
+        },
+        TutorialStep {
+            title: "Like",
+            text: format!(
+                "In the opened post, select Like and press {} to like it.",
+                keys(ColumnAction::Enter)
+            ),
+            target: TutorialTarget::SelectedPost,
+        },
+        TutorialStep {
+            title: "Compose",
+            text: compose,
+            target: TutorialTarget::Header,
+        },
+    ]
+}
+
+/// Describe the activity of a follow that brought the post into the timeline,
+/// e.g. `★ alice reposted a post by bob`.
+fn activity_line(feed_view_post: &FeedViewPost) -> Option<Line<'static>> {
+    let Some(Union::Refs(FeedViewPostReasonRefs::ReasonRepost(repost))) = &feed_view_post.reason
+    else {
+        return None;
+    };
+    Some(
+        Line::from(format!(
+            "★ {} reposted a post by {}",
+            profile_name_as_str(&repost.by),
+            profile_name_as_str(&feed_view_post.post.author)
+        ))
+        .bold()
+        .yellow(),
+    )
+}
+
+/// Replace the lines of a dismissed post with `✕`, fading out with each frame.
+fn dismissed_text(text: &Text<'_>, frame: u32) -> Text<'static> {
+    let color = match frame {
+        0 => Color::White,
+        1 => Color::Gray,
+        _ => Color::DarkGray,
+    };
+    Text::from(
+        text.lines
+            .iter()
+            .map(|line| Line::from("✕".repeat(line.width())).fg(color))
+            .collect::<Vec<_>>(),
+    )
+}
+
+/// Whether the post was indexed long after its creation, which suggests that it was edited.
+fn is_edited(record: &RecordData, post_view: &PostView) -> bool {
+    (*post_view.indexed_at.as_ref() - *record.created_at.as_ref()).abs() > EDITED_THRESHOLD
+}
+
+/// The URI of the root of the thread of a reply, or of the post itself.
+fn thread_root(feed_view_post: &FeedViewPost) -> &str {
+    feed_view_post
+        .reply
+        .as_ref()
+        .and_then(|reply| match &reply.root {
+            Union::Refs(ReplyRefRootRefs::PostView(post_view)) => Some(post_view.uri.as_str()),
+            _ => None,
+        })
+        .unwrap_or(&feed_view_post.post.uri)
+}
+
+/// The date the post was indexed, in the local time zone.
+fn local_date(post_view: &PostView) -> NaiveDate {
+    post_view
+        .indexed_at
+        .as_ref()
+        .with_timezone(&Local)
+        .date_naive()
+}
+
+/// Whether any language of the post is one of `languages`, or any of its subtags (e.g. `en-US` for
+/// `en`).
+fn in_languages(post_view: &PostView, languages: &[String], show_untagged: bool) -> bool {
+    let langs = match &post_view.record {
+        Record::Known(KnownRecord::AppBskyFeedPost(record)) => record.langs.as_deref(),
+        _ => None,
+    };
+    match langs.filter(|langs| !langs.is_empty()) {
+        Some(langs) => langs.iter().any(|lang| {
+            let lang = lang.as_ref().as_str().to_lowercase();
+            languages.iter().any(|language| {
+                let language = language.to_lowercase();
+                lang == language || lang.starts_with(&format!("{language}-"))
+            })
+        }),
+        None => show_untagged,
+    }
+}
+
+/// Whether the post has images or a video, also with a quoted post.
+fn has_media(feed_view_post: &FeedViewPost) -> bool {
+    match &feed_view_post.post.embed {
+        Some(Union::Refs(PostViewEmbedRefs::AppBskyEmbedImagesView(_))) => true,
+        Some(Union::Refs(PostViewEmbedRefs::AppBskyEmbedRecordWithMediaView(view))) => {
+            match &view.media {
+                Union::Refs(ViewMediaRefs::AppBskyEmbedImagesView(_)) => true,
+                Union::Refs(ViewMediaRefs::AppBskyEmbedExternalView(_)) => false,
+                Union::Unknown(unknown) => unknown.r#type == VIDEO_VIEW_TYPE,
+            }
+        }
+        // not supported by the API types yet
+        Some(Union::Unknown(unknown)) => unknown.r#type == VIDEO_VIEW_TYPE,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::{json, Value};
+
+    /// A post by alice, changed by `f` before it is deserialized.
+    fn post_with(f: impl FnOnce(&mut Value)) -> FeedViewPost {
+        let mut post = json!({
+            "author": { "did": "did:plc:alice", "handle": "alice.bsky.social" },
+            "cid": "bafyreidfayvfuwqa7qlnopdjiqrxzs6blmoeu4rujcjtnci5beludirz2a",
+            "indexedAt": "2024-09-10T12:00:00Z",
+            "record": {
+                "$type": "app.bsky.feed.post",
+                "text": "hello",
+                "createdAt": "2024-09-10T12:00:00Z",
+            },
+            "uri": "at://did:plc:alice/app.bsky.feed.post/1",
+        });
+        f(&mut post);
+        serde_json::from_value(json!({ "post": post })).expect("invalid post")
+    }
+
+    fn post(embed: Option<Value>) -> FeedViewPost {
+        post_with(|post| {
+            if let Some(embed) = embed {
+                post["embed"] = embed;
+            }
+        })
+    }
+
+    fn images() -> Value {
+        json!({
+            "$type": "app.bsky.embed.images#view",
+            "images": [{ "alt": "", "fullsize": "https://example.com/1", "thumb": "https://example.com/1" }],
+        })
+    }
+
+    fn video() -> Value {
+        json!({
+            "$type": "app.bsky.embed.video#view",
+            "cid": "bafkreidfayvfuwqa7qlnopdjiqrxzs6blmoeu4rujcjtnci5beludirz2a",
+            "playlist": "https://example.com/playlist.m3u8",
+        })
+    }
+
+    fn external() -> Value {
+        json!({
+            "$type": "app.bsky.embed.external#view",
+            "external": { "uri": "https://example.com", "title": "", "description": "" },
+        })
+    }
+
+    fn record_with_media(media: Value) -> Value {
+        json!({
+            "$type": "app.bsky.embed.recordWithMedia#view",
+            "record": {
+                "record": {
+                    "$type": "app.bsky.embed.record#viewNotFound",
+                    "uri": "at://did:plc:bob/app.bsky.feed.post/1",
+                    "notFound": true,
+                },
+            },
+            "media": media,
+        })
+    }
+
+    #[test]
+    fn has_media_without_embed() {
+        assert!(!has_media(&post(None)));
+    }
+
+    #[test]
+    fn has_media_with_images() {
+        assert!(has_media(&post(Some(images()))));
+    }
+
+    #[test]
+    fn has_media_with_video() {
+        assert!(has_media(&post(Some(video()))));
+    }
+
+    #[test]
+    fn has_media_with_external() {
+        assert!(!has_media(&post(Some(external()))));
+    }
+
+    #[test]
+    fn has_media_with_record_with_media() {
+        assert!(has_media(&post(Some(record_with_media(images())))));
+        assert!(has_media(&post(Some(record_with_media(video())))));
+        assert!(!has_media(&post(Some(record_with_media(external())))));
+    }
+
+    #[test]
+    fn edited_after_threshold() {
+        let edited = |indexed_at: &str| {
+            let feed_view_post = post_with(|post| post["indexedAt"] = json!(indexed_at));
+            let Record::Known(KnownRecord::AppBskyFeedPost(record)) = &feed_view_post.post.record
+            else {
+                panic!("not a post record");
+            };
+            is_edited(record, &feed_view_post.post)
+        };
+        assert!(!edited("2024-09-10T12:00:00Z"));
+        assert!(!edited("2024-09-10T12:10:00Z"));
+        assert!(edited("2024-09-10T12:10:01Z"));
+        // backdated posts as well
+        assert!(edited("2024-09-10T11:49:59Z"));
+    }
+
+    #[test]
+    fn in_languages_with_subtags() {
+        let languages = [String::from("en"), String::from("ja")];
+        let in_languages = |langs: Value, show_untagged| {
+            let feed_view_post = post_with(|post| post["record"]["langs"] = langs);
+            in_languages(&feed_view_post.post, &languages, show_untagged)
+        };
+        assert!(in_languages(json!(["en"]), false));
+        assert!(in_languages(json!(["EN-us"]), false));
+        assert!(in_languages(json!(["de", "ja"]), false));
+        assert!(!in_languages(json!(["de"]), true));
+        // not a subtag of `en`
+        assert!(!in_languages(json!(["eno"]), false));
+        assert!(in_languages(json!([]), true));
+        assert!(!in_languages(json!([]), false));
+    }
+
+    #[test]
+    fn feed_stats() {
+        let by = |handle: &str, text: &str| {
+            post_with(|post| {
+                post["author"]["handle"] = json!(handle);
+                post["record"]["text"] = json!(text);
+            })
+        };
+        assert_eq!(FeedStats::new(&[]), FeedStats::default());
+        assert_eq!(
+            FeedStats::new(&[
+                by("bob.test", "ab"),
+                by("alice.test", "あいう"),
+                by("bob.test", "abcd"),
+                by("alice.test", ""),
+            ]),
+            FeedStats {
+                posts: 4,
+                average_length: 2,
+                // tied, the first one in alphabetical order
+                top_poster: Some(String::from("alice.test")),
+            }
+        );
+    }
+
+    #[test]
+    fn thread_root_of_reply() {
+        assert_eq!(
+            thread_root(&post(None)),
+            "at://did:plc:alice/app.bsky.feed.post/1"
+        );
+        let mut reply = serde_json::to_value(post(None)).expect("failed to serialize");
+        let mut root = serde_json::to_value(post(None).post.clone()).expect("failed to serialize");
+        root["$type"] = json!("app.bsky.feed.defs#postView");
+        root["uri"] = json!("at://did:plc:bob/app.bsky.feed.post/root");
+        reply["reply"] = json!({ "root": root, "parent": root });
+        let reply = serde_json::from_value(reply).expect("invalid reply");
+        assert_eq!(
+            thread_root(&reply),
+            "at://did:plc:bob/app.bsky.feed.post/root"
+        );
+    }
+
+    #[test]
+    fn parent_uri_of_reply() {
+        assert_eq!(parent_uri(&post(None).post), None);
+        let reply = post_with(|post| {
+            let parent = json!({
+                "cid": "bafyreidfayvfuwqa7qlnopdjiqrxzs6blmoeu4rujcjtnci5beludirz3a",
+                "uri": "at://did:plc:bob/app.bsky.feed.post/parent",
+            });
+            post["record"]["reply"] = json!({ "root": parent, "parent": parent });
+        });
+        assert_eq!(
+            parent_uri(&reply.post),
+            Some("at://did:plc:bob/app.bsky.feed.post/parent")
+        );
+    }
+
+    #[test]
+    fn activity_line_of_repost() {
+        assert_eq!(activity_line(&post(None)), None);
+        let mut repost = serde_json::to_value(post(None)).expect("failed to serialize");
+        repost["reason"] = json!({
+            "$type": "app.bsky.feed.defs#reasonRepost",
+            "by": { "did": "did:plc:bob", "handle": "bob.test", "displayName": "Bob" },
+            "indexedAt": "2024-09-10T12:30:00Z",
+        });
+        let repost = serde_json::from_value(repost).expect("invalid repost");
+        assert_eq!(
+            activity_line(&repost).map(|line| line.to_string()),
+            Some(String::from("★ Bob reposted a post by alice.bsky.social"))
+        );
+    }
+
+    #[test]
+    fn fade_dismissed_text() {
+        let text = Text::from(vec![Line::from("abc"), Line::from("あ")]);
+        let dismissed = dismissed_text(&text, 0);
+        assert_eq!(dismissed.lines[0].to_string(), "✕✕✕");
+        assert_eq!(dismissed.lines[1].to_string(), "✕✕");
+        assert_eq!(dismissed.lines[0].style.fg, Some(Color::White));
+        assert_eq!(
+            dismissed_text(&text, DISMISS_FRAMES - 1).lines[0].style.fg,
+            Some(Color::DarkGray)
+        );
+    }
+}
@@ -0,0 +1,97 @@
+use super::{has_media, in_languages, local_date};
+use bsky_sdk::api::app::bsky::feed::defs::FeedViewPost;
+use bsky_sdk::api::types::string::{Cid, Did};
+use chrono::{DateTime, NaiveDate, TimeDelta, Utc};
+use std::collections::{BTreeSet, HashSet};
+
+/// The max age of the posts when toggled without `max_age_hours` in the config.
+const DEFAULT_MAX_AGE_HOURS: u64 = 24;
+
+/// The filters of the posts shown in the feed.
+#[derive(Debug, Default)]
+pub(super) struct FeedFilters {
+    pub only_with_media: bool,
+    // only the posts by this author (DID and handle) are shown
+    pub author_focus: Option<(Did, String)>,
+    // the posts older than this are hidden, toggled between `None` and the configured value
+    pub max_age_hours: Option<u64>,
+    pub configured_max_age_hours: Option<u64>,
+    // the tag of the posts shown
+    pub tag: Option<String>,
+    // the languages of the posts shown, from the config of the feed
+    pub languages: Option<Vec<String>>,
+    pub show_untagged: bool,
+    // chosen in the calendar
+    pub date: Option<NaiveDate>,
+    pub hide_duplicates: bool,
+    // the suggested posts which the user is not interested in
+    pub not_interested: HashSet<Cid>,
+    pub blocked_domains: Vec<String>,
+    // the posts of the blocked domains revealed in this session
+    pub revealed: HashSet<Cid>,
+    // the domain to block when the keybinding is pressed again
+    pub pending_block: Option<String>,
+}
+
+impl FeedFilters {
+    pub fn toggle_max_age(&mut self) {
+        self.max_age_hours = match self.max_age_hours {
+            Some(_) => None,
+            None => Some(
+                self.configured_max_age_hours
+                    .unwrap_or(DEFAULT_MAX_AGE_HOURS),
+            ),
+        };
+    }
+    /// The time before which the posts are hidden, if the max age is set.
+    pub fn cutoff(&self, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        self.max_age_hours
+            .and_then(|hours| TimeDelta::try_hours(i64::try_from(hours).ok()?))
+            .and_then(|max_age| now.checked_sub_signed(max_age))
+    }
+    /// Whether the post is shown, with the local `tags` of the post.
+    pub fn matches(
+        &self,
+        feed_view_post: &FeedViewPost,
+        cutoff: Option<DateTime<Utc>>,
+        tags: Option<&BTreeSet<String>>,
+    ) -> bool {
+        let post_view = &feed_view_post.post;
+        (!self.only_with_media || has_media(feed_view_post))
+            && self
+                .author_focus
+                .as_ref()
+                .map_or(true, |(did, _)| &post_view.author.did == did)
+            && cutoff.map_or(true, |cutoff| *post_view.indexed_at.as_ref() >= cutoff)
+            && !self.not_interested.contains(&post_view.cid)
+            && self
+                .tag
+                .as_ref()
+                .map_or(true, |tag| tags.is_some_and(|tags| tags.contains(tag)))
+            && self.languages.as_ref().map_or(true, |languages| {
+                in_languages(post_view, languages, self.show_untagged)
+            })
+            && self.date.map_or(true, |date| local_date(post_view) == date)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn toggle_max_age() {
+        let now = Utc::now();
+        let mut filters = FeedFilters::default();
+        assert_eq!(filters.cutoff(now), None);
+        filters.toggle_max_age();
+        assert_eq!(filters.max_age_hours, Some(DEFAULT_MAX_AGE_HOURS));
+        assert_eq!(filters.cutoff(now), Some(now - TimeDelta::hours(24)));
+        filters.toggle_max_age();
+        assert_eq!(filters.max_age_hours, None);
+
+        filters.configured_max_age_hours = Some(6);
+        filters.toggle_max_age();
+        assert_eq!(filters.cutoff(now), Some(now - TimeDelta::hours(6)));
+    }
+}
@@ -0,0 +1,97 @@
+use super::super::thread::ConversationViewComponent;
+use super::super::types::{Action, Data};
+use super::super::ViewComponent;
+use crate::backend::Watcher;
+use color_eyre::Result;
+use ratatui::layout::Rect;
+use ratatui::Frame;
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+use tokio::sync::mpsc::UnboundedSender;
+use tokio::task::JoinHandle;
+
+/// How long a post must stay selected before its thread is loaded in the preview pane.
+const PREVIEW_DELAY: Duration = Duration::from_millis(300);
+
+/// The thread of the selected post, next to the feed.
+pub(super) struct SplitPreview {
+    pub enabled: bool,
+    preview: Option<(String, ConversationViewComponent)>,
+    // the post to preview once it has stayed selected, and the task waiting for it
+    uri: Arc<RwLock<Option<String>>>,
+    task: Option<(String, JoinHandle<()>)>,
+}
+
+impl SplitPreview {
+    pub fn new(enabled: bool) -> Self {
+        Self {
+            enabled,
+            preview: None,
+            uri: Arc::new(RwLock::new(None)),
+            task: None,
+        }
+    }
+    /// Wait for the selected post to stay selected, and then replace the preview with its thread.
+    pub fn update(
+        &mut self,
+        selected: Option<String>,
+        action_tx: &UnboundedSender<Action>,
+        threads: &Arc<Watcher>,
+    ) -> Result<()> {
+        if selected.is_some() && selected.as_ref() != self.task.as_ref().map(|(uri, _)| uri) {
+            if let Some((_, task)) = self.task.take() {
+                task.abort();
+            }
+            let (tx, uri) = (action_tx.clone(), selected.clone());
+            let preview_uri = self.uri.clone();
+            let task = tokio::spawn(async move {
+                tokio::time::sleep(PREVIEW_DELAY).await;
+                if let Ok(mut preview_uri) = preview_uri.write() {
+                    *preview_uri = uri;
+                }
+                if let Err(e) = tx.send(Action::Render) {
+                    log::error!("failed to send render action: {e}");
+                }
+            });
+            self.task = selected.map(|uri| (uri, task));
+        }
+        let uri = self.uri.read().ok().and_then(|uri| uri.clone());
+        if uri.is_some() && uri.as_ref() != self.preview.as_ref().map(|(uri, _)| uri) {
+            if let Some((_, mut preview)) = self.preview.take() {
+                preview.deactivate()?;
+            }
+            if let Some(uri) = uri {
+                let mut preview =
+                    ConversationViewComponent::new(action_tx.clone(), threads.clone(), uri.clone());
+                preview.activate()?;
+                self.preview = Some((uri, preview));
+            }
+        }
+        Ok(())
+    }
+    /// Pass the thread of the post `uri` to the preview, if it is still the previewed post.
+    pub fn update_thread(&mut self, uri: &str, data: Box<Data>) -> Result<Option<Action>> {
+        // the updates of the previous previews may still be on their way
+        match &mut self.preview {
+            Some((preview_uri, preview)) if preview_uri == uri => {
+                preview.update(Action::Update(data))
+            }
+            _ => Ok(None),
+        }
+    }
+    pub fn deactivate(&mut self) -> Result<()> {
+        if let Some((_, task)) = self.task.take() {
+            task.abort();
+        }
+        if let Some((_, mut preview)) = self.preview.take() {
+            preview.deactivate()?;
+        }
+        Ok(())
+    }
+    pub fn draw(&mut self, f: &mut Frame<'_>, area: Rect) -> Result<()> {
+        if let Some((_, preview)) = &mut self.preview {
+            preview.draw(f, area)?;
+        }
+        Ok(())
+    }
+}
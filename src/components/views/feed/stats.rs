@@ -0,0 +1,55 @@
+use super::super::utils::compute_hourly_buckets;
+use bsky_sdk::api::app::bsky::feed::defs::FeedViewPost;
+use bsky_sdk::api::records::{KnownRecord, Record};
+use chrono::Utc;
+use std::collections::HashMap;
+
+/// The statistics of the feed, shown in the header and the footer.
+#[derive(Debug, Default)]
+pub(super) struct FeedAnalytics {
+    pub show_stats: bool,
+    // of the visible items, updated with them
+    pub stats: FeedStats,
+    // the number of posts of the feed in each of the past 24 hours
+    pub hourly_buckets: [u32; 24],
+}
+
+impl FeedAnalytics {
+    pub fn update_hourly_buckets(&mut self, feed: &[FeedViewPost]) {
+        self.hourly_buckets = compute_hourly_buckets(feed, Utc::now());
+    }
+}
+
+/// The statistics of the visible posts, shown in the footer.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub(super) struct FeedStats {
+    pub posts: usize,
+    // in characters
+    pub average_length: usize,
+    pub top_poster: Option<String>,
+}
+
+impl FeedStats {
+    pub fn new(items: &[FeedViewPost]) -> Self {
+        let mut authors = HashMap::<&str, usize>::new();
+        let mut total_length = 0;
+        for feed_view_post in items {
+            if let Record::Known(KnownRecord::AppBskyFeedPost(record)) = &feed_view_post.post.record
+            {
+                total_length += record.text.chars().count();
+            }
+            *authors
+                .entry(feed_view_post.post.author.handle.as_str())
+                .or_default() += 1;
+        }
+        Self {
+            posts: items.len(),
+            average_length: total_length / items.len().max(1),
+            // the first handle in alphabetical order if tied, not to change on every update
+            top_poster: authors
+                .into_iter()
+                .max_by(|(h1, c1), (h2, c2)| c1.cmp(c2).then_with(|| h2.cmp(h1)))
+                .map(|(handle, _)| handle.to_string()),
+        }
+    }
+}
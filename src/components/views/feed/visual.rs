@@ -0,0 +1,22 @@
+use bsky_sdk::api::types::string::Cid;
+use std::collections::HashSet;
+
+/// The posts selected in visual mode, for the actions on all of them at once.
+#[derive(Debug, Default)]
+pub(super) struct VisualMode {
+    pub active: bool,
+    pub selection: HashSet<Cid>,
+}
+
+impl VisualMode {
+    /// Add the post to the selection, or remove it if it is already selected.
+    pub fn toggle(&mut self, cid: Cid) {
+        if !self.selection.remove(&cid) {
+            self.selection.insert(cid);
+        }
+    }
+    pub fn exit(&mut self) {
+        self.active = false;
+        self.selection.clear();
+    }
+}
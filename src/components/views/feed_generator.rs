@@ -0,0 +1,326 @@
+use super::types::{Action, Data, Transition, View};
+use super::utils::{profile_name, profile_name_as_str};
+use super::ViewComponent;
+use crate::backend::preferences::PreferencesQueue;
+use crate::backend::types::{FeedGeneratorDetail, FeedSourceInfo};
+use crate::backend::{Watch, Watcher};
+use bsky_sdk::api::records::{KnownRecord, Record};
+use color_eyre::Result;
+use ratatui::layout::{Alignment, Constraint, Layout, Margin, Rect};
+use ratatui::style::{Color, Style, Stylize};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{
+    Block, Borders, Cell, List, ListItem, ListState, Padding, Paragraph, Row, Table,
+};
+use ratatui::Frame;
+use std::sync::Arc;
+use tokio::sync::mpsc::UnboundedSender;
+use tokio::sync::oneshot;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum GeneratorAction {
+    Subscribe,
+    Unsubscribe,
+    Open,
+    OpenSkeleton,
+}
+
+impl From<&GeneratorAction> for ListItem<'_> {
+    fn from(action: &GeneratorAction) -> Self {
+        match action {
+            GeneratorAction::Subscribe => Self::from("Subscribe"),
+            GeneratorAction::Unsubscribe => Self::from("Unsubscribe").red(),
+            GeneratorAction::Open => Self::from("Open feed"),
+            GeneratorAction::OpenSkeleton => Self::from("Open feed from the generator (skeleton)"),
+        }
+    }
+}
+
+pub struct FeedGeneratorDetailViewComponent {
+    uri: String,
+    detail: Option<FeedGeneratorDetail>,
+    actions: Vec<GeneratorAction>,
+    state: ListState,
+    action_tx: UnboundedSender<Action>,
+    preferences_queue: PreferencesQueue,
+    watcher: Box<dyn Watch<Output = Option<FeedGeneratorDetail>>>,
+    quit: Option<oneshot::Sender<()>>,
+}
+
+impl FeedGeneratorDetailViewComponent {
+    pub fn new(action_tx: UnboundedSender<Action>, watcher: Arc<Watcher>, uri: String) -> Self {
+        let preferences_queue = watcher.preferences_queue.clone();
+        let watcher = Box::new(watcher.feed_generator(uri.clone()));
+        Self {
+            uri,
+            detail: None,
+            actions: Vec::new(),
+            state: ListState::default(),
+            action_tx,
+            preferences_queue,
+            watcher,
+            quit: None,
+        }
+    }
+    fn detail_rows(detail: &FeedGeneratorDetail, width: u16) -> (Vec<Row<'_>>, u16) {
+        let yes_no = |b| if b { "yes".green() } else { "no".red() };
+        let description = textwrap::wrap(
+            detail.view.description.as_deref().unwrap_or_default(),
+            usize::from(width),
+        );
+        let height = 6 + description.len() as u16;
+        let rows = vec![
+            Row::new(vec![
+                Cell::from("URI:".gray().into_right_aligned_line()),
+                Cell::from(detail.view.uri.as_str()),
+            ]),
+            Row::new(vec![
+                Cell::from("DID:".gray().into_right_aligned_line()),
+                Cell::from(detail.view.did.as_str()),
+            ]),
+            Row::new(vec![
+                Cell::from("Creator:".gray().into_right_aligned_line()),
+                Cell::from(Line::from(profile_name(&detail.view.creator))),
+            ]),
+            Row::new(vec![
+                Cell::from("Online:".gray().into_right_aligned_line()),
+                Cell::from(yes_no(detail.is_online)),
+            ]),
+            Row::new(vec![
+                Cell::from("Valid:".gray().into_right_aligned_line()),
+                Cell::from(yes_no(detail.is_valid)),
+            ]),
+            Row::new(vec![
+                Cell::from("Likes:".gray().into_right_aligned_line()),
+                Cell::from(detail.view.like_count.unwrap_or_default().to_string()),
+            ]),
+            Row::default().height(description.len() as u16).cells(vec![
+                Cell::from("Description:".gray().into_right_aligned_line()),
+                Cell::from(
+                    description
+                        .iter()
+                        .map(|s| Line::from(s.to_string()))
+                        .collect::<Vec<_>>(),
+                ),
+            ]),
+        ];
+        (rows, height)
+    }
+    fn sample_lines(detail: &FeedGeneratorDetail, width: u16) -> Vec<Line<'_>> {
+        let mut lines = Vec::new();
+        for feed_view_post in &detail.posts {
+            let Record::Known(KnownRecord::AppBskyFeedPost(record)) = &feed_view_post.post.record
+            else {
+                continue;
+            };
+            lines.push(Line::from(profile_name(&feed_view_post.post.author)));
+            lines.extend(
+                textwrap::wrap(
+                    &record.text,
+                    textwrap::Options::new(usize::from(width))
+                        .initial_indent("  ")
+                        .subsequent_indent("  "),
+                )
+                .iter()
+                .map(|s| Line::from(s.to_string())),
+            );
+        }
+        lines
+    }
+}
+
+impl ViewComponent for FeedGeneratorDetailViewComponent {
+    fn view(&self) -> View {
+        View::FeedGenerator(self.uri.clone())
+    }
+    fn activate(&mut self) -> Result<()> {
+        let (tx, mut rx) = (self.action_tx.clone(), self.watcher.subscribe());
+        let (quit_tx, mut quit_rx) = oneshot::channel();
+        self.quit = Some(quit_tx);
+        tokio::spawn(async move {
+            loop {
+                tokio::select! {
+                    changed = rx.changed() => {
+                        if changed.is_ok() {
+                            if let Some(detail) = rx.borrow_and_update().clone() {
+                                if let Err(e) = tx.send(Action::Update(Box::new(Data::FeedGenerator(
+                                    Box::new(detail),
+                                )))) {
+                                    log::error!("failed to send update action: {e}");
+                                }
+                            }
+                        } else {
+                            break log::warn!("feed generator channel closed");
+                        }
+                    }
+                    _ = &mut quit_rx => {
+                        break;
+                    }
+                }
+            }
+            log::debug!("subscription finished");
+        });
+        Ok(())
+    }
+    fn deactivate(&mut self) -> Result<()> {
+        if let Some(tx) = self.quit.take() {
+            if tx.send(()).is_err() {
+                log::error!("failed to send quit signal");
+            }
+        }
+        self.watcher.unsubscribe();
+        Ok(())
+    }
+    fn update(&mut self, action: Action) -> Result<Option<Action>> {
+        match action {
+            Action::NextItem if !self.actions.is_empty() => {
+                self.state.select(Some(
+                    self.state
+                        .selected()
+                        .map_or(0, |s| (s + 1) % self.actions.len()),
+                ));
+                return Ok(Some(Action::Render));
+            }
+            Action::PrevItem if !self.actions.is_empty() => {
+                self.state.select(Some(
+                    self.state
+                        .selected()
+                        .map_or(0, |s| (s + self.actions.len() - 1) % self.actions.len()),
+                ));
+                return Ok(Some(Action::Render));
+            }
+            Action::Enter => {
+                let (Some(action), Some(detail)) = (
+                    self.state.selected().and_then(|i| self.actions.get(i)),
+                    &self.detail,
+                ) else {
+                    return Ok(None);
+                };
+                match action {
+                    GeneratorAction::Subscribe | GeneratorAction::Unsubscribe => {
+                        let subscribe = *action == GeneratorAction::Subscribe;
+                        let (queue, tx) = (self.preferences_queue.clone(), self.action_tx.clone());
+                        let uri = self.uri.clone();
+                        tokio::spawn(async move {
+                            let result = if subscribe {
+                                queue.save_feed(&uri).await
+                            } else {
+                                queue.unsave_feed(&uri).await
+                            };
+                            match result {
+                                Ok(()) => {
+                                    log::info!("updated saved feeds: {uri}");
+                                    tx.send(Action::Refresh).ok();
+                                }
+                                Err(e) => {
+                                    log::error!("failed to update saved feeds: {e}");
+                                }
+                            }
+                        });
+                    }
+                    GeneratorAction::Open => {
+                        return Ok(Some(Action::Transition(Transition::Push(Box::new(
+                            View::Feed(Box::new(FeedSourceInfo::Feed(Box::new(
+                                detail.view.clone(),
+                            )))),
+                        )))));
+                    }
+                    GeneratorAction::OpenSkeleton => {
+                        return Ok(Some(Action::Transition(Transition::Push(Box::new(
+                            View::Feed(Box::new(FeedSourceInfo::Skeleton(Box::new(
+                                detail.view.clone(),
+                            )))),
+                        )))));
+                    }
+                }
+            }
+            Action::Back => return Ok(Some(Action::Transition(Transition::Pop))),
+            Action::Refresh => {
+                self.watcher.refresh();
+            }
+            Action::Update(data) => {
+                let Data::FeedGenerator(detail) = data.as_ref() else {
+                    return Ok(None);
+                };
+                self.actions = vec![
+                    if detail.saved {
+                        GeneratorAction::Unsubscribe
+                    } else {
+                        GeneratorAction::Subscribe
+                    },
+                    GeneratorAction::Open,
+                    GeneratorAction::OpenSkeleton,
+                ];
+                if self.state.selected().is_none() {
+                    self.state.select(Some(0));
+                }
+                self.detail = Some(detail.as_ref().clone());
+                return Ok(Some(Action::Render));
+            }
+            _ => {}
+        }
+        Ok(None)
+    }
+    fn draw(&mut self, f: &mut Frame<'_>, area: Rect) -> Result<()> {
+        let widths = [Constraint::Length(12), Constraint::Percentage(100)];
+        let width = Layout::horizontal(widths).split(area.inner(Margin::new(1, 0)))[1].width;
+
+        let (header, (rows, rows_height), samples) = if let Some(detail) = &self.detail {
+            (
+                Line::from(vec![
+                    Span::from(detail.view.display_name.as_str()).bold(),
+                    Span::from(" "),
+                    Span::from(format!("by {}", profile_name_as_str(&detail.view.creator))).gray(),
+                ]),
+                Self::detail_rows(detail, width),
+                Self::sample_lines(detail, area.width.saturating_sub(2)),
+            )
+        } else {
+            (Line::from(self.uri.as_str()), (Vec::new(), 0), Vec::new())
+        };
+
+        let layout = Layout::vertical([
+            Constraint::Length(2),
+            Constraint::Length(rows_height),
+            Constraint::Percentage(100),
+            Constraint::Length(self.actions.len() as u16 + 1),
+        ])
+        .split(area);
+        f.render_widget(
+            Paragraph::new(header).bold().block(
+                Block::default()
+                    .borders(Borders::BOTTOM)
+                    .border_style(Color::Gray)
+                    .padding(Padding::horizontal(1)),
+            ),
+            layout[0],
+        );
+        f.render_widget(Table::new(rows, widths), layout[1]);
+        f.render_widget(
+            Paragraph::new(samples).block(
+                Block::default()
+                    .title("Recent posts")
+                    .title_alignment(Alignment::Center)
+                    .borders(Borders::TOP)
+                    .border_style(Color::Gray)
+                    .padding(Padding::horizontal(1)),
+            ),
+            layout[2],
+        );
+        f.render_stateful_widget(
+            List::new(&self.actions)
+                .highlight_style(Style::default().reversed())
+                .block(
+                    Block::default()
+                        .title("Actions")
+                        .title_alignment(Alignment::Center)
+                        .borders(Borders::TOP)
+                        .border_style(Color::Gray)
+                        .padding(Padding::horizontal(1)),
+                ),
+            layout[3],
+            &mut self.state,
+        );
+        Ok(())
+    }
+}
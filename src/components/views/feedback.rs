@@ -0,0 +1,162 @@
+use super::types::{Action, Transition, View};
+use super::ViewComponent;
+use crate::utils::{open_url, recent_logs};
+use color_eyre::Result;
+use crossterm::event::KeyEvent;
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::style::{Color, Style, Stylize};
+use ratatui::text::Line;
+use ratatui::widgets::{Block, Borders, Padding, Paragraph, Wrap};
+use ratatui::Frame;
+use std::env;
+use tui_textarea::TextArea;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Focus {
+    Text,
+    Submit,
+}
+
+pub struct FeedbackViewComponent {
+    textarea: TextArea<'static>,
+    focus: Focus,
+    browser_command: Option<String>,
+}
+
+impl FeedbackViewComponent {
+    pub fn new(browser_command: Option<String>) -> Self {
+        let mut textarea = TextArea::default();
+        textarea.set_block(Block::bordered().title("What happened?"));
+        textarea.set_cursor_line_style(Style::default());
+        Self {
+            textarea,
+            focus: Focus::Text,
+            browser_command,
+        }
+    }
+    fn report(&self) -> String {
+        let logs = recent_logs();
+        format!(
+            "## Description\n\n{}\n\n## Environment\n\n- tuisky: {}\n- OS: {} ({})\n- Terminal: {}\n\n## Logs\n\n```\n{}\n```\n",
+            self.textarea.lines().join("\n"),
+            env!("CARGO_PKG_VERSION"),
+            env::consts::OS,
+            env::consts::ARCH,
+            env::var("TERM").unwrap_or_else(|_| String::from("unknown")),
+            if logs.is_empty() {
+                String::from("(no warnings or errors, set RUST_LOG for the other logs)")
+            } else {
+                logs.join("\n")
+            },
+        )
+    }
+    fn submit(&self) {
+        let report = self.report();
+        match arboard::Clipboard::new().and_then(|mut clipboard| clipboard.set_text(&report)) {
+            Ok(()) => log::info!("copied feedback report to clipboard"),
+            Err(e) => log::warn!("failed to copy feedback report to clipboard: {e}"),
+        }
+        let url = format!(
+            "{}/issues/new?body={}",
+            env!("CARGO_PKG_REPOSITORY"),
+            percent_encode(&report)
+        );
+        open_url(&url, self.browser_command.as_deref());
+    }
+}
+
+fn percent_encode(s: &str) -> String {
+    s.bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                char::from(b).to_string()
+            }
+            _ => format!("%{b:02X}"),
+        })
+        .collect()
+}
+
+impl ViewComponent for FeedbackViewComponent {
+    fn view(&self) -> View {
+        View::Feedback
+    }
+    fn handle_key_events(&mut self, key: KeyEvent) -> Result<Option<Action>> {
+        if self.focus == Focus::Text {
+            let cursor = self.textarea.cursor();
+            if self.textarea.input(key) || self.textarea.cursor() != cursor {
+                return Ok(Some(Action::Render));
+            }
+        }
+        Ok(None)
+    }
+    fn update(&mut self, action: Action) -> Result<Option<Action>> {
+        match action {
+            Action::NextItem | Action::PrevItem => {
+                self.focus = match self.focus {
+                    Focus::Text => Focus::Submit,
+                    Focus::Submit => Focus::Text,
+                };
+                self.textarea
+                    .set_cursor_style(if self.focus == Focus::Text {
+                        Style::default().reversed()
+                    } else {
+                        Style::default()
+                    });
+                Ok(Some(Action::Render))
+            }
+            Action::Enter if self.focus == Focus::Submit => {
+                self.submit();
+                Ok(Some(Action::Transition(Transition::Pop)))
+            }
+            Action::Back | Action::Feedback => Ok(Some(Action::Transition(Transition::Pop))),
+            _ => Ok(None),
+        }
+    }
+    fn draw(&mut self, f: &mut Frame<'_>, area: Rect) -> Result<()> {
+        let layout = Layout::vertical([
+            Constraint::Length(2),
+            Constraint::Length(3),
+            Constraint::Length(10),
+            Constraint::Length(1),
+        ])
+        .split(area);
+
+        let mut submit = Line::from("Open issue").centered().blue();
+        if self.focus == Focus::Submit {
+            submit = submit.reversed();
+        }
+        f.render_widget(
+            Paragraph::new("Send feedback").bold().block(
+                Block::default()
+                    .borders(Borders::BOTTOM)
+                    .border_style(Color::Gray)
+                    .padding(Padding::horizontal(1)),
+            ),
+            layout[0],
+        );
+        f.render_widget(
+            Paragraph::new(
+                "The report is copied to the clipboard with version, OS, terminal and recent logs, and a new GitHub issue is opened.",
+            )
+            .gray()
+            .wrap(Wrap { trim: true })
+            .block(Block::default().padding(Padding::horizontal(1))),
+            layout[1],
+        );
+        f.render_widget(self.textarea.widget(), layout[2]);
+        f.render_widget(submit, layout[3]);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn percent_encode_report() {
+        assert_eq!(percent_encode("a-Z_0.~"), "a-Z_0.~");
+        assert_eq!(percent_encode("a b\n&"), "a%20b%0A%26");
+        assert_eq!(percent_encode("é"), "%C3%A9");
+    }
+}
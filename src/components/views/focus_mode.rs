@@ -0,0 +1,218 @@
+use super::post::PostViewComponent;
+use super::types::{Action, Transition, View};
+use super::utils::{counts, format_timestamp, links, profile_name, profile_name_as_str};
+use super::ViewComponent;
+use crate::backend::types::FeedSourceInfo;
+use crate::config::TimestampFormat;
+use bsky_sdk::api::app::bsky::actor::defs::ProfileViewBasic;
+use bsky_sdk::api::app::bsky::embed::record_with_media::ViewMediaRefs;
+use bsky_sdk::api::app::bsky::feed::defs::{PostView, PostViewEmbedRefs};
+use bsky_sdk::api::records::{KnownRecord, Record};
+use bsky_sdk::api::types::Union;
+use color_eyre::Result;
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::layout::{Alignment, Constraint, Layout, Rect};
+use ratatui::style::{Color, Style, Stylize};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, List, ListItem, ListState, Padding, Paragraph, Wrap};
+use ratatui::Frame;
+
+#[derive(Debug, Clone)]
+enum FocusAction {
+    Profile(Box<ProfileViewBasic>),
+    Open(String),
+}
+
+impl<'a> From<&'a FocusAction> for ListItem<'a> {
+    fn from(action: &'a FocusAction) -> Self {
+        match action {
+            FocusAction::Profile(profile) => Self::from(Line::from(vec![
+                Span::from("Show "),
+                Span::from(profile_name_as_str(profile.as_ref())).bold(),
+                Span::from("'s posts"),
+            ])),
+            FocusAction::Open(uri) => Self::from(format!("Open {uri}")),
+        }
+    }
+}
+
+pub struct FocusModeViewComponent {
+    post_view: PostView,
+    actions: Vec<FocusAction>,
+    state: ListState,
+}
+
+impl FocusModeViewComponent {
+    pub fn new(post_view: PostView) -> Self {
+        let actions = [
+            vec![FocusAction::Profile(Box::new(post_view.author.clone()))],
+            links(&post_view)
+                .iter()
+                .map(|s| FocusAction::Open(s.to_string()))
+                .collect(),
+        ]
+        .concat();
+        Self {
+            post_view,
+            actions,
+            state: ListState::default(),
+        }
+    }
+    fn embed_lines(&self, width: u16) -> Vec<Line<'_>> {
+        let mut lines = Vec::new();
+        match &self.post_view.embed {
+            Some(Union::Refs(PostViewEmbedRefs::AppBskyEmbedImagesView(images))) => {
+                lines.extend(PostViewComponent::images_lines(images));
+            }
+            Some(Union::Refs(PostViewEmbedRefs::AppBskyEmbedExternalView(external))) => {
+                lines.extend(PostViewComponent::external_lines(external));
+            }
+            Some(Union::Refs(PostViewEmbedRefs::AppBskyEmbedRecordView(record))) => {
+                lines.extend(PostViewComponent::record_lines(record, width));
+            }
+            Some(Union::Refs(PostViewEmbedRefs::AppBskyEmbedRecordWithMediaView(
+                record_with_media,
+            ))) => {
+                match &record_with_media.media {
+                    Union::Refs(ViewMediaRefs::AppBskyEmbedImagesView(images)) => {
+                        lines.extend(PostViewComponent::images_lines(images));
+                    }
+                    Union::Refs(ViewMediaRefs::AppBskyEmbedExternalView(external)) => {
+                        lines.extend(PostViewComponent::external_lines(external));
+                    }
+                    _ => {}
+                }
+                lines.extend(PostViewComponent::record_lines(
+                    &record_with_media.record,
+                    width,
+                ));
+            }
+            _ => {}
+        }
+        lines
+    }
+}
+
+impl ViewComponent for FocusModeViewComponent {
+    fn view(&self) -> View {
+        View::FocusMode(Box::new(self.post_view.clone()))
+    }
+    fn handle_key_events(&mut self, key: KeyEvent) -> Result<Option<Action>> {
+        Ok(match key.code {
+            KeyCode::Esc => Some(Action::Back),
+            _ => None,
+        })
+    }
+    fn update(&mut self, action: Action) -> Result<Option<Action>> {
+        match action {
+            Action::NextItem => {
+                self.state.select(Some(
+                    self.state
+                        .selected()
+                        .map_or(0, |s| (s + 1) % self.actions.len()),
+                ));
+                return Ok(Some(Action::Render));
+            }
+            Action::PrevItem => {
+                self.state.select(Some(
+                    self.state
+                        .selected()
+                        .map_or(0, |s| (s + self.actions.len() - 1) % self.actions.len()),
+                ));
+                return Ok(Some(Action::Render));
+            }
+            Action::Enter => {
+                if let Some(action) = self.state.selected().and_then(|i| self.actions.get(i)) {
+                    match action {
+                        FocusAction::Open(uri) => {
+                            if let Err(e) = open::that(uri) {
+                                log::error!("failed to open: {e}");
+                            }
+                        }
+                        FocusAction::Profile(profile) => {
+                            return Ok(Some(Action::Transition(Transition::Push(Box::new(
+                                View::Feed(Box::new(FeedSourceInfo::Author(
+                                    profile.did.to_string(),
+                                ))),
+                            )))));
+                        }
+                    }
+                }
+            }
+            Action::Back | Action::FocusMode => {
+                return Ok(Some(Action::Transition(Transition::Pop)));
+            }
+            _ => {}
+        }
+        Ok(None)
+    }
+    fn draw(&mut self, f: &mut Frame<'_>, area: Rect) -> Result<()> {
+        let width = area.width.saturating_sub(2);
+        let mut lines = Vec::new();
+        if let Record::Known(KnownRecord::AppBskyFeedPost(record)) = &self.post_view.record {
+            lines.extend(record.text.lines().map(|s| Line::from(s.to_string())));
+        }
+        let embed_lines = self.embed_lines(width);
+        if !embed_lines.is_empty() {
+            lines.push(Line::default());
+            lines.extend(embed_lines);
+        }
+
+        let layout = Layout::vertical([
+            Constraint::Length(2),
+            Constraint::Percentage(100),
+            Constraint::Length(1),
+            Constraint::Length(self.actions.len() as u16 + 1),
+        ])
+        .split(area);
+        f.render_widget(
+            Paragraph::new(Line::from(
+                [
+                    profile_name(&self.post_view.author),
+                    vec![
+                        Span::from(" "),
+                        Span::from(format_timestamp(
+                            self.post_view.indexed_at.as_ref(),
+                            TimestampFormat::FullDatetime,
+                        ))
+                        .green(),
+                    ],
+                ]
+                .concat(),
+            ))
+            .block(
+                Block::default()
+                    .borders(Borders::BOTTOM)
+                    .border_style(Color::Gray)
+                    .padding(Padding::horizontal(1)),
+            ),
+            layout[0],
+        );
+        f.render_widget(
+            Paragraph::new(lines)
+                .wrap(Wrap { trim: false })
+                .block(Block::default().padding(Padding::uniform(1))),
+            layout[1],
+        );
+        f.render_widget(
+            Paragraph::new(Line::from(counts(&self.post_view, 0)))
+                .block(Block::default().padding(Padding::horizontal(1))),
+            layout[2],
+        );
+        f.render_stateful_widget(
+            List::new(&self.actions)
+                .highlight_style(Style::default().reversed())
+                .block(
+                    Block::default()
+                        .title("Actions")
+                        .title_alignment(Alignment::Center)
+                        .borders(Borders::TOP)
+                        .border_style(Color::Gray)
+                        .padding(Padding::horizontal(1)),
+                ),
+            layout[3],
+            &mut self.state,
+        );
+        Ok(())
+    }
+}
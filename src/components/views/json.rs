@@ -0,0 +1,63 @@
+use super::types::{Action, Transition, View};
+use super::ViewComponent;
+use color_eyre::Result;
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::style::{Color, Stylize};
+use ratatui::widgets::{Block, Borders, Padding, Paragraph};
+use ratatui::Frame;
+
+/// The raw record of a post as stored in the PDS, in pretty-printed JSON.
+pub struct JsonViewComponent {
+    uri: String,
+    json: String,
+    scroll: u16,
+}
+
+impl JsonViewComponent {
+    pub fn new(uri: String, json: String) -> Self {
+        Self {
+            uri,
+            json,
+            scroll: 0,
+        }
+    }
+}
+
+impl ViewComponent for JsonViewComponent {
+    fn view(&self) -> View {
+        View::RawRecord(Box::new((self.uri.clone(), self.json.clone())))
+    }
+    fn update(&mut self, action: Action) -> Result<Option<Action>> {
+        Ok(match action {
+            Action::NextItem => {
+                let max = u16::try_from(self.json.lines().count()).unwrap_or(u16::MAX);
+                self.scroll = (self.scroll + 1).min(max.saturating_sub(1));
+                Some(Action::Render)
+            }
+            Action::PrevItem => {
+                self.scroll = self.scroll.saturating_sub(1);
+                Some(Action::Render)
+            }
+            Action::Back | Action::RawRecord => Some(Action::Transition(Transition::Pop)),
+            _ => None,
+        })
+    }
+    fn draw(&mut self, f: &mut Frame<'_>, area: Rect) -> Result<()> {
+        let header = Paragraph::new(self.uri.as_str()).bold().block(
+            Block::default()
+                .borders(Borders::BOTTOM)
+                .border_style(Color::Gray)
+                .padding(Padding::horizontal(1)),
+        );
+        let layout =
+            Layout::vertical([Constraint::Length(2), Constraint::Percentage(100)]).split(area);
+        f.render_widget(header, layout[0]);
+        f.render_widget(
+            Paragraph::new(self.json.as_str())
+                .scroll((self.scroll, 0))
+                .block(Block::default().padding(Padding::horizontal(1))),
+            layout[1],
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,100 @@
+use super::types::{Action, Transition, View};
+use super::utils::profile_name;
+use super::ViewComponent;
+use bsky_sdk::api::app::bsky::actor::defs::{ProfileView, ProfileViewBasic};
+use color_eyre::Result;
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::style::{Color, Style, Stylize};
+use ratatui::text::{Line, Span, Text};
+use ratatui::widgets::{Block, Borders, List, ListState, Padding, Paragraph};
+use ratatui::Frame;
+
+/// The followers of an account who are followed by the user.
+pub struct KnownFollowersViewComponent {
+    profile: ProfileViewBasic,
+    items: Vec<ProfileView>,
+    state: ListState,
+}
+
+impl KnownFollowersViewComponent {
+    pub fn new(profile: ProfileViewBasic, items: Vec<ProfileView>) -> Self {
+        let mut state = ListState::default();
+        if !items.is_empty() {
+            state.select(Some(0));
+        }
+        Self {
+            profile,
+            items,
+            state,
+        }
+    }
+}
+
+impl ViewComponent for KnownFollowersViewComponent {
+    fn view(&self) -> View {
+        View::KnownFollowers(Box::new((self.profile.clone(), self.items.clone())))
+    }
+    fn update(&mut self, action: Action) -> Result<Option<Action>> {
+        Ok(match action {
+            Action::NextItem if !self.items.is_empty() => {
+                self.state.select(Some(
+                    self.state
+                        .selected()
+                        .map(|s| (s + 1).min(self.items.len() - 1))
+                        .unwrap_or_default(),
+                ));
+                Some(Action::Render)
+            }
+            Action::PrevItem if !self.items.is_empty() => {
+                self.state.select(Some(
+                    self.state
+                        .selected()
+                        .map(|s| s.max(1) - 1)
+                        .unwrap_or_default(),
+                ));
+                Some(Action::Render)
+            }
+            Action::Back => Some(Action::Transition(Transition::Pop)),
+            _ => None,
+        })
+    }
+    fn draw(&mut self, f: &mut Frame<'_>, area: Rect) -> Result<()> {
+        let header = Paragraph::new(Line::from(
+            [
+                vec![Span::from("Followers you know of ")],
+                profile_name(&self.profile),
+            ]
+            .concat(),
+        ))
+        .bold()
+        .block(
+            Block::default()
+                .borders(Borders::BOTTOM)
+                .border_style(Color::Gray)
+                .padding(Padding::horizontal(1)),
+        );
+        let items = self
+            .items
+            .iter()
+            .map(|profile| {
+                let mut lines = vec![Line::from(profile_name(profile))];
+                if let Some(description) = profile.description.as_ref().filter(|s| !s.is_empty()) {
+                    lines.push(Line::from(format!("  {}", description.replace('\n', " "))).dim());
+                }
+                Text::from(lines)
+            })
+            .collect::<Vec<_>>();
+
+        let layout =
+            Layout::vertical([Constraint::Length(2), Constraint::Percentage(100)]).split(area);
+        f.render_widget(header, layout[0]);
+        f.render_stateful_widget(
+            List::new(items)
+                .highlight_style(Style::default().reset().reversed())
+                .block(Block::default().padding(Padding::horizontal(1))),
+            layout[1],
+            &mut self.state,
+        );
+        Ok(())
+    }
+}
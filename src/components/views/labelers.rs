@@ -0,0 +1,221 @@
+use super::types::{Action, Data, Transition, View};
+use super::utils::profile_name;
+use super::ViewComponent;
+use crate::backend::preferences::PreferencesQueue;
+use crate::backend::types::LabelerSettings;
+use crate::backend::{Watch, Watcher};
+use color_eyre::Result;
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::style::{Color, Style, Stylize};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, List, ListState, Padding, Paragraph};
+use ratatui::Frame;
+use std::sync::Arc;
+use tokio::sync::mpsc::UnboundedSender;
+use tokio::sync::oneshot;
+
+const VISIBILITIES: [&str; 3] = ["ignore", "warn", "hide"];
+
+pub struct LabelersViewComponent {
+    labelers: Vec<LabelerSettings>,
+    // (labeler index, policy index) of each row, with `None` for the labeler itself
+    rows: Vec<(usize, Option<usize>)>,
+    state: ListState,
+    action_tx: UnboundedSender<Action>,
+    preferences_queue: PreferencesQueue,
+    watcher: Box<dyn Watch<Output = Vec<LabelerSettings>>>,
+    quit: Option<oneshot::Sender<()>>,
+}
+
+impl LabelersViewComponent {
+    pub fn new(action_tx: UnboundedSender<Action>, watcher: Arc<Watcher>) -> Self {
+        let preferences_queue = watcher.preferences_queue.clone();
+        let watcher = Box::new(watcher.labelers());
+        Self {
+            labelers: Vec::new(),
+            rows: Vec::new(),
+            state: ListState::default(),
+            action_tx,
+            preferences_queue,
+            watcher,
+            quit: None,
+        }
+    }
+    /// Move the visibility of the selected policy by `delta` steps in [`VISIBILITIES`].
+    fn change_visibility(&mut self, delta: isize) -> Option<Action> {
+        let (i, Some(j)) = *self.state.selected().and_then(|s| self.rows.get(s))? else {
+            return None;
+        };
+        let labeler = &mut self.labelers[i];
+        let policy = &mut labeler.policies[j];
+        let current = VISIBILITIES
+            .iter()
+            .position(|v| *v == policy.visibility)
+            .unwrap_or(1);
+        let next = current
+            .saturating_add_signed(delta)
+            .min(VISIBILITIES.len() - 1);
+        if next == current {
+            return None;
+        }
+        policy.visibility = VISIBILITIES[next].to_string();
+        let (queue, tx) = (self.preferences_queue.clone(), self.action_tx.clone());
+        let (did, label, visibility) = (
+            labeler.view.creator.did.clone(),
+            policy.identifier.clone(),
+            policy.visibility.clone(),
+        );
+        tokio::spawn(async move {
+            match queue.set_label_preference(&did, &label, &visibility).await {
+                Ok(()) => {
+                    log::info!("set label preference: {label} = {visibility}");
+                }
+                Err(e) => {
+                    log::error!("failed to set label preference: {e}");
+                    // restore the actual state
+                    tx.send(Action::Refresh).ok();
+                }
+            }
+        });
+        Some(Action::Render)
+    }
+}
+
+impl ViewComponent for LabelersViewComponent {
+    fn view(&self) -> View {
+        View::Labelers
+    }
+    fn activate(&mut self) -> Result<()> {
+        let (tx, mut rx) = (self.action_tx.clone(), self.watcher.subscribe());
+        let (quit_tx, mut quit_rx) = oneshot::channel();
+        self.quit = Some(quit_tx);
+        tokio::spawn(async move {
+            loop {
+                tokio::select! {
+                    changed = rx.changed() => {
+                        if changed.is_ok() {
+                            if let Err(e) = tx.send(Action::Update(Box::new(Data::Labelers(
+                                rx.borrow_and_update().clone(),
+                            )))) {
+                                log::error!("failed to send update action: {e}");
+                            }
+                        } else {
+                            break log::warn!("labelers channel closed");
+                        }
+                    }
+                    _ = &mut quit_rx => {
+                        break;
+                    }
+                }
+            }
+            log::debug!("subscription finished");
+        });
+        Ok(())
+    }
+    fn deactivate(&mut self) -> Result<()> {
+        if let Some(tx) = self.quit.take() {
+            if tx.send(()).is_err() {
+                log::error!("failed to send quit signal");
+            }
+        }
+        self.watcher.unsubscribe();
+        Ok(())
+    }
+    fn update(&mut self, action: Action) -> Result<Option<Action>> {
+        match action {
+            Action::NextItem if !self.rows.is_empty() => {
+                self.state.select(Some(
+                    self.state
+                        .selected()
+                        .map(|s| (s + 1).min(self.rows.len() - 1))
+                        .unwrap_or_default(),
+                ));
+                return Ok(Some(Action::Render));
+            }
+            Action::PrevItem if !self.rows.is_empty() => {
+                self.state.select(Some(
+                    self.state
+                        .selected()
+                        .map(|s| s.max(1) - 1)
+                        .unwrap_or_default(),
+                ));
+                return Ok(Some(Action::Render));
+            }
+            Action::Increase => return Ok(self.change_visibility(1)),
+            Action::Decrease => return Ok(self.change_visibility(-1)),
+            Action::Back => return Ok(Some(Action::Transition(Transition::Pop))),
+            Action::Refresh => {
+                self.watcher.refresh();
+            }
+            Action::Update(data) => {
+                let Data::Labelers(labelers) = data.as_ref() else {
+                    return Ok(None);
+                };
+                self.labelers.clone_from(labelers);
+                self.rows = self
+                    .labelers
+                    .iter()
+                    .enumerate()
+                    .flat_map(|(i, labeler)| {
+                        [(i, None)]
+                            .into_iter()
+                            .chain((0..labeler.policies.len()).map(move |j| (i, Some(j))))
+                    })
+                    .collect();
+                self.state.select(match self.state.selected() {
+                    _ if self.rows.is_empty() => None,
+                    Some(s) => Some(s.min(self.rows.len() - 1)),
+                    None => Some(0),
+                });
+                return Ok(Some(Action::Render));
+            }
+            _ => {}
+        }
+        Ok(None)
+    }
+    fn draw(&mut self, f: &mut Frame<'_>, area: Rect) -> Result<()> {
+        let header = Paragraph::new(Line::from(vec![
+            Span::from("Labelers").bold(),
+            Span::from(" (+/- to change)").gray(),
+        ]))
+        .block(
+            Block::default()
+                .borders(Borders::BOTTOM)
+                .border_style(Color::Gray)
+                .padding(Padding::horizontal(1)),
+        );
+        let items = self
+            .rows
+            .iter()
+            .map(|(i, j)| {
+                let labeler = &self.labelers[*i];
+                let Some(policy) = j.map(|j| &labeler.policies[j]) else {
+                    return Line::from(profile_name(&labeler.view.creator));
+                };
+                let visibility = match policy.visibility.as_str() {
+                    "hide" => Span::from("hide").red(),
+                    "warn" => Span::from("warn").yellow(),
+                    s => Span::from(s.to_string()).dim(),
+                };
+                let mut spans = vec![Span::from("  "), Span::from(policy.name.as_str())];
+                if !policy.severity.is_empty() {
+                    spans.push(Span::from(format!(" ({})", policy.severity)).gray());
+                }
+                spans.extend([Span::from(": "), visibility]);
+                Line::from(spans)
+            })
+            .collect::<Vec<_>>();
+
+        let layout =
+            Layout::vertical([Constraint::Length(2), Constraint::Percentage(100)]).split(area);
+        f.render_widget(header, layout[0]);
+        f.render_stateful_widget(
+            List::new(items)
+                .highlight_style(Style::default().reset().reversed())
+                .block(Block::default().padding(Padding::horizontal(1))),
+            layout[1],
+            &mut self.state,
+        );
+        Ok(())
+    }
+}
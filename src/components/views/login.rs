@@ -1,6 +1,7 @@
 use super::types::{Action, View};
 use super::ViewComponent;
-use bsky_sdk::BskyAgent;
+use crate::backend::client::agent_builder;
+use crate::backend::rate_limit::RateLimit;
 use color_eyre::Result;
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use ratatui::layout::{Constraint, Layout, Rect};
@@ -42,10 +43,16 @@ pub struct LoginComponent {
     focus: Focus,
     error_message: Arc<RwLock<Option<String>>>,
     action_tx: UnboundedSender<Action>,
+    proxy: Option<String>,
+    rate_limit: RateLimit,
 }
 
 impl LoginComponent {
-    pub fn new(action_tx: UnboundedSender<Action>) -> Self {
+    pub fn new(
+        action_tx: UnboundedSender<Action>,
+        proxy: Option<String>,
+        rate_limit: RateLimit,
+    ) -> Self {
         let mut identifier = TextArea::default();
         identifier.set_block(
             Block::bordered()
@@ -68,6 +75,8 @@ impl LoginComponent {
             focus: Focus::Identifier,
             error_message: Arc::new(RwLock::new(None)),
             action_tx,
+            proxy,
+            rate_limit,
         }
     }
     fn current_textarea(&mut self) -> Option<&mut TextArea<'static>> {
@@ -97,8 +106,9 @@ impl LoginComponent {
         let password = self.password.lines().join("");
         let error_message = Arc::clone(&self.error_message);
         let action_tx = self.action_tx.clone();
+        let builder = agent_builder(self.proxy.as_deref(), self.rate_limit.clone())?;
         tokio::spawn(async move {
-            let Ok(agent) = BskyAgent::builder().build().await else {
+            let Ok(agent) = builder.build().await else {
                 return log::error!("failed to build agent");
             };
             match agent.login(identifier, password).await {
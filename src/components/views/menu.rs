@@ -1,4 +1,4 @@
-use super::types::Action;
+use super::types::{Action, Transition, View};
 use crate::config::{ColumnAction, Key, Keybindings};
 use color_eyre::Result;
 use ratatui::layout::Rect;
@@ -12,6 +12,11 @@ enum MenuAction {
     NewPost(Vec<String>),
     Refresh(Vec<String>),
     Back(Vec<String>),
+    BlockedLists,
+    Labelers,
+    MutedWords,
+    Suggestions,
+    PopularFeeds,
 }
 
 impl<'a> From<&'a MenuAction> for ListItem<'a> {
@@ -32,6 +37,11 @@ impl<'a> From<&'a MenuAction> for ListItem<'a> {
                 Span::from(format!("({})", v.join(", "))).dim(),
             ])),
             MenuAction::Back(_) => Self::from("Back".reset()),
+            MenuAction::BlockedLists => Self::from("Moderation: Blocked lists".reset()),
+            MenuAction::Labelers => Self::from("Moderation: Labelers".reset()),
+            MenuAction::MutedWords => Self::from("Moderation: Muted words".reset()),
+            MenuAction::Suggestions => Self::from("Discover: Who to follow".reset()),
+            MenuAction::PopularFeeds => Self::from("Discover: Popular feeds".reset()),
         }
     }
 }
@@ -66,6 +76,11 @@ impl MenuViewComponent {
                 MenuAction::NewPost(to_string(&keys[0])),
                 MenuAction::Refresh(to_string(&keys[1])),
                 MenuAction::Back(to_string(&keys[2])),
+                MenuAction::BlockedLists,
+                MenuAction::Labelers,
+                MenuAction::MutedWords,
+                MenuAction::Suggestions,
+                MenuAction::PopularFeeds,
             ],
             state: ListState::default().with_selected(Some(0)),
         }
@@ -91,6 +106,21 @@ impl MenuViewComponent {
                         MenuAction::NewPost(_) => Action::NewPost,
                         MenuAction::Refresh(_) => Action::Refresh,
                         MenuAction::Back(_) => Action::Back,
+                        MenuAction::BlockedLists => {
+                            Action::Transition(Transition::Push(Box::new(View::BlockedLists)))
+                        }
+                        MenuAction::Labelers => {
+                            Action::Transition(Transition::Push(Box::new(View::Labelers)))
+                        }
+                        MenuAction::MutedWords => {
+                            Action::Transition(Transition::Push(Box::new(View::MutedWords)))
+                        }
+                        MenuAction::Suggestions => {
+                            Action::Transition(Transition::Push(Box::new(View::Suggestions)))
+                        }
+                        MenuAction::PopularFeeds => {
+                            Action::Transition(Transition::Push(Box::new(View::PopularFeeds)))
+                        }
                     };
                     self.action_tx.send(action).ok();
                     return Ok(Some(Action::Menu));
@@ -0,0 +1,426 @@
+use super::types::{Action, Data, Transition, View};
+use super::ViewComponent;
+use crate::backend::muted_words::{excludes_following, expires_at, muted_word, targets, Targets};
+use crate::backend::preferences::PreferencesQueue;
+use crate::backend::{Watch, Watcher};
+use crate::components::widgets::TextInput;
+use bsky_sdk::api::app::bsky::actor::defs::MutedWord;
+use bsky_sdk::preference::Preferences;
+use chrono::{DateTime, FixedOffset, TimeDelta, Utc};
+use color_eyre::Result;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::style::{Color, Style, Stylize};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, List, ListState, Padding, Paragraph};
+use ratatui::Frame;
+use std::sync::Arc;
+use tokio::sync::mpsc::UnboundedSender;
+use tokio::sync::oneshot;
+
+/// The durations of the temporary mutes, in days.
+const EXPIRIES: [Option<i64>; 4] = [None, Some(1), Some(7), Some(30)];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Field {
+    Word,
+    Targets,
+    Actors,
+    Expiry,
+}
+
+impl Field {
+    fn next(&self) -> Self {
+        match self {
+            Self::Word => Self::Targets,
+            Self::Targets => Self::Actors,
+            Self::Actors => Self::Expiry,
+            Self::Expiry => Self::Word,
+        }
+    }
+    fn prev(&self) -> Self {
+        match self {
+            Self::Word => Self::Expiry,
+            Self::Targets => Self::Word,
+            Self::Actors => Self::Targets,
+            Self::Expiry => Self::Actors,
+        }
+    }
+}
+
+/// The form to add a muted word.
+struct NewWord {
+    input: TextInput,
+    targets: Targets,
+    exclude_following: bool,
+    // index of `EXPIRIES`
+    expiry: usize,
+    focus: Field,
+}
+
+impl NewWord {
+    fn new() -> Self {
+        let mut input = TextInput::default();
+        input.set_block(Block::bordered().title("Word or #tag"));
+        input.set_cursor_style(Style::default().reversed());
+        Self {
+            input,
+            targets: Targets::default(),
+            exclude_following: false,
+            expiry: 0,
+            focus: Field::Word,
+        }
+    }
+    fn update_focus(&mut self, focus: Field) {
+        self.focus = focus;
+        let focused = self.focus == Field::Word;
+        self.input.set_cursor_style(if focused {
+            Style::default().reversed()
+        } else {
+            Style::default()
+        });
+        if let Some(block) = self.input.block().cloned() {
+            self.input
+                .set_block(if focused { block.reset() } else { block.dim() });
+        }
+    }
+    fn word(&self) -> Option<MutedWord> {
+        let value = self.input.value();
+        let expires_at = EXPIRIES[self.expiry]
+            .and_then(TimeDelta::try_days)
+            .and_then(|delta| Utc::now().checked_add_signed(delta));
+        (!value.trim().is_empty())
+            .then(|| muted_word(&value, self.targets, self.exclude_following, expires_at))
+    }
+}
+
+/// The muted words of the user, edited locally and saved together.
+pub struct MutedWordsViewComponent {
+    words: Vec<MutedWord>,
+    // changed since the last save, so the updates of the preferences are not applied
+    dirty: bool,
+    new_word: Option<NewWord>,
+    state: ListState,
+    action_tx: UnboundedSender<Action>,
+    preferences_queue: PreferencesQueue,
+    watcher: Box<dyn Watch<Output = Preferences>>,
+    quit: Option<oneshot::Sender<()>>,
+}
+
+impl MutedWordsViewComponent {
+    pub fn new(action_tx: UnboundedSender<Action>, watcher: Arc<Watcher>) -> Self {
+        let preferences_queue = watcher.preferences_queue.clone();
+        let watcher = Box::new(watcher.preferences());
+        Self {
+            words: Vec::new(),
+            dirty: false,
+            new_word: None,
+            state: ListState::default(),
+            action_tx,
+            preferences_queue,
+            watcher,
+            quit: None,
+        }
+    }
+    fn delete(&mut self) -> Option<Action> {
+        let index = self.state.selected().filter(|&i| i < self.words.len())?;
+        self.words.remove(index);
+        self.dirty = true;
+        self.state.select(if self.words.is_empty() {
+            None
+        } else {
+            Some(index.min(self.words.len() - 1))
+        });
+        Some(Action::Render)
+    }
+    fn save(&mut self) -> Option<Action> {
+        if !self.dirty {
+            return None;
+        }
+        self.dirty = false;
+        let (queue, tx) = (self.preferences_queue.clone(), self.action_tx.clone());
+        let words = self.words.clone();
+        tokio::spawn(async move {
+            match queue.set_muted_words(words).await {
+                Ok(()) => log::info!("saved muted words"),
+                Err(e) => log::error!("failed to save muted words: {e}"),
+            }
+            // show the saved state, or restore the actual state if failed
+            tx.send(Action::Refresh).ok();
+        });
+        Some(Action::Render)
+    }
+    fn handle_form_key(&mut self, key: KeyEvent) -> Option<Action> {
+        let new_word = self.new_word.as_mut()?;
+        match (key.code, new_word.focus) {
+            (KeyCode::Esc, _) => self.new_word = None,
+            (KeyCode::Enter, _) => {
+                if let Some(word) = new_word.word() {
+                    self.words.push(word);
+                    self.dirty = true;
+                    self.state.select(Some(self.words.len() - 1));
+                    self.new_word = None;
+                }
+            }
+            (KeyCode::Tab | KeyCode::Down, _) => new_word.update_focus(new_word.focus.next()),
+            (KeyCode::BackTab | KeyCode::Up, _) => new_word.update_focus(new_word.focus.prev()),
+            (KeyCode::Left | KeyCode::Right | KeyCode::Char(' '), Field::Targets) => {
+                new_word.targets = new_word.targets.next();
+            }
+            (KeyCode::Left | KeyCode::Right | KeyCode::Char(' '), Field::Actors) => {
+                new_word.exclude_following = !new_word.exclude_following;
+            }
+            (KeyCode::Left, Field::Expiry) => {
+                new_word.expiry = (new_word.expiry + EXPIRIES.len() - 1) % EXPIRIES.len();
+            }
+            (KeyCode::Right | KeyCode::Char(' '), Field::Expiry) => {
+                new_word.expiry = (new_word.expiry + 1) % EXPIRIES.len();
+            }
+            (_, Field::Word) => {
+                new_word.input.input(key);
+            }
+            _ => {}
+        }
+        // all the keys are consumed while the form is open
+        Some(Action::Render)
+    }
+    fn draw_form(new_word: &NewWord, f: &mut Frame<'_>, area: Rect) {
+        let layout = Layout::vertical([
+            Constraint::Length(3),
+            Constraint::Length(1),
+            Constraint::Length(1),
+            Constraint::Length(1),
+            Constraint::Length(1),
+        ])
+        .split(area);
+        f.render_widget(&new_word.input, layout[0]);
+        let option = |field: Field, label: &'static str, value: String| {
+            let value = Span::from(value);
+            Line::from(vec![
+                Span::from(label),
+                if new_word.focus == field {
+                    value.reversed()
+                } else {
+                    value
+                },
+            ])
+        };
+        let expiry = match EXPIRIES[new_word.expiry] {
+            None => String::from("forever"),
+            Some(1) => String::from("24 hours"),
+            Some(days) => format!("{days} days"),
+        };
+        let lines = [
+            option(Field::Targets, "Mute in: ", new_word.targets.label().into()),
+            option(
+                Field::Actors,
+                "Mute for: ",
+                String::from(if new_word.exclude_following {
+                    "users I don't follow"
+                } else {
+                    "all users"
+                }),
+            ),
+            option(Field::Expiry, "Duration: ", expiry),
+            Line::from("Enter to add, Esc to cancel").dim(),
+        ];
+        for (line, area) in lines.into_iter().zip(layout.iter().skip(1)) {
+            f.render_widget(
+                Paragraph::new(line).block(Block::default().padding(Padding::horizontal(1))),
+                *area,
+            );
+        }
+    }
+}
+
+/// The remaining time of a temporary mute, e.g. `expires in 3 d`.
+fn remaining(expires_at: DateTime<FixedOffset>) -> String {
+    let seconds = (expires_at.with_timezone(&Utc) - Utc::now()).num_seconds();
+    if seconds <= 0 {
+        String::from("expired")
+    } else if seconds < 60 * 60 {
+        format!("expires in {} m", seconds / 60 + 1)
+    } else if seconds < 24 * 60 * 60 {
+        format!("expires in {} h", seconds / (60 * 60))
+    } else {
+        format!("expires in {} d", seconds / (24 * 60 * 60))
+    }
+}
+
+impl ViewComponent for MutedWordsViewComponent {
+    fn view(&self) -> View {
+        View::MutedWords
+    }
+    fn activate(&mut self) -> Result<()> {
+        let (tx, mut rx) = (self.action_tx.clone(), self.watcher.subscribe());
+        let (quit_tx, mut quit_rx) = oneshot::channel();
+        self.quit = Some(quit_tx);
+        tokio::spawn(async move {
+            loop {
+                tokio::select! {
+                    changed = rx.changed() => {
+                        if changed.is_ok() {
+                            let words = rx.borrow_and_update().moderation_prefs.muted_words.clone();
+                            if let Err(e) = tx.send(Action::Update(Box::new(Data::MutedWords(words)))) {
+                                log::error!("failed to send update action: {e}");
+                            }
+                        } else {
+                            break log::warn!("preferences channel closed");
+                        }
+                    }
+                    _ = &mut quit_rx => {
+                        break;
+                    }
+                }
+            }
+            log::debug!("subscription finished");
+        });
+        Ok(())
+    }
+    fn deactivate(&mut self) -> Result<()> {
+        if let Some(tx) = self.quit.take() {
+            if tx.send(()).is_err() {
+                log::error!("failed to send quit signal");
+            }
+        }
+        self.watcher.unsubscribe();
+        Ok(())
+    }
+    fn handle_key_events(&mut self, key: KeyEvent) -> Result<Option<Action>> {
+        if self.new_word.is_some() {
+            return Ok(self.handle_form_key(key));
+        }
+        if !matches!(key.modifiers, KeyModifiers::NONE) {
+            return Ok(None);
+        }
+        Ok(match key.code {
+            KeyCode::Char('a') => {
+                self.new_word = Some(NewWord::new());
+                Some(Action::Render)
+            }
+            KeyCode::Char('d') | KeyCode::Delete => self.delete(),
+            KeyCode::Char('s') => self.save(),
+            _ => None,
+        })
+    }
+    fn update(&mut self, action: Action) -> Result<Option<Action>> {
+        match action {
+            Action::NextItem if !self.words.is_empty() => {
+                self.state.select(Some(
+                    self.state
+                        .selected()
+                        .map(|s| (s + 1).min(self.words.len() - 1))
+                        .unwrap_or_default(),
+                ));
+                return Ok(Some(Action::Render));
+            }
+            Action::PrevItem if !self.words.is_empty() => {
+                self.state.select(Some(
+                    self.state
+                        .selected()
+                        .map(|s| s.max(1) - 1)
+                        .unwrap_or_default(),
+                ));
+                return Ok(Some(Action::Render));
+            }
+            Action::Back => return Ok(Some(Action::Transition(Transition::Pop))),
+            Action::Refresh => {
+                self.dirty = false;
+                self.watcher.refresh();
+            }
+            Action::Update(data) => {
+                let Data::MutedWords(words) = data.as_ref() else {
+                    return Ok(None);
+                };
+                if self.dirty {
+                    return Ok(None);
+                }
+                self.words.clone_from(words);
+                self.state.select(match self.state.selected() {
+                    _ if self.words.is_empty() => None,
+                    Some(s) => Some(s.min(self.words.len() - 1)),
+                    None => Some(0),
+                });
+                return Ok(Some(Action::Render));
+            }
+            _ => {}
+        }
+        Ok(None)
+    }
+    fn draw(&mut self, f: &mut Frame<'_>, area: Rect) -> Result<()> {
+        let mut title = vec![Span::from("Muted words").bold()];
+        title.push(if self.dirty {
+            Span::from(" (unsaved, s to save)").yellow()
+        } else {
+            Span::from(" (a: add, d: delete)").gray()
+        });
+        let header = Paragraph::new(Line::from(title)).block(
+            Block::default()
+                .borders(Borders::BOTTOM)
+                .border_style(Color::Gray)
+                .padding(Padding::horizontal(1)),
+        );
+        let items = self
+            .words
+            .iter()
+            .map(|word| {
+                let mut details = vec![targets(word).label().to_string()];
+                if excludes_following(word) {
+                    details.push(String::from("except follows"));
+                }
+                if let Some(expires_at) = expires_at(word) {
+                    details.push(remaining(expires_at));
+                }
+                Line::from(vec![
+                    Span::from(word.value.as_str()),
+                    Span::from(format!("  {}", details.join(" · "))).gray(),
+                ])
+            })
+            .collect::<Vec<_>>();
+
+        let layout = Layout::vertical([
+            Constraint::Length(2),
+            Constraint::Percentage(100),
+            Constraint::Length(if self.new_word.is_some() { 8 } else { 0 }),
+        ])
+        .split(area);
+        f.render_widget(header, layout[0]);
+        if items.is_empty() {
+            f.render_widget(
+                Paragraph::new("No muted words")
+                    .dim()
+                    .block(Block::default().padding(Padding::horizontal(1))),
+                layout[1],
+            );
+        } else {
+            f.render_stateful_widget(
+                List::new(items)
+                    .highlight_style(Style::default().reset().reversed())
+                    .block(Block::default().padding(Padding::horizontal(1))),
+                layout[1],
+                &mut self.state,
+            );
+        }
+        if let Some(new_word) = &self.new_word {
+            Self::draw_form(new_word, f, layout[2]);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn remaining_time() {
+        let after = |delta: TimeDelta| remaining((Utc::now() + delta).fixed_offset());
+        assert_eq!(after(-TimeDelta::minutes(1)), "expired");
+        assert_eq!(after(TimeDelta::seconds(90)), "expires in 2 m");
+        assert_eq!(after(TimeDelta::minutes(150)), "expires in 2 h");
+        assert_eq!(
+            after(TimeDelta::days(3) + TimeDelta::hours(1)),
+            "expires in 3 d"
+        );
+    }
+}
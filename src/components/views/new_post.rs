@@ -1,9 +1,18 @@
 use super::types::{Action, Transition, View};
+use super::utils::profile_name_as_str;
 use super::ViewComponent;
+use crate::backend::client::BskyAgent;
+use crate::backend::video::upload_video;
+use crate::components::store::LocalStore;
+use crate::components::widgets::TextInput;
+use bsky_sdk::api;
+use bsky_sdk::api::app::bsky::feed::defs::PostView;
+use bsky_sdk::api::app::bsky::feed::post::{RecordEmbedRefs, ReplyRefData};
+use bsky_sdk::api::records::{KnownRecord, Record};
 use bsky_sdk::api::types::string::Datetime;
 use bsky_sdk::api::types::string::Language;
+use bsky_sdk::api::types::Union;
 use bsky_sdk::rich_text::RichText;
-use bsky_sdk::BskyAgent;
 use color_eyre::Result;
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use ratatui::layout::{Constraint, Layout};
@@ -11,7 +20,9 @@ use ratatui::style::{Color, Style, Stylize};
 use ratatui::text::Line;
 use ratatui::widgets::{Block, Borders, Padding};
 use ratatui::{layout::Rect, widgets::Paragraph, Frame};
-use std::sync::Arc;
+use reqwest::Client;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
 use tokio::sync::mpsc::UnboundedSender;
 use tui_textarea::TextArea;
 
@@ -19,6 +30,7 @@ use tui_textarea::TextArea;
 enum Focus {
     Text,
     Langs,
+    Video,
     Submit,
 }
 
@@ -26,7 +38,8 @@ impl Focus {
     fn next(&self) -> Self {
         match self {
             Self::Text => Self::Langs,
-            Self::Langs => Self::Submit,
+            Self::Langs => Self::Video,
+            Self::Video => Self::Submit,
             Self::Submit => Self::Text,
         }
     }
@@ -34,72 +47,208 @@ impl Focus {
         match self {
             Self::Text => Self::Submit,
             Self::Langs => Self::Text,
-            Self::Submit => Self::Langs,
+            Self::Video => Self::Langs,
+            Self::Submit => Self::Video,
         }
     }
 }
 
+/// The post which the new post replies to or quotes.
+#[derive(Debug, Clone)]
+pub enum PostContext {
+    Reply(Box<PostView>),
+    Quote(Box<PostView>),
+}
+
+fn strong_ref(post_view: &PostView) -> api::com::atproto::repo::strong_ref::Main {
+    api::com::atproto::repo::strong_ref::MainData {
+        cid: post_view.cid.clone(),
+        uri: post_view.uri.clone(),
+    }
+    .into()
+}
+
+/// The reference of a reply to the post, in the same thread as the post.
+fn reply_ref(post_view: &PostView) -> ReplyRefData {
+    let root = match &post_view.record {
+        Record::Known(KnownRecord::AppBskyFeedPost(record)) => {
+            record.reply.as_ref().map(|reply| reply.root.clone())
+        }
+        _ => None,
+    };
+    ReplyRefData {
+        parent: strong_ref(post_view),
+        root: root.unwrap_or_else(|| strong_ref(post_view)),
+    }
+}
+
+#[derive(Debug, Clone)]
+enum VideoState {
+    Uploading,
+    Processing(u8),
+    Attached(Union<RecordEmbedRefs>),
+    Failed(String),
+}
+
 pub struct NewPostViewComponent {
     action_tx: UnboundedSender<Action>,
     agent: Arc<BskyAgent>,
+    client: Client,
     textarea: TextArea<'static>,
-    langs: TextArea<'static>,
+    langs: TextInput,
+    video: TextInput,
+    // updated by the upload task
+    video_state: Arc<Mutex<Option<VideoState>>>,
+    max_video_mb: u64,
+    store: Arc<LocalStore>,
     focus: Focus,
     text_len: usize,
+    context: Option<PostContext>,
 }
 
 impl NewPostViewComponent {
-    pub fn new(action_tx: UnboundedSender<Action>, agent: Arc<BskyAgent>) -> Self {
+    pub fn new(
+        action_tx: UnboundedSender<Action>,
+        agent: Arc<BskyAgent>,
+        client: Client,
+        max_video_mb: u64,
+        store: Arc<LocalStore>,
+        context: Option<PostContext>,
+    ) -> Self {
         let mut textarea = TextArea::default();
         textarea.set_block(Block::bordered().title("Text"));
         textarea.set_cursor_line_style(Style::default());
-        let mut langs = TextArea::default();
+        // shared by all columns, so that the langs entered once can be recalled in any new post
+        let mut langs = TextInput::with_history(
+            store
+                .langs_history
+                .with(|history| history.clone())
+                .unwrap_or_default(),
+        );
         langs.set_block(Block::bordered().title("Langs").dim());
-        langs.set_cursor_line_style(Style::default());
         langs.set_cursor_style(Style::default());
+        let mut video = TextInput::default();
+        video.set_block(
+            Block::bordered()
+                .title("Video (path, Enter to upload)")
+                .dim(),
+        );
+        video.set_cursor_style(Style::default());
         Self {
             action_tx,
             agent,
+            client,
             textarea,
             langs,
+            video,
+            video_state: Arc::new(Mutex::new(None)),
+            max_video_mb,
+            store,
             focus: Focus::Text,
             text_len: 0,
+            context,
         }
     }
-    fn current_textarea(&mut self) -> Option<&mut TextArea<'static>> {
+    fn update_focus(&mut self, focus: Focus) {
+        self.set_focused(false);
+        self.focus = focus;
+        self.set_focused(true);
+    }
+    fn set_focused(&mut self, focused: bool) {
+        let cursor_style = if focused {
+            Style::default().reversed()
+        } else {
+            Style::default()
+        };
+        let style_block = |block: &Block<'static>| {
+            if focused {
+                block.clone().reset()
+            } else {
+                block.clone().dim()
+            }
+        };
         match self.focus {
-            Focus::Text => Some(&mut self.textarea),
-            Focus::Langs => Some(&mut self.langs),
-            Focus::Submit => None,
+            Focus::Text => {
+                self.textarea.set_cursor_style(cursor_style);
+                if let Some(block) = self.textarea.block().map(style_block) {
+                    self.textarea.set_block(block);
+                }
+            }
+            Focus::Langs => {
+                self.langs.set_cursor_style(cursor_style);
+                if let Some(block) = self.langs.block().map(style_block) {
+                    self.langs.set_block(block);
+                }
+            }
+            Focus::Video => {
+                self.video.set_cursor_style(cursor_style);
+                if let Some(block) = self.video.block().map(style_block) {
+                    self.video.set_block(block);
+                }
+            }
+            Focus::Submit => {}
         }
     }
-    fn update_focus(&mut self, focus: Focus) {
-        if let Some(curr) = self.current_textarea() {
-            curr.set_cursor_style(Style::default());
-            if let Some(block) = curr.block() {
-                curr.set_block(block.clone().dim());
-            }
+    fn video_state(&self) -> Option<VideoState> {
+        self.video_state.lock().ok().and_then(|state| state.clone())
+    }
+    /// Upload the video in the background, replacing the attached one.
+    fn upload_video(&self) {
+        let path = PathBuf::from(self.video.value().trim());
+        if let Ok(mut state) = self.video_state.lock() {
+            *state = if path.as_os_str().is_empty() {
+                None
+            } else {
+                Some(VideoState::Uploading)
+            };
         }
-        self.focus = focus;
-        if let Some(curr) = self.current_textarea() {
-            curr.set_cursor_style(Style::default().reversed());
-            if let Some(block) = curr.block() {
-                curr.set_block(block.clone().reset());
-            }
+        if path.as_os_str().is_empty() {
+            return;
         }
+        let (agent, client, state, tx, max_video_mb) = (
+            self.agent.clone(),
+            self.client.clone(),
+            self.video_state.clone(),
+            self.action_tx.clone(),
+            self.max_video_mb,
+        );
+        tokio::spawn(async move {
+            let set_state = |video_state| {
+                if let Ok(mut state) = state.lock() {
+                    *state = Some(video_state);
+                }
+                if let Err(e) = tx.send(Action::Render) {
+                    log::error!("failed to send render action: {e}");
+                }
+            };
+            let result = upload_video(&agent, &client, &path, max_video_mb, |progress| {
+                set_state(VideoState::Processing(progress));
+            })
+            .await;
+            set_state(match result {
+                Ok(embed) => VideoState::Attached(embed),
+                Err(e) => {
+                    log::error!("failed to upload video: {e}");
+                    VideoState::Failed(e.to_string())
+                }
+            });
+        });
     }
 }
 
 impl ViewComponent for NewPostViewComponent {
     fn view(&self) -> View {
-        View::NewPost
+        match &self.context {
+            Some(PostContext::Reply(post_view)) => View::Reply(post_view.clone()),
+            Some(PostContext::Quote(post_view)) => View::Quote(post_view.clone()),
+            None => View::NewPost,
+        }
     }
     fn handle_key_events(&mut self, key: KeyEvent) -> Result<Option<Action>> {
-        let focus = self.focus;
-        if let Some(textarea) = self.current_textarea() {
-            if focus == Focus::Text {
-                let cursor = textarea.cursor();
-                let result = textarea.input(key) || textarea.cursor() != cursor;
+        match self.focus {
+            Focus::Text => {
+                let cursor = self.textarea.cursor();
+                let result = self.textarea.input(key) || self.textarea.cursor() != cursor;
                 self.text_len =
                     RichText::new(self.textarea.lines().join("\n"), None).grapheme_len();
                 if let Some(block) = self.textarea.block() {
@@ -112,19 +261,19 @@ impl ViewComponent for NewPostViewComponent {
                     self.textarea.set_block(block);
                 }
                 return Ok(if result { Some(Action::Render) } else { None });
-            } else if matches!(
-                (key.code, key.modifiers),
-                (KeyCode::Enter, _) | (KeyCode::Char('m'), KeyModifiers::CONTROL)
-            ) {
-                return Ok(Some(Action::Enter));
-            } else if textarea.input(key) {
-                if self.focus == Focus::Langs {
+            }
+            Focus::Langs => {
+                if matches!(
+                    (key.code, key.modifiers),
+                    (KeyCode::Enter, _) | (KeyCode::Char('m'), KeyModifiers::CONTROL)
+                ) {
+                    return Ok(Some(Action::Enter));
+                } else if self.langs.input(key) {
                     if let Some(block) = self.langs.block() {
                         let mut block = block.clone();
                         if self
                             .langs
-                            .lines()
-                            .join("")
+                            .value()
                             .split(',')
                             .map(str::trim)
                             .all(|s| s.parse::<Language>().is_ok())
@@ -135,9 +284,20 @@ impl ViewComponent for NewPostViewComponent {
                         }
                         self.langs.set_block(block);
                     }
+                    return Ok(Some(Action::Render));
                 }
-                return Ok(Some(Action::Render));
             }
+            Focus::Video => {
+                if matches!(
+                    (key.code, key.modifiers),
+                    (KeyCode::Enter, _) | (KeyCode::Char('m'), KeyModifiers::CONTROL)
+                ) {
+                    return Ok(Some(Action::Enter));
+                } else if self.video.input(key) {
+                    return Ok(Some(Action::Render));
+                }
+            }
+            Focus::Submit => {}
         }
         Ok(None)
     }
@@ -151,14 +311,48 @@ impl ViewComponent for NewPostViewComponent {
                 self.update_focus(self.focus.prev());
                 Ok(Some(Action::Render))
             }
+            Action::Enter if self.focus == Focus::Video => {
+                self.upload_video();
+                Ok(Some(Action::Render))
+            }
             Action::Enter if self.focus == Focus::Submit => {
+                let embed = match self.video_state() {
+                    Some(VideoState::Attached(embed)) => Some(embed),
+                    Some(VideoState::Uploading | VideoState::Processing(_)) => {
+                        log::warn!("the video is still being processed");
+                        return Ok(None);
+                    }
+                    Some(VideoState::Failed(_)) | None => None,
+                };
+                let embed = match (&self.context, embed) {
+                    (Some(PostContext::Quote(_)), Some(_)) => {
+                        log::warn!("a video cannot be attached to a quote");
+                        return Ok(None);
+                    }
+                    (Some(PostContext::Quote(post_view)), None) => Some(Union::Refs(
+                        RecordEmbedRefs::AppBskyEmbedRecordMain(Box::new(
+                            api::app::bsky::embed::record::MainData {
+                                record: strong_ref(post_view),
+                            }
+                            .into(),
+                        )),
+                    )),
+                    (_, embed) => embed,
+                };
+                let reply = match &self.context {
+                    Some(PostContext::Reply(post_view)) => Some(reply_ref(post_view).into()),
+                    _ => None,
+                };
                 let tx = self.action_tx.clone();
                 let agent = self.agent.clone();
                 let text = self.textarea.lines().join("\n");
+                self.langs.push_history();
+                self.store
+                    .langs_history
+                    .with(|history| *history = self.langs.history().to_vec());
                 let langs = Some(
                     self.langs
-                        .lines()
-                        .join("")
+                        .value()
                         .split(',')
                         .map(str::trim)
                         .filter_map(|s| s.parse::<Language>().ok())
@@ -169,12 +363,12 @@ impl ViewComponent for NewPostViewComponent {
                     match agent
                         .create_record(bsky_sdk::api::app::bsky::feed::post::RecordData {
                             created_at: Datetime::now(),
-                            embed: None,
+                            embed,
                             entities: None,
                             facets: None,
                             labels: None,
                             langs,
-                            reply: None,
+                            reply,
                             tags: None,
                             text,
                         })
@@ -205,6 +399,8 @@ impl ViewComponent for NewPostViewComponent {
             Constraint::Length(1),
             Constraint::Length(8),
             Constraint::Length(3),
+            Constraint::Length(3),
+            Constraint::Length(1),
             Constraint::Length(1),
         ])
         .split(area);
@@ -213,8 +409,17 @@ impl ViewComponent for NewPostViewComponent {
         if self.focus == Focus::Submit {
             submit = submit.reversed();
         }
+        let title = match &self.context {
+            Some(PostContext::Reply(post_view)) => {
+                format!("Reply to {}", profile_name_as_str(&post_view.author))
+            }
+            Some(PostContext::Quote(post_view)) => {
+                format!("Quote {}", profile_name_as_str(&post_view.author))
+            }
+            None => String::from("New post"),
+        };
         f.render_widget(
-            Paragraph::new("New post").bold().block(
+            Paragraph::new(title).bold().block(
                 Block::default()
                     .borders(Borders::BOTTOM)
                     .border_style(Color::Gray)
@@ -229,8 +434,26 @@ impl ViewComponent for NewPostViewComponent {
             layout[1],
         );
         f.render_widget(self.textarea.widget(), layout[2]);
-        f.render_widget(self.langs.widget(), layout[3]);
-        f.render_widget(submit, layout[4]);
+        f.render_widget(&self.langs, layout[3]);
+        f.render_widget(&self.video, layout[4]);
+        if let Some(state) = self.video_state() {
+            let status = match state {
+                VideoState::Uploading => Line::from(" Uploading the video...").gray(),
+                VideoState::Processing(progress) => {
+                    let filled = usize::from(progress.min(100)) / 5;
+                    Line::from(format!(
+                        " Processing [{}{}] {progress}%",
+                        "#".repeat(filled),
+                        " ".repeat(20 - filled)
+                    ))
+                    .yellow()
+                }
+                VideoState::Attached(_) => Line::from(" Video attached").green(),
+                VideoState::Failed(e) => Line::from(format!(" {e}")).red(),
+            };
+            f.render_widget(status, layout[5]);
+        }
+        f.render_widget(submit, layout[6]);
         Ok(())
     }
 }
@@ -0,0 +1,336 @@
+use super::types::{Action, Data, Transition, View};
+use super::utils::profile_name_as_str;
+use super::ViewComponent;
+use crate::backend::client::BskyAgent;
+use crate::backend::discover::{popular_feeds, PopularFeeds};
+use crate::backend::preferences::PreferencesQueue;
+use crate::backend::types::FeedSourceInfo;
+use crate::backend::Watcher;
+use bsky_sdk::api::app::bsky::feed::defs::GeneratorView;
+use color_eyre::Result;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::style::{Color, Style, Stylize};
+use ratatui::text::{Line, Span, Text};
+use ratatui::widgets::{Block, Borders, List, ListState, Padding, Paragraph};
+use ratatui::Frame;
+use std::collections::HashSet;
+use std::sync::Arc;
+use tokio::sync::mpsc::UnboundedSender;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+enum Order {
+    #[default]
+    Likes,
+    Recent,
+}
+
+impl Order {
+    fn label(self) -> &'static str {
+        match self {
+            Self::Likes => "most liked",
+            Self::Recent => "recently active",
+        }
+    }
+}
+
+/// Sort the feeds by their likes, or by when they were last indexed.
+fn sort_feeds(feeds: &mut [GeneratorView], order: Order) {
+    match order {
+        Order::Likes => feeds.sort_by_key(|feed| std::cmp::Reverse(feed.like_count.unwrap_or(0))),
+        Order::Recent => {
+            feeds.sort_by(|a, b| b.indexed_at.as_ref().cmp(a.indexed_at.as_ref()));
+        }
+    }
+}
+
+/// The feed generators popular on Bluesky, which can be subscribed to inline.
+pub struct PopularFeedsViewComponent {
+    items: Vec<GeneratorView>,
+    cursor: Option<String>,
+    order: Order,
+    // subscribed from this view, as the saved feeds are not known here
+    subscribed: HashSet<String>,
+    loading: bool,
+    state: ListState,
+    action_tx: UnboundedSender<Action>,
+    agent: Arc<BskyAgent>,
+    preferences_queue: PreferencesQueue,
+}
+
+impl PopularFeedsViewComponent {
+    pub fn new(action_tx: UnboundedSender<Action>, watcher: Arc<Watcher>) -> Self {
+        Self {
+            items: Vec::new(),
+            cursor: None,
+            order: Order::default(),
+            subscribed: HashSet::new(),
+            loading: false,
+            state: ListState::default(),
+            action_tx,
+            agent: watcher.agent.clone(),
+            preferences_queue: watcher.preferences_queue.clone(),
+        }
+    }
+    fn load(&mut self, cursor: Option<String>) {
+        if self.loading {
+            return;
+        }
+        self.loading = true;
+        let (agent, tx) = (self.agent.clone(), self.action_tx.clone());
+        tokio::spawn(async move {
+            let append = cursor.is_some();
+            match popular_feeds(&agent, cursor).await {
+                Ok(feeds) => {
+                    let data = Data::PopularFeeds(Box::new((feeds, append)));
+                    if let Err(e) = tx.send(Action::Update(Box::new(data))) {
+                        log::error!("failed to send update action: {e}");
+                    }
+                }
+                Err(e) => {
+                    log::error!("failed to get popular feeds: {e}");
+                }
+            }
+        });
+    }
+    fn len(&self) -> usize {
+        self.items.len() + usize::from(self.cursor.is_some())
+    }
+    fn selected(&self) -> Option<&GeneratorView> {
+        self.state
+            .selected()
+            .and_then(|index| self.items.get(index))
+    }
+    fn subscribe(&mut self) -> Option<Action> {
+        let uri = self.selected()?.uri.clone();
+        if !self.subscribed.insert(uri.clone()) {
+            return None;
+        }
+        let queue = self.preferences_queue.clone();
+        tokio::spawn(async move {
+            match queue.save_feed(&uri).await {
+                Ok(()) => log::info!("updated saved feeds: {uri}"),
+                Err(e) => log::error!("failed to update saved feeds: {e}"),
+            }
+        });
+        Some(Action::Render)
+    }
+    fn switch_order(&mut self) -> Option<Action> {
+        self.order = match self.order {
+            Order::Likes => Order::Recent,
+            Order::Recent => Order::Likes,
+        };
+        sort_feeds(&mut self.items, self.order);
+        if !self.items.is_empty() {
+            self.state.select(Some(0));
+        }
+        Some(Action::Render)
+    }
+}
+
+impl ViewComponent for PopularFeedsViewComponent {
+    fn view(&self) -> View {
+        View::PopularFeeds
+    }
+    fn activate(&mut self) -> Result<()> {
+        if self.items.is_empty() {
+            self.load(None);
+        }
+        Ok(())
+    }
+    fn handle_key_events(&mut self, key: KeyEvent) -> Result<Option<Action>> {
+        if key.modifiers != KeyModifiers::NONE {
+            return Ok(None);
+        }
+        Ok(match key.code {
+            KeyCode::Char('s') => self.subscribe(),
+            KeyCode::Char('o') => self.switch_order(),
+            _ => None,
+        })
+    }
+    fn update(&mut self, action: Action) -> Result<Option<Action>> {
+        match action {
+            Action::NextItem if self.len() > 0 => {
+                self.state.select(Some(
+                    self.state
+                        .selected()
+                        .map(|s| (s + 1).min(self.len() - 1))
+                        .unwrap_or_default(),
+                ));
+                return Ok(Some(Action::Render));
+            }
+            Action::PrevItem if self.len() > 0 => {
+                self.state.select(Some(
+                    self.state
+                        .selected()
+                        .map(|s| s.max(1) - 1)
+                        .unwrap_or_default(),
+                ));
+                return Ok(Some(Action::Render));
+            }
+            // the last item is "Load more"
+            Action::Enter
+                if self.cursor.is_some() && self.state.selected() == Some(self.items.len()) =>
+            {
+                self.load(self.cursor.clone());
+                return Ok(Some(Action::Render));
+            }
+            Action::Enter => {
+                if let Some(generator_view) = self.selected() {
+                    return Ok(Some(Action::Transition(Transition::Push(Box::new(
+                        View::Feed(Box::new(FeedSourceInfo::Feed(Box::new(
+                            generator_view.clone(),
+                        )))),
+                    )))));
+                }
+            }
+            Action::Info => {
+                if let Some(generator_view) = self.selected() {
+                    return Ok(Some(Action::Transition(Transition::Push(Box::new(
+                        View::FeedGenerator(generator_view.uri.clone()),
+                    )))));
+                }
+            }
+            Action::Back => return Ok(Some(Action::Transition(Transition::Pop))),
+            Action::Refresh => {
+                self.load(None);
+            }
+            Action::Update(data) => {
+                let Data::PopularFeeds(boxed) = data.as_ref() else {
+                    return Ok(None);
+                };
+                let (PopularFeeds { feeds, cursor }, append) = boxed.as_ref().clone();
+                if !append {
+                    self.items.clear();
+                }
+                self.items.extend(feeds);
+                sort_feeds(&mut self.items, self.order);
+                self.cursor = cursor;
+                self.loading = false;
+                if self.state.selected().is_none() && self.len() > 0 {
+                    self.state.select(Some(0));
+                }
+                return Ok(Some(Action::Render));
+            }
+            _ => {}
+        }
+        Ok(None)
+    }
+    fn draw(&mut self, f: &mut Frame<'_>, area: Rect) -> Result<()> {
+        let header = Paragraph::new(Line::from(vec![
+            Span::from("Popular feeds").bold(),
+            Span::from(format!(" ({})", self.order.label())).gray(),
+        ]))
+        .block(
+            Block::default()
+                .borders(Borders::BOTTOM)
+                .border_style(Color::Gray)
+                .padding(Padding::horizontal(1)),
+        );
+        let mut items = self
+            .items
+            .iter()
+            .map(|generator_view| {
+                let mut spans = vec![
+                    Span::from(generator_view.display_name.as_str()).bold(),
+                    Span::from(format!(
+                        " by {}",
+                        profile_name_as_str(&generator_view.creator)
+                    ))
+                    .gray(),
+                ];
+                if self.subscribed.contains(&generator_view.uri) {
+                    spans.push(Span::from(" ✓ Subscribed").green());
+                }
+                let mut lines = vec![Line::from(spans)];
+                if let Some(description) = generator_view
+                    .description
+                    .as_ref()
+                    .filter(|s| !s.is_empty())
+                {
+                    lines.push(Line::from(format!("  {}", description.replace('\n', " "))).dim());
+                }
+                lines.push(
+                    Line::from(format!(
+                        "  ♡ {} likes",
+                        generator_view.like_count.unwrap_or_default()
+                    ))
+                    .gray(),
+                );
+                Text::from(lines)
+            })
+            .collect::<Vec<_>>();
+        if self.cursor.is_some() {
+            items.push(
+                Text::from(if self.loading {
+                    "Loading..."
+                } else {
+                    "Load more"
+                })
+                .blue(),
+            );
+        } else if self.items.is_empty() && !self.loading {
+            items.push(Text::from("No feeds").dim());
+        }
+
+        let layout = Layout::vertical([
+            Constraint::Length(2),
+            Constraint::Percentage(100),
+            Constraint::Length(1),
+        ])
+        .split(area);
+        f.render_widget(header, layout[0]);
+        f.render_stateful_widget(
+            List::new(items)
+                .highlight_style(Style::default().reset().reversed())
+                .block(Block::default().padding(Padding::horizontal(1))),
+            layout[1],
+            &mut self.state,
+        );
+        f.render_widget(
+            Line::from("s: subscribe, o: sort by likes/activity")
+                .dim()
+                .centered(),
+            layout[2],
+        );
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn feed(rkey: &str, like_count: i64, indexed_at: &str) -> GeneratorView {
+        serde_json::from_value(json!({
+            "cid": "bafyreidfayvfuwqa7qlnopdjiqrxzs6blmoeu4rujcjtnci5beludirz2a",
+            "creator": { "did": "did:plc:alice", "handle": "alice.test" },
+            "did": "did:web:feeds.example.com",
+            "displayName": rkey,
+            "indexedAt": indexed_at,
+            "likeCount": like_count,
+            "uri": format!("at://did:plc:alice/app.bsky.feed.generator/{rkey}"),
+        }))
+        .expect("invalid feed")
+    }
+
+    #[test]
+    fn sort_by_order() {
+        let mut feeds = [
+            feed("a", 1, "2024-09-10T12:00:00Z"),
+            feed("b", 3, "2024-09-08T12:00:00Z"),
+            feed("c", 2, "2024-09-09T12:00:00Z"),
+        ];
+        let names = |feeds: &[GeneratorView]| {
+            feeds
+                .iter()
+                .map(|feed| feed.display_name.clone())
+                .collect::<Vec<_>>()
+        };
+        sort_feeds(&mut feeds, Order::Likes);
+        assert_eq!(names(&feeds), ["b", "c", "a"]);
+        sort_feeds(&mut feeds, Order::Recent);
+        assert_eq!(names(&feeds), ["a", "c", "b"]);
+    }
+}
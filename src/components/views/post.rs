@@ -1,9 +1,14 @@
 use super::types::{Action, Data, Transition, View};
-use super::utils::{counts, profile_name, profile_name_as_str};
+use super::utils::{counts, format_timestamp, links, profile_name, profile_name_as_str};
 use super::ViewComponent;
+use crate::backend::cache::PostCache;
+use crate::backend::client::BskyAgent;
+use crate::backend::graph::known_followers;
 use crate::backend::{Watch, Watcher};
+use crate::config::TimestampFormat;
+use bsky_sdk::api;
 use bsky_sdk::api::agent::Session;
-use bsky_sdk::api::app::bsky::actor::defs::ProfileViewBasic;
+use bsky_sdk::api::app::bsky::actor::defs::{ProfileView, ProfileViewBasic};
 use bsky_sdk::api::app::bsky::embed::record::{self, ViewRecordRefs};
 use bsky_sdk::api::app::bsky::embed::record_with_media::ViewMediaRefs;
 use bsky_sdk::api::app::bsky::embed::{external, images};
@@ -15,10 +20,7 @@ use bsky_sdk::api::app::bsky::richtext::facet::MainFeaturesItem;
 use bsky_sdk::api::records::{KnownRecord, Record};
 use bsky_sdk::api::types::string::Datetime;
 use bsky_sdk::api::types::Union;
-use bsky_sdk::{api, BskyAgent};
-use chrono::Local;
 use color_eyre::Result;
-use indexmap::IndexSet;
 use ratatui::layout::{Alignment, Constraint, Layout, Margin, Rect};
 use ratatui::style::{Color, Style, Stylize};
 use ratatui::text::{Line, Span, Text};
@@ -30,16 +32,21 @@ use std::sync::Arc;
 use tokio::sync::mpsc::UnboundedSender;
 use tokio::sync::oneshot;
 
+/// The number of the known followers named in the summary of the post actions.
+const KNOWN_FOLLOWERS_NAMED: usize = 2;
+
 #[derive(Debug, Clone)]
 enum PostAction {
-    Profile(ProfileViewBasic),
+    Profile(Box<ProfileViewBasic>),
+    KnownFollowers(Vec<ProfileView>),
     Reply,
     Repost,
     Like,
     Unlike(String),
     Delete,
+    Conversation,
     Open(String),
-    ViewRecord(record::ViewRecord),
+    ViewRecord(Box<record::ViewRecord>),
 }
 
 impl<'a> From<&'a PostAction> for ListItem<'a> {
@@ -47,15 +54,37 @@ impl<'a> From<&'a PostAction> for ListItem<'a> {
         match action {
             PostAction::Profile(profile) => Self::from(Line::from(vec![
                 Span::from("Show "),
-                Span::from(profile_name_as_str(profile)).bold(),
+                Span::from(profile_name_as_str(profile.as_ref())).bold(),
                 Span::from("'s profile"),
             ]))
             .dim(),
+            PostAction::KnownFollowers(followers) => {
+                let mut spans = vec![Span::from("👥 Followed by ")];
+                for (i, profile) in followers.iter().take(KNOWN_FOLLOWERS_NAMED).enumerate() {
+                    if i > 0 {
+                        spans.push(Span::from(", "));
+                    }
+                    spans.push(Span::from(profile_name_as_str(profile)).bold());
+                }
+                let others = followers.len().saturating_sub(KNOWN_FOLLOWERS_NAMED);
+                if others > 0 {
+                    spans.extend([
+                        Span::from(", and "),
+                        Span::from(format!(
+                            "{others} other{}",
+                            if others == 1 { "" } else { "s" }
+                        ))
+                        .underlined(),
+                    ]);
+                }
+                Self::from(Line::from(spans))
+            }
             PostAction::Reply => Self::from("Reply").dim(),
             PostAction::Repost => Self::from("Repost").dim(),
             PostAction::Like => Self::from("Like"),
             PostAction::Unlike(_) => Self::from("Unlike"),
             PostAction::Delete => Self::from("Delete").red(),
+            PostAction::Conversation => Self::from("Show conversation"),
             PostAction::Open(uri) => Self::from(format!("Open {uri}")),
             PostAction::ViewRecord(view_record) => Self::from(Line::from(vec![
                 Span::from("Show "),
@@ -75,9 +104,12 @@ pub struct PostViewComponent {
     list_state: ListState,
     action_tx: UnboundedSender<Action>,
     agent: Arc<BskyAgent>,
+    posts: PostCache,
     watcher: Box<dyn Watch<Output = Union<OutputThreadRefs>>>,
     quit: Option<oneshot::Sender<()>>,
     session: Option<Session>,
+    // the followers of the author who are followed by the user
+    known_followers: Vec<ProfileView>,
 }
 
 impl PostViewComponent {
@@ -88,8 +120,8 @@ impl PostViewComponent {
         reply: Option<PostView>,
         session: Option<Session>,
     ) -> Self {
-        let actions = Self::post_view_actions(&post_view, &session);
-        let agent = watcher.agent.clone();
+        let actions = Self::post_view_actions(&post_view, &session, &[]);
+        let (agent, posts) = (watcher.agent.clone(), watcher.posts.clone());
         let watcher = Box::new(watcher.post_thread(post_view.uri.clone()));
         Self {
             post_view,
@@ -99,18 +131,27 @@ impl PostViewComponent {
             list_state: ListState::default(),
             action_tx,
             agent,
+            posts,
             watcher,
             quit: None,
             session,
+            known_followers: Vec::new(),
         }
     }
-    fn post_view_actions(post_view: &PostView, session: &Option<Session>) -> Vec<PostAction> {
+    fn post_view_actions(
+        post_view: &PostView,
+        session: &Option<Session>,
+        known_followers: &[ProfileView],
+    ) -> Vec<PostAction> {
         let mut liked = None;
         if let Some(viewer) = &post_view.viewer {
             liked = viewer.like.as_ref();
         }
-        let mut actions = vec![
-            PostAction::Profile(post_view.author.clone()),
+        let mut actions = vec![PostAction::Profile(Box::new(post_view.author.clone()))];
+        if !known_followers.is_empty() {
+            actions.push(PostAction::KnownFollowers(known_followers.to_vec()));
+        }
+        actions.extend([
             PostAction::Reply,
             PostAction::Repost,
             if let Some(uri) = liked {
@@ -118,66 +159,25 @@ impl PostViewComponent {
             } else {
                 PostAction::Like
             },
-        ];
+        ]);
         if Some(&post_view.author.did) == session.as_ref().map(|s| &s.data.did) {
             actions.push(PostAction::Delete);
         }
-        let mut links = IndexSet::new();
-        if let Record::Known(KnownRecord::AppBskyFeedPost(record)) = &post_view.record {
-            if let Some(facets) = &record.facets {
-                for facet in facets {
-                    for feature in &facet.features {
-                        match feature {
-                            Union::Refs(MainFeaturesItem::Mention(_)) => {
-                                // TODO
-                            }
-                            Union::Refs(MainFeaturesItem::Link(link)) => {
-                                links.insert(link.uri.as_str());
-                            }
-                            Union::Refs(MainFeaturesItem::Tag(_)) => {
-                                // TODO
-                            }
-                            _ => {}
-                        }
-                    }
-                }
+        actions.push(PostAction::Conversation);
+        match &post_view.embed {
+            Some(Union::Refs(PostViewEmbedRefs::AppBskyEmbedRecordView(record))) => {
+                actions.extend(Self::record_actions(record));
             }
-        }
-        if let Some(embed) = &post_view.embed {
-            match embed {
-                Union::Refs(PostViewEmbedRefs::AppBskyEmbedImagesView(images)) => {
-                    for image in &images.images {
-                        links.insert(image.fullsize.as_str());
-                    }
-                }
-                Union::Refs(PostViewEmbedRefs::AppBskyEmbedExternalView(external)) => {
-                    links.insert(external.external.uri.as_str());
-                }
-                Union::Refs(PostViewEmbedRefs::AppBskyEmbedRecordView(record)) => {
-                    actions.extend(Self::record_actions(record));
-                }
-                Union::Refs(PostViewEmbedRefs::AppBskyEmbedRecordWithMediaView(
-                    record_with_media,
-                )) => {
-                    match &record_with_media.media {
-                        Union::Refs(ViewMediaRefs::AppBskyEmbedImagesView(images)) => {
-                            for image in &images.images {
-                                links.insert(image.fullsize.as_str());
-                            }
-                        }
-                        Union::Refs(ViewMediaRefs::AppBskyEmbedExternalView(external)) => {
-                            links.insert(external.external.uri.as_str());
-                        }
-                        _ => {}
-                    }
-                    actions.extend(Self::record_actions(&record_with_media.record));
-                }
-                _ => {}
+            Some(Union::Refs(PostViewEmbedRefs::AppBskyEmbedRecordWithMediaView(
+                record_with_media,
+            ))) => {
+                actions.extend(Self::record_actions(&record_with_media.record));
             }
+            _ => {}
         }
         [
             actions,
-            links
+            links(post_view)
                 .iter()
                 .map(|s| PostAction::Open(s.to_string()))
                 .collect::<Vec<_>>(),
@@ -188,7 +188,7 @@ impl PostViewComponent {
         let mut actions = Vec::new();
         match &record.record {
             Union::Refs(ViewRecordRefs::ViewRecord(view_record)) => {
-                actions.push(PostAction::ViewRecord(view_record.as_ref().clone()));
+                actions.push(PostAction::ViewRecord(view_record.clone()));
             }
             Union::Refs(ViewRecordRefs::AppBskyFeedDefsGeneratorView(_)) => {
                 // TODO
@@ -206,7 +206,7 @@ impl PostViewComponent {
         }
         actions
     }
-    fn post_view_rows(post_view: &PostView, width: u16) -> Option<Vec<Row>> {
+    fn post_view_rows(post_view: &PostView, width: u16) -> Option<Vec<Row<'_>>> {
         let Record::Known(KnownRecord::AppBskyFeedPost(record)) = &post_view.record else {
             return None;
         };
@@ -236,14 +236,10 @@ impl PostViewComponent {
             ]),
             Row::new(vec![
                 Cell::from("IndexedAt:".gray().into_right_aligned_line()),
-                Cell::from(
-                    post_view
-                        .indexed_at
-                        .as_ref()
-                        .with_timezone(&Local)
-                        .format("%Y-%m-%d %H:%M:%S %z")
-                        .to_string(),
-                )
+                Cell::from(format_timestamp(
+                    post_view.indexed_at.as_ref(),
+                    TimestampFormat::FullDatetime,
+                ))
                 .green(),
             ]),
             Row::default().height(author_lines.len() as u16).cells(vec![
@@ -362,7 +358,7 @@ impl PostViewComponent {
         }
         Some(rows)
     }
-    fn images_lines(images: &images::View) -> Vec<Line> {
+    pub(super) fn images_lines(images: &images::View) -> Vec<Line<'_>> {
         images
             .images
             .iter()
@@ -375,7 +371,7 @@ impl PostViewComponent {
             })
             .collect()
     }
-    fn external_lines(external: &external::View) -> Vec<Line> {
+    pub(super) fn external_lines(external: &external::View) -> Vec<Line<'_>> {
         vec![
             Line::from(
                 Span::from(external.external.uri.as_str())
@@ -386,20 +382,16 @@ impl PostViewComponent {
             Line::from(external.external.description.as_str()),
         ]
     }
-    fn record_lines(record: &record::View, width: u16) -> Vec<Line> {
+    pub(super) fn record_lines(record: &record::View, width: u16) -> Vec<Line<'_>> {
         match &record.record {
             Union::Refs(ViewRecordRefs::ViewRecord(view_record)) => {
                 if let Record::Known(KnownRecord::AppBskyFeedPost(record)) = &view_record.value {
                     return [
                         vec![
-                            Line::from(
-                                view_record
-                                    .indexed_at
-                                    .as_ref()
-                                    .with_timezone(&Local)
-                                    .format("%Y-%m-%d %H:%M:%S %z")
-                                    .to_string(),
-                            )
+                            Line::from(format_timestamp(
+                                view_record.indexed_at.as_ref(),
+                                TimestampFormat::FullDatetime,
+                            ))
                             .green(),
                             Line::from(profile_name(&view_record.author)),
                         ],
@@ -458,6 +450,24 @@ impl ViewComponent for PostViewComponent {
             }
             log::debug!("subscription finished");
         });
+        let author = self.post_view.author.did.clone();
+        if self.known_followers.is_empty()
+            && Some(&author) != self.session.as_ref().map(|s| &s.data.did)
+        {
+            let (agent, tx) = (self.agent.clone(), self.action_tx.clone());
+            tokio::spawn(async move {
+                match known_followers(&agent, &author).await {
+                    Ok(followers) => {
+                        if let Err(e) =
+                            tx.send(Action::Update(Box::new(Data::KnownFollowers(followers))))
+                        {
+                            log::error!("failed to send update action: {e}");
+                        }
+                    }
+                    Err(e) => log::warn!("failed to get the known followers: {e}"),
+                }
+            });
+        }
         Ok(())
     }
     fn deactivate(&mut self) -> Result<()> {
@@ -489,6 +499,12 @@ impl ViewComponent for PostViewComponent {
             }
             Action::Enter => {
                 if let Some(action) = self.list_state.selected().and_then(|i| self.actions.get(i)) {
+                    if matches!(
+                        action,
+                        PostAction::Like | PostAction::Unlike(_) | PostAction::Delete
+                    ) {
+                        self.posts.evict(&self.post_view.uri);
+                    }
                     match action {
                         PostAction::Like => {
                             let (agent, tx) = (self.agent.clone(), self.action_tx.clone());
@@ -563,6 +579,19 @@ impl ViewComponent for PostViewComponent {
                                 }
                             });
                         }
+                        PostAction::KnownFollowers(followers) => {
+                            return Ok(Some(Action::Transition(Transition::Push(Box::new(
+                                View::KnownFollowers(Box::new((
+                                    self.post_view.author.clone(),
+                                    followers.clone(),
+                                ))),
+                            )))));
+                        }
+                        PostAction::Conversation => {
+                            return Ok(Some(Action::Transition(Transition::Push(Box::new(
+                                View::Conversation(self.post_view.uri.clone()),
+                            )))));
+                        }
                         PostAction::Open(uri) => {
                             if let Err(e) = open::that(uri) {
                                 log::error!("failed to open: {e}");
@@ -631,9 +660,13 @@ impl ViewComponent for PostViewComponent {
                             Some(self.post_view.like_count.unwrap_or_default() + diff);
                         self.post_view.viewer.clone_from(viewer);
                     }
+                    Data::KnownFollowers(followers) => {
+                        self.known_followers.clone_from(followers);
+                    }
                     _ => return Ok(None),
                 }
-                self.actions = Self::post_view_actions(&self.post_view, &self.session);
+                self.actions =
+                    Self::post_view_actions(&self.post_view, &self.session, &self.known_followers);
                 return Ok(Some(Action::Render));
             }
             Action::Transition(_) => {
@@ -0,0 +1,201 @@
+use super::feed::{FeedViewComponent, ReplyParent};
+use super::types::{Action, Data, Transition, View};
+use super::utils::profile_name;
+use super::ViewComponent;
+use crate::backend::client::BskyAgent;
+use crate::backend::quotes::{get_quotes, quote_count, Quotes};
+use crate::config::{ReplyContext, TimestampFormat};
+use bsky_sdk::api::app::bsky::feed::defs::{FeedViewPost, FeedViewPostData, PostView};
+use color_eyre::Result;
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::style::{Color, Style, Stylize};
+use ratatui::text::{Line, Span, Text};
+use ratatui::widgets::{Block, Borders, List, ListState, Padding, Paragraph};
+use ratatui::Frame;
+use std::sync::Arc;
+use tokio::sync::mpsc::UnboundedSender;
+
+pub struct QuotePostsViewComponent {
+    post_view: PostView,
+    items: Vec<FeedViewPost>,
+    cursor: Option<String>,
+    loading: bool,
+    state: ListState,
+    action_tx: UnboundedSender<Action>,
+    agent: Arc<BskyAgent>,
+}
+
+impl QuotePostsViewComponent {
+    pub fn new(
+        action_tx: UnboundedSender<Action>,
+        agent: Arc<BskyAgent>,
+        post_view: PostView,
+    ) -> Self {
+        Self {
+            post_view,
+            items: Vec::new(),
+            cursor: None,
+            loading: false,
+            state: ListState::default(),
+            action_tx,
+            agent,
+        }
+    }
+    fn load(&mut self, cursor: Option<String>) {
+        if self.loading {
+            return;
+        }
+        self.loading = true;
+        let (agent, tx) = (self.agent.clone(), self.action_tx.clone());
+        let uri = self.post_view.uri.clone();
+        tokio::spawn(async move {
+            match get_quotes(&agent, &uri, cursor.as_deref()).await {
+                Ok(quotes) => {
+                    let data = Data::Quotes(Box::new((quotes, cursor.is_some())));
+                    if let Err(e) = tx.send(Action::Update(Box::new(data))) {
+                        log::error!("failed to send update action: {e}");
+                    }
+                }
+                Err(e) => {
+                    log::error!("failed to get quotes: {e}");
+                }
+            }
+        });
+    }
+    fn len(&self) -> usize {
+        self.items.len() + usize::from(self.cursor.is_some())
+    }
+}
+
+impl ViewComponent for QuotePostsViewComponent {
+    fn view(&self) -> View {
+        View::Quotes(Box::new(self.post_view.clone()))
+    }
+    fn activate(&mut self) -> Result<()> {
+        if self.items.is_empty() {
+            self.load(None);
+        }
+        Ok(())
+    }
+    fn update(&mut self, action: Action) -> Result<Option<Action>> {
+        match action {
+            Action::NextItem if self.len() > 0 => {
+                self.state.select(Some(
+                    self.state
+                        .selected()
+                        .map(|s| (s + 1).min(self.len() - 1))
+                        .unwrap_or_default(),
+                ));
+                return Ok(Some(Action::Render));
+            }
+            Action::PrevItem if self.len() > 0 => {
+                self.state.select(Some(
+                    self.state
+                        .selected()
+                        .map(|s| s.max(1) - 1)
+                        .unwrap_or_default(),
+                ));
+                return Ok(Some(Action::Render));
+            }
+            Action::Enter => {
+                let Some(index) = self.state.selected() else {
+                    return Ok(None);
+                };
+                if let Some(feed_view_post) = self.items.get(index) {
+                    return Ok(Some(Action::Transition(Transition::Push(Box::new(
+                        View::Conversation(feed_view_post.post.uri.clone()),
+                    )))));
+                }
+                // the last item is "Load more"
+                if self.cursor.is_some() {
+                    self.load(self.cursor.clone());
+                    return Ok(Some(Action::Render));
+                }
+            }
+            Action::Back => return Ok(Some(Action::Transition(Transition::Pop))),
+            Action::Refresh => {
+                self.load(None);
+            }
+            Action::Update(data) => {
+                let Data::Quotes(boxed) = data.as_ref() else {
+                    return Ok(None);
+                };
+                let (Quotes { posts, cursor }, append) = boxed.as_ref();
+                let feed_view_posts = posts.iter().map(|post_view| {
+                    FeedViewPost::from(FeedViewPostData {
+                        feed_context: None,
+                        post: post_view.clone(),
+                        reason: None,
+                        reply: None,
+                    })
+                });
+                if !append {
+                    self.items.clear();
+                }
+                self.items.extend(feed_view_posts);
+                self.cursor.clone_from(cursor);
+                self.loading = false;
+                if self.state.selected().is_none() && self.len() > 0 {
+                    self.state.select(Some(0));
+                }
+                return Ok(Some(Action::Render));
+            }
+            _ => {}
+        }
+        Ok(None)
+    }
+    fn draw(&mut self, f: &mut Frame<'_>, area: Rect) -> Result<()> {
+        let mut title = vec![Span::from("Quotes of ")];
+        title.extend(profile_name(&self.post_view.author));
+        if let Some(count) = quote_count(&self.post_view) {
+            title.push(Span::from(format!(" ({count})")).gray());
+        }
+        let header = Paragraph::new(Line::from(title)).bold().block(
+            Block::default()
+                .borders(Borders::BOTTOM)
+                .border_style(Color::Gray)
+                .padding(Padding::horizontal(1)),
+        );
+        let mut items = self
+            .items
+            .iter()
+            .filter_map(|feed_view_post| {
+                FeedViewComponent::lines(
+                    feed_view_post,
+                    area,
+                    false,
+                    false,
+                    false,
+                    None,
+                    ReplyParent::new(feed_view_post, ReplyContext::Auto, None),
+                    TimestampFormat::default(),
+                )
+            })
+            .map(Text::from)
+            .collect::<Vec<_>>();
+        if self.cursor.is_some() {
+            items.push(
+                Text::from(if self.loading {
+                    "Loading..."
+                } else {
+                    "Load more"
+                })
+                .blue(),
+            );
+        } else if self.items.is_empty() && !self.loading {
+            items.push(Text::from("No quotes").dim());
+        }
+
+        let layout =
+            Layout::vertical([Constraint::Length(2), Constraint::Percentage(100)]).split(area);
+        f.render_widget(header, layout[0]);
+        f.render_stateful_widget(
+            List::new(items)
+                .highlight_style(Style::default().reset().reversed())
+                .block(Block::default().padding(Padding::horizontal(1))),
+            layout[1],
+            &mut self.state,
+        );
+        Ok(())
+    }
+}
@@ -19,18 +19,45 @@ pub struct RootComponent {
     action_tx: UnboundedSender<Action>,
     watcher: Box<dyn Watch<Output = Vec<PinnedFeed>>>,
     quit: Option<oneshot::Sender<()>>,
+    // listed after the pinned feeds
+    rss_feeds: Vec<String>,
 }
 
 impl RootComponent {
-    pub fn new(action_tx: UnboundedSender<Action>, watcher: Arc<Watcher>) -> Self {
+    pub fn new(
+        action_tx: UnboundedSender<Action>,
+        watcher: Arc<Watcher>,
+        rss_feeds: Vec<String>,
+    ) -> Self {
         Self {
             items: Vec::new(),
             state: ListState::default(),
             action_tx,
             watcher: Box::new(watcher.pinned_feeds()),
             quit: None,
+            rss_feeds,
         }
     }
+    /// The pinned feeds, followed by all of them merged into one if there are several of them,
+    /// and the Discover feed.
+    fn sources(&self) -> Vec<FeedSourceInfo> {
+        let mut sources = self
+            .items
+            .iter()
+            .map(|feed| feed.info.clone())
+            .collect::<Vec<_>>();
+        if self.items.len() > 1 {
+            sources.push(FeedSourceInfo::Combined(sources.clone()));
+        }
+        if !self.items.is_empty() {
+            sources.push(FeedSourceInfo::Suggested);
+        }
+        sources
+    }
+    /// The number of feeds, including the RSS feeds.
+    fn len(&self) -> usize {
+        self.sources().len() + self.rss_feeds.len()
+    }
 }
 
 impl ViewComponent for RootComponent {
@@ -83,7 +110,7 @@ impl ViewComponent for RootComponent {
                 self.state.select(Some(
                     self.state
                         .selected()
-                        .map(|s| (s + 1).min(self.items.len()))
+                        .map(|s| (s + 1).min(self.len()))
                         .unwrap_or_default(),
                 ));
                 return Ok(Some(Action::Render));
@@ -99,15 +126,33 @@ impl ViewComponent for RootComponent {
             }
             Action::Enter if !self.items.is_empty() => {
                 if let Some(index) = self.state.selected() {
-                    if index == self.items.len() {
+                    if index == self.len() {
                         self.deactivate()?;
                         return Ok(Some(Action::Logout));
                     }
-                    if let Some(feed) = self.items.get(index) {
+                    let sources = self.sources();
+                    if let Some(info) = sources.get(index) {
                         return Ok(Some(Action::Transition(Transition::Push(Box::new(
-                            View::Feed(Box::new(feed.info.clone())),
+                            View::Feed(Box::new(info.clone())),
                         )))));
                     }
+                    if let Some(url) = self.rss_feeds.get(index - sources.len()) {
+                        return Ok(Some(Action::Transition(Transition::Push(Box::new(
+                            View::Rss(url.clone()),
+                        )))));
+                    }
+                }
+            }
+            Action::Info => {
+                if let Some(FeedSourceInfo::Feed(generator_view)) = self
+                    .state
+                    .selected()
+                    .and_then(|i| self.items.get(i))
+                    .map(|feed| &feed.info)
+                {
+                    return Ok(Some(Action::Transition(Transition::Push(Box::new(
+                        View::FeedGenerator(generator_view.uri.clone()),
+                    )))));
                 }
             }
             Action::Refresh => {
@@ -128,28 +173,30 @@ impl ViewComponent for RootComponent {
         Ok(None)
     }
     fn draw(&mut self, f: &mut Frame<'_>, area: Rect) -> Result<()> {
-        let mut items = self
-            .items
+        let sources = self.sources();
+        let mut items = sources
             .iter()
-            .map(|feed| match &feed.info {
-                FeedSourceInfo::Feed(generator_view) => Text::from(vec![
-                    Line::from(vec![
-                        Span::from("[feed]").blue(),
-                        Span::from(" "),
-                        Span::from(generator_view.display_name.clone()).bold(),
-                        Span::from(" "),
-                        Span::from(format!(
-                            "by {}",
-                            profile_name_as_str(&generator_view.creator)
+            .map(|info| match info {
+                FeedSourceInfo::Feed(generator_view) | FeedSourceInfo::Skeleton(generator_view) => {
+                    Text::from(vec![
+                        Line::from(vec![
+                            Span::from("[feed]").blue(),
+                            Span::from(" "),
+                            Span::from(generator_view.display_name.clone()).bold(),
+                            Span::from(" "),
+                            Span::from(format!(
+                                "by {}",
+                                profile_name_as_str(&generator_view.creator)
+                            ))
+                            .gray(),
+                        ]),
+                        Line::from(format!(
+                            "  {}",
+                            generator_view.description.as_deref().unwrap_or_default()
                         ))
-                        .gray(),
-                    ]),
-                    Line::from(format!(
-                        "  {}",
-                        generator_view.description.as_deref().unwrap_or_default()
-                    ))
-                    .dim(),
-                ]),
+                        .dim(),
+                    ])
+                }
                 FeedSourceInfo::List(list_view) => Text::from(vec![
                     Line::from(vec![
                         Span::from("[list]").yellow(),
@@ -173,9 +220,80 @@ impl ViewComponent for RootComponent {
                     ]),
                     Line::from("  Your following feed").dim(),
                 ]),
+                FeedSourceInfo::Mutuals(_) => Text::from(vec![
+                    Line::from(vec![
+                        Span::from("[timeline]").green(),
+                        Span::from(" "),
+                        Span::from("Mutuals").bold(),
+                    ]),
+                    Line::from("  Your following feed, mutuals only").dim(),
+                ]),
+                FeedSourceInfo::Activity(_) => Text::from(vec![
+                    Line::from(vec![
+                        Span::from("[timeline]").green(),
+                        Span::from(" "),
+                        Span::from("Repost activity").bold(),
+                    ]),
+                    Line::from("  What your follows have reposted").dim(),
+                ]),
+                FeedSourceInfo::Own => Text::from(vec![
+                    Line::from(vec![
+                        Span::from("[author]").blue(),
+                        Span::from(" "),
+                        Span::from("Own posts").bold(),
+                    ]),
+                    Line::from("  Your posts and reposts").dim(),
+                ]),
+                FeedSourceInfo::Author(actor) => Text::from(vec![Line::from(vec![
+                    Span::from("[author]").blue(),
+                    Span::from(" "),
+                    Span::from(actor.as_str()).bold(),
+                ])]),
+                FeedSourceInfo::Suggested => Text::from(vec![
+                    Line::from(vec![
+                        Span::from("[discover]").cyan(),
+                        Span::from(" "),
+                        Span::from("Discover").bold(),
+                    ]),
+                    Line::from("  Suggested posts from outside of your follows").dim(),
+                ]),
+                FeedSourceInfo::Combined(feed_infos) => Text::from(vec![
+                    Line::from(vec![
+                        Span::from("[combined]").magenta(),
+                        Span::from(" "),
+                        Span::from("All pinned feeds").bold(),
+                    ]),
+                    Line::from(format!("  {} feeds merged into one", feed_infos.len())).dim(),
+                ]),
+                FeedSourceInfo::ExternalLinks(feed_info) => Text::from(vec![
+                    Line::from(vec![
+                        Span::from("[links]").cyan(),
+                        Span::from(" "),
+                        Span::from(feed_info.label()).bold(),
+                    ]),
+                    Line::from("  Only the posts with a link card").dim(),
+                ]),
+                FeedSourceInfo::Conversation(uri) => Text::from(vec![
+                    Line::from(vec![
+                        Span::from("[conversation]").cyan(),
+                        Span::from(" "),
+                        Span::from(uri.as_str()).bold(),
+                    ]),
+                    Line::from("  The direct replies to a post").dim(),
+                ]),
             })
             .collect::<Vec<_>>();
         if !items.is_empty() {
+            items.extend(self.rss_feeds.iter().map(|url| {
+                Text::from(vec![
+                    Line::from(vec![
+                        Span::from("[RSS]").light_red(),
+                        Span::from(" "),
+                        Span::from(url.as_str()).bold(),
+                    ]),
+                    Line::from("  RSS/Atom feed").dim(),
+                ])
+            }));
             items.push(Text::from("Sign out").red());
         }
         f.render_stateful_widget(
@@ -0,0 +1,206 @@
+use super::types::{Action, Data, Transition, View};
+use super::utils::format_timestamp;
+use super::ViewComponent;
+use crate::backend::rss::{RssFeed, RssItem};
+use crate::backend::{Watch, Watcher};
+use crate::config::TimestampFormat;
+use crate::utils::open_url;
+use color_eyre::Result;
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::style::{Color, Style, Stylize};
+use ratatui::text::{Line, Span, Text};
+use ratatui::widgets::{Block, Borders, List, ListState, Padding, Paragraph};
+use ratatui::Frame;
+use reqwest::Client;
+use std::sync::Arc;
+use tokio::sync::mpsc::UnboundedSender;
+use tokio::sync::oneshot;
+
+/// The number of lines of the summary shown for each item.
+const SUMMARY_LINES: usize = 3;
+
+pub struct RssViewComponent {
+    url: String,
+    feed: RssFeed,
+    state: ListState,
+    action_tx: UnboundedSender<Action>,
+    watcher: Box<dyn Watch<Output = Option<RssFeed>>>,
+    quit: Option<oneshot::Sender<()>>,
+    browser_command: Option<String>,
+}
+
+impl RssViewComponent {
+    pub fn new(
+        action_tx: UnboundedSender<Action>,
+        watcher: Arc<Watcher>,
+        url: String,
+        client: Client,
+        browser_command: Option<String>,
+    ) -> Self {
+        let watcher = Box::new(watcher.rss(url.clone(), client));
+        Self {
+            url,
+            feed: RssFeed::default(),
+            state: ListState::default(),
+            action_tx,
+            watcher,
+            quit: None,
+            browser_command,
+        }
+    }
+    fn lines(item: &RssItem, width: usize) -> Vec<Line<'static>> {
+        let mut lines = vec![Line::from(vec![
+            Span::from("[RSS]").light_red(),
+            Span::from(" "),
+            Span::from(item.title.clone()).bold(),
+        ])];
+        if let Some(published) = item.published {
+            lines.push(
+                Line::from(format!(
+                    "  {}",
+                    format_timestamp(&published, TimestampFormat::FullDatetime)
+                ))
+                .gray(),
+            );
+        }
+        lines.extend(
+            textwrap::wrap(&item.summary, width.saturating_sub(2).max(1))
+                .into_iter()
+                .take(SUMMARY_LINES)
+                .map(|line| Line::from(format!("  {line}"))),
+        );
+        if let Some(link) = &item.link {
+            lines.push(Line::from(format!("  {link}")).blue().underlined());
+        }
+        lines
+    }
+}
+
+impl ViewComponent for RssViewComponent {
+    fn view(&self) -> View {
+        View::Rss(self.url.clone())
+    }
+    fn activate(&mut self) -> Result<()> {
+        let (tx, mut rx) = (self.action_tx.clone(), self.watcher.subscribe());
+        let (quit_tx, mut quit_rx) = oneshot::channel();
+        self.quit = Some(quit_tx);
+        tokio::spawn(async move {
+            loop {
+                tokio::select! {
+                    changed = rx.changed() => {
+                        if changed.is_ok() {
+                            let Some(feed) = rx.borrow_and_update().clone() else {
+                                continue;
+                            };
+                            if let Err(e) = tx.send(Action::Update(Box::new(Data::Rss(
+                                Box::new(feed),
+                            )))) {
+                                log::error!("failed to send update action: {e}");
+                            }
+                        } else {
+                            break log::warn!("rss channel closed");
+                        }
+                    }
+                    _ = &mut quit_rx => {
+                        break;
+                    }
+                }
+            }
+            log::debug!("subscription finished");
+        });
+        Ok(())
+    }
+    fn deactivate(&mut self) -> Result<()> {
+        if let Some(tx) = self.quit.take() {
+            if tx.send(()).is_err() {
+                log::error!("failed to send quit signal");
+            }
+        }
+        self.watcher.unsubscribe();
+        Ok(())
+    }
+    fn update(&mut self, action: Action) -> Result<Option<Action>> {
+        match action {
+            Action::NextItem if !self.feed.items.is_empty() => {
+                self.state.select(Some(
+                    self.state
+                        .selected()
+                        .map(|s| (s + 1).min(self.feed.items.len() - 1))
+                        .unwrap_or_default(),
+                ));
+                return Ok(Some(Action::Render));
+            }
+            Action::PrevItem if !self.feed.items.is_empty() => {
+                self.state.select(Some(
+                    self.state
+                        .selected()
+                        .map(|s| s.max(1) - 1)
+                        .unwrap_or_default(),
+                ));
+                return Ok(Some(Action::Render));
+            }
+            Action::Enter | Action::Open => {
+                if let Some(link) = self
+                    .state
+                    .selected()
+                    .and_then(|i| self.feed.items.get(i))
+                    .and_then(|item| item.link.as_ref())
+                {
+                    open_url(link, self.browser_command.as_deref());
+                }
+            }
+            Action::Back => return Ok(Some(Action::Transition(Transition::Pop))),
+            Action::Refresh => {
+                self.watcher.refresh();
+            }
+            Action::Update(data) => {
+                let Data::Rss(feed) = data.as_ref() else {
+                    return Ok(None);
+                };
+                self.feed = feed.as_ref().clone();
+                if self.state.selected().is_none() && !self.feed.items.is_empty() {
+                    self.state.select(Some(0));
+                }
+                return Ok(Some(Action::Render));
+            }
+            _ => {}
+        }
+        Ok(None)
+    }
+    fn draw(&mut self, f: &mut Frame<'_>, area: Rect) -> Result<()> {
+        let title = if self.feed.title.is_empty() {
+            self.url.as_str()
+        } else {
+            self.feed.title.as_str()
+        };
+        let header = Paragraph::new(Line::from(vec![
+            Span::from(title).bold(),
+            Span::from(" [RSS]").light_red(),
+        ]))
+        .block(
+            Block::default()
+                .borders(Borders::BOTTOM)
+                .border_style(Color::Gray)
+                .padding(Padding::horizontal(1)),
+        );
+        let width = usize::from(area.width.saturating_sub(2));
+        let items = self
+            .feed
+            .items
+            .iter()
+            .map(|item| Text::from([Self::lines(item, width), vec![Line::default()]].concat()))
+            .collect::<Vec<_>>();
+
+        let layout =
+            Layout::vertical([Constraint::Length(2), Constraint::Percentage(100)]).split(area);
+        f.render_widget(header, layout[0]);
+        f.render_stateful_widget(
+            List::new(items)
+                .highlight_style(Style::default().reset().reversed())
+                .block(Block::default().padding(Padding::horizontal(1))),
+            layout[1],
+            &mut self.state,
+        );
+        Ok(())
+    }
+}
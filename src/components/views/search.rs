@@ -0,0 +1,283 @@
+use super::feed::{FeedViewComponent, ReplyParent};
+use super::types::{Action, Data, Transition, View};
+use super::ViewComponent;
+use crate::backend::client::BskyAgent;
+use crate::backend::search::{search_posts, SearchQuery, SearchResults};
+use crate::components::store::LocalStore;
+use crate::components::widgets::TextInput;
+use crate::config::{ReplyContext, TimestampFormat};
+use bsky_sdk::api::app::bsky::feed::defs::{FeedViewPost, FeedViewPostData};
+use color_eyre::Result;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::style::{Color, Style, Stylize};
+use ratatui::text::{Line, Span, Text};
+use ratatui::widgets::{Block, Borders, List, ListState, Padding, Paragraph};
+use ratatui::Frame;
+use std::sync::Arc;
+use tokio::sync::mpsc::UnboundedSender;
+
+pub struct SearchViewComponent {
+    query: SearchQuery,
+    // the text of the query while it is edited
+    input: Option<TextInput>,
+    items: Vec<FeedViewPost>,
+    cursor: Option<String>,
+    loading: bool,
+    state: ListState,
+    action_tx: UnboundedSender<Action>,
+    agent: Arc<BskyAgent>,
+    store: Arc<LocalStore>,
+}
+
+impl SearchViewComponent {
+    pub fn new(
+        action_tx: UnboundedSender<Action>,
+        agent: Arc<BskyAgent>,
+        query: SearchQuery,
+        store: Arc<LocalStore>,
+    ) -> Self {
+        // the query is typed first when there is nothing to search for
+        let input =
+            (query.text.is_empty() && query.author.is_none()).then(|| Self::new_input(&store));
+        Self {
+            query,
+            input,
+            items: Vec::new(),
+            cursor: None,
+            loading: false,
+            state: ListState::default(),
+            action_tx,
+            agent,
+            store,
+        }
+    }
+    fn load(&mut self, cursor: Option<String>) {
+        if self.loading {
+            return;
+        }
+        self.loading = true;
+        let (agent, tx) = (self.agent.clone(), self.action_tx.clone());
+        let query = self.query.clone();
+        tokio::spawn(async move {
+            match search_posts(&agent, &query, cursor.as_deref()).await {
+                Ok(results) => {
+                    let data = Data::SearchResults(Box::new((results, cursor.is_some())));
+                    if let Err(e) = tx.send(Action::Update(Box::new(data))) {
+                        log::error!("failed to send update action: {e}");
+                    }
+                }
+                Err(e) => {
+                    log::error!("failed to search posts: {e}");
+                }
+            }
+        });
+    }
+    fn new_input(store: &LocalStore) -> TextInput {
+        let mut input = TextInput::with_history(
+            store
+                .search_history
+                .with(|history| history.clone())
+                .unwrap_or_default(),
+        );
+        input.set_cursor_style(Style::default().reversed());
+        input
+    }
+    fn edit(&mut self) {
+        let mut input = Self::new_input(&self.store);
+        input.set_value(&self.query.text);
+        self.input = Some(input);
+    }
+    fn submit(&mut self) {
+        let Some(mut input) = self.input.take() else {
+            return;
+        };
+        input.push_history();
+        self.store
+            .search_history
+            .with(|history| *history = input.history().to_vec());
+        let text = input.value().trim().to_string();
+        if text == self.query.text && !self.items.is_empty() {
+            return;
+        }
+        self.query.text = text;
+        self.items.clear();
+        self.cursor = None;
+        self.state.select(None);
+        self.load(None);
+    }
+    fn len(&self) -> usize {
+        self.items.len() + usize::from(self.cursor.is_some())
+    }
+}
+
+impl ViewComponent for SearchViewComponent {
+    fn view(&self) -> View {
+        View::Search(Box::new(self.query.clone()))
+    }
+    fn activate(&mut self) -> Result<()> {
+        if self.items.is_empty() && self.input.is_none() {
+            self.load(None);
+        }
+        Ok(())
+    }
+    fn handle_key_events(&mut self, key: KeyEvent) -> Result<Option<Action>> {
+        if let Some(input) = self.input.as_mut() {
+            match key.code {
+                KeyCode::Enter => self.submit(),
+                KeyCode::Esc => self.input = None,
+                _ => {
+                    input.input(key);
+                }
+            }
+            // all the keys are consumed while the query is edited
+            return Ok(Some(Action::Render));
+        }
+        Ok(match (key.code, key.modifiers) {
+            (KeyCode::Char('/'), KeyModifiers::NONE) => {
+                self.edit();
+                Some(Action::Render)
+            }
+            _ => None,
+        })
+    }
+    fn update(&mut self, action: Action) -> Result<Option<Action>> {
+        match action {
+            Action::NextItem if self.len() > 0 => {
+                self.state.select(Some(
+                    self.state
+                        .selected()
+                        .map(|s| (s + 1).min(self.len() - 1))
+                        .unwrap_or_default(),
+                ));
+                return Ok(Some(Action::Render));
+            }
+            Action::PrevItem if self.len() > 0 => {
+                self.state.select(Some(
+                    self.state
+                        .selected()
+                        .map(|s| s.max(1) - 1)
+                        .unwrap_or_default(),
+                ));
+                return Ok(Some(Action::Render));
+            }
+            Action::Enter => {
+                let Some(index) = self.state.selected() else {
+                    return Ok(None);
+                };
+                if let Some(feed_view_post) = self.items.get(index) {
+                    return Ok(Some(Action::Transition(Transition::Push(Box::new(
+                        View::Conversation(feed_view_post.post.uri.clone()),
+                    )))));
+                }
+                // the last item is "Load more"
+                if self.cursor.is_some() {
+                    self.load(self.cursor.clone());
+                    return Ok(Some(Action::Render));
+                }
+            }
+            Action::Back => return Ok(Some(Action::Transition(Transition::Pop))),
+            Action::Refresh => {
+                self.load(None);
+            }
+            Action::Update(data) => {
+                let Data::SearchResults(boxed) = data.as_ref() else {
+                    return Ok(None);
+                };
+                let (SearchResults { posts, cursor }, append) = boxed.as_ref();
+                let feed_view_posts = posts.iter().map(|post_view| {
+                    FeedViewPost::from(FeedViewPostData {
+                        feed_context: None,
+                        post: post_view.clone(),
+                        reason: None,
+                        reply: None,
+                    })
+                });
+                if !append {
+                    self.items.clear();
+                }
+                self.items.extend(feed_view_posts);
+                self.cursor.clone_from(cursor);
+                self.loading = false;
+                if self.state.selected().is_none() && self.len() > 0 {
+                    self.state.select(Some(0));
+                }
+                return Ok(Some(Action::Render));
+            }
+            _ => {}
+        }
+        Ok(None)
+    }
+    fn draw(&mut self, f: &mut Frame<'_>, area: Rect) -> Result<()> {
+        let mut title = vec![Span::from("Search: ")];
+        if self.input.is_none() {
+            title.push(Span::from(self.query.text.as_str()).gray());
+        }
+        if let Some(author) = &self.query.author {
+            title.push(Span::from(format!(" by @{author}")).dim());
+        }
+        let header = Paragraph::new(Line::from(title)).bold().block(
+            Block::default()
+                .borders(Borders::BOTTOM)
+                .border_style(Color::Gray)
+                .padding(Padding::horizontal(1)),
+        );
+        let mut items = self
+            .items
+            .iter()
+            .filter_map(|feed_view_post| {
+                FeedViewComponent::lines(
+                    feed_view_post,
+                    area,
+                    false,
+                    false,
+                    false,
+                    None,
+                    ReplyParent::new(feed_view_post, ReplyContext::Auto, None),
+                    TimestampFormat::default(),
+                )
+            })
+            .map(Text::from)
+            .collect::<Vec<_>>();
+        if self.cursor.is_some() {
+            items.push(
+                Text::from(if self.loading {
+                    "Loading..."
+                } else {
+                    "Load more"
+                })
+                .blue(),
+            );
+        } else if self.items.is_empty() && !self.loading {
+            items.push(Text::from("No results").dim());
+        }
+
+        let layout =
+            Layout::vertical([Constraint::Length(2), Constraint::Percentage(100)]).split(area);
+        f.render_widget(header, layout[0]);
+        if let Some(input) = &self.input {
+            // after the padding and the "Search: " label
+            let [_, input_area, _] = Layout::horizontal([
+                Constraint::Length(9),
+                Constraint::Fill(1),
+                Constraint::Length(1),
+            ])
+            .areas(layout[0]);
+            f.render_widget(
+                input,
+                Rect {
+                    height: 1,
+                    ..input_area
+                },
+            );
+        }
+        f.render_stateful_widget(
+            List::new(items)
+                .highlight_style(Style::default().reset().reversed())
+                .block(Block::default().padding(Padding::horizontal(1))),
+            layout[1],
+            &mut self.state,
+        );
+        Ok(())
+    }
+}
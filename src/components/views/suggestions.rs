@@ -0,0 +1,287 @@
+use super::types::{Action, Data, Transition, View};
+use super::utils::profile_name;
+use super::ViewComponent;
+use crate::backend::client::BskyAgent;
+use crate::backend::graph::{suggestions, Suggestions};
+use crate::components::store::LocalStore;
+use bsky_sdk::api;
+use bsky_sdk::api::app::bsky::actor::defs::ProfileView;
+use bsky_sdk::api::types::string::{Datetime, Did};
+use color_eyre::Result;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::style::{Color, Style, Stylize};
+use ratatui::text::{Line, Span, Text};
+use ratatui::widgets::{Block, Borders, List, ListState, Padding, Paragraph};
+use ratatui::Frame;
+use std::collections::HashSet;
+use std::sync::Arc;
+use tokio::sync::mpsc::UnboundedSender;
+
+/// The accounts suggested to follow, which can be followed or dismissed inline.
+pub struct SuggestionsViewComponent {
+    items: Vec<ProfileView>,
+    cursor: Option<String>,
+    // followed from this view, not reflected in the viewer states of the items
+    followed: HashSet<Did>,
+    loading: bool,
+    state: ListState,
+    action_tx: UnboundedSender<Action>,
+    agent: Arc<BskyAgent>,
+    store: Arc<LocalStore>,
+}
+
+impl SuggestionsViewComponent {
+    pub fn new(
+        action_tx: UnboundedSender<Action>,
+        agent: Arc<BskyAgent>,
+        store: Arc<LocalStore>,
+    ) -> Self {
+        Self {
+            items: Vec::new(),
+            cursor: None,
+            followed: HashSet::new(),
+            loading: false,
+            state: ListState::default(),
+            action_tx,
+            agent,
+            store,
+        }
+    }
+    fn load(&mut self, cursor: Option<String>) {
+        if self.loading {
+            return;
+        }
+        self.loading = true;
+        let (agent, tx) = (self.agent.clone(), self.action_tx.clone());
+        tokio::spawn(async move {
+            let append = cursor.is_some();
+            match suggestions(&agent, cursor).await {
+                Ok(suggestions) => {
+                    let data = Data::Suggestions(Box::new((suggestions, append)));
+                    if let Err(e) = tx.send(Action::Update(Box::new(data))) {
+                        log::error!("failed to send update action: {e}");
+                    }
+                }
+                Err(e) => {
+                    log::error!("failed to get suggestions: {e}");
+                }
+            }
+        });
+    }
+    fn set_items(&mut self, Suggestions { actors, cursor }: Suggestions, append: bool) {
+        if !append {
+            self.items.clear();
+        }
+        self.store.suggestions.with(|cache| {
+            self.items.extend(
+                actors
+                    .into_iter()
+                    .filter(|profile| !cache.is_dismissed(profile.did.as_str())),
+            );
+        });
+        self.cursor = cursor;
+        if self.state.selected().is_none() && self.len() > 0 {
+            self.state.select(Some(0));
+        }
+    }
+    fn len(&self) -> usize {
+        self.items.len() + usize::from(self.cursor.is_some())
+    }
+    fn selected(&self) -> Option<&ProfileView> {
+        self.state
+            .selected()
+            .and_then(|index| self.items.get(index))
+    }
+    fn follow(&mut self) -> Option<Action> {
+        let profile = self.selected()?;
+        let following = profile
+            .viewer
+            .as_ref()
+            .is_some_and(|viewer| viewer.following.is_some());
+        if following || self.followed.contains(&profile.did) {
+            return None;
+        }
+        let did = profile.did.clone();
+        self.followed.insert(did.clone());
+        let record_data = api::app::bsky::graph::follow::RecordData {
+            created_at: Datetime::now(),
+            subject: did,
+        };
+        let agent = self.agent.clone();
+        tokio::spawn(async move {
+            match agent.create_record(record_data).await {
+                Ok(output) => log::info!("created follow record: {}", output.cid.as_ref()),
+                Err(e) => log::error!("failed to create follow record: {e}"),
+            }
+        });
+        Some(Action::Render)
+    }
+    fn dismiss(&mut self) -> Option<Action> {
+        let index = self.state.selected().filter(|&i| i < self.items.len())?;
+        let profile = self.items.remove(index);
+        self.store
+            .suggestions
+            .with(|cache| cache.dismiss(profile.did.as_str()));
+        self.cache(true);
+        if self.len() == 0 {
+            self.state.select(None);
+        } else {
+            self.state.select(Some(index.min(self.len() - 1)));
+        }
+        Some(Action::Render)
+    }
+    fn cache(&self, append: bool) {
+        let suggestions = Suggestions {
+            actors: self.items.clone(),
+            cursor: self.cursor.clone(),
+        };
+        self.store
+            .suggestions
+            .with(|cache| cache.cache(suggestions, append));
+    }
+}
+
+impl ViewComponent for SuggestionsViewComponent {
+    fn view(&self) -> View {
+        View::Suggestions
+    }
+    fn activate(&mut self) -> Result<()> {
+        if self.items.is_empty() {
+            // fetched again once a week, or with refresh
+            match self
+                .store
+                .suggestions
+                .with(|cache| cache.cached())
+                .flatten()
+            {
+                Some(suggestions) => self.set_items(suggestions, false),
+                None => self.load(None),
+            }
+        }
+        Ok(())
+    }
+    fn handle_key_events(&mut self, key: KeyEvent) -> Result<Option<Action>> {
+        if key.modifiers != KeyModifiers::NONE {
+            return Ok(None);
+        }
+        Ok(match key.code {
+            KeyCode::Char('f') => self.follow(),
+            KeyCode::Char('x') => self.dismiss(),
+            _ => None,
+        })
+    }
+    fn update(&mut self, action: Action) -> Result<Option<Action>> {
+        match action {
+            Action::NextItem if self.len() > 0 => {
+                self.state.select(Some(
+                    self.state
+                        .selected()
+                        .map(|s| (s + 1).min(self.len() - 1))
+                        .unwrap_or_default(),
+                ));
+                return Ok(Some(Action::Render));
+            }
+            Action::PrevItem if self.len() > 0 => {
+                self.state.select(Some(
+                    self.state
+                        .selected()
+                        .map(|s| s.max(1) - 1)
+                        .unwrap_or_default(),
+                ));
+                return Ok(Some(Action::Render));
+            }
+            // the last item is "Load more"
+            Action::Enter
+                if self.cursor.is_some() && self.state.selected() == Some(self.items.len()) =>
+            {
+                self.load(self.cursor.clone());
+                return Ok(Some(Action::Render));
+            }
+            Action::Back => return Ok(Some(Action::Transition(Transition::Pop))),
+            Action::Refresh => {
+                self.load(None);
+            }
+            Action::Update(data) => {
+                let Data::Suggestions(boxed) = data.as_ref() else {
+                    return Ok(None);
+                };
+                let (suggestions, append) = boxed.as_ref().clone();
+                self.set_items(suggestions, append);
+                self.loading = false;
+                self.cache(append);
+                return Ok(Some(Action::Render));
+            }
+            _ => {}
+        }
+        Ok(None)
+    }
+    fn draw(&mut self, f: &mut Frame<'_>, area: Rect) -> Result<()> {
+        let header = Paragraph::new("Who to follow").bold().block(
+            Block::default()
+                .borders(Borders::BOTTOM)
+                .border_style(Color::Gray)
+                .padding(Padding::horizontal(1)),
+        );
+        let mut items = self
+            .items
+            .iter()
+            .map(|profile| {
+                let mut name = profile_name(profile);
+                let following = self.followed.contains(&profile.did)
+                    || profile
+                        .viewer
+                        .as_ref()
+                        .is_some_and(|viewer| viewer.following.is_some());
+                if following {
+                    name.push(Span::from(" ✓ Following").green());
+                }
+                let mut lines = vec![Line::from(name)];
+                if let Some(description) = profile.description.as_ref().filter(|s| !s.is_empty()) {
+                    lines.push(Line::from(format!("  {}", description.replace('\n', " "))).dim());
+                }
+                let known = profile
+                    .viewer
+                    .as_ref()
+                    .and_then(|viewer| viewer.known_followers.as_ref())
+                    .map_or(0, |known_followers| known_followers.count);
+                if known > 0 {
+                    lines.push(Line::from(format!("  👥 {known} followers you know")).gray());
+                }
+                Text::from(lines)
+            })
+            .collect::<Vec<_>>();
+        if self.cursor.is_some() {
+            items.push(
+                Text::from(if self.loading {
+                    "Loading..."
+                } else {
+                    "Load more"
+                })
+                .blue(),
+            );
+        } else if self.items.is_empty() && !self.loading {
+            items.push(Text::from("No suggestions").dim());
+        }
+
+        let layout = Layout::vertical([
+            Constraint::Length(2),
+            Constraint::Percentage(100),
+            Constraint::Length(1),
+        ])
+        .split(area);
+        f.render_widget(header, layout[0]);
+        f.render_stateful_widget(
+            List::new(items)
+                .highlight_style(Style::default().reset().reversed())
+                .block(Block::default().padding(Padding::horizontal(1))),
+            layout[1],
+            &mut self.state,
+        );
+        f.render_widget(
+            Line::from("f: follow, x: dismiss").dim().centered(),
+            layout[2],
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,261 @@
+use super::utils::link_domain;
+use ratatui::style::{Color, Style, Stylize};
+use ratatui::text::{Line, Span};
+use textwrap::core::display_width;
+use textwrap::Options;
+
+const INLINE_CODE: Style = Style::new().bg(Color::DarkGray).fg(Color::Cyan);
+/// The number of lines of the description shown in a link card.
+const CARD_DESCRIPTION_LINES: usize = 2;
+
+/// Wrap the text of a post, highlighting `inline code` and rendering ```` ``` ```` fences as code blocks.
+pub fn text_lines(text: &str, width: usize, indent: &str) -> Vec<Line<'static>> {
+    let segments = text.split("```").collect::<Vec<_>>();
+    // an even number of segments means that the last fence is not closed
+    let closed = segments.len() % 2 == 1;
+    let mut lines = Vec::new();
+    for (i, segment) in segments.iter().enumerate() {
+        if i % 2 == 0 {
+            lines.extend(inline_lines(segment.trim_matches('\n'), width, indent));
+        } else if closed || i < segments.len() - 1 {
+            lines.extend(code_block_lines(segment, width, indent));
+        } else {
+            // shown as it is, as the backticks are not the ones of inline code either
+            lines.extend(
+                textwrap::wrap(&format!("```{segment}"), wrap_options(width, indent))
+                    .into_iter()
+                    .map(|line| Line::from(line.into_owned())),
+            );
+        }
+    }
+    lines
+}
+
+fn wrap_options<'a>(width: usize, indent: &'a str) -> Options<'a> {
+    Options::new(width)
+        .initial_indent(indent)
+        .subsequent_indent(indent)
+}
+
+fn inline_lines(text: &str, width: usize, indent: &str) -> Vec<Line<'static>> {
+    if text.is_empty() {
+        return Vec::new();
+    }
+    // the code span may continue over wrapped lines
+    let mut in_code = false;
+    textwrap::wrap(text, wrap_options(width, indent))
+        .iter()
+        .map(|line| {
+            let mut spans = Vec::new();
+            for (i, part) in line.split('`').enumerate() {
+                if i > 0 {
+                    in_code = !in_code;
+                }
+                if part.is_empty() {
+                    continue;
+                }
+                spans.push(if in_code {
+                    Span::styled(part.to_string(), INLINE_CODE)
+                } else {
+                    Span::from(part.to_string())
+                });
+            }
+            Line::from(spans)
+        })
+        .collect()
+}
+
+/// Render the link card of an external embed as a box, like the web clients do: the title, the
+/// description in up to [`CARD_DESCRIPTION_LINES`] lines, and the domain of the link.
+pub fn link_card_lines(
+    title: &str,
+    description: &str,
+    uri: &str,
+    width: usize,
+    indent: &str,
+) -> Vec<Line<'static>> {
+    let inner = width.saturating_sub(display_width(indent) + 4).max(1);
+    let row = |span: Span<'static>| {
+        let pad = inner.saturating_sub(display_width(&span.content));
+        Line::from(vec![
+            Span::from(format!("{indent}│ ")).dark_gray(),
+            span,
+            Span::from(" ".repeat(pad)),
+            Span::from(" │").dark_gray(),
+        ])
+    };
+    let mut lines = vec![Line::from(format!("{indent}╭{}╮", "─".repeat(inner + 2))).dark_gray()];
+    if !title.is_empty() {
+        lines.push(row(Span::from(truncate(title, inner)).bold()));
+    }
+    let description = textwrap::wrap(description, inner);
+    for (i, line) in description.iter().take(CARD_DESCRIPTION_LINES).enumerate() {
+        lines.push(row(Span::from(
+            if i == CARD_DESCRIPTION_LINES - 1 && description.len() > CARD_DESCRIPTION_LINES {
+                truncate(&format!("{line}…"), inner)
+            } else {
+                line.to_string()
+            },
+        )));
+    }
+    lines.push(row(Span::from(truncate(link_domain(uri), inner)).gray()));
+    lines.push(Line::from(format!("{indent}╰{}╯", "─".repeat(inner + 2))).dark_gray());
+    lines
+}
+
+/// Cut the text to a single line of `width`, ending with `…` if anything is cut.
+fn truncate(text: &str, width: usize) -> String {
+    let text = text.replace('\n', " ");
+    if display_width(&text) <= width {
+        return text;
+    }
+    let mut truncated = String::new();
+    for c in text.chars() {
+        if display_width(&truncated) + display_width(c.encode_utf8(&mut [0; 4])) + 1 > width {
+            break;
+        }
+        truncated.push(c);
+    }
+    truncated.push('…');
+    truncated
+}
+
+fn code_block_lines(segment: &str, width: usize, indent: &str) -> Vec<Line<'static>> {
+    let (lang, code) = match segment.split_once('\n') {
+        Some((lang, code)) if !lang.trim().contains(' ') => (lang.trim(), code),
+        _ => ("", segment),
+    };
+    let code = code.trim_end_matches('\n');
+    let rule = |prefix: &str, title: &str| {
+        let len = width.saturating_sub(
+            indent.chars().count() + prefix.chars().count() + title.chars().count(),
+        );
+        Line::from(format!("{indent}{prefix}{title}{}", "─".repeat(len))).dark_gray()
+    };
+    let mut lines = vec![rule(
+        "┌─",
+        &if lang.is_empty() {
+            String::new()
+        } else {
+            format!(" {lang} ")
+        },
+    )];
+    let highlighted = highlight(code, lang).unwrap_or_else(|| {
+        code.lines()
+            .map(|line| vec![Span::from(line.to_string()).cyan()])
+            .collect()
+    });
+    lines.extend(highlighted.into_iter().map(|spans| {
+        Line::from([vec![Span::from(format!("{indent}│ ")).dark_gray()], spans].concat())
+    }));
+    lines.push(rule("└", ""));
+    lines
+}
+
+#[cfg(feature = "syntect")]
+fn highlight(code: &str, lang: &str) -> Option<Vec<Vec<Span<'static>>>> {
+    use std::sync::OnceLock;
+    use syntect::easy::HighlightLines;
+    use syntect::highlighting::ThemeSet;
+    use syntect::parsing::SyntaxSet;
+    use syntect::util::LinesWithEndings;
+
+    static SYNTAX_SET: OnceLock<SyntaxSet> = OnceLock::new();
+    static THEME_SET: OnceLock<ThemeSet> = OnceLock::new();
+
+    if lang.is_empty() {
+        return None;
+    }
+    let syntax_set = SYNTAX_SET.get_or_init(SyntaxSet::load_defaults_newlines);
+    let theme_set = THEME_SET.get_or_init(ThemeSet::load_defaults);
+    let syntax = syntax_set.find_syntax_by_token(lang)?;
+    let mut highlighter = HighlightLines::new(syntax, theme_set.themes.get("base16-ocean.dark")?);
+    LinesWithEndings::from(code)
+        .map(|line| {
+            let ranges = highlighter.highlight_line(line, syntax_set).ok()?;
+            Some(
+                ranges
+                    .into_iter()
+                    .map(|(style, s)| {
+                        let fg = style.foreground;
+                        Span::styled(
+                            s.trim_end_matches('\n').to_string(),
+                            Style::default().fg(Color::Rgb(fg.r, fg.g, fg.b)),
+                        )
+                    })
+                    .collect(),
+            )
+        })
+        .collect()
+}
+
+#[cfg(not(feature = "syntect"))]
+fn highlight(_code: &str, _lang: &str) -> Option<Vec<Vec<Span<'static>>>> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn strings(lines: &[Line<'_>]) -> Vec<String> {
+        lines.iter().map(ToString::to_string).collect()
+    }
+
+    #[test]
+    fn inline_code() {
+        let lines = text_lines("run `cargo test` now", 80, "");
+        assert_eq!(strings(&lines), ["run cargo test now"]);
+        assert_eq!(lines[0].spans[1].content, "cargo test");
+        assert_eq!(lines[0].spans[1].style, INLINE_CODE);
+    }
+
+    #[test]
+    fn code_blocks() {
+        assert_eq!(
+            strings(&text_lines("before\n```\nlet a = 1;\n```\nafter", 10, "")),
+            [
+                "before",
+                "┌─────────",
+                "│ let a = 1;",
+                "└─────────",
+                "after"
+            ]
+        );
+        assert_eq!(
+            strings(&text_lines("```text\na\n```", 12, "")),
+            ["┌─ text ────", "│ a", "└───────────"]
+        );
+        // not closed
+        assert_eq!(strings(&text_lines("a ```b", 80, "")), ["a", "```b"]);
+    }
+
+    #[test]
+    fn truncate_to_width() {
+        assert_eq!(truncate("abc", 3), "abc");
+        assert_eq!(truncate("abcd", 3), "ab…");
+        assert_eq!(truncate("あいう", 5), "あい…");
+        assert_eq!(truncate("a\nb", 3), "a b");
+    }
+
+    #[test]
+    fn link_card() {
+        assert_eq!(
+            strings(&link_card_lines(
+                "Title",
+                "one two three four five six",
+                "https://www.example.com/page",
+                16,
+                ""
+            )),
+            [
+                "╭──────────────╮",
+                "│ Title        │",
+                "│ one two      │",
+                "│ three four…  │",
+                "│ example.com  │",
+                "╰──────────────╯",
+            ]
+        );
+    }
+}
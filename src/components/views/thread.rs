@@ -0,0 +1,366 @@
+use super::types::{Action, Data, Transition, View};
+use super::utils::profile_name;
+use super::ViewComponent;
+use crate::backend::types::FeedSourceInfo;
+use crate::backend::{Watch, Watcher};
+use bsky_sdk::api::app::bsky::feed::defs::{PostView, ThreadViewPost, ThreadViewPostRepliesItem};
+use bsky_sdk::api::app::bsky::feed::get_post_thread::OutputThreadRefs;
+use bsky_sdk::api::records::{KnownRecord, Record};
+use bsky_sdk::api::types::Union;
+use color_eyre::Result;
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::style::{Color, Style, Stylize};
+use ratatui::text::{Line, Span, Text};
+use ratatui::widgets::{Block, Borders, List, ListState, Padding, Paragraph};
+use ratatui::Frame;
+use std::collections::HashSet;
+use std::sync::Arc;
+use tokio::sync::mpsc::UnboundedSender;
+use tokio::sync::oneshot;
+
+struct Node<'a> {
+    thread: &'a ThreadViewPost,
+    parent: Option<&'a PostView>,
+    line: Line<'a>,
+    indent: String,
+}
+
+pub struct ConversationViewComponent {
+    uri: String,
+    thread: Option<ThreadViewPost>,
+    collapsed: HashSet<String>,
+    state: ListState,
+    action_tx: UnboundedSender<Action>,
+    watcher: Box<dyn Watch<Output = Union<OutputThreadRefs>>>,
+    quit: Option<oneshot::Sender<()>>,
+}
+
+impl ConversationViewComponent {
+    pub fn new(action_tx: UnboundedSender<Action>, watcher: Arc<Watcher>, uri: String) -> Self {
+        let watcher = Box::new(watcher.post_thread(uri.clone()));
+        Self {
+            uri,
+            thread: None,
+            collapsed: HashSet::new(),
+            state: ListState::default(),
+            action_tx,
+            watcher,
+            quit: None,
+        }
+    }
+}
+
+fn nodes<'a>(thread: Option<&'a ThreadViewPost>, collapsed: &HashSet<String>) -> Vec<Node<'a>> {
+    let mut nodes = Vec::new();
+    if let Some(thread) = thread {
+        render_tree(thread, None, "", None, collapsed, &mut nodes);
+    }
+    nodes
+}
+
+fn replies(thread: &ThreadViewPost) -> Vec<&ThreadViewPost> {
+    thread
+        .replies
+        .iter()
+        .flatten()
+        .filter_map(|reply| match reply {
+            Union::Refs(ThreadViewPostRepliesItem::ThreadViewPost(thread)) => Some(thread.as_ref()),
+            _ => None,
+        })
+        .collect()
+}
+
+fn count_branches(thread: &ThreadViewPost) -> usize {
+    replies(thread)
+        .into_iter()
+        .map(|reply| 1 + count_branches(reply))
+        .sum()
+}
+
+/// Traverse the replies of `thread` recursively, flattening them into `nodes`.
+///
+/// `last` is `None` for the root of the tree, otherwise it tells whether the node is the last child of its parent.
+fn render_tree<'a>(
+    thread: &'a ThreadViewPost,
+    parent: Option<&'a PostView>,
+    prefix: &str,
+    last: Option<bool>,
+    collapsed: &HashSet<String>,
+    nodes: &mut Vec<Node<'a>>,
+) {
+    let (connector, child_prefix) = match last {
+        None => ("", prefix.to_string()),
+        Some(false) => ("├─ ", format!("{prefix}│ ")),
+        Some(true) => ("└─ ", format!("{prefix}  ")),
+    };
+    let children = replies(thread);
+    let is_collapsed = collapsed.contains(&thread.post.uri);
+    let mut spans = [
+        vec![Span::from(format!("{prefix}{connector}")).gray()],
+        profile_name(&thread.post.author),
+    ]
+    .concat();
+    if is_collapsed && !children.is_empty() {
+        spans.push(Span::from(" "));
+        spans.push(format!("[+{}]", count_branches(thread)).cyan());
+    }
+    let indent = if is_collapsed || children.is_empty() {
+        format!("{child_prefix}   ")
+    } else {
+        format!("{child_prefix}│  ")
+    };
+    nodes.push(Node {
+        thread,
+        parent,
+        line: Line::from(spans),
+        indent,
+    });
+    if is_collapsed {
+        return;
+    }
+    let len = children.len();
+    for (i, child) in children.into_iter().enumerate() {
+        render_tree(
+            child,
+            Some(&thread.post),
+            &child_prefix,
+            Some(i + 1 == len),
+            collapsed,
+            nodes,
+        );
+    }
+}
+
+impl ViewComponent for ConversationViewComponent {
+    fn view(&self) -> View {
+        View::Conversation(self.uri.clone())
+    }
+    fn activate(&mut self) -> Result<()> {
+        let (tx, mut rx) = (self.action_tx.clone(), self.watcher.subscribe());
+        let (quit_tx, mut quit_rx) = oneshot::channel();
+        self.quit = Some(quit_tx);
+        tokio::spawn(async move {
+            loop {
+                tokio::select! {
+                    changed = rx.changed() => {
+                        if changed.is_ok() {
+                            if let Err(e) = tx.send(Action::Update(Box::new(Data::PostThread(
+                                rx.borrow_and_update().clone(),
+                            )))) {
+                                log::error!("failed to send update action: {e}");
+                            }
+                        } else {
+                            break log::warn!("post thread channel closed");
+                        }
+                    }
+                    _ = &mut quit_rx => {
+                        break;
+                    }
+                }
+            }
+            log::debug!("subscription finished");
+        });
+        Ok(())
+    }
+    fn deactivate(&mut self) -> Result<()> {
+        if let Some(tx) = self.quit.take() {
+            if tx.send(()).is_err() {
+                log::error!("failed to send quit signal");
+            }
+        }
+        self.watcher.unsubscribe();
+        Ok(())
+    }
+    fn update(&mut self, action: Action) -> Result<Option<Action>> {
+        match action {
+            Action::NextItem if self.thread.is_some() => {
+                let len = nodes(self.thread.as_ref(), &self.collapsed).len();
+                self.state.select(Some(
+                    self.state
+                        .selected()
+                        .map(|s| (s + 1).min(len - 1))
+                        .unwrap_or_default(),
+                ));
+                return Ok(Some(Action::Render));
+            }
+            Action::PrevItem if self.thread.is_some() => {
+                self.state.select(Some(
+                    self.state
+                        .selected()
+                        .map(|s| s.max(1) - 1)
+                        .unwrap_or_default(),
+                ));
+                return Ok(Some(Action::Render));
+            }
+            Action::Toggle => {
+                let uri = self.state.selected().and_then(|i| {
+                    nodes(self.thread.as_ref(), &self.collapsed)
+                        .get(i)
+                        .map(|node| node.thread.post.uri.clone())
+                });
+                if let Some(uri) = uri {
+                    if !self.collapsed.remove(&uri) {
+                        self.collapsed.insert(uri);
+                    }
+                    return Ok(Some(Action::Render));
+                }
+            }
+            Action::Enter => {
+                let nodes = nodes(self.thread.as_ref(), &self.collapsed);
+                if let Some(node) = self.state.selected().and_then(|i| nodes.get(i)) {
+                    return Ok(Some(Action::Transition(Transition::Push(Box::new(
+                        View::Post(Box::new((node.thread.post.clone(), node.parent.cloned()))),
+                    )))));
+                }
+            }
+            Action::Note => {
+                let nodes = nodes(self.thread.as_ref(), &self.collapsed);
+                if let Some(node) = self.state.selected().and_then(|i| nodes.get(i)) {
+                    return Ok(Some(Action::AddNote(Box::new(node.thread.post.clone()))));
+                }
+            }
+            Action::Quotes => {
+                let nodes = nodes(self.thread.as_ref(), &self.collapsed);
+                if let Some(node) = self.state.selected().and_then(|i| nodes.get(i)) {
+                    return Ok(Some(Action::Transition(Transition::Push(Box::new(
+                        View::Quotes(Box::new(node.thread.post.clone())),
+                    )))));
+                }
+            }
+            Action::PinConversation => {
+                let nodes = nodes(self.thread.as_ref(), &self.collapsed);
+                let uri = self
+                    .state
+                    .selected()
+                    .and_then(|i| nodes.get(i))
+                    .map_or_else(|| self.uri.clone(), |node| node.thread.post.uri.clone());
+                return Ok(Some(Action::Transition(Transition::Push(Box::new(
+                    View::Feed(Box::new(FeedSourceInfo::Conversation(uri))),
+                )))));
+            }
+            Action::Back => return Ok(Some(Action::Transition(Transition::Pop))),
+            Action::Refresh => {
+                self.watcher.refresh();
+            }
+            Action::Update(data) => {
+                let Data::PostThread(Union::Refs(OutputThreadRefs::AppBskyFeedDefsThreadViewPost(
+                    thread_view,
+                ))) = data.as_ref()
+                else {
+                    return Ok(None);
+                };
+                self.thread = Some(thread_view.as_ref().clone());
+                let len = nodes(self.thread.as_ref(), &self.collapsed).len();
+                self.state.select(match self.state.selected() {
+                    Some(s) => Some(s.min(len.max(1) - 1)),
+                    None => Some(0),
+                });
+                return Ok(Some(Action::Render));
+            }
+            _ => {}
+        }
+        Ok(None)
+    }
+    fn draw(&mut self, f: &mut Frame<'_>, area: Rect) -> Result<()> {
+        let header = Paragraph::new("Conversation").bold().block(
+            Block::default()
+                .borders(Borders::BOTTOM)
+                .border_style(Color::Gray)
+                .padding(Padding::horizontal(1)),
+        );
+        let width = usize::from(area.width).saturating_sub(2);
+        let items = nodes(self.thread.as_ref(), &self.collapsed)
+            .into_iter()
+            .map(|node| {
+                let mut lines = vec![node.line];
+                if let Record::Known(KnownRecord::AppBskyFeedPost(record)) =
+                    &node.thread.post.record
+                {
+                    let indent = node.indent.chars().count();
+                    lines.extend(
+                        textwrap::wrap(&record.text, width.saturating_sub(indent).max(1))
+                            .iter()
+                            .map(|s| {
+                                Line::from(vec![
+                                    Span::from(node.indent.clone()).gray(),
+                                    Span::from(s.to_string()),
+                                ])
+                            }),
+                    );
+                }
+                Text::from(lines)
+            })
+            .collect::<Vec<_>>();
+
+        let layout =
+            Layout::vertical([Constraint::Length(2), Constraint::Percentage(100)]).split(area);
+        f.render_widget(header, layout[0]);
+        f.render_stateful_widget(
+            List::new(items)
+                .highlight_style(Style::default().reset().reversed())
+                .block(Block::default().padding(Padding::horizontal(1))),
+            layout[1],
+            &mut self.state,
+        );
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::{json, Value};
+
+    fn thread(rkey: &str, replies: Vec<Value>) -> Value {
+        json!({
+            "$type": "app.bsky.feed.defs#threadViewPost",
+            "post": {
+                "author": { "did": "did:plc:alice", "handle": format!("{rkey}.test") },
+                "cid": "bafyreidfayvfuwqa7qlnopdjiqrxzs6blmoeu4rujcjtnci5beludirz2a",
+                "indexedAt": "2024-09-10T12:00:00Z",
+                "record": {
+                    "$type": "app.bsky.feed.post",
+                    "text": rkey,
+                    "createdAt": "2024-09-10T12:00:00Z",
+                },
+                "uri": format!("at://did:plc:alice/app.bsky.feed.post/{rkey}"),
+            },
+            "replies": replies,
+        })
+    }
+
+    #[test]
+    fn render_replies_as_tree() {
+        let thread = serde_json::from_value::<ThreadViewPost>(thread(
+            "root",
+            vec![
+                thread("a", vec![thread("a1", vec![]), thread("a2", vec![])]),
+                thread("b", vec![]),
+            ],
+        ))
+        .expect("invalid thread");
+        assert_eq!(count_branches(&thread), 4);
+        let lines = |collapsed: &HashSet<String>| {
+            nodes(Some(&thread), collapsed)
+                .iter()
+                .map(|node| node.line.to_string())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            lines(&HashSet::new()),
+            [
+                "@root.test",
+                "├─ @a.test",
+                "│ ├─ @a1.test",
+                "│ └─ @a2.test",
+                "└─ @b.test",
+            ]
+        );
+        let collapsed = HashSet::from([String::from("at://did:plc:alice/app.bsky.feed.post/a")]);
+        assert_eq!(
+            lines(&collapsed),
+            ["@root.test", "├─ @a.test [+2]", "└─ @b.test"]
+        );
+        assert!(nodes(None, &collapsed).is_empty());
+    }
+}
@@ -1,13 +1,24 @@
-use crate::backend::types::{FeedSourceInfo, PinnedFeed};
-use bsky_sdk::api::app::bsky::feed::defs::{FeedViewPost, PostView, ViewerState};
+use crate::backend::analytics::PostAnalytics;
+use crate::backend::client::BskyAgent;
+use crate::backend::discover::PopularFeeds;
+use crate::backend::graph::Suggestions;
+use crate::backend::quotes::Quotes;
+use crate::backend::rss::RssFeed;
+use crate::backend::search::{SearchQuery, SearchResults};
+use crate::backend::types::{FeedGeneratorDetail, FeedSourceInfo, LabelerSettings, PinnedFeed};
+use bsky_sdk::api::app::bsky::actor::defs::{MutedWord, ProfileView, ProfileViewBasic};
+use bsky_sdk::api::app::bsky::feed::defs::{FeedViewPost, GeneratorView, PostView, ViewerState};
 use bsky_sdk::api::app::bsky::feed::get_post_thread::OutputThreadRefs;
+use bsky_sdk::api::app::bsky::graph::defs::ListView;
 use bsky_sdk::api::types::Union;
-use bsky_sdk::BskyAgent;
+use chrono::NaiveDate;
+use std::collections::BTreeMap;
 use std::fmt::{Debug, Formatter, Result};
 
 #[derive(Clone)]
 pub enum Action {
     Render,
+    Tick(usize),
     NextItem,
     PrevItem,
     Enter,
@@ -15,6 +26,57 @@ pub enum Action {
     Refresh,
     NewPost,
     Menu,
+    Tack,
+    Untack,
+    Toggle,
+    FocusMode,
+    Info,
+    Feeds,
+    Open,
+    Feedback,
+    Delete,
+    Quotes,
+    Metrics,
+    Media,
+    Expand,
+    Analytics,
+    Compact,
+    Header,
+    Increase,
+    Decrease,
+    Live,
+    Command,
+    Note,
+    AddNote(Box<PostView>),
+    Translate,
+    Mutuals,
+    SearchAuthor,
+    Diff,
+    Comment,
+    Calendar,
+    MarkAllRead,
+    NotInterested,
+    WordCloud,
+    Export,
+    GroupThreads,
+    NextByAuthor,
+    PrevByAuthor,
+    MaxAge,
+    FocusAuthor,
+    ExternalLinks,
+    TimestampFormat,
+    BlockDomain,
+    FeedStats,
+    CompareAnchor,
+    PdsRecord,
+    RawRecord,
+    Repliers,
+    PinConversation,
+    CopyCid,
+    TagPost,
+    Visual,
+    FilterDate(Option<NaiveDate>),
+    FilterTag(Option<String>),
     Login(Box<BskyAgent>),
     Logout,
     Update(Box<Data>),
@@ -25,6 +87,7 @@ impl Debug for Action {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
         match self {
             Action::Render => write!(f, "Render"),
+            Action::Tick(i) => f.debug_tuple("Tick").field(i).finish(),
             Action::NextItem => write!(f, "NextItem"),
             Action::PrevItem => write!(f, "PrevItem"),
             Action::Enter => write!(f, "Enter"),
@@ -32,6 +95,57 @@ impl Debug for Action {
             Action::Refresh => write!(f, "Refresh"),
             Action::NewPost => write!(f, "NewPost"),
             Action::Menu => write!(f, "Menu"),
+            Action::Tack => write!(f, "Tack"),
+            Action::Untack => write!(f, "Untack"),
+            Action::Toggle => write!(f, "Toggle"),
+            Action::FocusMode => write!(f, "FocusMode"),
+            Action::Info => write!(f, "Info"),
+            Action::Feeds => write!(f, "Feeds"),
+            Action::Open => write!(f, "Open"),
+            Action::Feedback => write!(f, "Feedback"),
+            Action::Delete => write!(f, "Delete"),
+            Action::Quotes => write!(f, "Quotes"),
+            Action::Metrics => write!(f, "Metrics"),
+            Action::Media => write!(f, "Media"),
+            Action::Expand => write!(f, "Expand"),
+            Action::Analytics => write!(f, "Analytics"),
+            Action::Compact => write!(f, "Compact"),
+            Action::Header => write!(f, "Header"),
+            Action::Increase => write!(f, "Increase"),
+            Action::Decrease => write!(f, "Decrease"),
+            Action::Live => write!(f, "Live"),
+            Action::Command => write!(f, "Command"),
+            Action::Note => write!(f, "Note"),
+            Action::Translate => write!(f, "Translate"),
+            Action::Mutuals => write!(f, "Mutuals"),
+            Action::SearchAuthor => write!(f, "SearchAuthor"),
+            Action::Diff => write!(f, "Diff"),
+            Action::Comment => write!(f, "Comment"),
+            Action::Calendar => write!(f, "Calendar"),
+            Action::MarkAllRead => write!(f, "MarkAllRead"),
+            Action::NotInterested => write!(f, "NotInterested"),
+            Action::WordCloud => write!(f, "WordCloud"),
+            Action::Export => write!(f, "Export"),
+            Action::GroupThreads => write!(f, "GroupThreads"),
+            Action::NextByAuthor => write!(f, "NextByAuthor"),
+            Action::PrevByAuthor => write!(f, "PrevByAuthor"),
+            Action::MaxAge => write!(f, "MaxAge"),
+            Action::FocusAuthor => write!(f, "FocusAuthor"),
+            Action::ExternalLinks => write!(f, "ExternalLinks"),
+            Action::TimestampFormat => write!(f, "TimestampFormat"),
+            Action::BlockDomain => write!(f, "BlockDomain"),
+            Action::FeedStats => write!(f, "FeedStats"),
+            Action::CompareAnchor => write!(f, "CompareAnchor"),
+            Action::PdsRecord => write!(f, "PdsRecord"),
+            Action::RawRecord => write!(f, "RawRecord"),
+            Action::Repliers => write!(f, "Repliers"),
+            Action::PinConversation => write!(f, "PinConversation"),
+            Action::CopyCid => write!(f, "CopyCid"),
+            Action::TagPost => write!(f, "TagPost"),
+            Action::Visual => write!(f, "Visual"),
+            Action::FilterDate(date) => f.debug_tuple("FilterDate").field(date).finish(),
+            Action::FilterTag(tag) => f.debug_tuple("FilterTag").field(tag).finish(),
+            Action::AddNote(post_view) => f.debug_tuple("AddNote").field(&post_view.uri).finish(),
             Action::Login(_) => write!(f, "Login"),
             Action::Logout => write!(f, "Logout"),
             Action::Update(_) => write!(f, "Update"),
@@ -46,6 +160,19 @@ pub enum Data {
     Feed(Vec<FeedViewPost>),
     PostThread(Union<OutputThreadRefs>),
     ViewerState(Option<ViewerState>),
+    FeedGenerator(Box<FeedGeneratorDetail>),
+    CreatedFeeds(Vec<GeneratorView>),
+    ListBlocks(Vec<ListView>),
+    Lists(Vec<ListView>),
+    Quotes(Box<(Quotes, bool)>),
+    SearchResults(Box<(SearchResults, bool)>),
+    Analytics(Box<PostAnalytics>),
+    Labelers(Vec<LabelerSettings>),
+    Rss(Box<RssFeed>),
+    KnownFollowers(Vec<ProfileView>),
+    Suggestions(Box<(Suggestions, bool)>),
+    PopularFeeds(Box<(PopularFeeds, bool)>),
+    MutedWords(Vec<MutedWord>),
 }
 
 #[derive(Debug, Clone)]
@@ -60,6 +187,29 @@ pub enum View {
     Login,
     Root,
     NewPost,
+    Reply(Box<PostView>),
+    Quote(Box<PostView>),
     Feed(Box<FeedSourceInfo>),
     Post(Box<(PostView, Option<PostView>)>),
+    Conversation(String),
+    FocusMode(Box<PostView>),
+    FeedGenerator(String),
+    CreatedFeeds(Box<ProfileViewBasic>),
+    Feedback,
+    BlockedLists,
+    Quotes(Box<PostView>),
+    Analytics(Box<PostView>),
+    Labelers,
+    Search(Box<SearchQuery>),
+    Diff(Box<(String, PostView)>),
+    Comment(Box<PostView>),
+    Calendar(Box<(BTreeMap<NaiveDate, usize>, NaiveDate)>),
+    Rss(String),
+    WordCloud(Box<FeedSourceInfo>),
+    Export(Vec<FeedViewPost>),
+    KnownFollowers(Box<(ProfileViewBasic, Vec<ProfileView>)>),
+    Suggestions,
+    PopularFeeds,
+    MutedWords,
+    RawRecord(Box<(String, String)>),
 }
@@ -1,7 +1,17 @@
+use crate::config::TimestampFormat;
 use bsky_sdk::api::app::bsky::actor::defs::{ProfileView, ProfileViewBasic};
-use bsky_sdk::api::app::bsky::feed::defs::PostView;
-use ratatui::style::{Style, Stylize};
-use ratatui::text::Span;
+use bsky_sdk::api::app::bsky::embed::record_with_media::ViewMediaRefs;
+use bsky_sdk::api::app::bsky::feed::defs::{FeedViewPost, PostView, PostViewEmbedRefs};
+use bsky_sdk::api::app::bsky::richtext::facet::MainFeaturesItem;
+use bsky_sdk::api::records::{KnownRecord, Record};
+use bsky_sdk::api::types::string::Did;
+use bsky_sdk::api::types::Union;
+use chrono::{DateTime, Local, TimeDelta, TimeZone, Utc};
+use indexmap::IndexSet;
+use ratatui::style::{Color, Style, Stylize};
+use ratatui::text::{Line, Span, Text};
+use std::ops::Range;
+use std::time::{Duration, Instant};
 
 pub trait Profile {
     fn display_name(&self) -> Option<&str>;
@@ -30,7 +40,7 @@ pub fn profile_name_as_str(author: &dyn Profile) -> &str {
     author.display_name().unwrap_or(author.handle())
 }
 
-pub fn profile_name(author: &dyn Profile) -> Vec<Span> {
+pub fn profile_name(author: &dyn Profile) -> Vec<Span<'_>> {
     if let Some(display_name) = author.display_name() {
         vec![
             Span::from(display_name.to_string()).bold(),
@@ -42,7 +52,7 @@ pub fn profile_name(author: &dyn Profile) -> Vec<Span> {
     }
 }
 
-pub fn counts(post_view: &PostView, pad: usize) -> Vec<Span> {
+pub fn counts(post_view: &PostView, pad: usize) -> Vec<Span<'_>> {
     let (mut reposted, mut liked) = (false, false);
     if let Some(viewer) = &post_view.viewer {
         reposted = viewer.repost.is_some();
@@ -78,3 +88,615 @@ pub fn counts(post_view: &PostView, pad: usize) -> Vec<Span> {
         Span::from(" likes").style(style(likes > 0)),
     ]
 }
+
+/// Build the bsky.app URL of a post from its AT URI.
+pub fn post_url(post_view: &PostView) -> Option<String> {
+    let rkey = post_view.uri.rsplit_once("/app.bsky.feed.post/")?.1;
+    Some(format!(
+        "https://bsky.app/profile/{}/post/{rkey}",
+        post_view.author.handle.as_str()
+    ))
+}
+
+/// The URL of `com.atproto.repo.getRecord` of the PDS at `base`, for the record of the post.
+pub fn record_url(base: &str, post_view: &PostView) -> Option<String> {
+    let (repo, path) = post_view.uri.strip_prefix("at://")?.split_once('/')?;
+    let (collection, rkey) = path.split_once('/')?;
+    Some(format!(
+        "{}/xrpc/com.atproto.repo.getRecord?repo={repo}&collection={collection}&rkey={rkey}",
+        base.trim_end_matches('/')
+    ))
+}
+
+/// Whether the text of the post mentions the account of `did`.
+pub fn mentions(post_view: &PostView, did: &Did) -> bool {
+    let Record::Known(KnownRecord::AppBskyFeedPost(record)) = &post_view.record else {
+        return false;
+    };
+    record.facets.iter().flatten().any(|facet| {
+        facet.features.iter().any(|feature| {
+            matches!(feature, Union::Refs(MainFeaturesItem::Mention(mention)) if &mention.did == did)
+        })
+    })
+}
+
+pub fn links(post_view: &PostView) -> IndexSet<&str> {
+    let mut links = IndexSet::new();
+    if let Record::Known(KnownRecord::AppBskyFeedPost(record)) = &post_view.record {
+        if let Some(facets) = &record.facets {
+            for facet in facets {
+                for feature in &facet.features {
+                    match feature {
+                        Union::Refs(MainFeaturesItem::Mention(_)) => {
+                            // TODO
+                        }
+                        Union::Refs(MainFeaturesItem::Link(link)) => {
+                            links.insert(link.uri.as_str());
+                        }
+                        Union::Refs(MainFeaturesItem::Tag(_)) => {
+                            // TODO
+                        }
+                        _ => {}
+                    }
+                }
+            }
+        }
+    }
+    if let Some(embed) = &post_view.embed {
+        match embed {
+            Union::Refs(PostViewEmbedRefs::AppBskyEmbedImagesView(images)) => {
+                for image in &images.images {
+                    links.insert(image.fullsize.as_str());
+                }
+            }
+            Union::Refs(PostViewEmbedRefs::AppBskyEmbedExternalView(external)) => {
+                links.insert(external.external.uri.as_str());
+            }
+            Union::Refs(PostViewEmbedRefs::AppBskyEmbedRecordWithMediaView(record_with_media)) => {
+                match &record_with_media.media {
+                    Union::Refs(ViewMediaRefs::AppBskyEmbedImagesView(images)) => {
+                        for image in &images.images {
+                            links.insert(image.fullsize.as_str());
+                        }
+                    }
+                    Union::Refs(ViewMediaRefs::AppBskyEmbedExternalView(external)) => {
+                        links.insert(external.external.uri.as_str());
+                    }
+                    _ => {}
+                }
+            }
+            _ => {}
+        }
+    }
+    links
+}
+
+/// The host of a URL without `www.`, or the URL itself if it has no scheme.
+pub fn link_domain(uri: &str) -> &str {
+    let Some((_, rest)) = uri.split_once("://") else {
+        return uri;
+    };
+    let host = rest.split(['/', '?', '#']).next().unwrap_or(rest);
+    host.strip_prefix("www.").unwrap_or(host)
+}
+
+/// The URI of the link card of the post, if any.
+pub fn external_uri(post_view: &PostView) -> Option<&str> {
+    match &post_view.embed {
+        Some(Union::Refs(PostViewEmbedRefs::AppBskyEmbedExternalView(external))) => {
+            Some(&external.external.uri)
+        }
+        Some(Union::Refs(PostViewEmbedRefs::AppBskyEmbedRecordWithMediaView(view))) => {
+            match &view.media {
+                Union::Refs(ViewMediaRefs::AppBskyEmbedExternalView(external)) => {
+                    Some(&external.external.uri)
+                }
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// The index of the next (or the previous, if not `forward`) post by `did` after `current`,
+/// wrapping around at the end of the items.
+pub fn find_next_by_author(
+    items: &[FeedViewPost],
+    current: usize,
+    did: &Did,
+    forward: bool,
+) -> Option<usize> {
+    let len = items.len();
+    (1..len)
+        .map(|offset| {
+            if forward {
+                (current + offset) % len
+            } else {
+                (current + len - offset) % len
+            }
+        })
+        .find(|&i| &items[i].post.author.did == did)
+}
+
+/// The heights of the posts rendered in a list, to place the scrollbar by rows rather than
+/// by items, as the posts have different heights.
+#[derive(Debug, Default)]
+pub struct PostHeightEstimator {
+    heights: Vec<u16>,
+}
+
+impl PostHeightEstimator {
+    /// The rendered posts are not wrapped by the list, so their heights are the numbers of lines.
+    pub fn update(&mut self, items: &[Text<'_>]) {
+        self.heights = items
+            .iter()
+            .map(|text| u16::try_from(text.height()).unwrap_or(u16::MAX))
+            .collect();
+    }
+    pub fn total(&self) -> usize {
+        self.heights.iter().copied().map(usize::from).sum()
+    }
+    /// The indices of the posts shown from `offset` within `height` rows, at least partially.
+    pub fn visible(&self, offset: usize, height: usize) -> Range<usize> {
+        let mut rows = 0;
+        let end = self.heights[offset.min(self.heights.len())..]
+            .iter()
+            .take_while(|h| {
+                let visible = rows < height;
+                rows += usize::from(**h);
+                visible
+            })
+            .count();
+        offset..offset + end
+    }
+    /// The index of the post rendered at `row`, from the top of the list.
+    pub fn index_at(&self, row: usize) -> Option<usize> {
+        let mut top = 0;
+        self.heights.iter().position(|h| {
+            top += usize::from(*h);
+            row < top
+        })
+    }
+    /// The row of the top of the post at `index`, from the top of the list.
+    pub fn top_row(&self, index: usize) -> usize {
+        self.heights[..index.min(self.heights.len())]
+            .iter()
+            .copied()
+            .map(usize::from)
+            .sum()
+    }
+}
+
+/// The number of items to move by, growing while an arrow key is held down.
+///
+/// The terminals do not report the key releases, so a key is held down as long as its repeated
+/// events keep coming quickly.
+#[derive(Debug)]
+pub struct ScrollAccelerator {
+    step: usize,
+    last_event: Instant,
+    // when the key was pressed, and whether it moves down
+    held_since: Instant,
+    down: bool,
+}
+
+impl ScrollAccelerator {
+    /// Between the repeated events of a held key.
+    const REPEAT: Duration = Duration::from_millis(50);
+    /// Between the events of separate key presses.
+    const RELEASE: Duration = Duration::from_millis(100);
+    /// The steps after holding the key down for the durations.
+    const STEPS: [(Duration, usize); 3] = [
+        (Duration::from_millis(600), 10),
+        (Duration::from_millis(400), 5),
+        (Duration::from_millis(200), 3),
+    ];
+
+    /// The step of a move at `now`, down or up.
+    pub fn step(&mut self, down: bool, now: Instant) -> usize {
+        let gap = now.saturating_duration_since(self.last_event);
+        self.last_event = now;
+        if gap > Self::RELEASE || down != self.down {
+            self.step = 1;
+            self.held_since = now;
+            self.down = down;
+        } else if gap <= Self::REPEAT {
+            let held = now.saturating_duration_since(self.held_since);
+            self.step = Self::STEPS
+                .iter()
+                .find(|(duration, _)| held >= *duration)
+                .map_or(1, |(_, step)| *step);
+        }
+        self.step
+    }
+}
+
+impl Default for ScrollAccelerator {
+    fn default() -> Self {
+        let now = Instant::now();
+        Self {
+            step: 1,
+            last_event: now,
+            held_since: now,
+            down: true,
+        }
+    }
+}
+
+/// Count the posts indexed in each of the past 24 hours, from the oldest hour to the current one.
+pub fn compute_hourly_buckets(posts: &[FeedViewPost], now: DateTime<Utc>) -> [u32; 24] {
+    let mut buckets = [0; 24];
+    for feed_view_post in posts {
+        let hours = now
+            .signed_duration_since(feed_view_post.post.indexed_at.as_ref())
+            .num_hours();
+        if let Some(bucket) = usize::try_from(hours)
+            .ok()
+            .and_then(|hours| 23usize.checked_sub(hours))
+        {
+            buckets[bucket] += 1;
+        }
+    }
+    buckets
+}
+
+/// Render the hourly counts as a bar, colored from blue to red relative to the busiest hour.
+pub fn heat_map(buckets: &[u32; 24]) -> Line<'static> {
+    const BARS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+    let max = buckets.iter().copied().max().unwrap_or_default().max(1);
+    Line::from(
+        buckets
+            .iter()
+            .map(|&count| {
+                let bar = BARS[(count * 7 / max) as usize].to_string();
+                if count == 0 {
+                    Span::from(bar).dark_gray()
+                } else {
+                    let red = (count * 255 / max) as u8;
+                    Span::from(bar).fg(Color::Rgb(red, 0, 255 - red))
+                }
+            })
+            .collect::<Vec<_>>(),
+    )
+}
+
+/// Render the length of a duration, e.g. `2 h 14 m 32 s`, omitting the leading zero units.
+pub fn format_duration(delta: TimeDelta) -> String {
+    let seconds = delta.num_seconds().unsigned_abs();
+    let (days, hours, minutes, seconds) = (
+        seconds / (24 * 60 * 60),
+        seconds / (60 * 60) % 24,
+        seconds / 60 % 60,
+        seconds % 60,
+    );
+    if days > 0 {
+        format!("{days} d {hours} h {minutes} m {seconds} s")
+    } else if hours > 0 {
+        format!("{hours} h {minutes} m {seconds} s")
+    } else if minutes > 0 {
+        format!("{minutes} m {seconds} s")
+    } else {
+        format!("{seconds} s")
+    }
+}
+
+/// Render a timestamp in the local time zone, in the format chosen by the user.
+pub fn format_timestamp<Tz: TimeZone>(dt: &DateTime<Tz>, mode: TimestampFormat) -> String {
+    let dt = dt.with_timezone(&Local);
+    match mode {
+        TimestampFormat::FullDatetime => dt.format("%Y-%m-%d %H:%M:%S %z").to_string(),
+        TimestampFormat::TimeOnly => dt.format("%H:%M").to_string(),
+        TimestampFormat::Compact => dt.format("%m/%d %H:%M").to_string(),
+        TimestampFormat::Relative => {
+            let seconds = (Local::now() - dt).num_seconds().max(0);
+            if seconds < 60 {
+                String::from("just now")
+            } else if seconds < 60 * 60 {
+                format!("{} m ago", seconds / 60)
+            } else if seconds < 24 * 60 * 60 {
+                format!("{} h ago", seconds / (60 * 60))
+            } else {
+                format!("{} d ago", seconds / (24 * 60 * 60))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bsky_sdk::api::app::bsky::feed::defs::{FeedViewPostData, PostViewData};
+    use bsky_sdk::api::types::string::Datetime;
+
+    fn post(indexed_at: &str) -> FeedViewPost {
+        let author = serde_json::from_value(serde_json::json!({
+            "did": "did:plc:alice",
+            "handle": "alice.bsky.social",
+        }))
+        .expect("invalid author");
+        FeedViewPostData {
+            feed_context: None,
+            post: PostViewData {
+                author,
+                cid: "bafyreidfayvfuwqa7qlnopdjiqrxzs6blmoeu4rujcjtnci5beludirz2a"
+                    .parse()
+                    .expect("invalid cid"),
+                embed: None,
+                indexed_at: indexed_at.parse::<Datetime>().expect("invalid datetime"),
+                labels: None,
+                like_count: None,
+                record: serde_json::from_value(serde_json::json!({
+                    "$type": "app.bsky.feed.post",
+                    "text": "",
+                    "createdAt": indexed_at,
+                }))
+                .expect("invalid record"),
+                reply_count: None,
+                repost_count: None,
+                threadgate: None,
+                uri: String::from("at://did:plc:alice/app.bsky.feed.post/1"),
+                viewer: None,
+            }
+            .into(),
+            reason: None,
+            reply: None,
+        }
+        .into()
+    }
+
+    #[test]
+    fn accelerate_scroll() {
+        let mut accelerator = ScrollAccelerator::default();
+        let start = Instant::now() + Duration::from_secs(1);
+        let at = |ms| start + Duration::from_millis(ms);
+        assert_eq!(accelerator.step(true, at(0)), 1);
+        let steps = (1..=20)
+            .map(|i| accelerator.step(true, at(i * 40)))
+            .collect::<Vec<_>>();
+        assert_eq!(steps[3], 1);
+        assert_eq!(steps[4], 3);
+        assert_eq!(steps[9], 5);
+        assert_eq!(steps[14], 10);
+        // the other direction
+        assert_eq!(accelerator.step(false, at(820)), 1);
+        // released
+        assert_eq!(accelerator.step(false, at(1000)), 1);
+    }
+
+    #[test]
+    fn hourly_buckets() {
+        let now = "2024-09-10T12:30:00Z"
+            .parse::<DateTime<Utc>>()
+            .expect("invalid datetime");
+        let buckets = compute_hourly_buckets(
+            &[
+                post("2024-09-10T12:29:00Z"),
+                post("2024-09-10T12:00:00Z"),
+                post("2024-09-10T11:00:00Z"),
+                post("2024-09-09T13:00:00Z"),
+                post("2024-09-09T12:00:00Z"),
+            ],
+            now,
+        );
+        assert_eq!(buckets[23], 2);
+        assert_eq!(buckets[22], 1);
+        assert_eq!(buckets[0], 1);
+        assert_eq!(buckets.iter().sum::<u32>(), 4);
+    }
+
+    #[test]
+    fn next_by_author() {
+        let by = |did: &str| {
+            let mut feed_view_post = post("2024-09-10T12:00:00Z");
+            feed_view_post.post.author = serde_json::from_value(serde_json::json!({
+                "did": did,
+                "handle": "test.bsky.social",
+            }))
+            .expect("invalid author");
+            feed_view_post
+        };
+        let items = [
+            by("did:plc:alice"),
+            by("did:plc:bob"),
+            by("did:plc:alice"),
+            by("did:plc:carol"),
+        ];
+        let alice = "did:plc:alice".parse::<Did>().expect("invalid did");
+        assert_eq!(find_next_by_author(&items, 0, &alice, true), Some(2));
+        // wrapping around
+        assert_eq!(find_next_by_author(&items, 2, &alice, true), Some(0));
+        assert_eq!(find_next_by_author(&items, 0, &alice, false), Some(2));
+        assert_eq!(find_next_by_author(&items, 3, &alice, false), Some(2));
+        let carol = "did:plc:carol".parse::<Did>().expect("invalid did");
+        // not the current post itself
+        assert_eq!(find_next_by_author(&items, 3, &carol, true), None);
+        assert_eq!(find_next_by_author(&[], 0, &carol, true), None);
+    }
+
+    #[test]
+    fn format_timestamps() {
+        let dt = Local
+            .with_ymd_and_hms(2024, 9, 10, 8, 5, 3)
+            .single()
+            .expect("invalid datetime");
+        assert_eq!(
+            format_timestamp(&dt, TimestampFormat::FullDatetime),
+            dt.format("2024-09-10 08:05:03 %z").to_string()
+        );
+        assert_eq!(format_timestamp(&dt, TimestampFormat::TimeOnly), "08:05");
+        assert_eq!(
+            format_timestamp(&dt, TimestampFormat::Compact),
+            "09/10 08:05"
+        );
+        let now = Local::now();
+        for (ago, expected) in [
+            (TimeDelta::seconds(-30), "just now"),
+            (TimeDelta::seconds(30), "just now"),
+            (TimeDelta::minutes(5), "5 m ago"),
+            (TimeDelta::minutes(90), "1 h ago"),
+            (TimeDelta::days(3), "3 d ago"),
+        ] {
+            assert_eq!(
+                format_timestamp(&(now - ago), TimestampFormat::Relative),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn format_durations() {
+        assert_eq!(format_duration(TimeDelta::zero()), "0 s");
+        assert_eq!(format_duration(TimeDelta::seconds(59)), "59 s");
+        assert_eq!(format_duration(TimeDelta::seconds(8072)), "2 h 14 m 32 s");
+        assert_eq!(format_duration(TimeDelta::seconds(-61)), "1 m 1 s");
+        assert_eq!(
+            format_duration(TimeDelta::days(1) + TimeDelta::seconds(5)),
+            "1 d 0 h 0 m 5 s"
+        );
+    }
+
+    #[test]
+    fn heat_map_bars() {
+        let mut buckets = [0; 24];
+        buckets[0] = 1;
+        buckets[23] = 7;
+        let line = heat_map(&buckets);
+        assert_eq!(line.spans.len(), 24);
+        assert_eq!(line.spans[0].content, "▂");
+        assert_eq!(line.spans[1].content, "▁");
+        assert_eq!(line.spans[1].style.fg, Some(Color::DarkGray));
+        assert_eq!(line.spans[23].content, "█");
+        assert_eq!(line.spans[23].style.fg, Some(Color::Rgb(255, 0, 0)));
+        // no posts at all
+        assert!(heat_map(&[0; 24])
+            .spans
+            .iter()
+            .all(|span| span.content == "▁"));
+    }
+
+    #[test]
+    fn url_of_post() {
+        let feed_view_post = post("2024-09-10T12:00:00Z");
+        assert_eq!(
+            post_url(&feed_view_post.post).as_deref(),
+            Some("https://bsky.app/profile/alice.bsky.social/post/1")
+        );
+        let mut repost = feed_view_post.post.clone();
+        repost.uri = String::from("at://did:plc:alice/app.bsky.feed.repost/1");
+        assert_eq!(post_url(&repost), None);
+    }
+
+    #[test]
+    fn url_of_record() {
+        let feed_view_post = post("2024-09-10T12:00:00Z");
+        assert_eq!(
+            record_url("https://pds.example.com/", &feed_view_post.post).as_deref(),
+            Some("https://pds.example.com/xrpc/com.atproto.repo.getRecord?repo=did:plc:alice&collection=app.bsky.feed.post&rkey=1")
+        );
+    }
+
+    fn post_with_facets(facets: serde_json::Value) -> PostView {
+        let mut post_view = post("2024-09-10T12:00:00Z").post.clone();
+        post_view.record = serde_json::from_value(serde_json::json!({
+            "$type": "app.bsky.feed.post",
+            "text": "hello @bob.test https://example.com",
+            "createdAt": "2024-09-10T12:00:00Z",
+            "facets": facets,
+        }))
+        .expect("invalid record");
+        post_view
+    }
+
+    #[test]
+    fn mentions_in_facets() {
+        let post_view = post_with_facets(serde_json::json!([{
+            "index": { "byteStart": 6, "byteEnd": 15 },
+            "features": [{ "$type": "app.bsky.richtext.facet#mention", "did": "did:plc:bob" }],
+        }]));
+        assert!(mentions(
+            &post_view,
+            &"did:plc:bob".parse().expect("invalid did")
+        ));
+        assert!(!mentions(
+            &post_view,
+            &"did:plc:carol".parse().expect("invalid did")
+        ));
+        assert!(!mentions(
+            &post("2024-09-10T12:00:00Z").post,
+            &"did:plc:bob".parse().expect("invalid did")
+        ));
+    }
+
+    #[test]
+    fn links_of_post() {
+        let mut post_view = post_with_facets(serde_json::json!([{
+            "index": { "byteStart": 16, "byteEnd": 35 },
+            "features": [{ "$type": "app.bsky.richtext.facet#link", "uri": "https://example.com" }],
+        }]));
+        post_view.embed = serde_json::from_value(serde_json::json!({
+            "$type": "app.bsky.embed.images#view",
+            "images": [{ "alt": "", "fullsize": "https://cdn.example.com/1", "thumb": "https://cdn.example.com/t/1" }],
+        }))
+        .expect("invalid embed");
+        assert_eq!(
+            links(&post_view).into_iter().collect::<Vec<_>>(),
+            ["https://example.com", "https://cdn.example.com/1"]
+        );
+    }
+
+    #[test]
+    fn domain_of_link() {
+        assert_eq!(
+            link_domain("https://www.example.com/path?q=1"),
+            "example.com"
+        );
+        assert_eq!(link_domain("http://sub.example.com#top"), "sub.example.com");
+        assert_eq!(link_domain("https://example.com?q=1"), "example.com");
+        assert_eq!(link_domain("example.com/path"), "example.com/path");
+    }
+
+    #[test]
+    fn external_uri_of_post() {
+        let mut post_view = post("2024-09-10T12:00:00Z").post.clone();
+        assert_eq!(external_uri(&post_view), None);
+        let external = serde_json::json!({
+            "$type": "app.bsky.embed.external#view",
+            "external": { "uri": "https://example.com", "title": "", "description": "" },
+        });
+        post_view.embed = serde_json::from_value(external.clone()).expect("invalid embed");
+        assert_eq!(external_uri(&post_view), Some("https://example.com"));
+        post_view.embed = serde_json::from_value(serde_json::json!({
+            "$type": "app.bsky.embed.recordWithMedia#view",
+            "record": {
+                "record": {
+                    "$type": "app.bsky.embed.record#viewNotFound",
+                    "uri": "at://did:plc:bob/app.bsky.feed.post/1",
+                    "notFound": true,
+                },
+            },
+            "media": external,
+        }))
+        .expect("invalid embed");
+        assert_eq!(external_uri(&post_view), Some("https://example.com"));
+    }
+
+    #[test]
+    fn post_heights() {
+        let mut heights = PostHeightEstimator::default();
+        heights.update(&[
+            Text::from("a\nb\nc"),
+            Text::from("a"),
+            Text::from("a\nb"),
+            Text::from("a\nb\nc\nd"),
+        ]);
+        assert_eq!(heights.total(), 10);
+        assert_eq!(heights.visible(0, 4), 0..2);
+        // partially visible
+        assert_eq!(heights.visible(1, 2), 1..3);
+        assert_eq!(heights.visible(3, 10), 3..4);
+        assert_eq!(heights.index_at(3), Some(1));
+        assert_eq!(heights.index_at(10), None);
+        assert_eq!(heights.top_row(2), 4);
+        assert_eq!(heights.top_row(10), 10);
+    }
+}
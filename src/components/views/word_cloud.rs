@@ -0,0 +1,241 @@
+use super::types::{Action, Data, Transition, View};
+use super::ViewComponent;
+use crate::backend::types::FeedSourceInfo;
+use crate::backend::{Watch, Watcher};
+use bsky_sdk::api::app::bsky::feed::defs::FeedViewPost;
+use bsky_sdk::api::records::{KnownRecord, Record};
+use color_eyre::Result;
+use ratatui::layout::{Alignment, Constraint, Layout, Rect};
+use ratatui::style::{Color, Style, Stylize};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Padding, Paragraph, Wrap};
+use ratatui::Frame;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use tokio::sync::mpsc::UnboundedSender;
+use tokio::sync::oneshot;
+
+const MAX_WORDS: usize = 50;
+/// The number of the most frequent words rendered in bold.
+const BOLD_WORDS: usize = 10;
+/// From the least to the most frequent words.
+const PALETTE: [Color; 8] = [
+    Color::DarkGray,
+    Color::Gray,
+    Color::Blue,
+    Color::Cyan,
+    Color::Green,
+    Color::Yellow,
+    Color::LightRed,
+    Color::Red,
+];
+/// Excluded in addition to the stop words of the config.
+const STOP_WORDS: [&str; 72] = [
+    "the", "and", "for", "are", "but", "not", "you", "all", "any", "can", "had", "her", "was",
+    "one", "our", "out", "has", "him", "his", "how", "its", "may", "new", "now", "see", "two",
+    "who", "did", "get", "got", "let", "say", "she", "too", "use", "way", "that", "this", "with",
+    "have", "from", "they", "will", "would", "there", "their", "what", "about", "which", "when",
+    "make", "like", "just", "than", "them", "been", "into", "some", "could", "other", "then",
+    "these", "also", "more", "your", "it's", "i'm", "don't", "were", "here", "very", "only",
+];
+
+/// The most frequent words of the posts loaded in a feed.
+pub struct WordCloudViewComponent {
+    feed_info: FeedSourceInfo,
+    words: Vec<(String, usize)>,
+    stop_words: HashSet<String>,
+    action_tx: UnboundedSender<Action>,
+    watcher: Box<dyn Watch<Output = Vec<FeedViewPost>>>,
+    quit: Option<oneshot::Sender<()>>,
+}
+
+impl WordCloudViewComponent {
+    pub fn new(
+        action_tx: UnboundedSender<Action>,
+        watcher: Arc<Watcher>,
+        feed_info: FeedSourceInfo,
+        stop_words: &[String],
+    ) -> Self {
+        // the same feed as the feed view below, to be updated together with it
+        let watcher = watcher.feed_source(feed_info.clone());
+        Self {
+            feed_info,
+            words: Vec::new(),
+            stop_words: STOP_WORDS
+                .iter()
+                .map(|word| word.to_string())
+                .chain(stop_words.iter().map(|word| word.to_lowercase()))
+                .collect(),
+            action_tx,
+            watcher,
+            quit: None,
+        }
+    }
+}
+
+/// Count the words of the texts, and returns the most frequent ones in descending order.
+///
+/// Words shorter than 3 characters, numbers, URLs and mentions are skipped.
+fn word_counts<'a>(
+    texts: impl IntoIterator<Item = &'a str>,
+    stop_words: &HashSet<String>,
+    max: usize,
+) -> Vec<(String, usize)> {
+    let mut counts = HashMap::<String, usize>::new();
+    for word in texts.into_iter().flat_map(str::split_whitespace) {
+        if word.contains("://") || word.starts_with('@') {
+            continue;
+        }
+        let word = word
+            .trim_matches(|c: char| !c.is_alphanumeric() && c != '#')
+            .to_lowercase();
+        if word.chars().count() < 3
+            || word.chars().all(|c| c.is_numeric())
+            || stop_words.contains(&word)
+        {
+            continue;
+        }
+        *counts.entry(word).or_default() += 1;
+    }
+    let mut counts = counts.into_iter().collect::<Vec<_>>();
+    counts.sort_by(|(w1, c1), (w2, c2)| c2.cmp(c1).then_with(|| w1.cmp(w2)));
+    counts.truncate(max);
+    counts
+}
+
+impl ViewComponent for WordCloudViewComponent {
+    fn view(&self) -> View {
+        View::WordCloud(Box::new(self.feed_info.clone()))
+    }
+    fn activate(&mut self) -> Result<()> {
+        let (tx, mut rx) = (self.action_tx.clone(), self.watcher.subscribe());
+        let (quit_tx, mut quit_rx) = oneshot::channel();
+        self.quit = Some(quit_tx);
+        tokio::spawn(async move {
+            loop {
+                tokio::select! {
+                    changed = rx.changed() => {
+                        if changed.is_ok() {
+                            if let Err(e) = tx.send(Action::Update(Box::new(Data::Feed(
+                                rx.borrow_and_update().clone(),
+                            )))) {
+                                log::error!("failed to send update action: {e}");
+                            }
+                        } else {
+                            break log::warn!("feed channel closed");
+                        }
+                    }
+                    _ = &mut quit_rx => {
+                        break;
+                    }
+                }
+            }
+            log::debug!("subscription finished");
+        });
+        Ok(())
+    }
+    fn deactivate(&mut self) -> Result<()> {
+        if let Some(tx) = self.quit.take() {
+            if tx.send(()).is_err() {
+                log::error!("failed to send quit signal");
+            }
+        }
+        self.watcher.unsubscribe();
+        Ok(())
+    }
+    fn update(&mut self, action: Action) -> Result<Option<Action>> {
+        Ok(match action {
+            Action::Back | Action::WordCloud => Some(Action::Transition(Transition::Pop)),
+            Action::Refresh => {
+                self.watcher.refresh();
+                None
+            }
+            Action::Update(data) => {
+                let Data::Feed(feed) = data.as_ref() else {
+                    return Ok(None);
+                };
+                let texts =
+                    feed.iter()
+                        .filter_map(|feed_view_post| match &feed_view_post.post.record {
+                            Record::Known(KnownRecord::AppBskyFeedPost(record)) => {
+                                Some(record.text.as_str())
+                            }
+                            _ => None,
+                        });
+                self.words = word_counts(texts, &self.stop_words, MAX_WORDS);
+                Some(Action::Render)
+            }
+            _ => None,
+        })
+    }
+    fn draw(&mut self, f: &mut Frame<'_>, area: Rect) -> Result<()> {
+        let header = Paragraph::new(format!("Word cloud of {}", self.feed_info.label()))
+            .bold()
+            .block(
+                Block::default()
+                    .borders(Borders::BOTTOM)
+                    .border_style(Color::Gray)
+                    .padding(Padding::horizontal(1)),
+            );
+        let (min, max) = (
+            self.words.last().map_or(0, |(_, count)| *count),
+            self.words.first().map_or(0, |(_, count)| *count),
+        );
+        let mut words = self.words.iter().enumerate().collect::<Vec<_>>();
+        // in alphabetical order, so that the frequent words are not all at the top
+        words.sort_by(|(_, (w1, _)), (_, (w2, _))| w1.cmp(w2));
+        let mut spans = Vec::new();
+        for (rank, (word, count)) in words {
+            let intensity = (count - min) * (PALETTE.len() - 1) / (max - min).max(1);
+            let mut style = Style::default().fg(PALETTE[intensity]);
+            if rank < BOLD_WORDS {
+                style = style.bold();
+            }
+            spans.push(Span::styled(word.as_str(), style));
+            spans.push(Span::from("  "));
+        }
+        let paragraph = if spans.is_empty() {
+            Paragraph::new("No words in the loaded posts").dim()
+        } else {
+            Paragraph::new(Line::from(spans)).wrap(Wrap { trim: true })
+        };
+
+        let layout =
+            Layout::vertical([Constraint::Length(2), Constraint::Percentage(100)]).split(area);
+        f.render_widget(header, layout[0]);
+        f.render_widget(
+            paragraph
+                .alignment(Alignment::Center)
+                .block(Block::default().padding(Padding::uniform(1))),
+            layout[1],
+        );
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn count_words() {
+        let stop_words = HashSet::from_iter([String::from("the")]);
+        let counts = word_counts(
+            [
+                "The Rust release is out! https://example.com/rust",
+                "rust, #rustlang and 2024 @alice.bsky.social",
+                "Release notes",
+            ],
+            &stop_words,
+            3,
+        );
+        assert_eq!(
+            counts,
+            vec![
+                (String::from("release"), 2),
+                (String::from("rust"), 2),
+                (String::from("#rustlang"), 1),
+            ]
+        );
+    }
+}
@@ -0,0 +1,9 @@
+mod context_menu;
+mod text_input;
+mod tutorial_overlay;
+
+pub use self::context_menu::ContextMenu;
+pub use self::text_input::TextInput;
+pub use self::tutorial_overlay::{
+    TutorialAreas, TutorialOverlay, TutorialState, TutorialStep, TutorialTarget,
+};
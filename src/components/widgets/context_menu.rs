@@ -0,0 +1,88 @@
+use ratatui::layout::{Position, Rect};
+use ratatui::style::{Style, Stylize};
+use ratatui::widgets::{Block, Clear, List, ListState, Padding};
+use ratatui::Frame;
+
+/// A floating list of actions, opened at the position of the cursor.
+#[derive(Debug, Clone)]
+pub struct ContextMenu {
+    items: Vec<String>,
+    state: ListState,
+    position: Position,
+    // the area of the last draw, to find the clicked item
+    area: Rect,
+}
+
+impl ContextMenu {
+    pub fn new(items: Vec<String>, position: Position) -> Self {
+        Self {
+            items,
+            state: ListState::default().with_selected(Some(0)),
+            position,
+            area: Rect::default(),
+        }
+    }
+    pub fn next(&mut self) {
+        if !self.items.is_empty() {
+            self.state.select(Some(
+                self.state
+                    .selected()
+                    .map_or(0, |s| (s + 1) % self.items.len()),
+            ));
+        }
+    }
+    pub fn prev(&mut self) {
+        if !self.items.is_empty() {
+            self.state.select(Some(
+                self.state
+                    .selected()
+                    .map_or(0, |s| (s + self.items.len() - 1) % self.items.len()),
+            ));
+        }
+    }
+    pub fn position(&self) -> Position {
+        self.position
+    }
+    pub fn selected(&self) -> Option<usize> {
+        self.state.selected()
+    }
+    /// The index of the item at the position, or `None` if it is outside of the menu.
+    pub fn item_at(&self, position: Position) -> Option<usize> {
+        if !self.area.contains(position) {
+            return None;
+        }
+        let row = position.y.checked_sub(self.area.y + 1)?;
+        Some(usize::from(row)).filter(|i| *i < self.items.len())
+    }
+    /// Draw the menu at the cursor, moved to the left or up to fit within `bounds`.
+    pub fn draw(&mut self, f: &mut Frame<'_>, bounds: Rect) {
+        let width = (self
+            .items
+            .iter()
+            .map(|s| s.chars().count())
+            .max()
+            .unwrap_or(0)
+            + 4)
+        .min(usize::from(bounds.width)) as u16;
+        let height = (self.items.len() + 2).min(usize::from(bounds.height)) as u16;
+        let x = self
+            .position
+            .x
+            .min(bounds.right().saturating_sub(width))
+            .max(bounds.x);
+        let y = self
+            .position
+            .y
+            .min(bounds.bottom().saturating_sub(height))
+            .max(bounds.y);
+        self.area = Rect::new(x, y, width, height);
+        f.render_widget(Clear, self.area);
+        f.render_stateful_widget(
+            List::new(self.items.clone())
+                .highlight_style(Style::default().reversed())
+                .block(Block::bordered().padding(Padding::horizontal(1))),
+            self.area,
+            &mut self.state,
+        );
+    }
+}
@@ -0,0 +1,264 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::{Style, Stylize};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Paragraph, Widget};
+
+/// A single-line text input with cursor movement, kill commands, input history and clipboard paste.
+#[derive(Debug, Clone)]
+pub struct TextInput {
+    chars: Vec<char>,
+    cursor: usize,
+    history: Vec<String>,
+    history_index: Option<usize>,
+    draft: String,
+    block: Option<Block<'static>>,
+    style: Style,
+    cursor_style: Style,
+}
+
+impl Default for TextInput {
+    fn default() -> Self {
+        Self {
+            chars: Vec::new(),
+            cursor: 0,
+            history: Vec::new(),
+            history_index: None,
+            draft: String::new(),
+            block: None,
+            style: Style::default(),
+            cursor_style: Style::default().reversed(),
+        }
+    }
+}
+
+impl TextInput {
+    pub fn with_history(history: Vec<String>) -> Self {
+        Self {
+            history,
+            ..Default::default()
+        }
+    }
+    pub fn value(&self) -> String {
+        self.chars.iter().collect()
+    }
+    pub fn set_value(&mut self, value: &str) {
+        self.chars = value.chars().collect();
+        self.cursor = self.chars.len();
+    }
+    pub fn history(&self) -> &[String] {
+        &self.history
+    }
+    /// Add the current value to the history, skipping empty values and consecutive duplicates.
+    pub fn push_history(&mut self) {
+        let value = self.value();
+        if !value.is_empty() && self.history.last() != Some(&value) {
+            self.history.push(value);
+        }
+        self.history_index = None;
+    }
+    pub fn block(&self) -> Option<&Block<'static>> {
+        self.block.as_ref()
+    }
+    pub fn set_block(&mut self, block: Block<'static>) {
+        self.block = Some(block);
+    }
+    pub fn set_style(&mut self, style: Style) {
+        self.style = style;
+    }
+    pub fn set_cursor_style(&mut self, style: Style) {
+        self.cursor_style = style;
+    }
+    /// Handle a key event. Returns `true` if the input consumed the key.
+    pub fn input(&mut self, key: KeyEvent) -> bool {
+        match (key.code, key.modifiers) {
+            (KeyCode::Left, KeyModifiers::CONTROL) => self.move_cursor(self.prev_word()),
+            (KeyCode::Right, KeyModifiers::CONTROL) => self.move_cursor(self.next_word()),
+            (KeyCode::Left, _) => self.move_cursor(self.cursor.saturating_sub(1)),
+            (KeyCode::Right, _) => self.move_cursor((self.cursor + 1).min(self.chars.len())),
+            (KeyCode::Home, _) | (KeyCode::Char('a'), KeyModifiers::CONTROL) => self.move_cursor(0),
+            (KeyCode::End, _) | (KeyCode::Char('e'), KeyModifiers::CONTROL) => {
+                self.move_cursor(self.chars.len())
+            }
+            (KeyCode::Backspace, _) if self.cursor > 0 => {
+                self.cursor -= 1;
+                self.chars.remove(self.cursor);
+                true
+            }
+            (KeyCode::Delete, _) if self.cursor < self.chars.len() => {
+                self.chars.remove(self.cursor);
+                true
+            }
+            (KeyCode::Char('k'), KeyModifiers::CONTROL) if self.cursor < self.chars.len() => {
+                self.chars.truncate(self.cursor);
+                true
+            }
+            (KeyCode::Char('u'), KeyModifiers::CONTROL) if self.cursor > 0 => {
+                self.chars.drain(..self.cursor);
+                self.cursor = 0;
+                true
+            }
+            (KeyCode::Char('v'), KeyModifiers::CONTROL) => self.paste(),
+            (KeyCode::Up, KeyModifiers::NONE) => self.prev_history(),
+            (KeyCode::Down, KeyModifiers::NONE) => self.next_history(),
+            (KeyCode::Char(c), KeyModifiers::NONE | KeyModifiers::SHIFT) => {
+                self.chars.insert(self.cursor, c);
+                self.cursor += 1;
+                true
+            }
+            _ => false,
+        }
+    }
+    fn move_cursor(&mut self, cursor: usize) -> bool {
+        let moved = self.cursor != cursor;
+        self.cursor = cursor;
+        moved
+    }
+    fn prev_word(&self) -> usize {
+        let mut i = self.cursor;
+        while i > 0 && self.chars[i - 1].is_whitespace() {
+            i -= 1;
+        }
+        while i > 0 && !self.chars[i - 1].is_whitespace() {
+            i -= 1;
+        }
+        i
+    }
+    fn next_word(&self) -> usize {
+        let mut i = self.cursor;
+        while i < self.chars.len() && self.chars[i].is_whitespace() {
+            i += 1;
+        }
+        while i < self.chars.len() && !self.chars[i].is_whitespace() {
+            i += 1;
+        }
+        i
+    }
+    fn paste(&mut self) -> bool {
+        let text = match arboard::Clipboard::new().and_then(|mut clipboard| clipboard.get_text()) {
+            Ok(text) => text,
+            Err(e) => {
+                log::warn!("failed to read clipboard: {e}");
+                return false;
+            }
+        };
+        // the input is single-line, so newlines are replaced with spaces
+        let chars = text
+            .chars()
+            .map(|c| if c == '\n' { ' ' } else { c })
+            .filter(|c| !c.is_control())
+            .collect::<Vec<_>>();
+        let len = chars.len();
+        self.chars.splice(self.cursor..self.cursor, chars);
+        self.cursor += len;
+        len > 0
+    }
+    /// Returns `false` if there is no older entry, so that the key can be handled elsewhere.
+    fn prev_history(&mut self) -> bool {
+        let index = match self.history_index {
+            Some(0) => return false,
+            Some(i) => i - 1,
+            None if self.history.is_empty() => return false,
+            None => {
+                self.draft = self.value();
+                self.history.len() - 1
+            }
+        };
+        self.history_index = Some(index);
+        let value = self.history[index].clone();
+        self.set_value(&value);
+        true
+    }
+    /// Returns `false` if the draft is already shown, so that the key can be handled elsewhere.
+    fn next_history(&mut self) -> bool {
+        let Some(index) = self.history_index else {
+            return false;
+        };
+        if index + 1 < self.history.len() {
+            self.history_index = Some(index + 1);
+            let value = self.history[index + 1].clone();
+            self.set_value(&value);
+        } else {
+            self.history_index = None;
+            let draft = std::mem::take(&mut self.draft);
+            self.set_value(&draft);
+        }
+        true
+    }
+}
+
+impl Widget for &TextInput {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let inner_width = self
+            .block
+            .as_ref()
+            .map_or(area, |block| block.inner(area))
+            .width as usize;
+        // scroll horizontally so that the cursor is always visible
+        let offset = (self.cursor + 1).saturating_sub(inner_width);
+        let mut spans = vec![Span::from(
+            self.chars[offset..self.cursor].iter().collect::<String>(),
+        )];
+        spans.push(Span::styled(
+            self.chars
+                .get(self.cursor)
+                .map_or(String::from(" "), char::to_string),
+            self.cursor_style,
+        ));
+        if self.cursor < self.chars.len() {
+            spans.push(Span::from(
+                self.chars[self.cursor + 1..].iter().collect::<String>(),
+            ));
+        }
+        let mut paragraph = Paragraph::new(Line::from(spans)).style(self.style);
+        if let Some(block) = &self.block {
+            paragraph = paragraph.block(block.clone());
+        }
+        paragraph.render(area, buf);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(code: KeyCode, modifiers: KeyModifiers) -> KeyEvent {
+        KeyEvent::new(code, modifiers)
+    }
+
+    #[test]
+    fn edit() {
+        let mut input = TextInput::default();
+        input.set_value("foo bar baz");
+        assert!(input.input(key(KeyCode::Left, KeyModifiers::CONTROL)));
+        assert!(input.input(key(KeyCode::Left, KeyModifiers::CONTROL)));
+        assert!(input.input(key(KeyCode::Char('k'), KeyModifiers::CONTROL)));
+        assert_eq!(input.value(), "foo ");
+        assert!(input.input(key(KeyCode::Char('x'), KeyModifiers::NONE)));
+        assert!(input.input(key(KeyCode::Home, KeyModifiers::NONE)));
+        assert!(input.input(key(KeyCode::Delete, KeyModifiers::NONE)));
+        assert_eq!(input.value(), "oo x");
+        assert!(input.input(key(KeyCode::End, KeyModifiers::NONE)));
+        assert!(input.input(key(KeyCode::Char('u'), KeyModifiers::CONTROL)));
+        assert_eq!(input.value(), "");
+        assert!(!input.input(key(KeyCode::Backspace, KeyModifiers::NONE)));
+    }
+
+    #[test]
+    fn history() {
+        let mut input = TextInput::with_history(vec![String::from("en"), String::from("ja")]);
+        input.set_value("draft");
+        assert!(input.input(key(KeyCode::Up, KeyModifiers::NONE)));
+        assert_eq!(input.value(), "ja");
+        assert!(input.input(key(KeyCode::Up, KeyModifiers::NONE)));
+        assert_eq!(input.value(), "en");
+        assert!(!input.input(key(KeyCode::Up, KeyModifiers::NONE)));
+        assert!(input.input(key(KeyCode::Down, KeyModifiers::NONE)));
+        assert!(input.input(key(KeyCode::Down, KeyModifiers::NONE)));
+        assert_eq!(input.value(), "draft");
+        assert!(!input.input(key(KeyCode::Down, KeyModifiers::NONE)));
+        input.push_history();
+        assert_eq!(input.history(), ["en", "ja", "draft"]);
+    }
+}
@@ -0,0 +1,144 @@
+use ratatui::layout::{Alignment, Rect};
+use ratatui::style::{Color, Stylize};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Clear, Padding, Paragraph, Wrap};
+use ratatui::Frame;
+use serde::{Deserialize, Serialize};
+
+const HIGHLIGHT: Color = Color::Yellow;
+const CALLOUT_HEIGHT: u16 = 7;
+
+/// The area highlighted by a step of the tutorial.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TutorialTarget {
+    Header,
+    Feed,
+    SelectedPost,
+}
+
+/// The areas of the view for each [`TutorialTarget`], known when the view is drawn.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TutorialAreas {
+    pub header: Rect,
+    pub feed: Rect,
+    pub selected_post: Rect,
+}
+
+#[derive(Debug, Clone)]
+pub struct TutorialStep {
+    pub title: &'static str,
+    pub text: String,
+    pub target: TutorialTarget,
+}
+
+/// Whether the tutorial has been seen, saved in the local store.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct TutorialState {
+    seen: bool,
+    // shown in a single column, even if several feeds are activated on the first run
+    #[serde(skip)]
+    shown: bool,
+}
+
+impl TutorialState {
+    /// Never show the tutorial again, after it is completed or dismissed.
+    pub fn mark_seen(&mut self) {
+        self.seen = true;
+    }
+}
+
+/// Walks through the steps of the tutorial, highlighting the area of each step with a callout.
+#[derive(Debug, Clone)]
+pub struct TutorialOverlay {
+    steps: Vec<TutorialStep>,
+    current: usize,
+}
+
+impl TutorialOverlay {
+    /// Returns the tutorial unless it has been seen, or is already shown in this session.
+    pub fn first_run(steps: Vec<TutorialStep>, state: &mut TutorialState) -> Option<Self> {
+        if steps.is_empty() || state.seen || state.shown {
+            return None;
+        }
+        state.shown = true;
+        Some(Self { steps, current: 0 })
+    }
+    /// Go to the next step, returns `false` once all the steps are done.
+    pub fn next(&mut self) -> bool {
+        self.current += 1;
+        self.current < self.steps.len()
+    }
+    pub fn draw(&self, f: &mut Frame<'_>, areas: &TutorialAreas) {
+        let Some(step) = self.steps.get(self.current) else {
+            return;
+        };
+        let target = match step.target {
+            TutorialTarget::Header => areas.header,
+            TutorialTarget::Feed => areas.feed,
+            TutorialTarget::SelectedPost => areas.selected_post,
+        };
+        let outer = areas.header.union(areas.feed);
+        if !target.is_empty() {
+            f.render_widget(Block::bordered().border_style(HIGHLIGHT), target);
+        }
+        // below the target if there is room, otherwise above it
+        let height = CALLOUT_HEIGHT.min(outer.height);
+        let y = if target.bottom() + height <= outer.bottom() {
+            target.bottom()
+        } else {
+            target.y.saturating_sub(height).max(outer.y)
+        };
+        let callout = Rect::new(
+            outer.x + 2.min(outer.width),
+            y,
+            outer.width.saturating_sub(4),
+            height,
+        );
+        f.render_widget(Clear, callout);
+        f.render_widget(
+            Paragraph::new(vec![
+                Line::from(vec![
+                    Span::from("➜ ").fg(HIGHLIGHT),
+                    Span::from(step.title).bold(),
+                ]),
+                Line::from(step.text.as_str()),
+            ])
+            .wrap(Wrap { trim: true })
+            .block(
+                Block::bordered()
+                    .border_style(HIGHLIGHT)
+                    .title(format!(" {}/{} ", self.current + 1, self.steps.len()))
+                    .title_bottom(Line::from(" Space: next, Esc: skip ").dim())
+                    .title_alignment(Alignment::Center)
+                    .padding(Padding::horizontal(1)),
+            ),
+            callout,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn steps() -> Vec<TutorialStep> {
+        vec![TutorialStep {
+            title: "Feed",
+            text: String::from("The posts of the feed"),
+            target: TutorialTarget::Feed,
+        }]
+    }
+
+    #[test]
+    fn first_run() {
+        let mut state = TutorialState::default();
+        assert!(TutorialOverlay::first_run(Vec::new(), &mut state).is_none());
+        assert!(TutorialOverlay::first_run(steps(), &mut state).is_some());
+        // already shown in another column
+        assert!(TutorialOverlay::first_run(steps(), &mut state).is_none());
+
+        let mut state = TutorialState::default();
+        state.mark_seen();
+        assert!(TutorialOverlay::first_run(steps(), &mut state).is_none());
+    }
+}
@@ -1,9 +1,17 @@
+use crate::backend::client;
 use crate::backend::config::Config as WatcherConfig;
+use crate::backend::translation::Config as TranslationConfig;
 use crate::components::views::types::Action as ViewAction;
 use crate::types::Action as AppAction;
+use bsky_sdk::agent::config::Config as AgentConfig;
+use bsky_sdk::api::types::string::{AtIdentifier, Language};
+use color_eyre::{eyre, Result};
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::style::{Color, Modifier, Style};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::collections::HashMap;
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Default)]
 pub struct Config {
@@ -12,6 +20,588 @@ pub struct Config {
     pub keybindings: Keybindings,
     #[serde(default)]
     pub watcher: WatcherConfig,
+    #[serde(default, deserialize_with = "deserialize_proxy")]
+    pub proxy: Option<String>,
+    pub browser_command: Option<String>,
+    /// The base URL of the PDS to open the records of the posts with, e.g. `https://bsky.social`.
+    pub pds_browser_url: Option<String>,
+    #[serde(default)]
+    pub columns: Vec<ColumnConfig>,
+    /// The feeds opened in the columns at startup, one per column in order.
+    #[serde(default, deserialize_with = "deserialize_feeds")]
+    pub feeds: Vec<FeedConfig>,
+    /// URLs of the RSS or Atom feeds which can be opened in the columns.
+    #[serde(default)]
+    pub rss_feeds: Vec<String>,
+    /// Domains whose links hide the posts from the feeds, including their subdomains.
+    #[serde(default)]
+    pub blocked_domains: Vec<String>,
+    #[serde(default)]
+    pub ui: UiConfig,
+    pub translation: Option<TranslationConfig>,
+    #[serde(default)]
+    pub compose: ComposeConfig,
+    /// Where the sensitive fields are stored, instead of the config file.
+    #[serde(default)]
+    pub secure_store: EncryptionMethod,
+    #[serde(default)]
+    pub dev: bool,
+    /// The age identity which decrypted the config file, to encrypt the sessions to with `AgeFile`.
+    #[serde(skip)]
+    pub age_identity: Option<PathBuf>,
+}
+
+/// How the sensitive fields of the config are protected.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub enum EncryptionMethod {
+    /// Stored in plaintext in the config file.
+    #[default]
+    None,
+    /// Stored in the keyring of the OS, set with `--set-secret`. So are the sessions of the columns.
+    Keyring,
+    /// The whole config file is encrypted with an age public key, decrypted with `--age-identity`.
+    /// The sessions of the columns are encrypted to the same identity in the app data.
+    AgeFile,
+}
+
+/// The config read from the file, with the sensitive fields resolved by `secure_store`.
+#[derive(Debug)]
+pub struct SecureConfig(Config);
+
+impl SecureConfig {
+    /// The fields kept out of the config file with a secure store.
+    pub const SENSITIVE_FIELDS: [&'static str; 2] = ["proxy", "translation.api_key"];
+
+    /// Parse the config file, which is decrypted with the age `identity` file if it is encrypted.
+    pub fn parse(input: &[u8], identity: &Path) -> Result<Self> {
+        let encrypted = AGE_HEADERS
+            .iter()
+            .any(|header| input.starts_with(header.as_bytes()));
+        let input = if encrypted {
+            decrypt_age(input, identity)?
+        } else {
+            input.to_vec()
+        };
+        let mut config = toml::from_str::<Config>(&String::from_utf8(input)?)?;
+        match config.secure_store {
+            EncryptionMethod::None => {}
+            EncryptionMethod::Keyring => Self::read_keyring(&mut config)?,
+            EncryptionMethod::AgeFile if !encrypted => {
+                return Err(eyre::eyre!(
+                    "secure_store = AgeFile, but the config file is not encrypted with age"
+                ));
+            }
+            EncryptionMethod::AgeFile => config.age_identity = Some(identity.to_path_buf()),
+        }
+        Ok(Self(config))
+    }
+    /// Store a sensitive field in the keyring of the OS, or remove it if `secret` is empty.
+    pub fn store_secret(field: &str, secret: &str) -> Result<()> {
+        if !Self::SENSITIVE_FIELDS.contains(&field) {
+            return Err(eyre::eyre!(
+                "{field} is not a sensitive field, expected one of {}",
+                Self::SENSITIVE_FIELDS.join(", ")
+            ));
+        }
+        if field == "proxy" && !secret.is_empty() {
+            client::proxy(secret).map_err(|e| eyre::eyre!(e))?;
+        }
+        let entry = keyring::Entry::new(KEYRING_SERVICE, field)?;
+        if secret.is_empty() {
+            match entry.delete_credential() {
+                Ok(()) | Err(keyring::Error::NoEntry) => Ok(()),
+                Err(e) => Err(e.into()),
+            }
+        } else {
+            Ok(entry.set_password(secret)?)
+        }
+    }
+    /// Fill the sensitive fields from the keyring, which must not be set in the file as well.
+    fn read_keyring(config: &mut Config) -> Result<()> {
+        let api_key = config
+            .translation
+            .as_ref()
+            .and_then(|translation| translation.api_key.as_ref());
+        for (field, value) in Self::SENSITIVE_FIELDS
+            .into_iter()
+            .zip([config.proxy.as_ref(), api_key])
+        {
+            if value.is_some() {
+                return Err(eyre::eyre!(
+                    "secure_store = Keyring, but {field} is set in the config file"
+                ));
+            }
+        }
+        config.proxy = keyring_secret("proxy")?;
+        if let Some(proxy) = &config.proxy {
+            client::proxy(proxy).map_err(|e| eyre::eyre!(e))?;
+        }
+        if let Some(translation) = config.translation.as_mut() {
+            translation.api_key = keyring_secret("translation.api_key")?;
+        }
+        Ok(())
+    }
+    pub fn into_inner(self) -> Config {
+        self.0
+    }
+}
+
+impl Config {
+    /// Take the session of the column at `index` out of `agent`, to keep it out of the app data.
+    ///
+    /// With `Keyring`, the session is stored in the keyring and nothing is returned.
+    /// With `AgeFile`, the session is returned encrypted to the age identity of the config file.
+    pub fn seal_session(&self, index: usize, agent: &mut AgentConfig) -> Result<Option<String>> {
+        match self.secure_store {
+            EncryptionMethod::None => Ok(None),
+            EncryptionMethod::Keyring => {
+                let entry = keyring::Entry::new(KEYRING_SERVICE, &session_field(index))?;
+                match agent.session.take() {
+                    Some(session) => entry.set_password(&serde_json::to_string(&session)?)?,
+                    None => match entry.delete_credential() {
+                        Ok(()) | Err(keyring::Error::NoEntry) => {}
+                        Err(e) => return Err(e.into()),
+                    },
+                }
+                Ok(None)
+            }
+            EncryptionMethod::AgeFile => agent
+                .session
+                .take()
+                .map(|session| encrypt_age(&serde_json::to_vec(&session)?, self.age_identity()?))
+                .transpose(),
+        }
+    }
+    /// Put the session of the column at `index` back into `agent`, the reverse of [`Self::seal_session`].
+    pub fn unseal_session(
+        &self,
+        index: usize,
+        agent: &mut AgentConfig,
+        sealed: Option<&str>,
+    ) -> Result<()> {
+        let session = match self.secure_store {
+            EncryptionMethod::None => return Ok(()),
+            EncryptionMethod::Keyring => keyring_secret(&session_field(index))?,
+            EncryptionMethod::AgeFile => sealed
+                .map(|sealed| {
+                    let plaintext = decrypt_age(sealed.as_bytes(), self.age_identity()?)?;
+                    Ok::<_, eyre::Report>(String::from_utf8(plaintext)?)
+                })
+                .transpose()?,
+        };
+        agent.session = session
+            .map(|session| serde_json::from_str(&session))
+            .transpose()?;
+        Ok(())
+    }
+    fn age_identity(&self) -> Result<&Path> {
+        self.age_identity
+            .as_deref()
+            .ok_or_else(|| eyre::eyre!("secure_store = AgeFile, but no age identity is given"))
+    }
+}
+
+/// The keyring entry of the session of the column at `index`.
+fn session_field(index: usize) -> String {
+    format!("session.{index}")
+}
+
+/// The service of the entries of the sensitive fields in the keyring.
+const KEYRING_SERVICE: &str = "tuisky";
+
+/// The first line of the files encrypted with age, in binary and in ASCII armor.
+const AGE_HEADERS: [&str; 2] = ["age-encryption.org/", "-----BEGIN AGE ENCRYPTED FILE-----"];
+
+fn keyring_secret(field: &str) -> Result<Option<String>> {
+    match keyring::Entry::new(KEYRING_SERVICE, field)?.get_password() {
+        Ok(secret) => Ok(Some(secret)),
+        Err(keyring::Error::NoEntry) => Ok(None),
+        Err(e) => Err(eyre::eyre!("failed to read {field} from the keyring: {e}")),
+    }
+}
+
+fn age_identity_file(identity: &Path) -> Result<age::IdentityFile<age::NoCallbacks>> {
+    age::IdentityFile::from_file(identity.to_string_lossy().into_owned()).map_err(|e| {
+        eyre::eyre!(
+            "failed to read the age identity file {}: {e}",
+            identity.display()
+        )
+    })
+}
+
+/// Encrypt `plaintext` to the public keys of the age `identity` file, in ASCII armor.
+fn encrypt_age(plaintext: &[u8], identity: &Path) -> Result<String> {
+    let recipients = age_identity_file(identity)?.to_recipients()?;
+    let encryptor = age::Encryptor::with_recipients(
+        recipients
+            .iter()
+            .map(|recipient| recipient.as_ref() as &dyn age::Recipient),
+    )?;
+    let mut output = Vec::new();
+    let mut writer = encryptor.wrap_output(age::armor::ArmoredWriter::wrap_output(
+        &mut output,
+        age::armor::Format::AsciiArmor,
+    )?)?;
+    writer.write_all(plaintext)?;
+    writer.finish()?.finish()?;
+    Ok(String::from_utf8(output)?)
+}
+
+fn decrypt_age(input: &[u8], identity: &Path) -> Result<Vec<u8>> {
+    let identities = age_identity_file(identity)?.into_identities()?;
+    let decryptor = age::Decryptor::new_buffered(age::armor::ArmoredReader::new(input))?;
+    let mut plaintext = Vec::new();
+    decryptor
+        .decrypt(identities.iter().map(|identity| identity.as_ref() as _))?
+        .read_to_end(&mut plaintext)?;
+    Ok(plaintext)
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(default)]
+pub struct ComposeConfig {
+    /// Maximum size of an attached video in megabytes.
+    pub max_video_mb: u64,
+}
+
+impl Default for ComposeConfig {
+    fn default() -> Self {
+        Self { max_video_mb: 50 }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(default)]
+pub struct UiConfig {
+    #[serde(default)]
+    pub compact_feed: bool,
+    #[serde(default)]
+    pub column_sizing: ColumnSizing,
+    /// Render posts as plain text for screen readers.
+    #[serde(default)]
+    pub accessible: bool,
+    /// Words excluded from the word cloud, in addition to the common English words.
+    #[serde(default)]
+    pub stop_words: Vec<String>,
+    #[serde(default)]
+    pub timestamp_format: TimestampFormat,
+    /// Skip the tutorial shown in the first feed on the first run.
+    #[serde(default)]
+    pub tutorial_seen: bool,
+    /// Capture the mouse, e.g. to open the context menu of a post with a right-click.
+    /// The text cannot be selected by the terminal while the mouse is captured.
+    #[serde(default)]
+    pub mouse: bool,
+    /// Ring the terminal bell when new posts arrive in a feed in live mode.
+    pub bell_on_new_posts: bool,
+    /// Minimum interval between the bells, in seconds.
+    pub bell_min_interval_secs: u64,
+    /// Hide the posts which are already shown in another column.
+    #[serde(default)]
+    pub hide_cross_column_duplicates: bool,
+    /// Ring the terminal bell when a post mentioning the account arrives in a feed.
+    #[serde(default)]
+    pub bell_on_mention: bool,
+    /// Show the thread of the selected post next to the feed.
+    #[serde(default)]
+    pub split_view: bool,
+    /// Alternate the background of the posts, to tell the adjacent ones apart.
+    #[serde(default)]
+    pub zebra_stripe: bool,
+}
+
+impl Default for UiConfig {
+    fn default() -> Self {
+        Self {
+            compact_feed: false,
+            column_sizing: ColumnSizing::default(),
+            accessible: false,
+            stop_words: Vec::new(),
+            timestamp_format: TimestampFormat::default(),
+            tutorial_seen: false,
+            mouse: false,
+            bell_on_new_posts: false,
+            bell_min_interval_secs: 10,
+            hide_cross_column_duplicates: false,
+            bell_on_mention: false,
+            split_view: false,
+            zebra_stripe: false,
+        }
+    }
+}
+
+/// How the timestamps of the posts are rendered.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub enum TimestampFormat {
+    /// `2024-09-10 12:34:56 +0900`
+    #[default]
+    FullDatetime,
+    /// `12:34`
+    TimeOnly,
+    /// `3 h ago`
+    Relative,
+    /// `09/10 12:34`
+    Compact,
+}
+
+impl TimestampFormat {
+    pub fn next(&self) -> Self {
+        match self {
+            Self::FullDatetime => Self::TimeOnly,
+            Self::TimeOnly => Self::Relative,
+            Self::Relative => Self::Compact,
+            Self::Compact => Self::FullDatetime,
+        }
+    }
+}
+
+/// How the width of the terminal is divided among the columns.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub enum ColumnSizing {
+    /// All the columns have the same width.
+    #[default]
+    Equal,
+    /// Columns with more unread notifications are wider.
+    Weighted,
+    /// Widths in terminal cells, in the order the columns are displayed.
+    /// The columns without a width share the rest equally.
+    Fixed(Vec<u16>),
+}
+
+/// The maximum number of the parents shown above a reply.
+pub const MAX_THREAD_DEPTH: u8 = 5;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ColumnConfig {
+    #[serde(default)]
+    pub style: HashMap<String, String>,
+    #[serde(default)]
+    pub only_with_media: bool,
+    /// Keep the column at the edge of the screen with a fixed width.
+    #[serde(default)]
+    pub pinned: Option<Pin>,
+    #[serde(default)]
+    pub show_reply_context: ReplyContext,
+    /// Hide the posts indexed more than this many hours ago.
+    #[serde(default)]
+    pub max_age_hours: Option<u64>,
+    /// The number of the parents shown above a reply, fetched if they are not in the feed.
+    #[serde(
+        default = "default_thread_depth",
+        deserialize_with = "deserialize_thread_depth"
+    )]
+    pub thread_depth: u8,
+    /// Tints the header and every other post of the feeds.
+    #[serde(
+        default,
+        deserialize_with = "deserialize_color",
+        serialize_with = "serialize_color"
+    )]
+    pub background: Option<Color>,
+}
+
+impl Default for ColumnConfig {
+    fn default() -> Self {
+        Self {
+            style: HashMap::new(),
+            only_with_media: false,
+            pinned: None,
+            show_reply_context: ReplyContext::default(),
+            max_age_hours: None,
+            thread_depth: default_thread_depth(),
+            background: None,
+        }
+    }
+}
+
+fn default_thread_depth() -> u8 {
+    1
+}
+
+/// A feed opened in a column at startup, after the login.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct FeedConfig {
+    #[serde(rename = "type")]
+    pub kind: FeedType,
+    /// The AT URI of the feed generator or the list, or the DID or handle of the actor.
+    pub uri: Option<String>,
+    /// Shown in the column title instead of the name of the feed.
+    pub label: Option<String>,
+    /// Show only the posts in these languages (BCP 47 language codes).
+    pub filter_languages: Option<Vec<String>>,
+    /// Show the posts without languages when `filter_languages` is set.
+    #[serde(default = "default_show_untagged")]
+    pub show_untagged: bool,
+    /// Update the feed once an hour, at the top of the hour.
+    #[serde(default)]
+    pub digest_mode: bool,
+}
+
+fn default_show_untagged() -> bool {
+    true
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum FeedType {
+    /// The following timeline.
+    Timeline,
+    Feed,
+    List,
+    /// The posts of an actor, or of the logged-in user without `uri`.
+    Actor,
+}
+
+impl FeedConfig {
+    /// The key of the feed source, to be matched without fetching the feed.
+    pub fn key(&self) -> String {
+        match (self.kind, &self.uri) {
+            (FeedType::Timeline, _) => String::from("timeline:following"),
+            (FeedType::Feed, Some(uri)) => format!("feed:{uri}"),
+            (FeedType::List, Some(uri)) => format!("list:{uri}"),
+            (FeedType::Actor, Some(actor)) => format!("author:{actor}"),
+            (FeedType::Actor, None) => String::from("own"),
+            (FeedType::Feed | FeedType::List, None) => String::new(),
+        }
+    }
+}
+
+/// How the parent of a reply is shown in the feeds.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub enum ReplyContext {
+    /// The author of the parent, if it is included in the feed.
+    #[default]
+    Auto,
+    /// The parent post, which is fetched if it is not included in the feed.
+    Always,
+    /// Nothing, only the reply.
+    Never,
+}
+
+/// The edge of the screen a pinned column is kept at.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum Pin {
+    Left,
+    Right,
+}
+
+/// Build a [`Style`] from properties such as `fg = "red"`, `bg = "#1e1e2e"` or `bold = "true"`.
+///
+/// Unknown properties and invalid values are ignored with a warning.
+pub fn parse_style(map: &HashMap<String, String>) -> Style {
+    let mut style = Style::default();
+    for (key, value) in map {
+        let modifier = match key.as_str() {
+            "fg" | "foreground" | "bg" | "background" => {
+                match value.parse::<Color>() {
+                    Ok(color) if key.starts_with('f') => style = style.fg(color),
+                    Ok(color) => style = style.bg(color),
+                    Err(e) => log::warn!("invalid color for `{key}`: {value} ({e})"),
+                }
+                continue;
+            }
+            "bold" => Modifier::BOLD,
+            "dim" => Modifier::DIM,
+            "italic" => Modifier::ITALIC,
+            "underlined" => Modifier::UNDERLINED,
+            "reversed" => Modifier::REVERSED,
+            _ => {
+                log::warn!("unknown style property: {key}");
+                continue;
+            }
+        };
+        match value.parse::<bool>() {
+            Ok(true) => style = style.add_modifier(modifier),
+            Ok(false) => style = style.remove_modifier(modifier),
+            Err(e) => log::warn!("invalid value for `{key}`: {value} ({e})"),
+        }
+    }
+    style
+}
+
+fn deserialize_proxy<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let proxy = Option::<String>::deserialize(deserializer)?;
+    if let Some(url) = &proxy {
+        client::proxy(url).map_err(serde::de::Error::custom)?;
+    }
+    Ok(proxy)
+}
+
+fn deserialize_color<'de, D>(deserializer: D) -> Result<Option<Color>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Option::<String>::deserialize(deserializer)?
+        .map(|s| {
+            s.parse::<Color>()
+                .map_err(|e| serde::de::Error::custom(format!("invalid color: {s} ({e})")))
+        })
+        .transpose()
+}
+
+fn serialize_color<S>(color: &Option<Color>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    match color {
+        Some(color) => serializer.serialize_some(&color.to_string()),
+        None => serializer.serialize_none(),
+    }
+}
+
+fn deserialize_thread_depth<'de, D>(deserializer: D) -> Result<u8, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let depth = u8::deserialize(deserializer)?;
+    if !(1..=MAX_THREAD_DEPTH).contains(&depth) {
+        return Err(serde::de::Error::custom(format!(
+            "invalid thread_depth: {depth} (expected 1 to {MAX_THREAD_DEPTH})"
+        )));
+    }
+    Ok(depth)
+}
+
+fn deserialize_feeds<'de, D>(deserializer: D) -> Result<Vec<FeedConfig>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let feeds = Vec::<FeedConfig>::deserialize(deserializer)?;
+    for feed in &feeds {
+        match (feed.kind, feed.uri.as_deref()) {
+            (FeedType::Feed | FeedType::List, None) => {
+                return Err(serde::de::Error::custom(format!(
+                    "`uri` is required for the feeds of type {:?}",
+                    feed.kind
+                )));
+            }
+            (FeedType::Feed | FeedType::List, Some(uri)) if !uri.starts_with("at://") => {
+                return Err(serde::de::Error::custom(format!(
+                    "invalid feed uri: {uri} (expected an AT URI)"
+                )));
+            }
+            (FeedType::Actor, Some(actor)) if actor.parse::<AtIdentifier>().is_err() => {
+                return Err(serde::de::Error::custom(format!(
+                    "invalid actor: {actor} (expected a DID or a handle)"
+                )));
+            }
+            _ => {}
+        }
+        if let Some(lang) = feed
+            .filter_languages
+            .iter()
+            .flatten()
+            .find(|lang| Language::new(lang.to_string()).is_err())
+        {
+            return Err(serde::de::Error::custom(format!(
+                "invalid language: {lang} (expected a BCP 47 language code)"
+            )));
+        }
+    }
+    Ok(feeds)
 }
 
 impl Config {
@@ -26,6 +616,16 @@ impl Config {
             .global
             .entry(Key(KeyCode::Char('o'), KeyModifiers::CONTROL))
             .or_insert(GlobalAction::NextFocus);
+        // global: Ctrl-l to ColumnManager
+        self.keybindings
+            .global
+            .entry(Key(KeyCode::Char('l'), KeyModifiers::CONTROL))
+            .or_insert(GlobalAction::ColumnManager);
+        // global: Ctrl-n to Noteboard
+        self.keybindings
+            .global
+            .entry(Key(KeyCode::Char('n'), KeyModifiers::CONTROL))
+            .or_insert(GlobalAction::Noteboard);
         // column: Down to NextItem
         self.keybindings
             .column
@@ -55,6 +655,248 @@ impl Config {
             .column
             .entry(Key(KeyCode::Char('x'), KeyModifiers::CONTROL))
             .or_insert(ColumnAction::Menu);
+        // column: t to Tack
+        self.keybindings
+            .column
+            .entry(Key(KeyCode::Char('t'), KeyModifiers::NONE))
+            .or_insert(ColumnAction::Tack);
+        // column: Shift-T to Untack
+        self.keybindings
+            .column
+            .entry(Key(KeyCode::Char('T'), KeyModifiers::SHIFT))
+            .or_insert(ColumnAction::Untack);
+        // column: Space to Toggle
+        self.keybindings
+            .column
+            .entry(Key(KeyCode::Char(' '), KeyModifiers::NONE))
+            .or_insert(ColumnAction::Toggle);
+        // column: z to FocusMode
+        self.keybindings
+            .column
+            .entry(Key(KeyCode::Char('z'), KeyModifiers::NONE))
+            .or_insert(ColumnAction::FocusMode);
+        // column: i to Info
+        self.keybindings
+            .column
+            .entry(Key(KeyCode::Char('i'), KeyModifiers::NONE))
+            .or_insert(ColumnAction::Info);
+        // column: Shift-F to Feeds
+        self.keybindings
+            .column
+            .entry(Key(KeyCode::Char('F'), KeyModifiers::SHIFT))
+            .or_insert(ColumnAction::Feeds);
+        // column: o to Open
+        self.keybindings
+            .column
+            .entry(Key(KeyCode::Char('o'), KeyModifiers::NONE))
+            .or_insert(ColumnAction::Open);
+        // column: F1 to Feedback
+        self.keybindings
+            .column
+            .entry(Key(KeyCode::F(1), KeyModifiers::NONE))
+            .or_insert(ColumnAction::Feedback);
+        // column: d to Delete
+        self.keybindings
+            .column
+            .entry(Key(KeyCode::Char('d'), KeyModifiers::NONE))
+            .or_insert(ColumnAction::Delete);
+        // column: Shift-Q to Quotes
+        self.keybindings
+            .column
+            .entry(Key(KeyCode::Char('Q'), KeyModifiers::SHIFT))
+            .or_insert(ColumnAction::Quotes);
+        // column: Shift-A to Analytics
+        self.keybindings
+            .column
+            .entry(Key(KeyCode::Char('A'), KeyModifiers::SHIFT))
+            .or_insert(ColumnAction::Analytics);
+        // column: + to Increase
+        self.keybindings
+            .column
+            .entry(Key(KeyCode::Char('+'), KeyModifiers::NONE))
+            .or_insert(ColumnAction::Increase);
+        // column: - to Decrease
+        self.keybindings
+            .column
+            .entry(Key(KeyCode::Char('-'), KeyModifiers::NONE))
+            .or_insert(ColumnAction::Decrease);
+        // column: Shift-L to Live
+        self.keybindings
+            .column
+            .entry(Key(KeyCode::Char('L'), KeyModifiers::SHIFT))
+            .or_insert(ColumnAction::Live);
+        // column: : to Command
+        self.keybindings
+            .column
+            .entry(Key(KeyCode::Char(':'), KeyModifiers::NONE))
+            .or_insert(ColumnAction::Command);
+        // column: p to Note
+        self.keybindings
+            .column
+            .entry(Key(KeyCode::Char('p'), KeyModifiers::NONE))
+            .or_insert(ColumnAction::Note);
+        // column: l to Translate (Shift-T is used for Untack)
+        self.keybindings
+            .column
+            .entry(Key(KeyCode::Char('l'), KeyModifiers::NONE))
+            .or_insert(ColumnAction::Translate);
+        // column: u to Mutuals (Shift-M is used for Media)
+        self.keybindings
+            .column
+            .entry(Key(KeyCode::Char('u'), KeyModifiers::NONE))
+            .or_insert(ColumnAction::Mutuals);
+        // column: a to SearchAuthor
+        self.keybindings
+            .column
+            .entry(Key(KeyCode::Char('a'), KeyModifiers::NONE))
+            .or_insert(ColumnAction::SearchAuthor);
+        // column: Shift-D to Diff (v is used for Visual)
+        self.keybindings
+            .column
+            .entry(Key(KeyCode::Char('D'), KeyModifiers::SHIFT))
+            .or_insert(ColumnAction::Diff);
+        // column: n to Comment (c is used for Compact)
+        self.keybindings
+            .column
+            .entry(Key(KeyCode::Char('n'), KeyModifiers::NONE))
+            .or_insert(ColumnAction::Comment);
+        // column: Ctrl-c to Calendar
+        self.keybindings
+            .column
+            .entry(Key(KeyCode::Char('c'), KeyModifiers::CONTROL))
+            .or_insert(ColumnAction::Calendar);
+        // column: Alt-a to MarkAllRead
+        self.keybindings
+            .column
+            .entry(Key(KeyCode::Char('a'), KeyModifiers::ALT))
+            .or_insert(ColumnAction::MarkAllRead);
+        // column: x to NotInterested (Ctrl-x is used for Menu)
+        self.keybindings
+            .column
+            .entry(Key(KeyCode::Char('x'), KeyModifiers::NONE))
+            .or_insert(ColumnAction::NotInterested);
+        // column: Shift-W to WordCloud
+        self.keybindings
+            .column
+            .entry(Key(KeyCode::Char('W'), KeyModifiers::SHIFT))
+            .or_insert(ColumnAction::WordCloud);
+        // column: Ctrl-e to Export
+        self.keybindings
+            .column
+            .entry(Key(KeyCode::Char('e'), KeyModifiers::CONTROL))
+            .or_insert(ColumnAction::Export);
+        // column: g to GroupThreads
+        self.keybindings
+            .column
+            .entry(Key(KeyCode::Char('g'), KeyModifiers::NONE))
+            .or_insert(ColumnAction::GroupThreads);
+        // column: ] to NextByAuthor (n is used for Comment)
+        self.keybindings
+            .column
+            .entry(Key(KeyCode::Char(']'), KeyModifiers::NONE))
+            .or_insert(ColumnAction::NextByAuthor);
+        // column: [ to PrevByAuthor (paired with ], as n is used for Comment)
+        self.keybindings
+            .column
+            .entry(Key(KeyCode::Char('['), KeyModifiers::NONE))
+            .or_insert(ColumnAction::PrevByAuthor);
+        // column: Ctrl-a to MaxAge
+        self.keybindings
+            .column
+            .entry(Key(KeyCode::Char('a'), KeyModifiers::CONTROL))
+            .or_insert(ColumnAction::MaxAge);
+        // column: Ctrl-f to FocusAuthor
+        self.keybindings
+            .column
+            .entry(Key(KeyCode::Char('f'), KeyModifiers::CONTROL))
+            .or_insert(ColumnAction::FocusAuthor);
+        // column: Shift-E to ExternalLinks
+        self.keybindings
+            .column
+            .entry(Key(KeyCode::Char('E'), KeyModifiers::SHIFT))
+            .or_insert(ColumnAction::ExternalLinks);
+        // column: Ctrl-t to TimestampFormat
+        self.keybindings
+            .column
+            .entry(Key(KeyCode::Char('t'), KeyModifiers::CONTROL))
+            .or_insert(ColumnAction::TimestampFormat);
+        // column: Shift-X to BlockDomain
+        self.keybindings
+            .column
+            .entry(Key(KeyCode::Char('X'), KeyModifiers::SHIFT))
+            .or_insert(ColumnAction::BlockDomain);
+        // column: Ctrl-s to FeedStats
+        self.keybindings
+            .column
+            .entry(Key(KeyCode::Char('s'), KeyModifiers::CONTROL))
+            .or_insert(ColumnAction::FeedStats);
+        // column: Shift-C to CompareAnchor
+        self.keybindings
+            .column
+            .entry(Key(KeyCode::Char('C'), KeyModifiers::SHIFT))
+            .or_insert(ColumnAction::CompareAnchor);
+        // column: Shift-P to PdsRecord
+        self.keybindings
+            .column
+            .entry(Key(KeyCode::Char('P'), KeyModifiers::SHIFT))
+            .or_insert(ColumnAction::PdsRecord);
+        // column: Shift-R to Repliers (e is used for Expand)
+        self.keybindings
+            .column
+            .entry(Key(KeyCode::Char('R'), KeyModifiers::SHIFT))
+            .or_insert(ColumnAction::Repliers);
+        // column: Ctrl-p to PinConversation
+        self.keybindings
+            .column
+            .entry(Key(KeyCode::Char('p'), KeyModifiers::CONTROL))
+            .or_insert(ColumnAction::PinConversation);
+        // column: y to CopyCid (Ctrl-c is used for Calendar)
+        self.keybindings
+            .column
+            .entry(Key(KeyCode::Char('y'), KeyModifiers::NONE))
+            .or_insert(ColumnAction::CopyCid);
+        // column: Ctrl-g to TagPost (Ctrl-t is used for TimestampFormat)
+        self.keybindings
+            .column
+            .entry(Key(KeyCode::Char('g'), KeyModifiers::CONTROL))
+            .or_insert(ColumnAction::TagPost);
+        // column: v to Visual
+        self.keybindings
+            .column
+            .entry(Key(KeyCode::Char('v'), KeyModifiers::NONE))
+            .or_insert(ColumnAction::Visual);
+        // column: h to Header
+        self.keybindings
+            .column
+            .entry(Key(KeyCode::Char('h'), KeyModifiers::NONE))
+            .or_insert(ColumnAction::Header);
+        // column: c to Compact
+        self.keybindings
+            .column
+            .entry(Key(KeyCode::Char('c'), KeyModifiers::NONE))
+            .or_insert(ColumnAction::Compact);
+        // column: e to Expand
+        self.keybindings
+            .column
+            .entry(Key(KeyCode::Char('e'), KeyModifiers::NONE))
+            .or_insert(ColumnAction::Expand);
+        // column: Shift-M to Media
+        self.keybindings
+            .column
+            .entry(Key(KeyCode::Char('M'), KeyModifiers::SHIFT))
+            .or_insert(ColumnAction::Media);
+        // column: Alt-m to Metrics (dev mode only, Shift-M is used for Media)
+        if self.dev {
+            self.keybindings
+                .column
+                .entry(Key(KeyCode::Char('m'), KeyModifiers::ALT))
+                .or_insert(ColumnAction::Metrics);
+            // column: Shift-J to RawRecord (dev mode only)
+            self.keybindings
+                .column
+                .entry(Key(KeyCode::Char('J'), KeyModifiers::SHIFT))
+                .or_insert(ColumnAction::RawRecord);
+        }
     }
 }
 
@@ -96,6 +938,7 @@ impl Serialize for Key {
     {
         let key_code = match self.0 {
             KeyCode::Char(c) => c.to_string(),
+            KeyCode::F(n) => format!("F{n}"),
             _ => format!("{:?}", self.0),
         };
         if self.1 == KeyModifiers::NONE {
@@ -104,6 +947,7 @@ impl Serialize for Key {
             let modifier = match self.1 {
                 KeyModifiers::CONTROL => "Ctrl",
                 KeyModifiers::SHIFT => "Shift",
+                KeyModifiers::ALT => "Alt",
                 _ => return Err(serde::ser::Error::custom("unsupported key modifier")),
             };
             format!("{modifier}-{key_code}").serialize(serializer)
@@ -125,6 +969,7 @@ impl<'de> Deserialize<'de> for Key {
                     match modifier {
                         "Ctrl" => KeyModifiers::CONTROL,
                         "Shift" => KeyModifiers::SHIFT,
+                        "Alt" => KeyModifiers::ALT,
                         _ => return Err(serde::de::Error::custom("invalid key modifier")),
                     },
                 ))
@@ -148,6 +993,10 @@ impl<'de> Deserialize<'de> for Key {
                 "Delete" => KeyCode::Delete,
                 "Insert" => KeyCode::Insert,
                 "Esc" => KeyCode::Esc,
+                _ if s.starts_with('F') && s.len() > 1 => match s[1..].parse() {
+                    Ok(n @ 1..=12) => KeyCode::F(n),
+                    _ => return Err(serde::de::Error::custom("unsupported key code")),
+                },
                 _ if s.len() == 1 => KeyCode::Char(s.chars().next().unwrap()),
                 _ => return Err(serde::de::Error::custom("unsupported key code")),
             };
@@ -160,6 +1009,8 @@ impl<'de> Deserialize<'de> for Key {
 pub enum GlobalAction {
     NextFocus,
     PrevFocus,
+    ColumnManager,
+    Noteboard,
     Quit,
 }
 
@@ -168,6 +1019,8 @@ impl From<&GlobalAction> for AppAction {
         match action {
             GlobalAction::NextFocus => Self::NextFocus,
             GlobalAction::PrevFocus => Self::PrevFocus,
+            GlobalAction::ColumnManager => Self::ColumnManager,
+            GlobalAction::Noteboard => Self::Noteboard,
             GlobalAction::Quit => Self::Quit,
         }
     }
@@ -182,6 +1035,54 @@ pub enum ColumnAction {
     Refresh,
     NewPost,
     Menu,
+    Tack,
+    Untack,
+    Toggle,
+    FocusMode,
+    Info,
+    Feeds,
+    Open,
+    Feedback,
+    Delete,
+    Quotes,
+    Metrics,
+    Media,
+    Expand,
+    Analytics,
+    Compact,
+    Header,
+    Increase,
+    Decrease,
+    Live,
+    Command,
+    Note,
+    Translate,
+    Mutuals,
+    SearchAuthor,
+    Diff,
+    Comment,
+    Calendar,
+    MarkAllRead,
+    NotInterested,
+    WordCloud,
+    Export,
+    GroupThreads,
+    NextByAuthor,
+    PrevByAuthor,
+    MaxAge,
+    FocusAuthor,
+    ExternalLinks,
+    TimestampFormat,
+    BlockDomain,
+    FeedStats,
+    CompareAnchor,
+    PdsRecord,
+    RawRecord,
+    Repliers,
+    PinConversation,
+    CopyCid,
+    TagPost,
+    Visual,
 }
 
 impl From<&ColumnAction> for ViewAction {
@@ -194,6 +1095,54 @@ impl From<&ColumnAction> for ViewAction {
             ColumnAction::Refresh => Self::Refresh,
             ColumnAction::NewPost => Self::NewPost,
             ColumnAction::Menu => Self::Menu,
+            ColumnAction::Tack => Self::Tack,
+            ColumnAction::Untack => Self::Untack,
+            ColumnAction::Toggle => Self::Toggle,
+            ColumnAction::FocusMode => Self::FocusMode,
+            ColumnAction::Info => Self::Info,
+            ColumnAction::Feeds => Self::Feeds,
+            ColumnAction::Open => Self::Open,
+            ColumnAction::Feedback => Self::Feedback,
+            ColumnAction::Delete => Self::Delete,
+            ColumnAction::Quotes => Self::Quotes,
+            ColumnAction::Metrics => Self::Metrics,
+            ColumnAction::Media => Self::Media,
+            ColumnAction::Expand => Self::Expand,
+            ColumnAction::Analytics => Self::Analytics,
+            ColumnAction::Compact => Self::Compact,
+            ColumnAction::Header => Self::Header,
+            ColumnAction::Increase => Self::Increase,
+            ColumnAction::Decrease => Self::Decrease,
+            ColumnAction::Live => Self::Live,
+            ColumnAction::Command => Self::Command,
+            ColumnAction::Note => Self::Note,
+            ColumnAction::Translate => Self::Translate,
+            ColumnAction::Mutuals => Self::Mutuals,
+            ColumnAction::SearchAuthor => Self::SearchAuthor,
+            ColumnAction::Diff => Self::Diff,
+            ColumnAction::Comment => Self::Comment,
+            ColumnAction::Calendar => Self::Calendar,
+            ColumnAction::MarkAllRead => Self::MarkAllRead,
+            ColumnAction::NotInterested => Self::NotInterested,
+            ColumnAction::WordCloud => Self::WordCloud,
+            ColumnAction::Export => Self::Export,
+            ColumnAction::GroupThreads => Self::GroupThreads,
+            ColumnAction::NextByAuthor => Self::NextByAuthor,
+            ColumnAction::PrevByAuthor => Self::PrevByAuthor,
+            ColumnAction::MaxAge => Self::MaxAge,
+            ColumnAction::FocusAuthor => Self::FocusAuthor,
+            ColumnAction::ExternalLinks => Self::ExternalLinks,
+            ColumnAction::TimestampFormat => Self::TimestampFormat,
+            ColumnAction::BlockDomain => Self::BlockDomain,
+            ColumnAction::FeedStats => Self::FeedStats,
+            ColumnAction::CompareAnchor => Self::CompareAnchor,
+            ColumnAction::PdsRecord => Self::PdsRecord,
+            ColumnAction::RawRecord => Self::RawRecord,
+            ColumnAction::Repliers => Self::Repliers,
+            ColumnAction::PinConversation => Self::PinConversation,
+            ColumnAction::CopyCid => Self::CopyCid,
+            ColumnAction::TagPost => Self::TagPost,
+            ColumnAction::Visual => Self::Visual,
         }
     }
 }
@@ -202,6 +1151,7 @@ impl From<&ColumnAction> for ViewAction {
 mod tests {
     use super::*;
     use crate::backend::config::Intervals;
+    use crate::backend::translation::Provider;
 
     #[test]
     fn deserialize_empty() {
@@ -211,7 +1161,9 @@ mod tests {
 
     #[test]
     fn deserialize() {
-        let input = r#"
+        let input = r##"
+proxy = "socks5://127.0.0.1:9050"
+
 [keybindings.global]
 Ctrl-c = "Quit"
 
@@ -222,7 +1174,27 @@ Left = "Back"
 
 [watcher.intervals]
 feed = 20
-"#;
+
+[[columns]]
+
+[[columns]]
+style = { fg = "red" }
+show_reply_context = "Never"
+max_age_hours = 24
+thread_depth = 3
+background = "#1e1e2e"
+
+[[feeds]]
+type = "timeline"
+filter_languages = ["en", "es"]
+show_untagged = false
+digest_mode = true
+
+[[feeds]]
+type = "list"
+uri = "at://did:plc:abc/app.bsky.graph.list/xyz"
+label = "Friends"
+"##;
         let config = toml::from_str::<Config>(input).expect("failed to deserialize config");
         assert_eq!(
             config,
@@ -250,8 +1222,54 @@ feed = 20
                         preferences: 600,
                         feed: 20,
                         post_thread: 60,
-                    }
-                }
+                        notification_count: 30,
+                        chat_unread_count: 30,
+                        rss: 600,
+                        suggested: 600,
+                    },
+                    firehose: false,
+                },
+                proxy: Some(String::from("socks5://127.0.0.1:9050")),
+                browser_command: None,
+                pds_browser_url: None,
+                columns: vec![
+                    ColumnConfig::default(),
+                    ColumnConfig {
+                        style: HashMap::from_iter([(String::from("fg"), String::from("red"))]),
+                        only_with_media: false,
+                        pinned: None,
+                        show_reply_context: ReplyContext::Never,
+                        max_age_hours: Some(24),
+                        thread_depth: 3,
+                        background: Some(Color::Rgb(0x1e, 0x1e, 0x2e)),
+                    },
+                ],
+                feeds: vec![
+                    FeedConfig {
+                        kind: FeedType::Timeline,
+                        uri: None,
+                        label: None,
+                        filter_languages: Some(vec![String::from("en"), String::from("es")]),
+                        show_untagged: false,
+                        digest_mode: true,
+                    },
+                    FeedConfig {
+                        kind: FeedType::List,
+                        uri: Some(String::from("at://did:plc:abc/app.bsky.graph.list/xyz")),
+                        label: Some(String::from("Friends")),
+                        filter_languages: None,
+                        show_untagged: true,
+                        digest_mode: false,
+                    },
+                ],
+                rss_feeds: Vec::new(),
+                blocked_domains: Vec::new(),
+                ui: UiConfig::default(),
+                translation: None,
+                compose: ComposeConfig::default(),
+                secure_store: EncryptionMethod::None,
+                dev: false,
+                age_identity: None,
             }
         )
     }
@@ -272,11 +1290,205 @@ feed = 20
                     feed: 10,
                     preferences: 10,
                     post_thread: 180,
+                    notification_count: 15,
+                    chat_unread_count: 60,
+                    rss: 600,
+                    suggested: 600,
+                },
+                firehose: true,
+            },
+            proxy: None,
+            browser_command: Some(String::from("w3m %s")),
+            pds_browser_url: Some(String::from("https://pds.example.com")),
+            columns: vec![
+                ColumnConfig::default(),
+                ColumnConfig {
+                    background: Some(Color::Blue),
+                    ..Default::default()
                 },
+            ],
+            feeds: vec![FeedConfig {
+                kind: FeedType::Actor,
+                uri: Some(String::from("alice.bsky.social")),
+                label: None,
+                filter_languages: Some(vec![String::from("ja")]),
+                show_untagged: true,
+                digest_mode: true,
+            }],
+            rss_feeds: vec![String::from("https://example.com/feed.xml")],
+            blocked_domains: vec![String::from("example.org")],
+            ui: UiConfig {
+                compact_feed: true,
+                column_sizing: ColumnSizing::Fixed(vec![80, 60]),
+                accessible: true,
+                stop_words: vec![String::from("bluesky")],
+                timestamp_format: TimestampFormat::Relative,
+                tutorial_seen: true,
+                mouse: true,
+                bell_on_new_posts: true,
+                bell_min_interval_secs: 30,
+                hide_cross_column_duplicates: true,
+                bell_on_mention: true,
+                split_view: true,
+                zebra_stripe: true,
             },
+            translation: Some(TranslationConfig {
+                url: String::from("https://api-free.deepl.com/v2/translate"),
+                provider: Provider::DeepL,
+                api_key: Some(String::from("xxx")),
+                target: String::from("ja"),
+            }),
+            compose: ComposeConfig { max_video_mb: 100 },
+            secure_store: EncryptionMethod::Keyring,
+            dev: true,
+            age_identity: None,
         };
         let s = toml::to_string(&config).expect("failed to serialize config");
         let deserialized = toml::from_str::<Config>(&s).expect("failed to deserialize config");
         assert_eq!(deserialized, config);
     }
+
+    #[test]
+    fn parse_column_style() {
+        let style = parse_style(&HashMap::from_iter(
+            [
+                ("fg", "red"),
+                ("background", "#1e1e2e"),
+                ("bold", "true"),
+                ("italic", "false"),
+                ("blink", "true"),
+            ]
+            .map(|(k, v)| (k.to_string(), v.to_string())),
+        ));
+        assert_eq!(
+            style,
+            Style::default()
+                .fg(Color::Red)
+                .bg(Color::Rgb(0x1e, 0x1e, 0x2e))
+                .add_modifier(Modifier::BOLD)
+                .remove_modifier(Modifier::ITALIC)
+        );
+    }
+
+    #[test]
+    fn deserialize_invalid_proxy() {
+        for input in [
+            r#"proxy = "127.0.0.1:8080""#,
+            r#"proxy = "ftp://127.0.0.1:8080""#,
+        ] {
+            let err = toml::from_str::<Config>(input).expect_err("proxy should be invalid");
+            assert!(err.to_string().contains("invalid proxy url"), "{err}");
+        }
+    }
+
+    #[test]
+    fn deserialize_invalid_feeds() {
+        for (input, expected) in [
+            (r#"feeds = [{ type = "home" }]"#, "unknown variant"),
+            (r#"feeds = [{ type = "feed" }]"#, "`uri` is required"),
+            (
+                r#"feeds = [{ type = "list", uri = "https://bsky.app" }]"#,
+                "invalid feed uri",
+            ),
+            (
+                r#"feeds = [{ type = "actor", uri = "@alice" }]"#,
+                "invalid actor",
+            ),
+            (
+                r#"feeds = [{ type = "timeline", filter_languages = ["en us"] }]"#,
+                "invalid language",
+            ),
+        ] {
+            let err = toml::from_str::<Config>(input).expect_err("feeds should be invalid");
+            assert!(err.to_string().contains(expected), "{err}");
+        }
+    }
+
+    #[test]
+    fn parse_secure_config() {
+        let config = SecureConfig::parse(b"dev = true", Path::new(""))
+            .expect("failed to parse config")
+            .into_inner();
+        assert!(config.dev);
+        for (input, expected) in [
+            (
+                "secure_store = \"Keyring\"\nproxy = \"http://localhost:8080\"",
+                "proxy is set in the config file",
+            ),
+            (
+                "secure_store = \"Keyring\"\n[translation]\nurl = \"https://example.com\"\nprovider = \"DeepL\"\napi_key = \"xxx\"\ntarget = \"ja\"",
+                "translation.api_key is set in the config file",
+            ),
+            ("secure_store = \"AgeFile\"", "not encrypted with age"),
+        ] {
+            let err = SecureConfig::parse(input.as_bytes(), Path::new(""))
+                .expect_err("secure config should be invalid");
+            assert!(err.to_string().contains(expected), "{err}");
+        }
+    }
+
+    #[test]
+    fn parse_age_encrypted_config() -> Result<()> {
+        use age::secrecy::ExposeSecret;
+
+        let identity = age::x25519::Identity::generate();
+        let path = std::env::temp_dir().join(format!("tuisky-age-{}.txt", std::process::id()));
+        std::fs::write(&path, identity.to_string().expose_secret())?;
+        let input = age::encrypt(
+            &identity.to_public(),
+            b"secure_store = \"AgeFile\"\nproxy = \"socks5://localhost:1080\"",
+        )?;
+        let config = SecureConfig::parse(&input, &path);
+        std::fs::remove_file(&path)?;
+        assert_eq!(
+            config?.into_inner().proxy.as_deref(),
+            Some("socks5://localhost:1080")
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn seal_session_with_age() -> Result<()> {
+        use age::secrecy::ExposeSecret;
+        use bsky_sdk::api::agent::Session;
+
+        let identity = age::x25519::Identity::generate();
+        let path = std::env::temp_dir().join(format!("tuisky-session-{}.txt", std::process::id()));
+        std::fs::write(&path, identity.to_string().expose_secret())?;
+        let config = Config {
+            secure_store: EncryptionMethod::AgeFile,
+            age_identity: Some(path.clone()),
+            ..Default::default()
+        };
+        let session = serde_json::from_str::<Session>(
+            r#"{"accessJwt":"access","refreshJwt":"refresh","did":"did:plc:test","handle":"test.bsky.social"}"#,
+        )?;
+        let mut agent = AgentConfig {
+            session: Some(session),
+            ..Default::default()
+        };
+        let sealed = config.seal_session(0, &mut agent);
+        let result = sealed.and_then(|sealed| {
+            assert!(agent.session.is_none());
+            let sealed = sealed.expect("session should be sealed");
+            assert!(!sealed.contains("refresh"));
+            config.unseal_session(0, &mut agent, Some(&sealed))
+        });
+        std::fs::remove_file(&path)?;
+        result?;
+        assert_eq!(
+            agent.session.map(|session| session.refresh_jwt.clone()),
+            Some(String::from("refresh"))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn deserialize_invalid_thread_depth() {
+        for depth in [0, MAX_THREAD_DEPTH + 1] {
+            let err = toml::from_str::<Config>(&format!("[[columns]]\nthread_depth = {depth}"))
+                .expect_err("thread_depth should be invalid");
+            assert!(err.to_string().contains("invalid thread_depth"), "{err}");
+        }
+    }
 }
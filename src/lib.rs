@@ -1,8 +1,10 @@
 mod action;
 pub mod app;
 mod backend;
+mod commands;
 mod components;
 pub mod config;
+pub mod output;
 mod tui;
 mod types;
 pub mod utils;
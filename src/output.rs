@@ -0,0 +1,230 @@
+use crate::backend::client::agent_builder;
+use crate::backend::export;
+use crate::backend::rate_limit::RateLimit;
+use crate::backend::types::FeedSourceInfo;
+use crate::backend::Watcher;
+use crate::components::main::MainComponent;
+use crate::config::Config;
+use bsky_sdk::api::app::bsky::actor::defs::{SavedFeed, SavedFeedData};
+use bsky_sdk::api::app::bsky::feed::defs::FeedViewPost;
+use color_eyre::{eyre, Result};
+use indexmap::IndexSet;
+use std::io::{self, stdout, Write};
+use std::sync::Arc;
+use tokio::sync::watch::Receiver;
+
+/// The number of the posts remembered with `--watch`, the oldest ones are forgotten first.
+const MAX_SEEN: usize = 10_000;
+
+/// How the posts are written with `--watch`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WatchFormat {
+    /// One JSON object per line.
+    Json,
+    /// One line per post, with the time, the handle and the text.
+    Text,
+    /// One section per post.
+    Markdown,
+}
+
+/// Print the posts of a feed to stdout as JSON, without starting the TUI.
+///
+/// `feed` is the AT-URI of a feed generator or a list, or `following` for the timeline.
+/// If it is not given, the first pinned feed is used.
+/// The session of the first signed-in column is used, so it must have been signed in once with the TUI.
+pub async fn print_feed(config: &Config, feed: Option<&str>, ndjson: bool) -> Result<()> {
+    let (index, watcher) = start_watcher(config, "--output").await?;
+    let result = print_posts(&watcher, feed, ndjson).await;
+    watcher.shutdown().await;
+    // also after a failure, as the tokens may have been refreshed in the meantime
+    let stored = MainComponent::store_agent_config(config, index, watcher.agent.to_config().await);
+    result.and(stored)
+}
+
+async fn print_posts(watcher: &Watcher, feed: Option<&str>, ndjson: bool) -> Result<()> {
+    let watch = watcher.feed_source(feed_source(watcher, feed).await?);
+    let mut rx = watch.subscribe();
+    // the first update of the watch, which fetches the feed once
+    let changed = rx.changed().await;
+    watch.unsubscribe();
+    changed?;
+    let posts = rx.borrow_and_update().clone();
+    let mut stdout = stdout().lock();
+    let result = if ndjson {
+        posts
+            .iter()
+            .try_for_each(|post| writeln!(stdout, "{}", serde_json::to_string(post)?))
+    } else {
+        serde_json::to_writer_pretty(&mut stdout, &posts)
+            .map_err(io::Error::from)
+            .and_then(|()| writeln!(stdout))
+    };
+    match result {
+        // e.g. `tuisky --output ndjson | head`
+        Err(e) if e.kind() == io::ErrorKind::BrokenPipe => Ok(()),
+        result => Ok(result?),
+    }
+}
+
+/// Write the new posts of a feed to stdout as they arrive, until Ctrl-C is pressed.
+///
+/// `feed` is the same as in [`print_feed`], with `timeline` for the timeline. The posts come from
+/// the feed watcher, and the ones already loaded at the start are skipped.
+pub async fn watch_feed(config: &Config, feed: &str, format: WatchFormat) -> Result<()> {
+    let (index, watcher) = start_watcher(config, "--watch").await?;
+    let result = write_new_posts(&watcher, feed, format).await;
+    watcher.shutdown().await;
+    // also after a failure, as the tokens may have been refreshed in the meantime
+    let stored = MainComponent::store_agent_config(config, index, watcher.agent.to_config().await);
+    result.and(stored)
+}
+
+/// Sign in with the session of the first signed-in column, and start a watcher with it.
+async fn start_watcher(config: &Config, flag: &str) -> Result<(usize, Watcher)> {
+    let (index, agent_config) = MainComponent::saved_agent_config(config)?
+        .ok_or_else(|| eyre::eyre!("not signed in, run tuisky without {flag} first"))?;
+    let rate_limit = RateLimit::default();
+    let agent = agent_builder(config.proxy.as_deref(), rate_limit.clone())?
+        .config(agent_config)
+        .build()
+        .await?;
+    Ok((
+        index,
+        Watcher::new(Arc::new(agent), rate_limit, config.watcher.clone()),
+    ))
+}
+
+async fn write_new_posts(watcher: &Watcher, feed: &str, format: WatchFormat) -> Result<()> {
+    let feed = if feed == "timeline" {
+        "following"
+    } else {
+        feed
+    };
+    let watch = watcher.feed_source(feed_source(watcher, Some(feed)).await?);
+    let result = stream_posts(watch.subscribe(), format).await;
+    watch.unsubscribe();
+    result
+}
+
+/// Write the posts which were not received before, until Ctrl-C is pressed or stdout is closed.
+async fn stream_posts(mut rx: Receiver<Vec<FeedViewPost>>, format: WatchFormat) -> Result<()> {
+    let mut seen = IndexSet::new();
+    let mut first = true;
+    let ctrl_c = tokio::signal::ctrl_c();
+    tokio::pin!(ctrl_c);
+    loop {
+        tokio::select! {
+            changed = rx.changed() => changed?,
+            _ = &mut ctrl_c => return Ok(()),
+        }
+        // from the oldest one
+        let new_posts = rx
+            .borrow_and_update()
+            .iter()
+            .rev()
+            .filter(|feed_view_post| seen.insert(feed_view_post.post.cid.clone()))
+            .cloned()
+            .collect::<Vec<_>>();
+        if seen.len() > MAX_SEEN {
+            let excess = seen.len() - MAX_SEEN;
+            seen.drain(..excess);
+        }
+        if std::mem::replace(&mut first, false) {
+            continue;
+        }
+        match write_posts(&new_posts, format) {
+            // e.g. `tuisky --watch timeline | head`
+            Err(e) if e.kind() == io::ErrorKind::BrokenPipe => return Ok(()),
+            result => result?,
+        }
+    }
+}
+
+fn write_posts(posts: &[FeedViewPost], format: WatchFormat) -> io::Result<()> {
+    let mut stdout = stdout().lock();
+    match format {
+        WatchFormat::Json => {
+            for post in posts {
+                writeln!(stdout, "{}", serde_json::to_string(post)?)?;
+            }
+        }
+        WatchFormat::Text => {
+            for post in posts {
+                writeln!(
+                    stdout,
+                    "{} @{}: {}",
+                    export::timestamp(&post.post),
+                    post.post.author.handle.as_str(),
+                    export::text(&post.post).replace('\n', " ")
+                )?;
+            }
+        }
+        WatchFormat::Markdown => write!(stdout, "{}", export::markdown(posts))?,
+    }
+    stdout.flush()
+}
+
+/// Resolve `feed` to its source, or the first pinned feed if it is not given.
+///
+/// `feed` is the AT-URI of a feed generator or a list, or `following` for the timeline.
+async fn feed_source(watcher: &Watcher, feed: Option<&str>) -> Result<FeedSourceInfo> {
+    let saved_feeds = match feed {
+        Some(value @ "following") => {
+            return Ok(FeedSourceInfo::Timeline(value.to_string()));
+        }
+        Some(uri) => vec![saved_feed(uri)?],
+        None => watcher.agent.get_preferences(true).await?.saved_feeds,
+    };
+    watcher
+        .resolve_feeds(&saved_feeds)
+        .await?
+        .into_iter()
+        .next()
+        .map(|pinned_feed| pinned_feed.info)
+        .ok_or_else(|| match feed {
+            Some(uri) => eyre::eyre!("feed not found: {uri}"),
+            None => eyre::eyre!("no pinned feeds"),
+        })
+}
+
+/// A pinned saved feed of the feed generator or the list at `uri`, by the collection of the URI.
+fn saved_feed(uri: &str) -> Result<SavedFeed> {
+    let r#type = match uri
+        .strip_prefix("at://")
+        .and_then(|path| path.split('/').nth(1))
+    {
+        Some("app.bsky.feed.generator") => "feed",
+        Some("app.bsky.graph.list") => "list",
+        _ => {
+            return Err(eyre::eyre!(
+                "unsupported feed: {uri} (expected a feed generator or list URI, or `following`)"
+            ))
+        }
+    };
+    Ok(SavedFeedData {
+        id: uri.to_string(),
+        pinned: true,
+        r#type: r#type.to_string(),
+        value: uri.to_string(),
+    }
+    .into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn saved_feed_by_collection() -> Result<()> {
+        let feed = saved_feed("at://did:plc:abc/app.bsky.feed.generator/whats-hot")?;
+        assert_eq!(feed.r#type, "feed");
+        assert!(feed.pinned);
+        let list = saved_feed("at://did:plc:abc/app.bsky.graph.list/3k")?;
+        assert_eq!(list.r#type, "list");
+        assert_eq!(list.value, "at://did:plc:abc/app.bsky.graph.list/3k");
+        // not by a substring of the URI
+        assert!(saved_feed("at://did:plc:abc/app.bsky.feed.post/app.bsky.graph.list").is_err());
+        assert!(saved_feed("https://bsky.app/profile/alice.test").is_err());
+        Ok(())
+    }
+}
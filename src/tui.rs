@@ -1,6 +1,8 @@
 use crate::types::Event;
 use color_eyre::Result;
-use crossterm::event::{Event as CrosstermEvent, EventStream};
+use crossterm::event::{
+    DisableMouseCapture, EnableMouseCapture, Event as CrosstermEvent, EventStream,
+};
 use crossterm::terminal::{disable_raw_mode, enable_raw_mode};
 use crossterm::terminal::{EnterAlternateScreen, LeaveAlternateScreen};
 use crossterm::{cursor, execute};
@@ -26,23 +28,28 @@ where
     task: Option<JoinHandle<()>>,
     event_tx: UnboundedSender<Event>,
     event_rx: UnboundedReceiver<Event>,
+    mouse: bool,
 }
 
 impl<B> Tui<B>
 where
     B: Backend,
 {
-    pub fn new(terminal: Terminal<B>) -> Self {
+    pub fn new(terminal: Terminal<B>, mouse: bool) -> Self {
         let (event_tx, event_rx) = mpsc::unbounded_channel();
         Self {
             terminal,
             task: None,
             event_tx,
             event_rx,
+            mouse,
         }
     }
     pub fn start(&mut self) -> Result<()> {
         init()?;
+        if self.mouse {
+            execute!(io(), EnableMouseCapture)?;
+        }
         let event_tx = self.event_tx.clone();
         self.task = Some(tokio::spawn(async move {
             let mut reader = EventStream::new();
@@ -124,7 +131,12 @@ fn init() -> Result<()> {
 
 /// Restore the terminal to its original state
 pub(crate) fn restore() -> Result<()> {
-    execute!(io(), LeaveAlternateScreen, cursor::Show)?;
+    execute!(
+        io(),
+        DisableMouseCapture,
+        LeaveAlternateScreen,
+        cursor::Show
+    )?;
     disable_raw_mode()?;
     Ok(())
 }
@@ -1,5 +1,7 @@
+use crate::backend::client::BskyAgent;
 use crate::components::views::types::Action as ViewAction;
-use bsky_sdk::BskyAgent;
+use crate::config::ColumnSizing;
+use bsky_sdk::api::app::bsky::feed::defs::PostView;
 use crossterm::event::{KeyEvent, MouseEvent};
 use std::fmt::{Debug, Formatter, Result};
 
@@ -13,6 +15,11 @@ pub enum Action {
     Render,
     NextFocus,
     PrevFocus,
+    ColumnManager,
+    CommandMode,
+    ColumnSizing(ColumnSizing),
+    Noteboard,
+    AddNote(Box<PostView>),
     View((IdType, ViewAction)),
     Login((IdType, Box<BskyAgent>)),
 }
@@ -26,6 +33,11 @@ impl Debug for Action {
             Self::Render => write!(f, "Render"),
             Self::NextFocus => write!(f, "NextFocus"),
             Self::PrevFocus => write!(f, "PrevFocus"),
+            Self::ColumnManager => write!(f, "ColumnManager"),
+            Self::CommandMode => write!(f, "CommandMode"),
+            Self::ColumnSizing(arg) => f.debug_tuple("ColumnSizing").field(arg).finish(),
+            Self::Noteboard => write!(f, "Noteboard"),
+            Self::AddNote(arg) => f.debug_tuple("AddNote").field(&arg.uri).finish(),
             Self::View(arg) => f.debug_tuple("View").field(arg).finish(),
             Self::Login((arg, _)) => f.debug_tuple("Login").field(arg).finish(),
         }
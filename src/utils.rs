@@ -1,7 +1,15 @@
 use crate::tui;
+use chrono::Utc;
 use color_eyre::{config::HookBuilder, eyre, Result};
 use directories::ProjectDirs;
-use std::{panic, path::PathBuf, process};
+use log::{Level, LevelFilter, Log, Metadata, Record, SetLoggerError};
+use std::collections::VecDeque;
+use std::sync::Mutex;
+use std::{panic, path::PathBuf, process, process::Command, process::Stdio};
+
+const RECENT_LOGS_CAPACITY: usize = 50;
+
+static RECENT_LOGS: Mutex<VecDeque<String>> = Mutex::new(VecDeque::new());
 
 pub fn initialize_panic_handler() -> Result<()> {
     let (panic_hook, eyre_hook) = HookBuilder::default().into_hooks();
@@ -24,7 +32,136 @@ pub fn get_config_dir() -> Result<PathBuf> {
     Ok(project_dirs()?.config_dir().to_path_buf())
 }
 
+/// Open `url` with `command` (e.g. `"w3m %s"`, where `%s` is replaced with the URL),
+/// or with the system's default browser if no command is given.
+pub fn open_url(url: &str, command: Option<&str>) {
+    let result = match command {
+        Some(command) => browser_command(url, command).and_then(|mut cmd| {
+            cmd.stdin(Stdio::null())
+                .stdout(Stdio::null())
+                .stderr(Stdio::null())
+                .spawn()
+                .map(|_| ())
+                .map_err(eyre::Report::from)
+        }),
+        None => open::that_detached(url).map_err(eyre::Report::from),
+    };
+    if let Err(e) = result {
+        log::warn!("failed to open {url}: {e}");
+        log::info!("open the url manually: {url}");
+    }
+}
+
+fn browser_command(url: &str, command: &str) -> Result<Command> {
+    let mut args = command.split_whitespace().map(|arg| arg.replace("%s", url));
+    let mut cmd = Command::new(
+        args.next()
+            .ok_or_else(|| eyre::eyre!("empty browser command"))?,
+    );
+    cmd.args(args);
+    if !command.contains("%s") {
+        cmd.arg(url);
+    }
+    Ok(cmd)
+}
+
+/// A logger that keeps the most recent lines in memory, and passes the records on to `inner`.
+///
+/// The warnings and the errors are always kept, regardless of the filter of `inner`.
+#[derive(Debug)]
+pub struct LogBuffer {
+    inner: env_logger::Logger,
+}
+
+impl LogBuffer {
+    /// Set up the logger of the app, wrapping `inner`.
+    pub fn init(inner: env_logger::Logger) -> Result<(), SetLoggerError> {
+        log::set_max_level(inner.filter().max(LevelFilter::Warn));
+        log::set_boxed_logger(Box::new(Self { inner }))
+    }
+}
+
+impl Log for LogBuffer {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= Level::Warn || self.inner.enabled(metadata)
+    }
+    fn log(&self, record: &Record) {
+        if record.level() <= Level::Warn || self.inner.matches(record) {
+            if let Ok(mut logs) = RECENT_LOGS.lock() {
+                push_log(
+                    &mut logs,
+                    format!(
+                        "[{} {} {}] {}",
+                        Utc::now().format("%Y-%m-%dT%H:%M:%SZ"),
+                        record.level(),
+                        record.target(),
+                        record.args()
+                    ),
+                );
+            }
+        }
+        self.inner.log(record);
+    }
+    fn flush(&self) {
+        self.inner.flush();
+    }
+}
+
+/// Keep the line, dropping the oldest one if there are [`RECENT_LOGS_CAPACITY`] lines already.
+fn push_log(logs: &mut VecDeque<String>, line: String) {
+    if logs.len() == RECENT_LOGS_CAPACITY {
+        logs.pop_front();
+    }
+    logs.push_back(line);
+}
+
+/// Returns the most recent log lines kept by [`LogBuffer`].
+pub fn recent_logs() -> Vec<String> {
+    RECENT_LOGS
+        .lock()
+        .map(|logs| logs.iter().cloned().collect())
+        .unwrap_or_default()
+}
+
 fn project_dirs() -> Result<ProjectDirs> {
     ProjectDirs::from("com", "sugyan", "tuisky")
         .ok_or_else(|| eyre::eyre!("failed to get project directories"))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn recent_logs_capacity() {
+        let mut logs = VecDeque::new();
+        for i in 0..RECENT_LOGS_CAPACITY + 2 {
+            push_log(&mut logs, i.to_string());
+        }
+        assert_eq!(logs.len(), RECENT_LOGS_CAPACITY);
+        assert_eq!(logs.front().map(String::as_str), Some("2"));
+        assert_eq!(logs.back(), Some(&(RECENT_LOGS_CAPACITY + 1).to_string()));
+    }
+
+    #[test]
+    fn browser_command_args() -> Result<()> {
+        let args = |cmd: &Command| {
+            std::iter::once(cmd.get_program())
+                .chain(cmd.get_args())
+                .map(|arg| arg.to_string_lossy().into_owned())
+                .collect::<Vec<_>>()
+        };
+        let url = "https://example.com";
+        assert_eq!(
+            args(&browser_command(url, "w3m -o %s")?),
+            ["w3m", "-o", "https://example.com"]
+        );
+        // appended if not placed
+        assert_eq!(
+            args(&browser_command(url, "firefox --new-tab")?),
+            ["firefox", "--new-tab", "https://example.com"]
+        );
+        assert!(browser_command(url, " ").is_err());
+        Ok(())
+    }
+}